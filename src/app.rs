@@ -1,14 +1,29 @@
+use crate::aws_cli::identity::AccountIdentity;
+use crate::aws_cli::organizations::{DEFAULT_ORG_ROLE_NAME, OrgAccount, profile_sections};
+use crate::aws_cli::sso::SsoDeviceAuthorization;
 use crate::aws_cli::{
-    self, AsgDetail, AwsAuthError, AwsAuthErrorCode, AwsResource, Ec2Detail, EcrDetail,
+    self, AccountTarget, ApiGatewayDetail, AsgDetail, AwsAuthError, AwsAuthErrorCode,
+    AwsQueryError, AwsQueryErrorCode, AwsResource, CloudWatchAlarm, Ec2Detail, EcrDetail,
+    EfsDetail, EventRuleDetail, KmsKeyDetail, LambdaFunctionDetail, NetworkPart, PartialFailure,
+    Partition, SdkProvider, SecretDetail, SsmParameterDetail, StateMachineDetail, VirtualProfile,
 };
 use crate::blueprint::{
     Blueprint, BlueprintResource, BlueprintStore, ResourceType, load_blueprints, save_blueprints,
 };
+use crate::cache::ResponseCache;
 use crate::i18n::{I18n, Language};
 use crate::settings::{AppSettings, load_settings, save_settings};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, mpsc};
 use std::time::{Duration, Instant};
 
 const LOGIN_SESSION_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+/// 자격 증명 만료가 이만큼(분) 남으면 헤더와 블루프린트 진행 화면에 경고
+pub const CREDENTIALS_WARNING_MINUTES: i64 = 10;
+/// Organizations 화면에서 내보내는 `~/.aws/config` 프로필 섹션 파일
+const ORG_PROFILES_FILENAME: &str = "aws-org-profiles.config";
 
 fn is_login_required_error(error: &AwsAuthError) -> bool {
     matches!(
@@ -16,6 +31,7 @@ fn is_login_required_error(error: &AwsAuthError) -> bool {
         AwsAuthErrorCode::CredentialsProviderMissing
             | AwsAuthErrorCode::CredentialsLoadFailed
             | AwsAuthErrorCode::CallerIdentityFailed
+            | AwsAuthErrorCode::MfaRequired
     )
 }
 
@@ -28,17 +44,63 @@ fn auth_error_message(error: &AwsAuthError, i18n: &I18n) -> String {
         AwsAuthErrorCode::CredentialsProviderMissing => i18n.auth_provider_missing().to_string(),
         AwsAuthErrorCode::CredentialsLoadFailed => i18n.auth_credentials_load_failed().to_string(),
         AwsAuthErrorCode::CallerIdentityFailed => i18n.auth_caller_identity_failed().to_string(),
+        AwsAuthErrorCode::MfaRequired => i18n.auth_mfa_required().to_string(),
         AwsAuthErrorCode::Network => i18n.auth_network_error().to_string(),
         AwsAuthErrorCode::Unknown => i18n.auth_unknown_error().to_string(),
     }
 }
 
+/// 로그인한 계정의 별칭을 채움. 이전과 같은 계정이면 다시 조회하지 않고 이전 별칭을 씀
+pub fn fill_account_alias(
+    login: &mut aws_cli::AwsLogin,
+    previous: Option<&AccountIdentity>,
+    provider: &dyn aws_cli::AwsProvider,
+) {
+    login.identity.alias = match previous {
+        Some(previous) if previous.account_id == login.identity.account_id => {
+            previous.alias.clone()
+        }
+        _ => {
+            // 별칭 조회 권한이 없으면 계정 ID만 표시
+            let (aliases, _) = aws_cli::capture_query_errors(|| provider.list_account_aliases());
+            aliases.into_iter().next()
+        }
+    };
+}
+
+pub fn query_error_reason(code: AwsQueryErrorCode, i18n: &I18n) -> &'static str {
+    match code {
+        AwsQueryErrorCode::AccessDenied => i18n.query_error_access_denied(),
+        AwsQueryErrorCode::NotFound => i18n.query_error_not_found(),
+        AwsQueryErrorCode::Throttled => i18n.query_error_throttled(),
+        AwsQueryErrorCode::Network => i18n.query_error_network(),
+        AwsQueryErrorCode::Timeout => i18n.query_error_timeout(),
+        AwsQueryErrorCode::Credentials => i18n.query_error_credentials(),
+        AwsQueryErrorCode::Parse => i18n.query_error_parse(),
+        AwsQueryErrorCode::Unknown => i18n.query_error_unknown(),
+    }
+}
+
+/// 첫 번째 실패의 원인과 API, 나머지 실패 건수를 담은 상태 메시지
+pub fn query_error_message(errors: &[AwsQueryError], i18n: &I18n) -> Option<String> {
+    let first = errors.first()?;
+    Some(i18n.query_error(
+        query_error_reason(first.code, i18n),
+        &first.operation,
+        errors.len() - 1,
+    ))
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Screen {
     Login,
+    MfaInput,
     BlueprintSelect,
     BlueprintDetail,
     BlueprintNameInput,
+    AccountInput,
+    OrganizationSelect,
+    OrgRoleInput,
     BlueprintPreview,
     RegionSelect,
     ServiceSelect,
@@ -48,6 +110,15 @@ pub enum Screen {
     LoadBalancerSelect,
     EcrSelect,
     AsgSelect,
+    ApiGatewaySelect,
+    KmsKeySelect,
+    SecretSelect,
+    SsmParameterSelect,
+    EfsSelect,
+    AlarmSelect,
+    EventRuleSelect,
+    StateMachineSelect,
+    LambdaSelect,
     Preview,
     Settings,
 }
@@ -55,6 +126,8 @@ pub enum Screen {
 #[derive(Debug, Clone, PartialEq)]
 pub enum LoadingTask {
     None,
+    LoadRegions,
+    LoadOrganization,
     RefreshEc2,
     RefreshVpc,
     RefreshPreview,
@@ -62,20 +135,107 @@ pub enum LoadingTask {
     RefreshLoadBalancer,
     RefreshEcr,
     RefreshAsg,
+    RefreshApiGateway,
+    RefreshKmsKey,
+    RefreshSecret,
+    RefreshSsmParameter,
+    RefreshEfs,
+    RefreshAlarm,
+    RefreshEventRule,
+    RefreshStateMachine,
+    RefreshLambda,
     LoadEc2,
     LoadVpc,
     LoadSecurityGroup,
     LoadLoadBalancer,
     LoadEcr,
     LoadAsg,
+    LoadApiGateway,
+    LoadKmsKey,
+    LoadSecret,
+    LoadSsmParameter,
+    LoadEfs,
+    LoadAlarm,
+    LoadEventRule,
+    LoadStateMachine,
+    LoadLambda,
     LoadEc2Detail(String),
     LoadVpcDetail(String, u8), // (vpc_id, step: 0-6)
     LoadSecurityGroupDetail(String),
     LoadLoadBalancerDetail(String),
     LoadEcrDetail(String),
     LoadAsgDetail(String),
+    LoadApiGatewayDetail(String),
+    LoadKmsKeyDetail(String),
+    LoadSecretDetail(String),
+    LoadSsmParameterDetail(String),
+    LoadEfsDetail(String),
+    LoadAlarmDetail(String),
+    LoadEventRuleDetail(String),
+    LoadStateMachineDetail(String),
+    LoadLambdaDetail(String),
+
+    LoadBlueprintResources(usize), // (loaded_resource_count)
+    SsoLogin(String),              // (profile)
+    MfaLogin,
+    ValidateLogin(Option<Box<LoadingTask>>), // (로그인이 유효하면 이어서 시작할 작업)
+    CheckLogin,
+    CheckSession,
+    LoadProfileIdentities,
+}
+
+impl LoadingTask {
+    /// 캐시 없이 AWS에서만 가져올 수 있는 상세 조회인지 (오프라인 모드에서 차단)
+    pub fn requires_aws(&self) -> bool {
+        matches!(
+            self,
+            LoadingTask::RefreshPreview
+                | LoadingTask::LoadOrganization
+                | LoadingTask::LoadProfileIdentities
+                | LoadingTask::ValidateLogin(_)
+                | LoadingTask::CheckLogin
+                | LoadingTask::CheckSession
+                | LoadingTask::LoadEc2Detail(_)
+                | LoadingTask::LoadVpcDetail(_, _)
+                | LoadingTask::LoadSecurityGroupDetail(_)
+                | LoadingTask::LoadLoadBalancerDetail(_)
+                | LoadingTask::LoadEcrDetail(_)
+                | LoadingTask::LoadAsgDetail(_)
+                | LoadingTask::LoadApiGatewayDetail(_)
+                | LoadingTask::LoadKmsKeyDetail(_)
+                | LoadingTask::LoadSecretDetail(_)
+                | LoadingTask::LoadSsmParameterDetail(_)
+                | LoadingTask::LoadEfsDetail(_)
+                | LoadingTask::LoadAlarmDetail(_)
+                | LoadingTask::LoadEventRuleDetail(_)
+                | LoadingTask::LoadStateMachineDetail(_)
+                | LoadingTask::LoadLambdaDetail(_)
+        )
+    }
+
+    /// 로딩 화면을 띄우지 않고 뒤에서 도는 작업 (키 입력이 오면 버림)
+    pub fn is_quiet(&self) -> bool {
+        matches!(self, LoadingTask::CheckSession)
+    }
+}
+
+/// 백그라운드 조회 결과를 App에 반영하는 함수 (UI 스레드에서 실행)
+pub type LoadApply = Box<dyn FnOnce(&mut App) + Send>;
+
+/// 백그라운드 작업이 UI 스레드로 보내는 메시지
+pub enum LoadMessage {
+    /// 작업 도중 진행 상황 (VPC 하위 조회, 블루프린트 리소스별 완료 등)
+    Progress(LoadApply),
+    /// 작업 완료 결과
+    Done(LoadApply),
+}
 
-    LoadBlueprintResources(usize), // (current_resource_index)
+/// 블루프린트 리소스별 조회 상태 (완료 시 마크다운 보관)
+#[derive(Debug, Clone, PartialEq)]
+pub enum BlueprintPart {
+    Pending,
+    Loading,
+    Done(String),
 }
 
 #[derive(Debug, Clone, Default)]
@@ -93,6 +253,17 @@ impl LoadingProgress {
     pub fn reset(&mut self) {
         *self = Self::default();
     }
+
+    pub fn mark(&mut self, part: NetworkPart) {
+        match part {
+            NetworkPart::Subnets => self.subnets = true,
+            NetworkPart::InternetGateways => self.igws = true,
+            NetworkPart::NatGateways => self.nats = true,
+            NetworkPart::RouteTables => self.route_tables = true,
+            NetworkPart::Eips => self.eips = true,
+            NetworkPart::DnsAttributes => self.dns_attrs = true,
+        }
+    }
 }
 
 pub struct Region {
@@ -110,6 +281,8 @@ impl Region {
     }
 }
 
+/// 내장 리전 표. `DescribeRegions`를 쓸 수 없을 때(권한 없음, 오프라인)의 목록이자
+/// 표시 이름 출처
 pub const REGIONS: &[Region] = &[
     Region {
         code: "ap-northeast-2",
@@ -171,8 +344,183 @@ pub const REGIONS: &[Region] = &[
         name_ko: "프랑크푸르트",
         name_en: "Frankfurt",
     },
+    Region {
+        code: "af-south-1",
+        name_ko: "케이프타운",
+        name_en: "Cape Town",
+    },
+    Region {
+        code: "ap-east-1",
+        name_ko: "홍콩",
+        name_en: "Hong Kong",
+    },
+    Region {
+        code: "ap-east-2",
+        name_ko: "타이베이",
+        name_en: "Taipei",
+    },
+    Region {
+        code: "ap-south-2",
+        name_ko: "하이데라바드",
+        name_en: "Hyderabad",
+    },
+    Region {
+        code: "ap-southeast-3",
+        name_ko: "자카르타",
+        name_en: "Jakarta",
+    },
+    Region {
+        code: "ap-southeast-4",
+        name_ko: "멜버른",
+        name_en: "Melbourne",
+    },
+    Region {
+        code: "ap-southeast-5",
+        name_ko: "말레이시아",
+        name_en: "Malaysia",
+    },
+    Region {
+        code: "ap-southeast-6",
+        name_ko: "뉴질랜드",
+        name_en: "New Zealand",
+    },
+    Region {
+        code: "ap-southeast-7",
+        name_ko: "태국",
+        name_en: "Thailand",
+    },
+    Region {
+        code: "ca-central-1",
+        name_ko: "캐나다 중부",
+        name_en: "Canada Central",
+    },
+    Region {
+        code: "ca-west-1",
+        name_ko: "캘거리",
+        name_en: "Calgary",
+    },
+    Region {
+        code: "eu-central-2",
+        name_ko: "취리히",
+        name_en: "Zurich",
+    },
+    Region {
+        code: "eu-north-1",
+        name_ko: "스톡홀름",
+        name_en: "Stockholm",
+    },
+    Region {
+        code: "eu-south-1",
+        name_ko: "밀라노",
+        name_en: "Milan",
+    },
+    Region {
+        code: "eu-south-2",
+        name_ko: "스페인",
+        name_en: "Spain",
+    },
+    Region {
+        code: "eu-west-2",
+        name_ko: "런던",
+        name_en: "London",
+    },
+    Region {
+        code: "eu-west-3",
+        name_ko: "파리",
+        name_en: "Paris",
+    },
+    Region {
+        code: "il-central-1",
+        name_ko: "텔아비브",
+        name_en: "Tel Aviv",
+    },
+    Region {
+        code: "me-central-1",
+        name_ko: "아랍에미리트",
+        name_en: "UAE",
+    },
+    Region {
+        code: "me-south-1",
+        name_ko: "바레인",
+        name_en: "Bahrain",
+    },
+    Region {
+        code: "mx-central-1",
+        name_ko: "멕시코",
+        name_en: "Mexico",
+    },
+    Region {
+        code: "sa-east-1",
+        name_ko: "상파울루",
+        name_en: "São Paulo",
+    },
+    Region {
+        code: "us-gov-west-1",
+        name_ko: "GovCloud 미국 서부",
+        name_en: "GovCloud (US-West)",
+    },
+    Region {
+        code: "us-gov-east-1",
+        name_ko: "GovCloud 미국 동부",
+        name_en: "GovCloud (US-East)",
+    },
+    Region {
+        code: "cn-north-1",
+        name_ko: "베이징",
+        name_en: "Beijing",
+    },
+    Region {
+        code: "cn-northwest-1",
+        name_ko: "닝샤",
+        name_en: "Ningxia",
+    },
 ];
 
+/// 내장 표에서 파티션에 속한 리전 코드
+pub fn builtin_regions(partition: Partition) -> Vec<String> {
+    REGIONS
+        .iter()
+        .filter(|region| Partition::from_region(region.code) == partition)
+        .map(|region| region.code.to_string())
+        .collect()
+}
+
+/// 현재 리전의 코드와 표시 이름
+pub struct RegionLabel {
+    pub code: String,
+    pub name: String,
+}
+
+/// 리전 표시 이름 (내장 표에 없는 리전은 코드 그대로)
+pub fn region_name(code: &str, lang: Language) -> &str {
+    REGIONS
+        .iter()
+        .find(|region| region.code == code)
+        .map_or(code, |region| region.name(lang))
+}
+
+/// 즐겨찾기 리전을 즐겨찾기 순서대로 앞에 두고, 나머지는 내장 표 순서 → 코드 순으로 정렬
+pub fn ordered_regions(available: &[String], favorites: &[String]) -> Vec<String> {
+    let table_index = |code: &str| {
+        REGIONS
+            .iter()
+            .position(|region| region.code == code)
+            .unwrap_or(REGIONS.len())
+    };
+    let mut rest: Vec<String> = available
+        .iter()
+        .filter(|code| !favorites.contains(code))
+        .cloned()
+        .collect();
+    rest.sort_by(|a, b| table_index(a).cmp(&table_index(b)).then_with(|| a.cmp(b)));
+    favorites
+        .iter()
+        .filter(|code| available.contains(code))
+        .cloned()
+        .chain(rest)
+        .collect()
+}
+
 // Service names (excluding exit which is handled separately)
 pub const SERVICE_KEYS: &[&str] = &[
     "EC2",
@@ -181,20 +529,78 @@ pub const SERVICE_KEYS: &[&str] = &[
     "Load Balancer",
     "ECR",
     "ASG",
+    "API Gateway",
+    "KMS",
+    "Secrets Manager",
+    "SSM Parameter Store",
+    "EFS",
+    "CloudWatch Alarms",
+    "EventBridge",
+    "Step Functions",
+    "Lambda",
 ];
 
+// 설정 화면에서 순환하는 값들
+pub const MAX_CONCURRENCY_CHOICES: &[usize] = &[1, 2, 4, 8, 16];
+/// 캐시 유효 기간(초): 끔, 5분, 1시간, 1일
+pub const CACHE_TTL_CHOICES: &[u64] = &[0, 5 * 60, 60 * 60, 24 * 60 * 60];
+pub const MAX_ATTEMPTS_CHOICES: &[u32] = &[1, 2, 3, 5, 10];
+/// 요청 제한 시간(초)
+pub const REQUEST_TIMEOUT_CHOICES: &[u64] = &[5, 10, 30, 60, 120];
+/// 초당 요청 수 (0: 제한 없음)
+pub const RATE_LIMIT_CHOICES: &[u32] = &[0, 5, 10, 20, 50];
+
+/// `current`보다 큰 첫 선택지, 마지막 다음은 처음으로 돌아감
+pub fn next_choice<T: Copy + PartialOrd>(choices: &[T], current: T) -> T {
+    choices
+        .iter()
+        .copied()
+        .find(|&n| n > current)
+        .unwrap_or(choices[0])
+}
+
 pub struct App {
     pub screen: Screen,
     pub running: bool,
     pub loading: bool,
     pub loading_task: LoadingTask,
     pub loading_progress: LoadingProgress,
+    /// 스피너 애니메이션 기준 시각
+    pub loading_started: Option<Instant>,
+    /// 진행 중인 백그라운드 작업의 결과 채널 (드롭하면 취소)
+    pub loading_receiver: Option<mpsc::Receiver<LoadMessage>>,
+    /// 진행 중인 백그라운드 작업의 취소 표시 (켜면 남은 AWS 호출과 캐시 저장을 건너뜀)
+    pub loading_cancelled: Option<Arc<AtomicBool>>,
     pub last_login_check: Option<Instant>,
-    pub login_info: Option<String>,
+    /// 로그인한 호출자 (헤더와 블루프린트 문서 머리에 표시)
+    pub login_info: Option<AccountIdentity>,
+    /// 현재 자격 증명 만료 시각 (SSO/역할 세션, 장기 키면 `None`)
+    pub credentials_expire_at: Option<DateTime<Utc>>,
+    /// 진행 중인 SSO 기기 인증
+    pub sso_login: Option<SsoDeviceAuthorization>,
     pub login_error: Option<AwsAuthError>,
     pub available_profiles: Vec<String>,
     pub selected_profile_index: usize,
+    /// 프로필 전환 창의 선택 위치 (닫혀 있으면 `None`)
+    pub profile_switcher: Option<usize>,
+    /// 프로필 전환 창에 보일 프로필별 계정 (`None`이면 확인하지 못함)
+    pub profile_identities: HashMap<String, Option<AccountIdentity>>,
+    /// 로그인한 자격 증명의 파티션 (선택할 수 있는 리전의 범위)
+    pub partition: Partition,
+    /// 리전 선택 화면 목록 (즐겨찾기 먼저)
+    pub regions: Vec<String>,
+    /// 계정의 리전 목록을 조회했는지 (실패해도 세션 동안 다시 묻지 않음)
+    pub regions_loaded: bool,
     pub selected_region: usize,
+    /// 리전 선택의 "모든 리전": 목록을 여러 리전에서 한꺼번에 조회
+    pub all_regions: bool,
+    /// 모든 리전 목록에서 고른 리소스의 리전 (상세 조회/블루프린트 추가에 사용)
+    pub resource_region: Option<String>,
+    /// 목록 조회와 블루프린트 추가에 쓰는 계정 (없으면 선택한 프로필)
+    pub account: Option<AccountTarget>,
+    /// Organizations 화면의 멤버 계정
+    pub org_accounts: Vec<OrgAccount>,
+    pub selected_org_account: usize,
     pub selected_service: usize,
     pub selected_index: usize,
     pub message: String,
@@ -211,6 +617,15 @@ pub struct App {
     pub load_balancers: Vec<AwsResource>,
     pub ecr_repositories: Vec<AwsResource>,
     pub auto_scaling_groups: Vec<AwsResource>,
+    pub api_gateways: Vec<AwsResource>,
+    pub kms_keys: Vec<AwsResource>,
+    pub secrets: Vec<AwsResource>,
+    pub ssm_parameters: Vec<AwsResource>,
+    pub efs_file_systems: Vec<AwsResource>,
+    pub alarms: Vec<AwsResource>,
+    pub event_rules: Vec<AwsResource>,
+    pub state_machines: Vec<AwsResource>,
+    pub lambda_functions: Vec<AwsResource>,
 
     // Selected EC2 Detail
     pub ec2_detail: Option<Ec2Detail>,
@@ -224,6 +639,24 @@ pub struct App {
     pub ecr_detail: Option<EcrDetail>,
     // Selected ASG Detail
     pub asg_detail: Option<AsgDetail>,
+    // Selected API Gateway Detail
+    pub api_gateway_detail: Option<ApiGatewayDetail>,
+    // Selected KMS Key Detail
+    pub kms_key_detail: Option<KmsKeyDetail>,
+    // Selected Secret Detail (metadata only)
+    pub secret_detail: Option<SecretDetail>,
+    // Selected SSM Parameter Detail (metadata only)
+    pub ssm_parameter_detail: Option<SsmParameterDetail>,
+    // Selected EFS Detail
+    pub efs_detail: Option<EfsDetail>,
+    // Selected CloudWatch Alarm Detail
+    pub alarm_detail: Option<CloudWatchAlarm>,
+    // Selected EventBridge Rule Detail
+    pub event_rule_detail: Option<EventRuleDetail>,
+    // Selected Step Functions State Machine Detail
+    pub state_machine_detail: Option<StateMachineDetail>,
+    // Selected Lambda Function Detail
+    pub lambda_detail: Option<LambdaFunctionDetail>,
 
     // Preview
     pub preview_content: String,
@@ -238,26 +671,69 @@ pub struct App {
     pub blueprint_mode: bool,
     pub blueprint_resource_index: usize,
     pub input_buffer: String,
-    pub blueprint_markdown_parts: Vec<String>,
+    pub blueprint_markdown_parts: Vec<BlueprintPart>,
+    /// 마지막 블루프린트 생성에서 캐시로 채운 리소스 수
+    pub blueprint_cache_hits: usize,
+
+    // Cache
+    pub response_cache: ResponseCache,
+    /// 캐시를 무시하고 다시 조회 (`r` 새로고침)
+    pub bypass_cache: bool,
+    /// 목록이 캐시에서 왔을 때 해당 화면과 저장 시각
+    pub list_cached_at: Option<(Screen, DateTime<Utc>)>,
+    /// `--offline`: AWS를 호출하지 않고 캐시만 사용
+    pub offline: bool,
+
+    // AWS API provider (tests inject FakeProvider)
+    pub provider: Arc<dyn aws_cli::AwsProvider>,
+    /// 현재/마지막 로딩 작업에서 기록된 조회 실패
+    pub query_errors: Vec<AwsQueryError>,
+    /// 현재/마지막 로딩 작업에서 페이지 조회가 중간에 실패한 호출
+    pub partial_failures: Vec<PartialFailure>,
 }
 
 impl App {
     pub fn new() -> Self {
+        Self::with_provider(Arc::new(SdkProvider))
+    }
+
+    pub fn with_provider(provider: Arc<dyn aws_cli::AwsProvider>) -> Self {
         let blueprint_store = load_blueprints();
         let settings = load_settings();
         let i18n = I18n::new(settings.language);
+        aws_cli::set_max_concurrency(settings.max_concurrency);
+        aws_cli::set_request_policy(settings.request_policy());
+        aws_cli::set_endpoint_overrides(settings.endpoint_overrides());
+        aws_cli::set_virtual_profiles(settings.virtual_profiles.clone());
+        let response_cache = ResponseCache::new(settings.cache_ttl_secs);
+        let regions = ordered_regions(&builtin_regions(Partition::Aws), &settings.favorite_regions);
         Self {
             screen: Screen::Login,
             running: true,
             loading: false,
             loading_task: LoadingTask::None,
             loading_progress: LoadingProgress::default(),
+            loading_started: None,
+            loading_receiver: None,
+            loading_cancelled: None,
             last_login_check: None,
             login_info: None,
+            credentials_expire_at: None,
+            sso_login: None,
             login_error: None,
             available_profiles: Vec::new(),
             selected_profile_index: 0,
+            profile_switcher: None,
+            profile_identities: HashMap::new(),
+            regions,
+            regions_loaded: false,
             selected_region: 0,
+            all_regions: false,
+            partition: Partition::Aws,
+            resource_region: None,
+            account: None,
+            org_accounts: Vec::new(),
+            selected_org_account: 0,
             selected_service: 0,
             selected_index: 0,
             message: String::new(),
@@ -273,12 +749,30 @@ impl App {
             load_balancers: Vec::new(),
             ecr_repositories: Vec::new(),
             auto_scaling_groups: Vec::new(),
+            api_gateways: Vec::new(),
+            kms_keys: Vec::new(),
+            secrets: Vec::new(),
+            ssm_parameters: Vec::new(),
+            efs_file_systems: Vec::new(),
+            alarms: Vec::new(),
+            event_rules: Vec::new(),
+            state_machines: Vec::new(),
+            lambda_functions: Vec::new(),
             ec2_detail: None,
             network_detail: None,
             sg_detail: None,
             lb_detail: None,
             ecr_detail: None,
             asg_detail: None,
+            api_gateway_detail: None,
+            kms_key_detail: None,
+            secret_detail: None,
+            ssm_parameter_detail: None,
+            efs_detail: None,
+            alarm_detail: None,
+            event_rule_detail: None,
+            state_machine_detail: None,
+            lambda_detail: None,
 
             preview_content: String::new(),
             preview_filename: String::new(),
@@ -292,6 +786,16 @@ impl App {
             blueprint_resource_index: 0,
             input_buffer: String::new(),
             blueprint_markdown_parts: Vec::new(),
+            blueprint_cache_hits: 0,
+
+            response_cache,
+            bypass_cache: false,
+            list_cached_at: None,
+            offline: false,
+
+            provider,
+            query_errors: Vec::new(),
+            partial_failures: Vec::new(),
         }
     }
 
@@ -364,10 +868,91 @@ impl App {
         }
     }
 
-    pub fn select_current_profile_and_login(&mut self) {
+    /// 어느 화면에서든 프로필 전환 창을 열 수 있는지 (로그인 화면 제외)
+    /// 로딩 화면을 띄울지 (뒤에서 도는 주기 확인은 화면을 가리지 않음)
+    pub fn shows_loading(&self) -> bool {
+        self.loading && !self.loading_task.is_quiet()
+    }
+
+    pub fn can_switch_profile(&self) -> bool {
+        !matches!(self.screen, Screen::Login | Screen::MfaInput)
+    }
+
+    /// 프로필 전환 창을 현재 프로필에 맞춰 엶
+    pub fn open_profile_switcher(&mut self) {
         if self.available_profiles.is_empty() {
             self.refresh_profiles();
-            return;
+        }
+        let current = aws_cli::current_profile();
+        let index = self
+            .available_profiles
+            .iter()
+            .position(|profile| *profile == current)
+            .unwrap_or_default();
+        self.profile_switcher = Some(index);
+    }
+
+    /// 화면을 유지한 채 프로필을 바꿈. 로그인 확인은 호출한 쪽에서 `LoadingTask::ValidateLogin`으로 실행
+    pub fn switch_profile(&mut self, profile: &str) {
+        self.profile_switcher = None;
+        aws_cli::set_aws_profile(profile);
+        self.settings.aws_profile = Some(profile.to_string());
+        if let Some(index) = self.available_profiles.iter().position(|p| p == profile) {
+            self.selected_profile_index = index;
+        }
+        self.remember_blueprint_profile();
+        if let Err(error) = save_settings(&self.settings) {
+            tracing::warn!(error = %error, profile = %profile, "Failed to persist selected profile");
+        }
+
+        self.login_info = None;
+        self.credentials_expire_at = None;
+        self.login_error = None;
+        self.message = self.i18n.profile_switched(profile);
+        tracing::info!(profile = %profile, "Profile switched");
+        // 주기 확인이 UI 스레드에서 바로 돌지 않도록 확인 시각을 갱신
+        self.last_login_check = Some(Instant::now());
+    }
+
+    /// 현재 블루프린트에 지금 쓰는 프로필을 기억 (파일 저장은 호출한 쪽에서)
+    fn remember_blueprint_profile(&mut self) {
+        if let Some(blueprint) = &self.current_blueprint {
+            self.settings
+                .blueprint_profiles
+                .insert(blueprint.id.clone(), aws_cli::current_profile());
+        }
+    }
+
+    /// 블루프린트를 조회할 때 마지막으로 쓴 프로필로 전환하고, 처음이면 지금 프로필을 기억.
+    /// 프로필을 바꿨으면 `true` (로그인 확인이 필요)
+    pub fn use_blueprint_profile(&mut self) -> bool {
+        let Some(blueprint) = &self.current_blueprint else {
+            return false;
+        };
+        let remembered = self.settings.blueprint_profiles.get(&blueprint.id).cloned();
+        match remembered {
+            Some(profile)
+                if profile != aws_cli::current_profile()
+                    && self.available_profiles.contains(&profile) =>
+            {
+                self.switch_profile(&profile);
+                true
+            }
+            Some(_) => false,
+            // 다른 블루프린트에서 프로필을 바꿀 때 함께 저장됨
+            None => {
+                self.remember_blueprint_profile();
+                false
+            }
+        }
+    }
+
+    /// 로그인 화면에서 고른 프로필로 전환. 로그인 확인이 필요하면 `true`
+    /// (확인은 호출한 쪽에서 `LoadingTask::CheckLogin`으로 실행)
+    pub fn select_current_profile(&mut self) -> bool {
+        if self.available_profiles.is_empty() {
+            self.refresh_profiles();
+            return false;
         }
 
         if self.selected_profile_index >= self.available_profiles.len() {
@@ -379,7 +964,7 @@ impl App {
             .get(self.selected_profile_index)
             .cloned()
         else {
-            return;
+            return false;
         };
 
         aws_cli::set_aws_profile(&profile);
@@ -389,15 +974,16 @@ impl App {
         }
 
         self.login_error = None;
-        self.check_login();
+        true
     }
 
-    pub fn check_login(&mut self) {
+    /// 로그인 화면의 확인 결과 반영. 성공하거나 인증 외의 이유로 실패하면 블루프린트 선택으로
+    pub fn apply_login_result(&mut self, result: Result<aws_cli::AwsLogin, AwsAuthError>) {
         self.message.clear();
         self.last_login_check = Some(Instant::now());
-        match aws_cli::check_aws_login() {
-            Ok(info) => {
-                self.login_info = Some(info);
+        match result {
+            Ok(login) => {
+                self.apply_login(login);
                 self.login_error = None;
                 self.screen = Screen::BlueprintSelect;
                 tracing::info!("Login check passed; screen moved to BlueprintSelect");
@@ -406,12 +992,12 @@ impl App {
                 tracing::warn!(code = ?e.code, detail = %e.detail, "Login check failed");
                 if is_login_required_error(&e) {
                     self.login_info = None;
-                    self.screen = Screen::Login;
+                    self.screen = self.login_screen_for(&e);
                     self.login_error = Some(AwsAuthError {
                         code: e.code,
                         detail: e.detail.clone(),
                     });
-                    tracing::warn!("Login required; screen moved to Login");
+                    tracing::warn!(screen = ?self.screen, "Login required");
                 } else {
                     self.login_info = None;
                     self.login_error = Some(AwsAuthError {
@@ -426,24 +1012,48 @@ impl App {
         }
     }
 
-    pub fn validate_login_for_session(&mut self) {
-        if self.screen == Screen::Login {
-            return;
+    /// 로그인 확인 결과 반영 (계정 별칭은 `fill_account_alias`로 미리 채움)
+    fn apply_login(&mut self, login: aws_cli::AwsLogin) {
+        self.set_partition(Partition::from_arn(&login.identity.arn));
+        self.login_info = Some(login.identity);
+        self.credentials_expire_at = login.expires_at;
+    }
+
+    /// 헤드리스 생성용: 로그인 화면 없이 현재 자격 증명의 호출자를 조회
+    pub fn load_identity(&mut self) {
+        let provider = self.provider.clone();
+        let (identity, _) =
+            aws_cli::capture_query_errors(|| aws_cli::identity::caller_identity(provider.as_ref()));
+        self.login_info = identity;
+    }
+
+    /// 로그인이 필요할 때 보낼 화면: MFA 세션이 없으면 코드 입력, 아니면 프로필 선택
+    fn login_screen_for(&mut self, error: &AwsAuthError) -> Screen {
+        if error.code == AwsAuthErrorCode::MfaRequired {
+            self.input_buffer.clear();
+            Screen::MfaInput
+        } else {
+            Screen::Login
         }
+    }
 
-        let need_check = match self.last_login_check {
+    /// 세션 중 로그인을 다시 확인할 때가 됐는지 (로그인 화면과 오프라인 모드에서는 확인하지 않음)
+    pub fn session_check_due(&self) -> bool {
+        if matches!(self.screen, Screen::Login | Screen::MfaInput) || self.offline {
+            return false;
+        }
+        match self.last_login_check {
             Some(last_checked_at) => last_checked_at.elapsed() >= LOGIN_SESSION_CHECK_INTERVAL,
             None => true,
-        };
-
-        if !need_check {
-            return;
         }
+    }
 
+    /// 세션 중 로그인 확인 결과 반영. 인증이 필요하면 로그인 화면으로, 그 밖의 실패는 화면 유지
+    pub fn apply_session_login(&mut self, result: Result<aws_cli::AwsLogin, AwsAuthError>) {
         self.last_login_check = Some(Instant::now());
-        match aws_cli::check_aws_login() {
-            Ok(info) => {
-                self.login_info = Some(info);
+        match result {
+            Ok(login) => {
+                self.apply_login(login);
                 self.login_error = None;
                 if is_aws_credential_message(&self.message, &self.i18n) {
                     self.message.clear();
@@ -457,7 +1067,7 @@ impl App {
                         code: e.code,
                         detail: e.detail.clone(),
                     });
-                    self.screen = Screen::Login;
+                    self.screen = self.login_screen_for(&e);
                     self.refresh_profiles();
                     tracing::warn!(
                         "Session check failed with auth error; returning to profile select"
@@ -472,25 +1082,193 @@ impl App {
         }
     }
 
-    pub fn check_login_if_needed_for_current_screen(&mut self) {
-        if self.screen == Screen::Login {
-            return;
-        }
-        self.validate_login_for_session();
+    /// 자격 증명 만료까지 남은 시간 (만료 시각을 모르면 `None`)
+    pub fn credentials_remaining(&self) -> Option<chrono::Duration> {
+        self.credentials_expire_at
+            .map(|expires_at| expires_at - Utc::now())
+    }
+
+    pub fn credentials_expiring_soon(&self) -> bool {
+        self.credentials_remaining().is_some_and(|remaining| {
+            remaining < chrono::Duration::minutes(CREDENTIALS_WARNING_MINUTES)
+        })
     }
 
     pub fn select_region(&mut self) {
-        let region = REGIONS[self.selected_region].code;
-        aws_cli::set_region(region);
+        self.all_regions = false;
+        self.resource_region = None;
+        aws_cli::set_region(&self.get_current_region());
         self.screen = Screen::ServiceSelect;
     }
 
+    /// 목록을 모든 리전에서 조회하도록 전환
+    pub fn select_all_regions(&mut self) {
+        self.all_regions = true;
+        self.resource_region = None;
+        self.screen = Screen::ServiceSelect;
+    }
+
+    /// "모든 리전"에서 조회할 리전: 즐겨찾기가 있으면 즐겨찾기, 없으면 목록 전체
+    pub fn fan_out_regions(&self) -> Vec<String> {
+        let favorites: Vec<String> = self
+            .regions
+            .iter()
+            .filter(|code| self.is_favorite_region(code))
+            .cloned()
+            .collect();
+        if favorites.is_empty() {
+            self.regions.clone()
+        } else {
+            favorites
+        }
+    }
+
+    /// 모든 리전 목록에서 고른 리소스의 리전으로 전환 (이후 상세 조회가 그 리전에서 실행됨)
+    pub fn focus_resource(&mut self, resource: &AwsResource) {
+        if let Some(region) = &resource.region {
+            aws_cli::set_region(region);
+            self.resource_region = Some(region.clone());
+        }
+    }
+
+    /// 블루프린트에 추가할 리소스의 리전
+    pub fn resource_region(&self) -> String {
+        self.resource_region
+            .clone()
+            .unwrap_or_else(|| self.get_current_region())
+    }
+
+    /// 계정에서 조회한 리전으로 목록 교체 (비어 있으면 내장 표 유지), 선택은 같은 리전 유지
+    pub fn set_available_regions(&mut self, regions: Vec<String>) {
+        self.regions_loaded = true;
+        if !regions.is_empty() {
+            self.reorder_regions(regions);
+        }
+    }
+
+    /// SDK가 쓰는 리전(선택 → 환경 변수 → 프로필 설정)이 목록에 있으면 그 리전을 선택
+    pub fn preselect_region(&mut self) {
+        let (region, _) = aws_cli::resolve_region();
+        if let Some(index) = self.regions.iter().position(|code| code == &region) {
+            self.selected_region = index;
+        }
+    }
+
+    /// 다른 파티션(GovCloud, 중국)의 자격 증명으로 바뀌면 그 파티션의 리전 목록으로 교체하고
+    /// 다음 리전 선택 때 계정의 리전을 다시 조회
+    pub fn set_partition(&mut self, partition: Partition) {
+        if self.partition == partition {
+            return;
+        }
+        self.partition = partition;
+        self.regions_loaded = false;
+        self.all_regions = false;
+        self.resource_region = None;
+        self.reorder_regions(builtin_regions(partition));
+    }
+
+    /// 선택한 리전을 즐겨찾기에 추가/제거하고 저장
+    pub fn toggle_favorite_region(&mut self) {
+        let code = self.get_current_region();
+        let favorites = &mut self.settings.favorite_regions;
+        if let Some(index) = favorites.iter().position(|favorite| favorite == &code) {
+            favorites.remove(index);
+        } else {
+            favorites.push(code);
+        }
+        self.reorder_regions(self.regions.clone());
+        self.save_settings();
+    }
+
+    pub fn is_favorite_region(&self, code: &str) -> bool {
+        self.settings
+            .favorite_regions
+            .iter()
+            .any(|favorite| favorite == code)
+    }
+
+    fn reorder_regions(&mut self, regions: Vec<String>) {
+        let current = self.get_current_region();
+        self.regions = ordered_regions(&regions, &self.settings.favorite_regions);
+        self.selected_region = self
+            .regions
+            .iter()
+            .position(|code| code == &current)
+            .unwrap_or(0);
+    }
+
     pub fn save_file(&mut self) -> Result<(), std::io::Error> {
         crate::output::save_markdown(&self.preview_filename, &self.preview_content)?;
         self.message = format!("{}: {}", self.i18n.save_complete(), self.preview_filename);
         Ok(())
     }
 
+    /// Organizations 화면에서 선택한 멤버 계정의 역할 ARN
+    pub fn selected_org_role_arn(&self) -> Option<String> {
+        self.org_accounts
+            .get(self.selected_org_account)
+            .map(|account| account.role_arn(self.partition, &self.settings.org_role_name))
+    }
+
+    /// 선택한 멤버 계정을 현재 프로필에서 역할을 맡아 조회하도록 전환
+    pub fn browse_org_account(&mut self) {
+        let Some(role_arn) = self.selected_org_role_arn() else {
+            return;
+        };
+        let account = AccountTarget {
+            profile: None,
+            role_chain: vec![role_arn],
+        };
+        self.message = self.i18n.account_selected(&account.label());
+        self.account = Some(account);
+        self.regions_loaded = false;
+    }
+
+    pub fn set_org_role_name(&mut self, role_name: &str) {
+        let role_name = role_name.trim();
+        self.settings.org_role_name = if role_name.is_empty() {
+            DEFAULT_ORG_ROLE_NAME.to_string()
+        } else {
+            role_name.to_string()
+        };
+        self.save_settings();
+    }
+
+    /// 멤버 계정마다 가상 프로필을 저장해 프로필 목록에서 고를 수 있게 함 (같은 이름은 덮어씀)
+    pub fn save_org_profiles(&mut self) {
+        let source_profile = aws_cli::current_profile();
+        for account in &self.org_accounts {
+            let profile = VirtualProfile {
+                name: account.profile_name(),
+                role_arn: account.role_arn(self.partition, &self.settings.org_role_name),
+                source_profile: Some(source_profile.clone()),
+            };
+            let profiles = &mut self.settings.virtual_profiles;
+            match profiles.iter_mut().find(|p| p.name == profile.name) {
+                Some(existing) => *existing = profile,
+                None => profiles.push(profile),
+            }
+        }
+        aws_cli::set_virtual_profiles(self.settings.virtual_profiles.clone());
+        self.save_settings();
+        self.refresh_profiles();
+        self.message = self.i18n.virtual_profiles_saved(self.org_accounts.len());
+    }
+
+    /// 멤버 계정 프로필을 `~/.aws/config`에 붙여 넣을 수 있는 파일로 저장
+    pub fn export_org_profiles(&mut self) -> Result<(), std::io::Error> {
+        let content = profile_sections(
+            &self.org_accounts,
+            self.partition,
+            &self.settings.org_role_name,
+            &aws_cli::current_profile(),
+            &aws_cli::effective_region(),
+        );
+        crate::output::save_markdown(ORG_PROFILES_FILENAME, &content)?;
+        self.message = format!("{}: {}", self.i18n.save_complete(), ORG_PROFILES_FILENAME);
+        Ok(())
+    }
+
     // Settings methods
     pub fn toggle_language(&mut self) {
         self.settings.language = self.settings.language.toggle();
@@ -498,6 +1276,75 @@ impl App {
         self.save_settings();
     }
 
+    /// 동시 요청 수를 다음 단계로 바꾸고 저장
+    pub fn cycle_max_concurrency(&mut self) {
+        let next = next_choice(MAX_CONCURRENCY_CHOICES, self.settings.max_concurrency);
+        self.settings.max_concurrency = next;
+        aws_cli::set_max_concurrency(next);
+        self.save_settings();
+    }
+
+    /// 캐시 유효 기간을 다음 단계로 바꾸고 저장
+    pub fn cycle_cache_ttl(&mut self) {
+        self.settings.cache_ttl_secs = next_choice(CACHE_TTL_CHOICES, self.settings.cache_ttl_secs);
+        self.response_cache = ResponseCache::new(self.settings.cache_ttl_secs);
+        self.save_settings();
+    }
+
+    pub fn cycle_max_attempts(&mut self) {
+        self.settings.max_attempts = next_choice(MAX_ATTEMPTS_CHOICES, self.settings.max_attempts);
+        self.apply_request_policy();
+    }
+
+    pub fn cycle_request_timeout(&mut self) {
+        self.settings.timeout_secs =
+            next_choice(REQUEST_TIMEOUT_CHOICES, self.settings.timeout_secs);
+        self.apply_request_policy();
+    }
+
+    /// 블루프린트 문서의 계정 정보 표시 방식을 다음 단계로 바꾸고 저장
+    pub fn cycle_document_identity(&mut self) {
+        self.settings.document_identity = self.settings.document_identity.next();
+        self.save_settings();
+    }
+
+    pub fn cycle_rate_limit(&mut self) {
+        self.settings.requests_per_second =
+            next_choice(RATE_LIMIT_CHOICES, self.settings.requests_per_second);
+        self.apply_request_policy();
+    }
+
+    fn apply_request_policy(&mut self) {
+        aws_cli::set_request_policy(self.settings.request_policy());
+        self.save_settings();
+    }
+
+    /// `--endpoint-url`: 환경 변수와 설정 파일보다 우선하는 엔드포인트
+    pub fn set_endpoint_url_flag(&self, url: Option<String>) {
+        if url.is_some() {
+            aws_cli::set_endpoint_overrides(aws_cli::EndpointOverrides {
+                flag: url,
+                ..self.settings.endpoint_overrides()
+            });
+        }
+    }
+
+    /// 로그인 화면 없이 저장된 프로필로 시작 (`--offline`, `emd generate`)
+    pub fn start_headless(&mut self, offline: bool) {
+        self.offline = offline;
+        if let Some(profile) = self.settings.aws_profile.clone() {
+            aws_cli::set_aws_profile(&profile);
+        }
+    }
+
+    /// `--offline` 시작: 로그인 확인 없이 저장된 프로필의 캐시로 블루프린트를 탐색
+    pub fn start_offline(&mut self) {
+        self.start_headless(true);
+        self.screen = Screen::BlueprintSelect;
+        self.selected_tab = 0;
+        self.message = self.i18n.offline_mode().to_string();
+    }
+
     pub fn save_settings(&mut self) {
         if save_settings(&self.settings).is_ok() {
             self.message = self.i18n.settings_saved().to_string();
@@ -613,6 +1460,24 @@ impl App {
             Some(ResourceType::Ecr)
         } else if self.asg_detail.is_some() {
             Some(ResourceType::Asg)
+        } else if self.api_gateway_detail.is_some() {
+            Some(ResourceType::ApiGateway)
+        } else if self.kms_key_detail.is_some() {
+            Some(ResourceType::KmsKey)
+        } else if self.secret_detail.is_some() {
+            Some(ResourceType::Secret)
+        } else if self.ssm_parameter_detail.is_some() {
+            Some(ResourceType::SsmParameter)
+        } else if self.efs_detail.is_some() {
+            Some(ResourceType::Efs)
+        } else if self.alarm_detail.is_some() {
+            Some(ResourceType::CloudWatchAlarm)
+        } else if self.event_rule_detail.is_some() {
+            Some(ResourceType::EventRule)
+        } else if self.state_machine_detail.is_some() {
+            Some(ResourceType::StateMachine)
+        } else if self.lambda_detail.is_some() {
+            Some(ResourceType::Lambda)
         } else {
             None
         }
@@ -629,26 +1494,70 @@ impl App {
             Some((detail.arn.clone(), detail.name.clone()))
         } else if let Some(ref detail) = self.ecr_detail {
             Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.asg_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.api_gateway_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.kms_key_detail {
+            Some((detail.key_id.clone(), detail.display_name().to_string()))
+        } else if let Some(ref detail) = self.secret_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.ssm_parameter_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.efs_detail {
+            let name = if detail.name.is_empty() {
+                detail.id.clone()
+            } else {
+                detail.name.clone()
+            };
+            Some((detail.id.clone(), name))
+        } else if let Some(ref detail) = self.alarm_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.event_rule_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.state_machine_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
         } else {
-            self.asg_detail
+            self.lambda_detail
                 .as_ref()
-                .map(|detail| (detail.name.clone(), detail.name.clone()))
+                .map(|detail| (detail.arn.clone(), detail.name.clone()))
         }
     }
 
     pub fn get_current_region(&self) -> String {
-        REGIONS[self.selected_region].code.to_string()
+        self.regions
+            .get(self.selected_region)
+            .cloned()
+            .or_else(|| builtin_regions(self.partition).into_iter().next())
+            .unwrap_or_else(|| self.partition.default_region().to_string())
+    }
+
+    /// 화면 제목에 쓰는 현재 리전 코드와 이름
+    pub fn region_label(&self) -> RegionLabel {
+        if self.all_regions {
+            return RegionLabel {
+                code: self.i18n.all_regions().to_string(),
+                name: self.i18n.region_count(self.fan_out_regions().len()),
+            };
+        }
+        let code = self.get_current_region();
+        let name = region_name(&code, self.settings.language).to_string();
+        RegionLabel { code, name }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{App, LoadingProgress, REGIONS, Region};
+    use super::{
+        App, CACHE_TTL_CHOICES, LoadingProgress, MAX_CONCURRENCY_CHOICES, Region, builtin_regions,
+        next_choice, ordered_regions, region_name,
+    };
+    use crate::aws_cli::Partition;
     use crate::aws_cli::iam::{AttachedPolicy, IamRoleDetail, InlinePolicy};
     use crate::aws_cli::{
-        AsgDetail, AwsAuthError, AwsAuthErrorCode, Ec2Detail, EcrDetail, EipDetail,
-        LoadBalancerDetail, NatDetail, NetworkDetail, RouteTableDetail, ScalingPolicy,
-        SecurityGroupDetail, SecurityRule, TargetGroupInfo,
+        ApiGatewayDetail, AsgDetail, AwsAuthError, AwsAuthErrorCode, Ec2Detail, EcrDetail,
+        EipDetail, LoadBalancerDetail, NatDetail, NetworkDetail, RouteTableDetail, ScalingPolicy,
+        SecurityGroupDetail, SecurityRule, SsmParameterDetail, TargetGroupInfo,
     };
     use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
     use crate::i18n::{I18n, Language};
@@ -726,6 +1635,7 @@ mod tests {
             tags: vec![("Name".to_string(), "web-a".to_string())],
             volumes: vec![],
             user_data: None,
+            alarms: vec![],
         }
     }
 
@@ -809,6 +1719,7 @@ mod tests {
                 unhealthy_threshold: 3,
                 targets: vec![],
             }],
+            alarms: vec![],
         }
     }
 
@@ -849,6 +1760,7 @@ mod tests {
                 cooldown: Some(60),
             }],
             tags: vec![],
+            alarms: vec![],
         }
     }
 
@@ -1069,13 +1981,154 @@ mod tests {
         app.ecr_detail = None;
         app.asg_detail = Some(sample_asg_detail());
         assert_eq!(app.get_current_resource_type(), Some(ResourceType::Asg));
+
+        app.asg_detail = None;
+        app.api_gateway_detail = Some(ApiGatewayDetail {
+            name: "orders-api".to_string(),
+            id: "a1b2c3d4e5".to_string(),
+            protocol: "HTTP".to_string(),
+            endpoint_type: "REGIONAL".to_string(),
+            api_endpoint: String::new(),
+            description: String::new(),
+            created_date: String::new(),
+            stages: vec![],
+            routes: vec![],
+            authorizers: vec![],
+            domain_mappings: vec![],
+        });
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::ApiGateway)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("a1b2c3d4e5".to_string(), "orders-api".to_string()))
+        );
+
+        app.api_gateway_detail = None;
+        app.ssm_parameter_detail = Some(SsmParameterDetail {
+            name: "/app/prod/db-url".to_string(),
+            arn: String::new(),
+            parameter_type: "SecureString".to_string(),
+            tier: "Standard".to_string(),
+            data_type: "text".to_string(),
+            version: 1,
+            key_id: None,
+            description: String::new(),
+            allowed_pattern: None,
+            last_modified_date: String::new(),
+            last_modified_user: String::new(),
+            policies: vec![],
+            tags: vec![],
+        });
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::SsmParameter)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "/app/prod/db-url".to_string(),
+                "/app/prod/db-url".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn govcloud_login_switches_region_list_to_its_partition() {
+        let mut app = App::new();
+        assert!(!app.regions.iter().any(|code| code.starts_with("us-gov-")));
+        app.regions_loaded = true;
+
+        app.set_partition(Partition::from_arn(
+            "arn:aws-us-gov:iam::123456789012:user/ops",
+        ));
+        assert_eq!(app.partition, Partition::AwsUsGov);
+        assert!(!app.regions_loaded);
+        assert_eq!(app.regions, builtin_regions(Partition::AwsUsGov));
+        assert_eq!(app.get_current_region(), "us-gov-west-1");
+        assert_eq!(
+            region_name("us-gov-west-1", crate::i18n::Language::English),
+            "GovCloud (US-West)"
+        );
     }
 
     #[test]
     fn get_current_region_returns_selected_code() {
         let mut app = App::new();
-        app.selected_region = REGIONS.len().saturating_sub(1);
-        assert_eq!(app.get_current_region(), REGIONS[REGIONS.len() - 1].code);
+        assert_eq!(app.regions, builtin_regions(Partition::Aws));
+        app.selected_region = app.regions.len().saturating_sub(1);
+        assert_eq!(app.get_current_region(), app.regions[app.regions.len() - 1]);
+        assert_eq!(app.region_label().code, app.get_current_region());
+    }
+
+    #[test]
+    fn preselect_region_picks_the_region_the_sdk_uses() {
+        let mut app = App::new();
+        crate::aws_cli::with_region("eu-west-1", || app.preselect_region());
+        assert_eq!(app.get_current_region(), "eu-west-1");
+
+        let selected = app.selected_region;
+        crate::aws_cli::with_region("zz-test-1", || app.preselect_region());
+        assert_eq!(app.selected_region, selected);
+    }
+
+    #[test]
+    fn ordered_regions_put_favorites_first_then_table_order() {
+        let codes = |codes: &[&str]| codes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        let available = codes(&["us-east-1", "zz-test-1", "sa-east-1", "ap-northeast-2"]);
+        assert_eq!(
+            ordered_regions(&available, &codes(&["sa-east-1", "eu-west-9"])),
+            codes(&["sa-east-1", "ap-northeast-2", "us-east-1", "zz-test-1"])
+        );
+        assert_eq!(region_name("sa-east-1", Language::Korean), "상파울루");
+        assert_eq!(region_name("zz-test-1", Language::English), "zz-test-1");
+    }
+
+    #[test]
+    fn discovered_regions_and_favorites_keep_selected_region() {
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let original_home = env::var_os("HOME");
+        let home = temp_home("regions");
+        fs::create_dir_all(&home).expect("create temp home");
+        unsafe {
+            env::set_var("HOME", &home);
+        }
+
+        let mut app = App::new();
+        let current = app.get_current_region();
+        app.set_available_regions(Vec::new());
+        assert!(app.regions_loaded);
+        assert_eq!(app.regions.len(), builtin_regions(Partition::Aws).len());
+
+        app.set_available_regions(vec![
+            "us-east-1".to_string(),
+            current.clone(),
+            "me-central-1".to_string(),
+        ]);
+        assert_eq!(app.regions.len(), 3);
+        assert_eq!(app.get_current_region(), current);
+
+        app.selected_region = app
+            .regions
+            .iter()
+            .position(|code| code == "me-central-1")
+            .expect("me-central-1");
+        app.toggle_favorite_region();
+        assert_eq!(app.regions[0], "me-central-1");
+        assert_eq!(app.selected_region, 0);
+        assert!(app.is_favorite_region("me-central-1"));
+        assert_eq!(
+            crate::settings::load_settings().favorite_regions,
+            vec!["me-central-1"]
+        );
+
+        app.toggle_favorite_region();
+        assert!(!app.is_favorite_region("me-central-1"));
+        assert_eq!(app.get_current_region(), "me-central-1");
+
+        restore_var("HOME", original_home);
+        let _ = fs::remove_dir_all(home);
     }
 
     #[test]
@@ -1087,12 +2140,14 @@ mod tests {
             region: "ap-northeast-2".to_string(),
             resource_id: "i-1".to_string(),
             resource_name: "web-1".to_string(),
+            account: None,
         });
         blueprint.add_resource(BlueprintResource {
             resource_type: ResourceType::Ec2,
             region: "ap-northeast-2".to_string(),
             resource_id: "i-2".to_string(),
             resource_name: "web-2".to_string(),
+            account: None,
         });
 
         app.current_blueprint = Some(blueprint.clone());
@@ -1113,6 +2168,16 @@ mod tests {
         assert_eq!(app.i18n.lang, app.settings.language);
     }
 
+    #[test]
+    fn next_choice_steps_through_choices_and_wraps() {
+        assert_eq!(next_choice(MAX_CONCURRENCY_CHOICES, 4), 8);
+        assert_eq!(next_choice(MAX_CONCURRENCY_CHOICES, 16), 1);
+        assert_eq!(next_choice(MAX_CONCURRENCY_CHOICES, 3), 4);
+        assert_eq!(next_choice(CACHE_TTL_CHOICES, 0), 300);
+        assert_eq!(next_choice(CACHE_TTL_CHOICES, 3600), 86_400);
+        assert_eq!(next_choice(CACHE_TTL_CHOICES, 86_400), 0);
+    }
+
     #[test]
     fn add_and_remove_resource_on_current_blueprint() {
         let mut app = App::new();
@@ -1126,6 +2191,7 @@ mod tests {
             region: "ap-northeast-2".to_string(),
             resource_id: "repo-a".to_string(),
             resource_name: "repo-a".to_string(),
+            account: None,
         });
         assert_eq!(
             app.current_blueprint
@@ -1144,4 +2210,33 @@ mod tests {
             0
         );
     }
+
+    #[test]
+    fn fill_account_alias_looks_up_only_when_the_account_changes() {
+        use crate::aws_cli::AwsLogin;
+        use crate::aws_cli::fake_provider::FakeProvider;
+        use crate::aws_cli::identity::AccountIdentity;
+
+        let provider = FakeProvider {
+            account_aliases: vec!["acme-prod".to_string()],
+            ..FakeProvider::sample()
+        };
+        let identity = |account_id: &str, alias: Option<&str>| AccountIdentity {
+            account_id: account_id.to_string(),
+            alias: alias.map(str::to_string),
+            arn: format!("arn:aws:iam::{}:user/alice", account_id),
+        };
+        let mut login = AwsLogin {
+            identity: identity("111122223333", None),
+            expires_at: None,
+        };
+
+        let previous = identity("111122223333", Some("cached"));
+        super::fill_account_alias(&mut login, Some(&previous), &provider);
+        assert_eq!(login.identity.alias.as_deref(), Some("cached"));
+
+        let other = identity("444455556666", Some("other"));
+        super::fill_account_alias(&mut login, Some(&other), &provider);
+        assert_eq!(login.identity.alias.as_deref(), Some("acme-prod"));
+    }
 }
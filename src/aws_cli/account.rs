@@ -0,0 +1,250 @@
+//! 여러 계정 문서화: 리소스별 프로필과 AssumeRole 체인
+
+use crate::aws_cli::partition::Arn;
+use aws_config::SdkConfig;
+use aws_config::sts::AssumeRoleProvider;
+use aws_credential_types::provider::SharedCredentialsProvider;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+/// AssumeRole 세션 이름 (CloudTrail에서 emd 호출을 구분)
+const SESSION_NAME: &str = "emd";
+
+/// 설정에 저장된 가상 프로필 (`~/.aws/config`를 고치지 않고 Organizations 계정을 조회)
+static VIRTUAL_PROFILES: Mutex<Vec<VirtualProfile>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// 현재 스레드의 AWS 호출에 쓸 계정 (`with_account`)
+    static ACCOUNT_OVERRIDE: RefCell<Option<AccountTarget>> = const { RefCell::new(None) };
+}
+
+/// 리소스를 조회할 계정: 시작 프로필(없으면 선택한 프로필)과 차례로 맡을 역할 ARN
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AccountTarget {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub role_chain: Vec<String>,
+}
+
+impl AccountTarget {
+    /// `prod`, `arn:aws:iam::111122223333:role/ReadOnly`, `hub > arn:...:role/a > arn:...:role/b`
+    /// 형식의 입력. 첫 항목이 ARN이 아니면 프로필, 나머지는 역할 ARN
+    pub fn parse(input: &str) -> Result<Option<Self>, String> {
+        let mut target = Self::default();
+        for (index, part) in input
+            .split('>')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .enumerate()
+        {
+            if Arn::parse(part).is_some_and(|arn| arn.service == "iam") {
+                target.role_chain.push(part.to_string());
+            } else if index == 0 && !part.starts_with("arn:") {
+                target.profile = Some(part.to_string());
+            } else {
+                return Err(part.to_string());
+            }
+        }
+        Ok((target != Self::default()).then_some(target))
+    }
+
+    /// 마지막으로 맡는 역할의 계정 ID
+    pub fn account_id(&self) -> Option<&str> {
+        self.role_chain
+            .last()
+            .and_then(|role| Arn::parse(role))
+            .map(|arn| arn.account_id)
+            .filter(|id| !id.is_empty())
+    }
+
+    /// 문서의 계정 머리글과 화면에 쓰는 이름 (`111122223333 (ReadOnly)` 또는 프로필 이름)
+    pub fn label(&self) -> String {
+        let role = self
+            .role_chain
+            .last()
+            .and_then(|role| Arn::parse(role))
+            .map(|arn| arn.resource_id());
+        match (self.account_id(), role, &self.profile) {
+            (Some(account), Some(role), _) => format!("{} ({})", account, role),
+            (_, _, Some(profile)) => profile.clone(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for AccountTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<&str> = self
+            .profile
+            .iter()
+            .chain(self.role_chain.iter())
+            .map(String::as_str)
+            .collect();
+        write!(f, "{}", parts.join(" > "))
+    }
+}
+
+/// 시작 프로필의 자격 증명으로 `role_arn`을 맡는 프로필
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VirtualProfile {
+    pub name: String,
+    pub role_arn: String,
+    /// 없으면 `default`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_profile: Option<String>,
+}
+
+/// 가상 프로필 목록 교체. 같은 이름의 프로필 자격 증명이 바뀔 수 있어 클라이언트 캐시도 비움
+pub fn set_virtual_profiles(profiles: Vec<VirtualProfile>) {
+    if let Ok(mut current) = VIRTUAL_PROFILES.lock() {
+        *current = profiles;
+    }
+    crate::aws_cli::client_cache::invalidate_client_cache();
+}
+
+pub(crate) fn virtual_profile_names() -> Vec<String> {
+    VIRTUAL_PROFILES
+        .lock()
+        .map(|profiles| profiles.iter().map(|p| p.name.clone()).collect())
+        .unwrap_or_default()
+}
+
+/// 가상 프로필이면 (시작 프로필, 프로필의 역할 + `role_chain`), 아니면 그대로
+pub(crate) fn resolve_profile(profile: String, role_chain: Vec<String>) -> (String, Vec<String>) {
+    match VIRTUAL_PROFILES.lock() {
+        Ok(profiles) => resolve_virtual_profile(&profiles, profile, role_chain),
+        Err(_) => (profile, role_chain),
+    }
+}
+
+fn resolve_virtual_profile(
+    profiles: &[VirtualProfile],
+    profile: String,
+    role_chain: Vec<String>,
+) -> (String, Vec<String>) {
+    match profiles.iter().find(|p| p.name == profile) {
+        Some(virtual_profile) => (
+            virtual_profile
+                .source_profile
+                .clone()
+                .unwrap_or_else(|| "default".to_string()),
+            std::iter::once(virtual_profile.role_arn.clone())
+                .chain(role_chain)
+                .collect(),
+        ),
+        None => (profile, role_chain),
+    }
+}
+
+/// `f` 실행 동안 현재 스레드의 AWS 호출을 `account`로 보냄 (`None`이면 선택한 프로필)
+pub fn with_account<R>(account: Option<&AccountTarget>, f: impl FnOnce() -> R) -> R {
+    with_account_override(account.cloned(), f)
+}
+
+pub(crate) fn with_account_override<R>(account: Option<AccountTarget>, f: impl FnOnce() -> R) -> R {
+    let previous = ACCOUNT_OVERRIDE.with(|a| a.replace(account));
+    let result = f();
+    ACCOUNT_OVERRIDE.with(|a| a.replace(previous));
+    result
+}
+
+pub(crate) fn account_override() -> Option<AccountTarget> {
+    ACCOUNT_OVERRIDE.with(|a| a.borrow().clone())
+}
+
+/// 현재 스레드 계정의 역할 체인 (없으면 빈 목록)
+pub(crate) fn current_role_chain() -> Vec<String> {
+    account_override()
+        .map(|account| account.role_chain)
+        .unwrap_or_default()
+}
+
+/// 프로필 자격 증명에서 시작해 역할을 차례로 맡은 자격 증명으로 바꾼 config
+pub(crate) async fn assume_role_chain(config: SdkConfig, role_chain: &[String]) -> SdkConfig {
+    let mut config = config;
+    for role_arn in role_chain {
+        let provider = AssumeRoleProvider::builder(role_arn)
+            .session_name(SESSION_NAME)
+            .configure(&config)
+            .build()
+            .await;
+        config = config
+            .to_builder()
+            .credentials_provider(SharedCredentialsProvider::new(provider))
+            .build();
+    }
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccountTarget, VirtualProfile, account_override, resolve_virtual_profile, with_account,
+    };
+
+    #[test]
+    fn parse_accepts_profiles_roles_and_chains() {
+        assert_eq!(AccountTarget::parse("  ").unwrap(), None);
+
+        let profile = AccountTarget::parse("prod").unwrap().expect("target");
+        assert_eq!(profile.profile.as_deref(), Some("prod"));
+        assert_eq!(profile.label(), "prod");
+
+        let chain = AccountTarget::parse(
+            "hub > arn:aws:iam::111122223333:role/Jump > arn:aws-us-gov:iam::444455556666:role/ReadOnly",
+        )
+        .unwrap()
+        .expect("target");
+        assert_eq!(chain.profile.as_deref(), Some("hub"));
+        assert_eq!(chain.role_chain.len(), 2);
+        assert_eq!(chain.account_id(), Some("444455556666"));
+        assert_eq!(chain.label(), "444455556666 (ReadOnly)");
+        assert_eq!(
+            chain.to_string(),
+            "hub > arn:aws:iam::111122223333:role/Jump > arn:aws-us-gov:iam::444455556666:role/ReadOnly"
+        );
+
+        assert!(AccountTarget::parse("arn:aws:s3:::bucket").is_err());
+        assert!(AccountTarget::parse("arn:aws:iam::1:role/a > prod").is_err());
+    }
+
+    #[test]
+    fn with_account_is_scoped_to_the_closure() {
+        let target = AccountTarget::parse("prod").unwrap();
+        with_account(target.as_ref(), || {
+            assert_eq!(account_override(), target);
+            with_account(None, || assert_eq!(account_override(), None));
+            assert_eq!(account_override(), target);
+        });
+        assert_eq!(account_override(), None);
+    }
+
+    #[test]
+    fn virtual_profiles_resolve_to_source_profile_and_role() {
+        let profiles = vec![VirtualProfile {
+            name: "org-prod".to_string(),
+            role_arn: "arn:aws:iam::444455556666:role/OrganizationAccountAccessRole".to_string(),
+            source_profile: Some("management".to_string()),
+        }];
+
+        let (profile, chain) = resolve_virtual_profile(
+            &profiles,
+            "org-prod".to_string(),
+            vec!["arn:aws:iam::444455556666:role/ReadOnly".to_string()],
+        );
+        assert_eq!(profile, "management");
+        assert_eq!(
+            chain,
+            vec![
+                "arn:aws:iam::444455556666:role/OrganizationAccountAccessRole",
+                "arn:aws:iam::444455556666:role/ReadOnly",
+            ]
+        );
+        assert_eq!(
+            resolve_virtual_profile(&profiles, "dev".to_string(), Vec::new()),
+            ("dev".to_string(), Vec::new())
+        );
+    }
+}
@@ -0,0 +1,512 @@
+pub use crate::aws_cli::api_gateway_sdk::{get_api_gateway_detail, list_api_gateways};
+use crate::aws_cli::partition::{Arn, console_url};
+use crate::i18n::{I18n, Language};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct ApiStage {
+    pub name: String,
+    pub deployment_id: Option<String>,
+    pub auto_deploy: Option<bool>,
+    pub throttling_burst_limit: Option<i32>,
+    pub throttling_rate_limit: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiIntegrationKind {
+    Lambda,
+    Http,
+    VpcLink,
+    AwsService,
+    Mock,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiIntegration {
+    pub integration_type: String,
+    pub uri: String,
+    pub connection_type: Option<String>,
+    pub connection_id: Option<String>,
+}
+
+impl ApiIntegration {
+    pub fn kind(&self) -> ApiIntegrationKind {
+        if self.connection_type.as_deref() == Some("VPC_LINK") {
+            return ApiIntegrationKind::VpcLink;
+        }
+        if lambda_function_name(&self.uri).is_some() {
+            return ApiIntegrationKind::Lambda;
+        }
+        match self.integration_type.as_str() {
+            "HTTP" | "HTTP_PROXY" => ApiIntegrationKind::Http,
+            "MOCK" => ApiIntegrationKind::Mock,
+            _ => ApiIntegrationKind::AwsService,
+        }
+    }
+
+    pub fn lambda_function(&self) -> Option<String> {
+        lambda_function_name(&self.uri)
+    }
+
+    /// Lambda 통합이면 별칭/버전을 뗀 함수 ARN (블루프린트의 Lambda 리소스 ID와 같은 형식)
+    pub fn lambda_function_arn(&self) -> Option<String> {
+        self.lambda_function()?;
+        // 호출 URI 안의 함수 ARN은 마지막 `arn:`부터 `/invocations` 앞까지
+        let arn = self.uri[self.uri.rfind("arn:")?..].split('/').next()?;
+        let parts: Vec<&str> = arn.split(':').take(7).collect();
+        (parts.len() == 7).then(|| parts.join(":"))
+    }
+
+    /// Lambda 통합이면 함수 ARN의 리전·파티션 콘솔에서 함수를 여는 주소
+    pub fn lambda_console_url(&self) -> Option<String> {
+        let function = self.lambda_function()?;
+        let function_arn = self.lambda_function_arn()?;
+        let arn = Arn::parse(&function_arn)?;
+        (arn.service == "lambda").then(|| {
+            console_url(
+                arn.region,
+                "lambda/home",
+                &format!("/functions/{}", function),
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiRoute {
+    pub method: String,
+    pub path: String,
+    pub authorization_type: String,
+    pub authorizer_id: Option<String>,
+    pub integration: Option<ApiIntegration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiAuthorizer {
+    pub id: String,
+    pub name: String,
+    pub authorizer_type: String,
+    pub identity_source: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiDomainMapping {
+    pub domain_name: String,
+    pub base_path: String,
+    pub stage: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiGatewayDetail {
+    pub name: String,
+    pub id: String,
+    pub protocol: String,
+    pub endpoint_type: String,
+    pub api_endpoint: String,
+    pub description: String,
+    pub created_date: String,
+    pub stages: Vec<ApiStage>,
+    pub routes: Vec<ApiRoute>,
+    pub authorizers: Vec<ApiAuthorizer>,
+    pub domain_mappings: Vec<ApiDomainMapping>,
+}
+
+/// Lambda 통합 URI에서 함수 이름 추출
+/// (`arn:aws:apigateway:...:lambda:path/.../functions/arn:aws:lambda:...:function:NAME/invocations`
+/// 또는 `arn:aws:lambda:...:function:NAME[:alias]`)
+pub fn lambda_function_name(uri: &str) -> Option<String> {
+    let idx = uri.find(":function:")?;
+    let rest = &uri[idx + ":function:".len()..];
+    let name = rest.split(['/', ':']).next().unwrap_or_default();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+impl ApiGatewayDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        self.to_markdown_with_links(lang, &HashMap::new())
+    }
+
+    /// `lambda_links`: Lambda 함수 ARN -> 같은 블루프린트 문서 내 앵커
+    pub fn to_markdown_with_links(
+        &self,
+        lang: Language,
+        lambda_links: &HashMap<String, String>,
+    ) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = if self.name.is_empty() {
+            &self.id
+        } else {
+            &self.name
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.api_gateway(), display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| ID | {} |", self.id),
+            format!("| {} | {} |", i18n.md_protocol(), self.protocol),
+        ];
+        if !self.endpoint_type.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_endpoint_type(),
+                self.endpoint_type
+            ));
+        }
+        if !self.api_endpoint.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_api_endpoint(),
+                self.api_endpoint
+            ));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.created_date.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                self.created_date
+            ));
+        }
+
+        // Stages
+        if !self.stages.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_stages()));
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_deployment(),
+                i18n.md_auto_deploy(),
+                i18n.md_throttling()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for stage in &self.stages {
+                let auto_deploy = match stage.auto_deploy {
+                    Some(true) => i18n.md_enabled(),
+                    Some(false) => i18n.md_disabled(),
+                    None => "-",
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    stage.name,
+                    stage.deployment_id.as_deref().unwrap_or("-"),
+                    auto_deploy,
+                    format_throttling(stage.throttling_burst_limit, stage.throttling_rate_limit)
+                ));
+            }
+        }
+
+        // Routes
+        if !self.routes.is_empty() {
+            let authorizer_names: HashMap<&str, &str> = self
+                .authorizers
+                .iter()
+                .map(|a| (a.id.as_str(), a.name.as_str()))
+                .collect();
+
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_routes()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_method(),
+                i18n.md_path(),
+                i18n.md_integration(),
+                i18n.md_target(),
+                i18n.md_authorization()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for route in &self.routes {
+                let (kind, target) = match &route.integration {
+                    Some(integration) => (
+                        integration_kind_label(&integration.kind()).to_string(),
+                        format_integration_target(integration, lambda_links),
+                    ),
+                    None => ("-".to_string(), "-".to_string()),
+                };
+                let authorization = match route
+                    .authorizer_id
+                    .as_deref()
+                    .and_then(|id| authorizer_names.get(id))
+                {
+                    Some(name) => format!("{} ({})", route.authorization_type, name),
+                    None => route.authorization_type.clone(),
+                };
+                lines.push(format!(
+                    "| {} | `{}` | {} | {} | {} |",
+                    route.method, route.path, kind, target, authorization
+                ));
+            }
+        }
+
+        // Authorizers
+        if !self.authorizers.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_authorizers()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_type(),
+                i18n.md_identity_source()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for authorizer in &self.authorizers {
+                let identity_source = if authorizer.identity_source.is_empty() {
+                    "-"
+                } else {
+                    authorizer.identity_source.as_str()
+                };
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    authorizer.name, authorizer.authorizer_type, identity_source
+                ));
+            }
+        }
+
+        // Custom Domains
+        if !self.domain_mappings.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_custom_domains()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_domain(),
+                i18n.md_base_path(),
+                i18n.md_stage()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for mapping in &self.domain_mappings {
+                lines.push(format!(
+                    "| {} | `/{}` | {} |",
+                    mapping.domain_name,
+                    mapping.base_path.trim_start_matches('/'),
+                    mapping.stage
+                ));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+fn integration_kind_label(kind: &ApiIntegrationKind) -> &'static str {
+    match kind {
+        ApiIntegrationKind::Lambda => "Lambda",
+        ApiIntegrationKind::Http => "HTTP",
+        ApiIntegrationKind::VpcLink => "VPC Link",
+        ApiIntegrationKind::AwsService => "AWS",
+        ApiIntegrationKind::Mock => "Mock",
+    }
+}
+
+/// Lambda 함수는 같은 블루프린트에 있으면 문서 내 앵커로, 없으면 콘솔 링크로 표시
+fn format_integration_target(
+    integration: &ApiIntegration,
+    lambda_links: &HashMap<String, String>,
+) -> String {
+    if let Some(function) = integration.lambda_function() {
+        let anchor = integration
+            .lambda_function_arn()
+            .and_then(|arn| lambda_links.get(&arn));
+        return match (anchor, integration.lambda_console_url()) {
+            (Some(anchor), _) => format!("[{}](#{})", function, anchor),
+            (None, Some(url)) => format!("[{}]({})", function, url),
+            (None, None) => format!("`{}`", function),
+        };
+    }
+
+    match (&integration.connection_id, integration.kind()) {
+        (Some(connection_id), ApiIntegrationKind::VpcLink) if integration.uri.is_empty() => {
+            format!("`{}`", connection_id)
+        }
+        (Some(connection_id), ApiIntegrationKind::VpcLink) => {
+            format!("{} (`{}`)", integration.uri, connection_id)
+        }
+        _ if integration.uri.is_empty() => "-".to_string(),
+        _ => integration.uri.clone(),
+    }
+}
+
+fn format_throttling(burst: Option<i32>, rate: Option<f64>) -> String {
+    match (burst, rate) {
+        (None, None) => "-".to_string(),
+        (burst, rate) => format!(
+            "burst {} / rate {}",
+            burst.map(|v| v.to_string()).unwrap_or("-".to_string()),
+            rate.map(|v| v.to_string()).unwrap_or("-".to_string())
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ApiAuthorizer, ApiDomainMapping, ApiGatewayDetail, ApiIntegration, ApiIntegrationKind,
+        ApiRoute, ApiStage, lambda_function_name,
+    };
+    use crate::i18n::Language;
+    use std::collections::HashMap;
+
+    fn lambda_integration(function: &str) -> ApiIntegration {
+        ApiIntegration {
+            integration_type: "AWS_PROXY".to_string(),
+            uri: format!(
+                "arn:aws:apigateway:ap-northeast-2:lambda:path/2015-03-31/functions/arn:aws:lambda:ap-northeast-2:123456789012:function:{function}/invocations"
+            ),
+            connection_type: None,
+            connection_id: None,
+        }
+    }
+
+    fn sample_detail() -> ApiGatewayDetail {
+        ApiGatewayDetail {
+            name: "orders-api".to_string(),
+            id: "a1b2c3d4e5".to_string(),
+            protocol: "REST".to_string(),
+            endpoint_type: "REGIONAL".to_string(),
+            api_endpoint: "https://a1b2c3d4e5.execute-api.ap-northeast-2.amazonaws.com".to_string(),
+            description: "Orders".to_string(),
+            created_date: "2026-01-01".to_string(),
+            stages: vec![ApiStage {
+                name: "prod".to_string(),
+                deployment_id: Some("dep-1".to_string()),
+                auto_deploy: None,
+                throttling_burst_limit: Some(100),
+                throttling_rate_limit: Some(50.0),
+            }],
+            routes: vec![
+                ApiRoute {
+                    method: "GET".to_string(),
+                    path: "/orders".to_string(),
+                    authorization_type: "CUSTOM".to_string(),
+                    authorizer_id: Some("auth-1".to_string()),
+                    integration: Some(lambda_integration("list-orders")),
+                },
+                ApiRoute {
+                    method: "ANY".to_string(),
+                    path: "/legacy/{proxy+}".to_string(),
+                    authorization_type: "NONE".to_string(),
+                    authorizer_id: None,
+                    integration: Some(ApiIntegration {
+                        integration_type: "HTTP_PROXY".to_string(),
+                        uri: "http://internal-nlb.example.com/{proxy}".to_string(),
+                        connection_type: Some("VPC_LINK".to_string()),
+                        connection_id: Some("vpclink-1".to_string()),
+                    }),
+                },
+            ],
+            authorizers: vec![ApiAuthorizer {
+                id: "auth-1".to_string(),
+                name: "jwt-auth".to_string(),
+                authorizer_type: "TOKEN".to_string(),
+                identity_source: "method.request.header.Authorization".to_string(),
+            }],
+            domain_mappings: vec![ApiDomainMapping {
+                domain_name: "api.example.com".to_string(),
+                base_path: String::new(),
+                stage: "prod".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn lambda_function_name_handles_invocation_uri_and_plain_arn() {
+        assert_eq!(
+            lambda_function_name(&lambda_integration("fn-a").uri),
+            Some("fn-a".to_string())
+        );
+        assert_eq!(
+            lambda_function_name("arn:aws:lambda:us-east-1:123456789012:function:fn-b:live"),
+            Some("fn-b".to_string())
+        );
+        assert_eq!(lambda_function_name("https://example.com"), None);
+    }
+
+    #[test]
+    fn integration_kind_detects_lambda_http_and_vpc_link() {
+        let detail = sample_detail();
+        let kinds: Vec<ApiIntegrationKind> = detail
+            .routes
+            .iter()
+            .filter_map(|r| r.integration.as_ref().map(|i| i.kind()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![ApiIntegrationKind::Lambda, ApiIntegrationKind::VpcLink]
+        );
+
+        let http = ApiIntegration {
+            integration_type: "HTTP_PROXY".to_string(),
+            uri: "https://example.com".to_string(),
+            connection_type: Some("INTERNET".to_string()),
+            connection_id: None,
+        };
+        assert_eq!(http.kind(), ApiIntegrationKind::Http);
+    }
+
+    #[test]
+    fn to_markdown_renders_route_table_stages_authorizers_and_domains() {
+        let md = sample_detail().to_markdown(Language::English);
+        assert!(md.contains("## API Gateway (orders-api)"));
+        assert!(md.contains(
+            "| GET | `/orders` | Lambda | [list-orders](https://console.aws.amazon.com/lambda/home?region=ap-northeast-2#/functions/list-orders) | CUSTOM (jwt-auth) |"
+        ));
+        assert!(md.contains("VPC Link"));
+        assert!(md.contains("vpclink-1"));
+        assert!(md.contains("burst 100 / rate 50"));
+        assert!(md.contains("| jwt-auth | TOKEN | method.request.header.Authorization |"));
+        assert!(md.contains("| api.example.com | `/` | prod |"));
+    }
+
+    #[test]
+    fn lambda_targets_link_to_the_function_console() {
+        let integration = lambda_integration("list-orders");
+        assert_eq!(
+            integration.lambda_console_url().as_deref(),
+            Some(
+                "https://console.aws.amazon.com/lambda/home?region=ap-northeast-2#/functions/list-orders"
+            )
+        );
+        let gov = ApiIntegration {
+            uri: "arn:aws-us-gov:lambda:us-gov-west-1:123456789012:function:fn-b:live".to_string(),
+            ..integration
+        };
+        assert_eq!(
+            gov.lambda_console_url().as_deref(),
+            Some(
+                "https://console.amazonaws-us-gov.com/lambda/home?region=us-gov-west-1#/functions/fn-b"
+            )
+        );
+        assert_eq!(
+            gov.lambda_function_arn().as_deref(),
+            Some("arn:aws-us-gov:lambda:us-gov-west-1:123456789012:function:fn-b")
+        );
+    }
+
+    #[test]
+    fn to_markdown_with_links_links_lambda_functions_in_blueprint() {
+        let mut links = HashMap::new();
+        links.insert(
+            "arn:aws:lambda:ap-northeast-2:123456789012:function:list-orders".to_string(),
+            "lambda-list-orders".to_string(),
+        );
+        let md = sample_detail().to_markdown_with_links(Language::Korean, &links);
+        assert!(md.contains("[list-orders](#lambda-list-orders)"));
+
+        // 블루프린트에 없는 함수는 콘솔 링크로
+        links.clear();
+        let md = sample_detail().to_markdown_with_links(Language::Korean, &links);
+        assert!(md.contains("[list-orders](https://console.aws.amazon.com/lambda/home"));
+    }
+}
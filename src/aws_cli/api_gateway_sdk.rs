@@ -0,0 +1,501 @@
+use crate::aws_cli::api_gateway::{
+    ApiAuthorizer, ApiDomainMapping, ApiGatewayDetail, ApiIntegration, ApiRoute, ApiStage,
+};
+use crate::aws_cli::common::{AwsResource, format_date};
+use crate::aws_cli::provider::AwsProvider;
+use std::collections::HashMap;
+
+/// List REST (v1) and HTTP/WebSocket (v2) APIs
+pub fn list_api_gateways(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut resources: Vec<AwsResource> = provider
+        .get_rest_apis()
+        .iter()
+        .map(map_rest_api_resource)
+        .collect();
+    resources.extend(provider.get_http_apis().iter().map(map_http_api_resource));
+
+    resources.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    resources
+}
+
+/// Get API Gateway detail (v2 first, falls back to REST v1)
+pub fn get_api_gateway_detail(
+    provider: &dyn AwsProvider,
+    api_id: &str,
+) -> Option<ApiGatewayDetail> {
+    if let Some(api) = provider.get_http_api(api_id) {
+        return Some(get_http_api_detail(provider, api_id, api));
+    }
+
+    provider
+        .get_rest_api(api_id)
+        .map(|api| get_rest_api_detail(provider, api_id, api))
+}
+
+fn get_rest_api_detail(
+    provider: &dyn AwsProvider,
+    api_id: &str,
+    api: aws_sdk_apigateway::operation::get_rest_api::GetRestApiOutput,
+) -> ApiGatewayDetail {
+    let stages = map_rest_stages(&provider.get_rest_stages(api_id));
+    let routes = map_rest_routes(&provider.get_rest_resources(api_id));
+    let authorizers = map_rest_authorizers(&provider.get_rest_authorizers(api_id));
+
+    let mut domain_mappings = Vec::new();
+    let domains = provider.get_rest_domain_names();
+    for domain in domains.iter().filter_map(|d| d.domain_name()) {
+        let mappings = provider.get_base_path_mappings(domain);
+        domain_mappings.extend(map_base_path_mappings(domain, &mappings, api_id));
+    }
+
+    let endpoint_type = api
+        .endpoint_configuration()
+        .map(|c| {
+            c.types()
+                .iter()
+                .map(|t| t.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+
+    ApiGatewayDetail {
+        name: api.name().unwrap_or_default().to_string(),
+        id: api_id.to_string(),
+        protocol: "REST".to_string(),
+        endpoint_type,
+        api_endpoint: String::new(),
+        description: api.description().unwrap_or_default().to_string(),
+        created_date: format_date(api.created_date()),
+        stages,
+        routes,
+        authorizers,
+        domain_mappings,
+    }
+}
+
+fn get_http_api_detail(
+    provider: &dyn AwsProvider,
+    api_id: &str,
+    api: aws_sdk_apigatewayv2::operation::get_api::GetApiOutput,
+) -> ApiGatewayDetail {
+    let stages = provider.get_http_stages(api_id);
+    let routes = provider.get_http_routes(api_id);
+    let integrations = provider.get_http_integrations(api_id);
+    let authorizers = provider.get_http_authorizers(api_id);
+
+    let mut domain_mappings = Vec::new();
+    let domains = provider.get_http_domain_names();
+    for domain in domains.iter().filter_map(|d| d.domain_name()) {
+        let mappings = provider.get_api_mappings(domain);
+        domain_mappings.extend(map_api_mappings(domain, &mappings, api_id));
+    }
+
+    ApiGatewayDetail {
+        name: api.name().unwrap_or_default().to_string(),
+        id: api_id.to_string(),
+        protocol: api
+            .protocol_type()
+            .map(|p| p.as_str().to_string())
+            .unwrap_or_else(|| "HTTP".to_string()),
+        endpoint_type: "REGIONAL".to_string(),
+        api_endpoint: api.api_endpoint().unwrap_or_default().to_string(),
+        description: api.description().unwrap_or_default().to_string(),
+        created_date: format_date(api.created_date()),
+        stages: map_http_stages(&stages),
+        routes: map_http_routes(&routes, &integrations),
+        authorizers: map_http_authorizers(&authorizers),
+        domain_mappings,
+    }
+}
+
+fn map_rest_api_resource(api: &aws_sdk_apigateway::types::RestApi) -> AwsResource {
+    AwsResource {
+        name: api.name().unwrap_or_default().to_string(),
+        id: api.id().unwrap_or_default().to_string(),
+        state: "REST".to_string(),
+        az: String::new(),
+        cidr: String::new(),
+        region: None,
+    }
+}
+
+fn map_http_api_resource(api: &aws_sdk_apigatewayv2::types::Api) -> AwsResource {
+    AwsResource {
+        name: api.name().unwrap_or_default().to_string(),
+        id: api.api_id().unwrap_or_default().to_string(),
+        state: api
+            .protocol_type()
+            .map(|p| p.as_str().to_string())
+            .unwrap_or_default(),
+        az: String::new(),
+        cidr: api.api_endpoint().unwrap_or_default().to_string(),
+        region: None,
+    }
+}
+
+fn map_rest_stages(stages: &[aws_sdk_apigateway::types::Stage]) -> Vec<ApiStage> {
+    let mut result: Vec<ApiStage> = stages
+        .iter()
+        .map(|stage| {
+            // "*/*" 키는 스테이지 전체 기본 메서드 설정
+            let defaults = stage.method_settings().and_then(|m| m.get("*/*"));
+            ApiStage {
+                name: stage.stage_name().unwrap_or_default().to_string(),
+                deployment_id: stage.deployment_id().map(|s| s.to_string()),
+                auto_deploy: None,
+                throttling_burst_limit: defaults.map(|s| s.throttling_burst_limit()),
+                throttling_rate_limit: defaults.map(|s| s.throttling_rate_limit()),
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+}
+
+fn map_http_stages(stages: &[aws_sdk_apigatewayv2::types::Stage]) -> Vec<ApiStage> {
+    let mut result: Vec<ApiStage> = stages
+        .iter()
+        .map(|stage| {
+            let defaults = stage.default_route_settings();
+            ApiStage {
+                name: stage.stage_name().unwrap_or_default().to_string(),
+                deployment_id: stage.deployment_id().map(|s| s.to_string()),
+                auto_deploy: stage.auto_deploy(),
+                throttling_burst_limit: defaults.and_then(|s| s.throttling_burst_limit()),
+                throttling_rate_limit: defaults.and_then(|s| s.throttling_rate_limit()),
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+}
+
+fn map_rest_routes(resources: &[aws_sdk_apigateway::types::Resource]) -> Vec<ApiRoute> {
+    let mut routes = Vec::new();
+    for resource in resources {
+        let path = resource.path().unwrap_or("/").to_string();
+        let Some(methods) = resource.resource_methods() else {
+            continue;
+        };
+        for (http_method, method) in methods {
+            let integration = method.method_integration().map(|i| ApiIntegration {
+                integration_type: i
+                    .r#type()
+                    .map(|t| t.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                uri: i.uri().unwrap_or_default().to_string(),
+                connection_type: i.connection_type().map(|c| c.as_str().to_string()),
+                connection_id: i.connection_id().map(|s| s.to_string()),
+            });
+            routes.push(ApiRoute {
+                method: method.http_method().unwrap_or(http_method).to_string(),
+                path: path.clone(),
+                authorization_type: method.authorization_type().unwrap_or("NONE").to_string(),
+                authorizer_id: method.authorizer_id().map(|s| s.to_string()),
+                integration,
+            });
+        }
+    }
+    routes.sort_by(|a, b| a.path.cmp(&b.path).then(a.method.cmp(&b.method)));
+    routes
+}
+
+fn map_http_routes(
+    routes: &[aws_sdk_apigatewayv2::types::Route],
+    integrations: &[aws_sdk_apigatewayv2::types::Integration],
+) -> Vec<ApiRoute> {
+    let integrations_by_id: HashMap<&str, &aws_sdk_apigatewayv2::types::Integration> = integrations
+        .iter()
+        .filter_map(|i| i.integration_id().map(|id| (id, i)))
+        .collect();
+
+    let mut result: Vec<ApiRoute> = routes
+        .iter()
+        .map(|route| {
+            let route_key = route.route_key().unwrap_or_default();
+            // "GET /orders" 형식, WebSocket/$default는 메서드 없음
+            let (method, path) = match route_key.split_once(' ') {
+                Some((method, path)) => (method.to_string(), path.to_string()),
+                None => ("-".to_string(), route_key.to_string()),
+            };
+            let integration = route
+                .target()
+                .and_then(|t| t.strip_prefix("integrations/"))
+                .and_then(|id| integrations_by_id.get(id))
+                .map(|i| ApiIntegration {
+                    integration_type: i
+                        .integration_type()
+                        .map(|t| t.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    uri: i.integration_uri().unwrap_or_default().to_string(),
+                    connection_type: i.connection_type().map(|c| c.as_str().to_string()),
+                    connection_id: i.connection_id().map(|s| s.to_string()),
+                });
+            ApiRoute {
+                method,
+                path,
+                authorization_type: route
+                    .authorization_type()
+                    .map(|a| a.as_str())
+                    .unwrap_or("NONE")
+                    .to_string(),
+                authorizer_id: route.authorizer_id().map(|s| s.to_string()),
+                integration,
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| a.path.cmp(&b.path).then(a.method.cmp(&b.method)));
+    result
+}
+
+fn map_rest_authorizers(
+    authorizers: &[aws_sdk_apigateway::types::Authorizer],
+) -> Vec<ApiAuthorizer> {
+    authorizers
+        .iter()
+        .map(|a| ApiAuthorizer {
+            id: a.id().unwrap_or_default().to_string(),
+            name: a.name().unwrap_or_default().to_string(),
+            authorizer_type: a
+                .r#type()
+                .map(|t| t.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            identity_source: a.identity_source().unwrap_or_default().to_string(),
+        })
+        .collect()
+}
+
+fn map_http_authorizers(
+    authorizers: &[aws_sdk_apigatewayv2::types::Authorizer],
+) -> Vec<ApiAuthorizer> {
+    authorizers
+        .iter()
+        .map(|a| ApiAuthorizer {
+            id: a.authorizer_id().unwrap_or_default().to_string(),
+            name: a.name().unwrap_or_default().to_string(),
+            authorizer_type: a
+                .authorizer_type()
+                .map(|t| t.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            identity_source: a.identity_source().join(", "),
+        })
+        .collect()
+}
+
+fn map_base_path_mappings(
+    domain: &str,
+    mappings: &[aws_sdk_apigateway::types::BasePathMapping],
+    api_id: &str,
+) -> Vec<ApiDomainMapping> {
+    mappings
+        .iter()
+        .filter(|m| m.rest_api_id() == Some(api_id))
+        .map(|m| ApiDomainMapping {
+            domain_name: domain.to_string(),
+            // REST API는 빈 base path를 "(none)"으로 반환
+            base_path: match m.base_path() {
+                Some("(none)") | None => String::new(),
+                Some(path) => path.to_string(),
+            },
+            stage: m.stage().unwrap_or_default().to_string(),
+        })
+        .collect()
+}
+
+fn map_api_mappings(
+    domain: &str,
+    mappings: &[aws_sdk_apigatewayv2::types::ApiMapping],
+    api_id: &str,
+) -> Vec<ApiDomainMapping> {
+    mappings
+        .iter()
+        .filter(|m| m.api_id() == Some(api_id))
+        .map(|m| ApiDomainMapping {
+            domain_name: domain.to_string(),
+            base_path: m.api_mapping_key().unwrap_or_default().to_string(),
+            stage: m.stage().unwrap_or_default().to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        map_api_mappings, map_base_path_mappings, map_http_api_resource, map_http_routes,
+        map_http_stages, map_rest_api_resource, map_rest_authorizers, map_rest_routes,
+        map_rest_stages,
+    };
+    use aws_sdk_apigateway::types as v1;
+    use aws_sdk_apigatewayv2::types as v2;
+
+    #[test]
+    fn map_api_resources_mark_protocol() {
+        let rest = v1::RestApi::builder().id("abc").name("orders").build();
+        let mapped = map_rest_api_resource(&rest);
+        assert_eq!(mapped.id, "abc");
+        assert_eq!(mapped.state, "REST");
+
+        let http = v2::Api::builder()
+            .api_id("xyz")
+            .name("users")
+            .protocol_type(v2::ProtocolType::Http)
+            .api_endpoint("https://xyz.execute-api.us-east-1.amazonaws.com")
+            .route_selection_expression("$request.method $request.path")
+            .build();
+        let mapped = map_http_api_resource(&http);
+        assert_eq!(mapped.id, "xyz");
+        assert_eq!(mapped.state, "HTTP");
+        assert!(mapped.cidr.starts_with("https://"));
+    }
+
+    #[test]
+    fn map_rest_stages_reads_default_method_throttling() {
+        let stage = v1::Stage::builder()
+            .stage_name("prod")
+            .deployment_id("dep-1")
+            .method_settings(
+                "*/*",
+                v1::MethodSetting::builder()
+                    .throttling_burst_limit(200)
+                    .throttling_rate_limit(100.0)
+                    .build(),
+            )
+            .build();
+        let stages = map_rest_stages(&[stage, v1::Stage::builder().stage_name("dev").build()]);
+        assert_eq!(stages[0].name, "dev");
+        assert_eq!(stages[0].throttling_burst_limit, None);
+        assert_eq!(stages[1].name, "prod");
+        assert_eq!(stages[1].throttling_burst_limit, Some(200));
+        assert_eq!(stages[1].throttling_rate_limit, Some(100.0));
+    }
+
+    #[test]
+    fn map_http_stages_reads_default_route_settings() {
+        let stage = v2::Stage::builder()
+            .stage_name("$default")
+            .auto_deploy(true)
+            .default_route_settings(
+                v2::RouteSettings::builder()
+                    .throttling_burst_limit(10)
+                    .throttling_rate_limit(5.0)
+                    .build(),
+            )
+            .build();
+        let stages = map_http_stages(&[stage]);
+        assert_eq!(stages[0].auto_deploy, Some(true));
+        assert_eq!(stages[0].throttling_burst_limit, Some(10));
+    }
+
+    #[test]
+    fn map_rest_routes_expands_methods_with_integrations() {
+        let method = v1::Method::builder()
+            .http_method("GET")
+            .authorization_type("COGNITO_USER_POOLS")
+            .authorizer_id("auth-1")
+            .method_integration(
+                v1::Integration::builder()
+                    .r#type(v1::IntegrationType::AwsProxy)
+                    .uri("arn:aws:apigateway:us-east-1:lambda:path/2015-03-31/functions/arn:aws:lambda:us-east-1:123456789012:function:list-orders/invocations")
+                    .build(),
+            )
+            .build();
+        let resources = vec![
+            v1::Resource::builder()
+                .path("/orders")
+                .resource_methods("GET", method)
+                .build(),
+            v1::Resource::builder().path("/").build(),
+        ];
+
+        let routes = map_rest_routes(&resources);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].method, "GET");
+        assert_eq!(routes[0].path, "/orders");
+        assert_eq!(routes[0].authorizer_id.as_deref(), Some("auth-1"));
+        assert_eq!(
+            routes[0]
+                .integration
+                .as_ref()
+                .and_then(|i| i.lambda_function()),
+            Some("list-orders".to_string())
+        );
+    }
+
+    #[test]
+    fn map_http_routes_resolves_integration_targets() {
+        let routes = vec![
+            v2::Route::builder()
+                .route_key("POST /users")
+                .target("integrations/int-1")
+                .authorization_type(v2::AuthorizationType::Jwt)
+                .build(),
+            v2::Route::builder().route_key("$default").build(),
+        ];
+        let integrations = vec![
+            v2::Integration::builder()
+                .integration_id("int-1")
+                .integration_type(v2::IntegrationType::HttpProxy)
+                .integration_uri(
+                    "arn:aws:elasticloadbalancing:us-east-1:123456789012:listener/app/alb/1/2",
+                )
+                .connection_type(v2::ConnectionType::VpcLink)
+                .connection_id("vl-1")
+                .build(),
+        ];
+
+        let mapped = map_http_routes(&routes, &integrations);
+        assert_eq!(mapped[0].path, "$default");
+        assert_eq!(mapped[0].method, "-");
+        assert!(mapped[0].integration.is_none());
+        assert_eq!(mapped[1].method, "POST");
+        assert_eq!(mapped[1].authorization_type, "JWT");
+        let integration = mapped[1].integration.as_ref().expect("integration");
+        assert_eq!(integration.connection_id.as_deref(), Some("vl-1"));
+    }
+
+    #[test]
+    fn map_authorizers_and_domain_mappings() {
+        let authorizers = map_rest_authorizers(&[v1::Authorizer::builder()
+            .id("auth-1")
+            .name("token-auth")
+            .r#type(v1::AuthorizerType::Token)
+            .identity_source("method.request.header.Authorization")
+            .build()]);
+        assert_eq!(authorizers[0].authorizer_type, "TOKEN");
+
+        let mappings = map_base_path_mappings(
+            "api.example.com",
+            &[
+                v1::BasePathMapping::builder()
+                    .base_path("(none)")
+                    .rest_api_id("abc")
+                    .stage("prod")
+                    .build(),
+                v1::BasePathMapping::builder()
+                    .base_path("other")
+                    .rest_api_id("zzz")
+                    .stage("prod")
+                    .build(),
+            ],
+            "abc",
+        );
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].base_path, "");
+
+        let mappings = map_api_mappings(
+            "users.example.com",
+            &[v2::ApiMapping::builder()
+                .api_id("xyz")
+                .api_mapping_key("v1")
+                .stage("$default")
+                .build()],
+            "xyz",
+        );
+        assert_eq!(mappings[0].base_path, "v1");
+    }
+}
@@ -1,4 +1,5 @@
 pub use crate::aws_cli::asg_sdk::{get_asg_detail, list_auto_scaling_groups};
+use crate::aws_cli::cloudwatch::{CloudWatchAlarm, alarms_section};
 use crate::i18n::{I18n, Language};
 
 #[derive(Debug, Clone)]
@@ -30,6 +31,7 @@ pub struct AsgDetail {
     pub created_time: String,
     pub scaling_policies: Vec<ScalingPolicy>,
     pub tags: Vec<(String, String)>,
+    pub alarms: Vec<CloudWatchAlarm>,
 }
 
 impl AsgDetail {
@@ -153,6 +155,9 @@ impl AsgDetail {
             }
         }
 
+        // Alarms
+        lines.extend(alarms_section(&self.alarms, &i18n));
+
         // Tags
         if !self.tags.is_empty() {
             lines.push(String::new());
@@ -174,6 +179,7 @@ impl AsgDetail {
 #[cfg(test)]
 mod tests {
     use super::{AsgDetail, ScalingPolicy};
+    use crate::aws_cli::cloudwatch::CloudWatchAlarm;
     use crate::i18n::Language;
 
     fn sample_asg_detail() -> AsgDetail {
@@ -206,6 +212,7 @@ mod tests {
                 ("Name".to_string(), "asg-prod".to_string()),
                 ("Env".to_string(), "prod".to_string()),
             ],
+            alarms: vec![],
         }
     }
 
@@ -255,6 +262,37 @@ mod tests {
         assert!(!markdown.contains("| Name | asg-prod |"));
     }
 
+    #[test]
+    fn scenario_asg_markdown_alarms_section() {
+        let mut detail = sample_asg_detail();
+        detail.alarms = vec![CloudWatchAlarm {
+            name: "asg-prod-cpu-high".to_string(),
+            arn: String::new(),
+            description: String::new(),
+            namespace: "AWS/EC2".to_string(),
+            metric: "CPUUtilization".to_string(),
+            statistic: "Average".to_string(),
+            period: Some(60),
+            evaluation_periods: Some(3),
+            comparison: "GreaterThanThreshold".to_string(),
+            threshold: Some(70.5),
+            state: "ALARM".to_string(),
+            state_reason: String::new(),
+            dimensions: vec![("AutoScalingGroupName".to_string(), "asg-prod".to_string())],
+            alarm_actions: vec![
+                "arn:aws:autoscaling:ap-northeast-2:123456789012:scalingPolicy:abcd:autoScalingGroupName/asg-prod:policyName/scale-out".to_string(),
+            ],
+            ok_actions: vec![],
+            insufficient_data_actions: vec![],
+            updated: String::new(),
+        }];
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("### 경보"));
+        assert!(markdown.contains(
+            "| asg-prod-cpu-high | `CPUUtilization` (Average) | > | 70.5 | ALARM | scale-out |"
+        ));
+    }
+
     #[test]
     fn scenario_asg_markdown_without_optional_sections() {
         let mut detail = sample_asg_detail();
@@ -269,5 +307,6 @@ mod tests {
         assert!(!markdown.contains("### 대상 그룹"));
         assert!(!markdown.contains("### 조정 정책"));
         assert!(!markdown.contains("### 태그"));
+        assert!(!markdown.contains("### 경보"));
     }
 }
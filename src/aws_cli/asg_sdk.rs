@@ -1,51 +1,21 @@
 use crate::aws_cli::asg::{AsgDetail, ScalingPolicy};
-use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config};
-use aws_sdk_autoscaling::Client;
-
-/// List all Auto Scaling Groups using AWS SDK
-pub fn list_auto_scaling_groups() -> Vec<AwsResource> {
-    get_runtime().block_on(list_auto_scaling_groups_async())
-}
-
-async fn list_auto_scaling_groups_async() -> Vec<AwsResource> {
-    let config = get_sdk_config().await;
-    let client = Client::new(&config);
-
-    let result = client
-        .describe_auto_scaling_groups()
-        .into_paginator()
-        .items()
-        .send()
-        .try_collect()
-        .await;
-
-    match result {
-        Ok(groups) => groups.iter().map(map_asg_resource).collect(),
-        Err(e) => {
-            tracing::error!("Error listing auto scaling groups: {:?}", e);
-            eprintln!("Error listing auto scaling groups: {:?}", e);
-            Vec::new()
-        }
-    }
-}
-
-/// Get Auto Scaling Group detail using AWS SDK
-pub fn get_asg_detail(asg_name: &str) -> Option<AsgDetail> {
-    get_runtime().block_on(get_asg_detail_async(asg_name))
+use crate::aws_cli::cloudwatch::{DIMENSION_ASG_NAME, list_alarms_for};
+use crate::aws_cli::common::AwsResource;
+use crate::aws_cli::provider::AwsProvider;
+
+/// List all Auto Scaling Groups
+pub fn list_auto_scaling_groups(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    provider
+        .describe_auto_scaling_groups(&[])
+        .iter()
+        .map(map_asg_resource)
+        .collect()
 }
 
-async fn get_asg_detail_async(asg_name: &str) -> Option<AsgDetail> {
-    let config = get_sdk_config().await;
-    let client = Client::new(&config);
-
-    let result = client
-        .describe_auto_scaling_groups()
-        .auto_scaling_group_names(asg_name)
-        .send()
-        .await
-        .ok()?;
-
-    let asg = result.auto_scaling_groups().first()?;
+/// Get Auto Scaling Group detail
+pub fn get_asg_detail(provider: &dyn AwsProvider, asg_name: &str) -> Option<AsgDetail> {
+    let groups = provider.describe_auto_scaling_groups(&[asg_name.to_string()]);
+    let asg = groups.first()?;
 
     let name = asg
         .auto_scaling_group_name()
@@ -53,21 +23,10 @@ async fn get_asg_detail_async(asg_name: &str) -> Option<AsgDetail> {
         .to_string();
     let arn = asg.auto_scaling_group_arn().unwrap_or_default().to_string();
 
-    let scaling_policies = get_scaling_policies_async(&client, &name).await;
-    Some(map_asg_detail(asg, scaling_policies, Some((name, arn))))
-}
-
-async fn get_scaling_policies_async(client: &Client, asg_name: &str) -> Vec<ScalingPolicy> {
-    let result = client
-        .describe_policies()
-        .auto_scaling_group_name(asg_name)
-        .send()
-        .await;
-
-    match result {
-        Ok(output) => map_scaling_policies(output.scaling_policies()),
-        Err(_) => Vec::new(),
-    }
+    let scaling_policies = map_scaling_policies(&provider.describe_policies(&name));
+    let mut detail = map_asg_detail(asg, scaling_policies, Some((name, arn)));
+    detail.alarms = list_alarms_for(provider, &[(DIMENSION_ASG_NAME, detail.name.clone())]);
+    Some(detail)
 }
 
 fn map_asg_resource(asg: &aws_sdk_autoscaling::types::AutoScalingGroup) -> AwsResource {
@@ -85,6 +44,7 @@ fn map_asg_resource(asg: &aws_sdk_autoscaling::types::AutoScalingGroup) -> AwsRe
         state: format!("Desired: {} (Min: {}, Max: {})", desired, min, max),
         az: String::new(),
         cidr: arn,
+        region: None,
     }
 }
 
@@ -189,6 +149,7 @@ fn map_asg_detail(
         created_time,
         scaling_policies,
         tags,
+        alarms: Vec::new(),
     }
 }
 
@@ -0,0 +1,353 @@
+use crate::aws_cli::account::{assume_role_chain, current_role_chain, resolve_profile};
+use crate::aws_cli::common::{current_profile, effective_region, load_sdk_config};
+use crate::aws_cli::mfa;
+use crate::aws_cli::partition::Partition;
+use aws_config::SdkConfig;
+use aws_credential_types::Credentials;
+use aws_credential_types::provider::{
+    ProvideCredentials, SharedCredentialsProvider, future as provider_future,
+};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+static CLIENT_CACHE: LazyLock<ClientCache> = LazyLock::new(ClientCache::default);
+
+/// (프로필, 역할 체인, 파티션)별 자격 증명 공급자. 리전마다 config를 새로 만들어도 같은 자격 증명을 씀
+type CredentialsKey = (String, Vec<String>, Partition);
+static CREDENTIALS: LazyLock<Mutex<HashMap<CredentialsKey, SharedCredentialsProvider>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 만료 직전 자격 증명으로 요청이 실패하지 않도록 남겨 두는 시간
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// 캐시 키: SDK config는 프로필, 맡을 역할, 리전으로 결정됨
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ClientKey {
+    pub profile: String,
+    pub role_chain: Vec<String>,
+    pub region: String,
+}
+
+impl ClientKey {
+    /// 현재 스레드 기준 프로필/역할/리전 (`with_account`, `with_region` 오버라이드 포함).
+    /// 가상 프로필은 시작 프로필과 역할로 풀어 씀
+    pub fn current() -> Self {
+        let (profile, role_chain) = resolve_profile(current_profile(), current_role_chain());
+        Self {
+            profile,
+            role_chain,
+            region: effective_region(),
+        }
+    }
+
+    /// 프로필 config를 읽고 역할 체인을 차례로 맡은 SDK config.
+    /// MFA 프로필은 SDK 대신 MFA 세션을 자격 증명으로 씀
+    pub async fn load_config(&self) -> SdkConfig {
+        let mut config = load_sdk_config(&self.profile, &self.region).await;
+        if let Some(provider) = mfa::credentials_provider(&self.profile) {
+            config = config.to_builder().credentials_provider(provider).build();
+        }
+        let config = assume_role_chain(config, &self.role_chain).await;
+        match config.credentials_provider() {
+            Some(provider) => config
+                .to_builder()
+                .credentials_provider(self.shared_credentials(provider))
+                .build(),
+            None => config,
+        }
+    }
+
+    /// 같은 프로필/역할/파티션이면 처음 만든 캐시 공급자를 재사용
+    fn shared_credentials(&self, provider: SharedCredentialsProvider) -> SharedCredentialsProvider {
+        let key = (
+            self.profile.clone(),
+            self.role_chain.clone(),
+            Partition::from_region(&self.region),
+        );
+        let Ok(mut providers) = CREDENTIALS.lock() else {
+            return provider;
+        };
+        providers
+            .entry(key)
+            .or_insert_with(|| SharedCredentialsProvider::new(CachingCredentials::new(provider)))
+            .clone()
+    }
+}
+
+/// 자격 증명을 만료 전까지 재사용하는 공급자. 동시에 요청해도 원래 공급자는 한 번만 호출
+/// (역할 체인이면 AssumeRole, SSO면 토큰 교환)
+#[derive(Debug)]
+struct CachingCredentials {
+    inner: SharedCredentialsProvider,
+    cached: Arc<tokio::sync::Mutex<Option<Credentials>>>,
+}
+
+impl CachingCredentials {
+    fn new(inner: SharedCredentialsProvider) -> Self {
+        Self {
+            inner,
+            cached: Arc::default(),
+        }
+    }
+}
+
+fn is_fresh(credentials: &Credentials, now: SystemTime) -> bool {
+    credentials
+        .expiry()
+        .is_none_or(|expiry| expiry > now + EXPIRY_MARGIN)
+}
+
+impl ProvideCredentials for CachingCredentials {
+    fn provide_credentials<'a>(&'a self) -> provider_future::ProvideCredentials<'a>
+    where
+        Self: 'a,
+    {
+        provider_future::ProvideCredentials::new(async move {
+            let mut cached = self.cached.lock().await;
+            if let Some(credentials) = cached.as_ref()
+                && is_fresh(credentials, SystemTime::now())
+            {
+                return Ok(credentials.clone());
+            }
+            let credentials = self.inner.provide_credentials().await?;
+            *cached = Some(credentials.clone());
+            Ok(credentials)
+        })
+    }
+}
+
+struct CacheEntry {
+    config: SdkConfig,
+    clients: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+/// (프로필, 리전)별 SDK config와 서비스 클라이언트 저장소
+#[derive(Default)]
+pub(crate) struct ClientCache {
+    entries: Mutex<HashMap<ClientKey, CacheEntry>>,
+}
+
+impl ClientCache {
+    fn config(&self, key: &ClientKey) -> Option<SdkConfig> {
+        let entries = self.entries.lock().ok()?;
+        entries.get(key).map(|entry| entry.config.clone())
+    }
+
+    fn insert(&self, key: ClientKey, config: SdkConfig) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.entry(key).or_insert(CacheEntry {
+                config,
+                clients: HashMap::new(),
+            });
+        }
+    }
+
+    /// 캐시된 클라이언트를 반환하고, 없으면 `build`로 만들어 저장.
+    /// 그 사이 캐시가 비워졌으면 저장하지 않고 새로 만든 클라이언트만 반환
+    fn client<C: Clone + Send + Sync + 'static>(
+        &self,
+        key: &ClientKey,
+        config: &SdkConfig,
+        build: impl FnOnce(&SdkConfig) -> C,
+    ) -> C {
+        let Ok(mut entries) = self.entries.lock() else {
+            return build(config);
+        };
+        let Some(entry) = entries.get_mut(key) else {
+            return build(config);
+        };
+        if let Some(client) = entry
+            .clients
+            .get(&TypeId::of::<C>())
+            .and_then(|client| client.downcast_ref::<C>())
+        {
+            return client.clone();
+        }
+        let client = build(config);
+        entry
+            .clients
+            .insert(TypeId::of::<C>(), Box::new(client.clone()));
+        client
+    }
+
+    fn clear(&self) -> usize {
+        self.entries
+            .lock()
+            .map(|mut entries| {
+                let count = entries.len();
+                entries.clear();
+                count
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// 한 요청에서 사용할 SDK config와 캐시된 서비스 클라이언트 핸들
+pub(crate) struct SdkClients {
+    key: ClientKey,
+    config: SdkConfig,
+    pub cached: bool,
+}
+
+impl SdkClients {
+    /// 서비스 클라이언트 (예: `clients.get(aws_sdk_ec2::Client::new)`)
+    pub fn get<C: Clone + Send + Sync + 'static>(&self, build: impl FnOnce(&SdkConfig) -> C) -> C {
+        CLIENT_CACHE.client(&self.key, &self.config, build)
+    }
+}
+
+/// 현재 프로필/리전의 SDK 클라이언트 핸들. 캐시에 없으면 config를 읽고 자격 증명을
+/// 확인한 뒤 저장 (자격 증명 실패는 캐시하지 않아 다음 요청에서 다시 시도)
+pub(crate) async fn load_clients() -> Result<SdkClients, String> {
+    let key = ClientKey::current();
+    if let Some(config) = CLIENT_CACHE.config(&key) {
+        return Ok(SdkClients {
+            key,
+            config,
+            cached: true,
+        });
+    }
+
+    let started_at = Instant::now();
+    let config = key.load_config().await;
+    let config_ms = started_at.elapsed().as_millis();
+
+    if let Some(credentials_provider) = config.credentials_provider() {
+        credentials_provider
+            .provide_credentials()
+            .await
+            .map_err(|error| error.to_string())?;
+    }
+    tracing::info!(
+        profile = %key.profile,
+        roles = key.role_chain.len(),
+        region = %key.region,
+        config_ms,
+        credentials_ms = started_at.elapsed().as_millis() - config_ms,
+        "AWS SDK config loaded and cached"
+    );
+
+    CLIENT_CACHE.insert(key.clone(), config.clone());
+    Ok(SdkClients {
+        key,
+        config,
+        cached: false,
+    })
+}
+
+/// 프로필이나 자격 증명이 바뀌면 캐시된 config, 클라이언트, 자격 증명을 모두 버림
+/// (리전은 캐시 키에 들어 있어 리전을 바꿀 때는 비우지 않음)
+pub(crate) fn invalidate_client_cache() {
+    if let Ok(mut providers) = CREDENTIALS.lock() {
+        providers.clear();
+    }
+    let cleared = CLIENT_CACHE.clear();
+    if cleared > 0 {
+        tracing::debug!(cleared, "AWS SDK client cache invalidated");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CachingCredentials, ClientCache, ClientKey};
+    use aws_config::SdkConfig;
+    use aws_credential_types::Credentials;
+    use aws_credential_types::provider::{
+        ProvideCredentials, SharedCredentialsProvider, future as provider_future,
+    };
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::{Duration, SystemTime};
+
+    fn key(profile: &str, region: &str) -> ClientKey {
+        ClientKey {
+            profile: profile.to_string(),
+            role_chain: Vec::new(),
+            region: region.to_string(),
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct FakeClient(usize);
+
+    #[test]
+    fn clients_are_built_once_per_profile_and_region() {
+        let cache = ClientCache::default();
+        let builds = AtomicUsize::new(0);
+        let build = |_: &SdkConfig| FakeClient(builds.fetch_add(1, Ordering::SeqCst));
+        let config = SdkConfig::builder().build();
+
+        let dev = key("dev", "ap-northeast-2");
+        assert!(cache.config(&dev).is_none());
+        cache.insert(dev.clone(), config.clone());
+        assert!(cache.config(&dev).is_some());
+
+        assert_eq!(cache.client(&dev, &config, build), FakeClient(0));
+        assert_eq!(cache.client(&dev, &config, build), FakeClient(0));
+
+        // 다른 리전은 별도 항목이라 캐시에 없으면 저장하지 않고 매번 생성
+        let other = key("dev", "us-east-1");
+        assert_eq!(cache.client(&other, &config, build), FakeClient(1));
+        assert_eq!(cache.client(&other, &config, build), FakeClient(2));
+        assert_eq!(builds.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn clear_drops_cached_configs_and_clients() {
+        let cache = ClientCache::default();
+        let config = SdkConfig::builder().build();
+        cache.insert(key("dev", "ap-northeast-2"), config.clone());
+        cache.insert(key("prod", "ap-northeast-2"), config);
+
+        assert_eq!(cache.clear(), 2);
+        assert!(cache.config(&key("dev", "ap-northeast-2")).is_none());
+        assert_eq!(cache.clear(), 0);
+    }
+
+    /// 호출할 때마다 `expires_in` 뒤에 만료되는 새 자격 증명을 내주는 공급자
+    #[derive(Debug)]
+    struct CountingProvider {
+        calls: Arc<AtomicUsize>,
+        expires_in: Duration,
+    }
+
+    impl ProvideCredentials for CountingProvider {
+        fn provide_credentials<'a>(&'a self) -> provider_future::ProvideCredentials<'a>
+        where
+            Self: 'a,
+        {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            provider_future::ProvideCredentials::ready(Ok(Credentials::new(
+                "AKIA",
+                "secret",
+                None,
+                Some(SystemTime::now() + self.expires_in),
+                "test",
+            )))
+        }
+    }
+
+    #[test]
+    fn caching_credentials_reuse_credentials_until_they_expire() {
+        let runtime = crate::aws_cli::common::get_runtime();
+        let provide = |expires_in: Duration| {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let provider =
+                CachingCredentials::new(SharedCredentialsProvider::new(CountingProvider {
+                    calls: calls.clone(),
+                    expires_in,
+                }));
+            for _ in 0..3 {
+                runtime
+                    .block_on(provider.provide_credentials())
+                    .expect("credentials");
+            }
+            calls.load(Ordering::SeqCst)
+        };
+        assert_eq!(provide(Duration::from_secs(3600)), 1);
+        // 만료 여유 시간 안이면 매번 새로 받음
+        assert_eq!(provide(Duration::from_secs(30)), 3);
+    }
+}
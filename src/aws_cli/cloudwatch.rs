@@ -0,0 +1,305 @@
+pub use crate::aws_cli::cloudwatch_sdk::{get_alarm_detail, list_alarms, list_alarms_for};
+use crate::aws_cli::partition::Arn;
+use crate::i18n::{I18n, Language};
+
+// 리소스를 참조하는 경보를 찾을 때 사용하는 CloudWatch 차원 이름
+pub const DIMENSION_INSTANCE_ID: &str = "InstanceId";
+pub const DIMENSION_LOAD_BALANCER: &str = "LoadBalancer";
+pub const DIMENSION_TARGET_GROUP: &str = "TargetGroup";
+pub const DIMENSION_ASG_NAME: &str = "AutoScalingGroupName";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloudWatchAlarm {
+    pub name: String,
+    pub arn: String,
+    pub description: String,
+    pub namespace: String,
+    pub metric: String,
+    pub statistic: String,
+    pub period: Option<i32>,
+    pub evaluation_periods: Option<i32>,
+    pub comparison: String,
+    pub threshold: Option<f64>,
+    pub state: String,
+    pub state_reason: String,
+    pub dimensions: Vec<(String, String)>,
+    pub alarm_actions: Vec<String>,
+    pub ok_actions: Vec<String>,
+    pub insufficient_data_actions: Vec<String>,
+    pub updated: String,
+}
+
+/// ELBv2 ARN에서 CloudWatch 차원 값 추출
+/// (`.../loadbalancer/app/name/id` -> `app/name/id`, `...:targetgroup/name/id` -> `targetgroup/name/id`)
+pub fn elb_dimension_value(arn: &str) -> String {
+    let resource = Arn::parse(arn).map_or(arn, |arn| arn.resource);
+    resource
+        .strip_prefix("loadbalancer/")
+        .unwrap_or(resource)
+        .to_string()
+}
+
+fn comparison_symbol(comparison: &str) -> &str {
+    match comparison {
+        "GreaterThanOrEqualToThreshold" => ">=",
+        "GreaterThanThreshold" => ">",
+        "LessThanThreshold" => "<",
+        "LessThanOrEqualToThreshold" => "<=",
+        "LessThanLowerOrGreaterThanUpperThreshold" => "outside band",
+        "LessThanLowerThreshold" => "< lower band",
+        "GreaterThanUpperThreshold" => "> upper band",
+        other => other,
+    }
+}
+
+/// SNS 토픽/스케일링 정책 ARN을 표시용 이름으로 축약
+fn action_label(arn: &str) -> &str {
+    if let Some((_, policy)) = arn.split_once(":policyName/") {
+        return policy;
+    }
+    arn.rsplit(':').next().unwrap_or(arn)
+}
+
+impl CloudWatchAlarm {
+    pub fn matches_any(&self, dimensions: &[(&str, String)]) -> bool {
+        self.dimensions.iter().any(|(name, value)| {
+            dimensions
+                .iter()
+                .any(|(dim_name, dim_value)| name == dim_name && value == dim_value)
+        })
+    }
+
+    fn metric_label(&self) -> String {
+        if self.statistic.is_empty() {
+            format!("`{}`", self.metric)
+        } else {
+            format!("`{}` ({})", self.metric, self.statistic)
+        }
+    }
+
+    fn threshold_label(&self) -> String {
+        self.threshold
+            .map(|t| t.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    fn action_labels(&self) -> String {
+        if self.alarm_actions.is_empty() {
+            "-".to_string()
+        } else {
+            self.alarm_actions
+                .iter()
+                .map(|arn| action_label(arn))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.cloudwatch_alarm(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+        ];
+
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_state(), self.state));
+        if !self.state_reason.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_state_reason(),
+                self.state_reason
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_namespace(), self.namespace));
+        lines.push(format!("| {} | `{}` |", i18n.md_metric(), self.metric));
+        if !self.statistic.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_statistic(), self.statistic));
+        }
+        if let Some(period) = self.period {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_period(),
+                i18n.asg_seconds(period)
+            ));
+        }
+        if let Some(periods) = self.evaluation_periods {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_evaluation_periods(),
+                periods
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_comparison(),
+            comparison_symbol(&self.comparison)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_threshold(),
+            self.threshold_label()
+        ));
+        if !self.dimensions.is_empty() {
+            let dimensions: Vec<String> = self
+                .dimensions
+                .iter()
+                .map(|(name, value)| format!("{}=`{}`", name, value))
+                .collect();
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_dimensions(),
+                dimensions.join(", ")
+            ));
+        }
+        if !self.updated.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_last_modified(),
+                self.updated
+            ));
+        }
+
+        // Actions
+        let actions: Vec<(&str, &String)> = [
+            ("ALARM", &self.alarm_actions),
+            ("OK", &self.ok_actions),
+            ("INSUFFICIENT_DATA", &self.insufficient_data_actions),
+        ]
+        .into_iter()
+        .flat_map(|(state, arns)| arns.iter().map(move |arn| (state, arn)))
+        .collect();
+        if !actions.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_actions()));
+            lines.push(format!("| {} | ARN |", i18n.md_state()));
+            lines.push("|:---|:---|".to_string());
+            for (state, arn) in actions {
+                lines.push(format!("| {} | {} |", state, arn));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+/// EC2/LB/ASG 상세 문서에 포함되는 "Alarms" 섹션 (경보가 없으면 빈 목록)
+pub fn alarms_section(alarms: &[CloudWatchAlarm], i18n: &I18n) -> Vec<String> {
+    if alarms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![
+        String::new(),
+        format!("### {}\n", i18n.md_alarms()),
+        format!(
+            "| {} | {} | {} | {} | {} | {} |",
+            i18n.md_name(),
+            i18n.md_metric(),
+            i18n.md_comparison(),
+            i18n.md_threshold(),
+            i18n.md_state(),
+            i18n.md_actions()
+        ),
+        "|:---|:---|:---:|---:|:---|:---|".to_string(),
+    ];
+    for alarm in alarms {
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} | {} |",
+            alarm.name,
+            alarm.metric_label(),
+            comparison_symbol(&alarm.comparison),
+            alarm.threshold_label(),
+            alarm.state,
+            alarm.action_labels()
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CloudWatchAlarm, DIMENSION_INSTANCE_ID, DIMENSION_TARGET_GROUP, alarms_section,
+        elb_dimension_value,
+    };
+    use crate::i18n::{I18n, Language};
+
+    fn sample_alarm() -> CloudWatchAlarm {
+        CloudWatchAlarm {
+            name: "web-cpu-high".to_string(),
+            arn: "arn:aws:cloudwatch:ap-northeast-2:123456789012:alarm:web-cpu-high".to_string(),
+            description: "CPU over 80%".to_string(),
+            namespace: "AWS/EC2".to_string(),
+            metric: "CPUUtilization".to_string(),
+            statistic: "Average".to_string(),
+            period: Some(300),
+            evaluation_periods: Some(2),
+            comparison: "GreaterThanOrEqualToThreshold".to_string(),
+            threshold: Some(80.0),
+            state: "OK".to_string(),
+            state_reason: String::new(),
+            dimensions: vec![("InstanceId".to_string(), "i-0123".to_string())],
+            alarm_actions: vec!["arn:aws:sns:ap-northeast-2:123456789012:ops-alerts".to_string()],
+            ok_actions: vec![],
+            insufficient_data_actions: vec![],
+            updated: "2026-09-30".to_string(),
+        }
+    }
+
+    #[test]
+    fn elb_dimension_value_strips_arn_prefix() {
+        assert_eq!(
+            elb_dimension_value(
+                "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/web/50dc6c495c0c9188"
+            ),
+            "app/web/50dc6c495c0c9188"
+        );
+        assert_eq!(
+            elb_dimension_value(
+                "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:targetgroup/web-blue/1111111111111111"
+            ),
+            "targetgroup/web-blue/1111111111111111"
+        );
+    }
+
+    #[test]
+    fn matches_any_compares_dimension_name_and_value() {
+        let alarm = sample_alarm();
+        assert!(alarm.matches_any(&[(DIMENSION_INSTANCE_ID, "i-0123".to_string())]));
+        assert!(!alarm.matches_any(&[(DIMENSION_INSTANCE_ID, "i-9999".to_string())]));
+        assert!(!alarm.matches_any(&[(DIMENSION_TARGET_GROUP, "i-0123".to_string())]));
+    }
+
+    #[test]
+    fn alarms_section_renders_metric_threshold_state_and_actions() {
+        let i18n = I18n::new(Language::English);
+        assert!(alarms_section(&[], &i18n).is_empty());
+
+        let md = alarms_section(&[sample_alarm()], &i18n).join("\n");
+        assert!(md.contains("### Alarms"));
+        assert!(
+            md.contains(
+                "| web-cpu-high | `CPUUtilization` (Average) | >= | 80 | OK | ops-alerts |"
+            )
+        );
+    }
+
+    #[test]
+    fn alarm_markdown_lists_dimensions_and_actions() {
+        let md = sample_alarm().to_markdown(Language::English);
+        assert!(md.contains("## CloudWatch Alarm (web-cpu-high)"));
+        assert!(md.contains("| Period | 300s |"));
+        assert!(md.contains("| Dimensions | InstanceId=`i-0123` |"));
+        assert!(md.contains("| ALARM | arn:aws:sns:ap-northeast-2:123456789012:ops-alerts |"));
+    }
+}
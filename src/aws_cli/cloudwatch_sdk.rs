@@ -0,0 +1,242 @@
+use crate::aws_cli::cloudwatch::CloudWatchAlarm;
+use crate::aws_cli::common::{AwsResource, format_date};
+use crate::aws_cli::provider::AwsProvider;
+use aws_sdk_cloudwatch::types::{Dimension, MetricAlarm};
+
+/// List CloudWatch metric alarms
+pub fn list_alarms(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut resources: Vec<AwsResource> = provider
+        .describe_metric_alarms(&[])
+        .iter()
+        .map(map_alarm_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get CloudWatch alarm detail
+pub fn get_alarm_detail(provider: &dyn AwsProvider, alarm_name: &str) -> Option<CloudWatchAlarm> {
+    provider
+        .describe_metric_alarms(&[alarm_name.to_string()])
+        .first()
+        .map(map_alarm)
+}
+
+/// 주어진 차원(InstanceId, LoadBalancer, TargetGroup, AutoScalingGroupName 등) 중
+/// 하나라도 참조하는 경보 목록
+pub fn list_alarms_for(
+    provider: &dyn AwsProvider,
+    dimensions: &[(&str, String)],
+) -> Vec<CloudWatchAlarm> {
+    if dimensions.is_empty() {
+        return Vec::new();
+    }
+    filter_alarms(&provider.describe_metric_alarms(&[]), dimensions)
+}
+
+fn filter_alarms(alarms: &[MetricAlarm], dimensions: &[(&str, String)]) -> Vec<CloudWatchAlarm> {
+    let mut matched: Vec<CloudWatchAlarm> = alarms
+        .iter()
+        .map(map_alarm)
+        .filter(|alarm| alarm.matches_any(dimensions))
+        .collect();
+    matched.sort_by(|a, b| a.name.cmp(&b.name));
+    matched
+}
+
+fn map_dimensions(dimensions: &[Dimension]) -> impl Iterator<Item = (String, String)> + '_ {
+    dimensions.iter().map(|dim| {
+        (
+            dim.name().unwrap_or_default().to_string(),
+            dim.value().unwrap_or_default().to_string(),
+        )
+    })
+}
+
+fn map_alarm_resource(alarm: &MetricAlarm) -> AwsResource {
+    let name = alarm.alarm_name().unwrap_or_default().to_string();
+    AwsResource {
+        name: name.clone(),
+        id: name,
+        state: alarm
+            .state_value()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        az: alarm.metric_name().unwrap_or_default().to_string(),
+        cidr: alarm.namespace().unwrap_or_default().to_string(),
+        region: None,
+    }
+}
+
+fn map_alarm(alarm: &MetricAlarm) -> CloudWatchAlarm {
+    let mut dimensions: Vec<(String, String)> = map_dimensions(alarm.dimensions()).collect();
+    let mut namespace = alarm.namespace().unwrap_or_default().to_string();
+    let mut metric = alarm.metric_name().unwrap_or_default().to_string();
+    let mut statistic = alarm
+        .statistic()
+        .map(|s| s.as_str().to_string())
+        .or_else(|| alarm.extended_statistic().map(|s| s.to_string()))
+        .unwrap_or_default();
+
+    // 지표 수식(metric math) 경보는 차원/지표가 Metrics 쿼리 안에 있음
+    if !alarm.metrics().is_empty() {
+        let stats: Vec<_> = alarm
+            .metrics()
+            .iter()
+            .filter_map(|query| query.metric_stat())
+            .collect();
+        for stat in &stats {
+            if let Some(m) = stat.metric() {
+                dimensions.extend(map_dimensions(m.dimensions()));
+            }
+        }
+        if metric.is_empty() {
+            metric = alarm
+                .metrics()
+                .iter()
+                .map(|query| {
+                    query
+                        .metric_stat()
+                        .and_then(|stat| stat.metric())
+                        .and_then(|m| m.metric_name())
+                        .or(query.expression())
+                        .unwrap_or(query.id().unwrap_or_default())
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+        }
+        if namespace.is_empty()
+            && let Some(ns) = stats
+                .iter()
+                .find_map(|stat| stat.metric().and_then(|m| m.namespace()))
+        {
+            namespace = ns.to_string();
+        }
+        if statistic.is_empty()
+            && let [stat] = stats.as_slice()
+        {
+            statistic = stat.stat().unwrap_or_default().to_string();
+        }
+    }
+
+    CloudWatchAlarm {
+        name: alarm.alarm_name().unwrap_or_default().to_string(),
+        arn: alarm.alarm_arn().unwrap_or_default().to_string(),
+        description: alarm.alarm_description().unwrap_or_default().to_string(),
+        namespace,
+        metric,
+        statistic,
+        period: alarm.period(),
+        evaluation_periods: alarm.evaluation_periods(),
+        comparison: alarm
+            .comparison_operator()
+            .map(|c| c.as_str().to_string())
+            .unwrap_or_default(),
+        threshold: alarm.threshold(),
+        state: alarm
+            .state_value()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        state_reason: alarm.state_reason().unwrap_or_default().to_string(),
+        dimensions,
+        alarm_actions: alarm.alarm_actions().to_vec(),
+        ok_actions: alarm.ok_actions().to_vec(),
+        insufficient_data_actions: alarm.insufficient_data_actions().to_vec(),
+        updated: format_date(alarm.alarm_configuration_updated_timestamp()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{filter_alarms, map_alarm, map_alarm_resource};
+    use crate::aws_cli::cloudwatch::{DIMENSION_ASG_NAME, DIMENSION_INSTANCE_ID};
+    use aws_sdk_cloudwatch::types::{
+        ComparisonOperator, Dimension, Metric, MetricAlarm, MetricDataQuery, MetricStat,
+        StateValue, Statistic,
+    };
+
+    fn dimension(name: &str, value: &str) -> Dimension {
+        Dimension::builder().name(name).value(value).build()
+    }
+
+    fn cpu_alarm(name: &str, instance_id: &str) -> MetricAlarm {
+        MetricAlarm::builder()
+            .alarm_name(name)
+            .namespace("AWS/EC2")
+            .metric_name("CPUUtilization")
+            .statistic(Statistic::Average)
+            .period(300)
+            .evaluation_periods(2)
+            .threshold(80.0)
+            .comparison_operator(ComparisonOperator::GreaterThanOrEqualToThreshold)
+            .state_value(StateValue::Alarm)
+            .dimensions(dimension("InstanceId", instance_id))
+            .alarm_actions("arn:aws:sns:ap-northeast-2:123456789012:ops-alerts")
+            .build()
+    }
+
+    #[test]
+    fn map_alarm_resource_shows_state_and_metric() {
+        let resource = map_alarm_resource(&cpu_alarm("web-cpu-high", "i-0123"));
+        assert_eq!(resource.id, "web-cpu-high");
+        assert_eq!(resource.state, "ALARM");
+        assert_eq!(resource.az, "CPUUtilization");
+        assert_eq!(resource.cidr, "AWS/EC2");
+    }
+
+    #[test]
+    fn map_alarm_reads_condition_and_actions() {
+        let alarm = map_alarm(&cpu_alarm("web-cpu-high", "i-0123"));
+        assert_eq!(alarm.statistic, "Average");
+        assert_eq!(alarm.comparison, "GreaterThanOrEqualToThreshold");
+        assert_eq!(alarm.threshold, Some(80.0));
+        assert_eq!(
+            alarm.dimensions,
+            vec![("InstanceId".to_string(), "i-0123".to_string())]
+        );
+        assert_eq!(alarm.alarm_actions.len(), 1);
+    }
+
+    #[test]
+    fn map_alarm_collects_metric_math_dimensions() {
+        let query = MetricDataQuery::builder()
+            .id("m1")
+            .metric_stat(
+                MetricStat::builder()
+                    .metric(
+                        Metric::builder()
+                            .namespace("AWS/AutoScaling")
+                            .metric_name("GroupInServiceInstances")
+                            .dimensions(dimension("AutoScalingGroupName", "asg-prod"))
+                            .build(),
+                    )
+                    .stat("Minimum")
+                    .period(60)
+                    .build(),
+            )
+            .build();
+        let alarm = map_alarm(
+            &MetricAlarm::builder()
+                .alarm_name("asg-capacity-low")
+                .metrics(query)
+                .build(),
+        );
+        assert_eq!(alarm.metric, "GroupInServiceInstances");
+        assert_eq!(alarm.namespace, "AWS/AutoScaling");
+        assert_eq!(alarm.statistic, "Minimum");
+        assert!(alarm.matches_any(&[(DIMENSION_ASG_NAME, "asg-prod".to_string())]));
+    }
+
+    #[test]
+    fn filter_alarms_keeps_only_referencing_alarms_sorted() {
+        let alarms = vec![
+            cpu_alarm("web-status-check", "i-0123"),
+            cpu_alarm("other-cpu-high", "i-9999"),
+            cpu_alarm("web-cpu-high", "i-0123"),
+        ];
+        let matched = filter_alarms(&alarms, &[(DIMENSION_INSTANCE_ID, "i-0123".to_string())]);
+        let names: Vec<&str> = matched.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["web-cpu-high", "web-status-check"]);
+    }
+}
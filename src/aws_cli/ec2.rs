@@ -1,85 +1,12 @@
-use crate::aws_cli::common::{AwsResource, extract_json_value, extract_tags, parse_name_tag};
-use crate::aws_cli::iam::IamRoleDetail;
+use crate::aws_cli::cloudwatch::{
+    CloudWatchAlarm, DIMENSION_INSTANCE_ID, alarms_section, list_alarms_for,
+};
+use crate::aws_cli::common::{AwsResource, ec2_name_tag, ec2_tags};
+use crate::aws_cli::iam::{IamRoleDetail, get_iam_role_detail};
+use crate::aws_cli::provider::{AwsProvider, Ec2Filter};
 use crate::i18n::{I18n, Language};
+use aws_sdk_ec2::types::{Instance, Volume};
 use base64::{Engine as _, engine::general_purpose};
-use serde::Deserialize;
-
-#[cfg(not(test))]
-mod cli_adapter {
-    pub(super) fn run(args: &[&str]) -> Option<String> {
-        crate::aws_cli::common::run_aws_cli(args)
-    }
-}
-
-#[cfg(test)]
-mod cli_adapter {
-    use std::collections::HashMap;
-    use std::sync::{Mutex, OnceLock};
-
-    static RESPONSES: OnceLock<Mutex<HashMap<String, Option<String>>>> = OnceLock::new();
-
-    fn key(args: &[&str]) -> String {
-        args.join("\x1f")
-    }
-
-    pub(super) fn run(args: &[&str]) -> Option<String> {
-        RESPONSES
-            .get_or_init(|| Mutex::new(HashMap::new()))
-            .lock()
-            .ok()
-            .and_then(|map| map.get(&key(args)).cloned().flatten())
-    }
-
-    pub(super) fn set(args: &[&str], output: Option<&str>) {
-        if let Ok(mut map) = RESPONSES.get_or_init(|| Mutex::new(HashMap::new())).lock() {
-            map.insert(key(args), output.map(str::to_string));
-        }
-    }
-
-    pub(super) fn clear() {
-        if let Ok(mut map) = RESPONSES.get_or_init(|| Mutex::new(HashMap::new())).lock() {
-            map.clear();
-        }
-    }
-}
-
-#[cfg(not(test))]
-mod iam_adapter {
-    use crate::aws_cli::iam::{IamRoleDetail, get_iam_role_detail};
-
-    pub(super) fn get(role_name: &str) -> Option<IamRoleDetail> {
-        get_iam_role_detail(role_name)
-    }
-}
-
-#[cfg(test)]
-mod iam_adapter {
-    use crate::aws_cli::iam::IamRoleDetail;
-    use std::collections::HashMap;
-    use std::sync::{Mutex, OnceLock};
-
-    static RESPONSES: OnceLock<Mutex<HashMap<String, Option<IamRoleDetail>>>> = OnceLock::new();
-
-    pub(super) fn get(role_name: &str) -> Option<IamRoleDetail> {
-        RESPONSES
-            .get_or_init(|| Mutex::new(HashMap::new()))
-            .lock()
-            .ok()
-            .and_then(|map| map.get(role_name).cloned().flatten())
-    }
-
-    pub(super) fn set(role_name: &str, detail: Option<IamRoleDetail>) {
-        if let Ok(mut map) = RESPONSES.get_or_init(|| Mutex::new(HashMap::new())).lock() {
-            map.insert(role_name.to_string(), detail);
-        }
-    }
-
-    pub(super) fn clear() {
-        if let Ok(mut map) = RESPONSES.get_or_init(|| Mutex::new(HashMap::new())).lock() {
-            map.clear();
-        }
-    }
-}
 
 #[derive(Debug, Clone)]
 pub struct VolumeDetail {
@@ -117,6 +44,7 @@ pub struct Ec2Detail {
     pub tags: Vec<(String, String)>,
     pub volumes: Vec<VolumeDetail>,
     pub user_data: Option<String>,
+    pub alarms: Vec<CloudWatchAlarm>,
 }
 
 impl Ec2Detail {
@@ -277,6 +205,9 @@ impl Ec2Detail {
             }
         }
 
+        // Alarms section
+        lines.extend(alarms_section(&self.alarms, &i18n));
+
         // User Data section
         if let Some(ref user_data) = self.user_data {
             lines.push(String::new());
@@ -290,433 +221,306 @@ impl Ec2Detail {
     }
 }
 
-pub fn list_instances() -> Vec<AwsResource> {
-    let output = match cli_adapter::run(&[
-        "ec2",
-        "describe-instances",
-        "--query",
-        "Reservations[*].Instances[*].[InstanceId,State.Name,Tags]",
-        "--output",
-        "json",
-    ]) {
-        Some(o) => o,
-        None => return Vec::new(),
-    };
-
-    parse_instance_resources(&output)
+/// 계정에서 사용할 수 있는 리전 코드 (조회 실패 시 빈 목록)
+pub fn list_regions(provider: &dyn AwsProvider) -> Vec<String> {
+    let mut regions: Vec<String> = provider
+        .describe_regions()
+        .iter()
+        .filter_map(|region| region.region_name())
+        .map(str::to_string)
+        .collect();
+    regions.sort();
+    regions.dedup();
+    regions
 }
 
-fn parse_instance_resources(json: &str) -> Vec<AwsResource> {
-    let mut resources = Vec::new();
-    let mut search_start = 0;
-
-    while let Some(pos) = json[search_start..].find("i-") {
-        let start = search_start + pos;
-        if let Some(end) = json[start..].find('"') {
-            let id = &json[start..start + end];
-            // Validate it's an instance ID
-            if id.starts_with("i-")
-                && id.len() > 3
-                && id.chars().skip(2).all(|c| c.is_alphanumeric())
-            {
-                let section_start = json[..start].rfind('[').unwrap_or(0);
-                let section_end = json[start..]
-                    .find(']')
-                    .map(|p| start + p)
-                    .unwrap_or(json.len());
-                let section = &json[section_start..section_end];
-
-                let name = parse_name_tag(section);
-                let state = extract_state(section);
-
-                let display_name = if name.is_empty() {
-                    id.to_string()
-                } else {
-                    name
-                };
-
-                resources.push(AwsResource {
-                    name: format!("{} - {} - {}", display_name, id, state),
-                    id: id.to_string(),
-                    state: state.clone(),
-                    az: String::new(),
-                    cidr: String::new(),
-                });
-            }
-            search_start = start + end;
-        } else {
-            break;
-        }
-    }
-
+pub fn list_instances(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut instances = provider.describe_instances(&Ec2Filter::all());
+    instances.sort_by(|a, b| a.instance_id().cmp(&b.instance_id()));
+    let mut resources: Vec<AwsResource> = instances
+        .iter()
+        .filter(|instance| instance.instance_id().is_some())
+        .map(map_instance_resource)
+        .collect();
     resources.dedup_by(|a, b| a.id == b.id);
     resources
 }
 
-fn extract_state(json: &str) -> String {
-    for state in [
-        "running",
-        "stopped",
-        "pending",
-        "terminated",
-        "stopping",
-        "shutting-down",
-    ] {
-        if json.contains(state) {
-            return state.to_string();
-        }
+fn map_instance_resource(instance: &Instance) -> AwsResource {
+    let id = instance.instance_id().unwrap_or_default();
+    let name = ec2_name_tag(instance.tags());
+    let state = instance_state(instance);
+    let display_name = if name.is_empty() { id } else { &name };
+
+    AwsResource {
+        name: format!("{} - {} - {}", display_name, id, state),
+        id: id.to_string(),
+        state,
+        az: String::new(),
+        cidr: String::new(),
+        region: None,
     }
-    "unknown".to_string()
 }
 
-pub fn get_instance_detail(instance_id: &str) -> Option<Ec2Detail> {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-instances",
-        "--instance-ids",
-        instance_id,
-        "--output",
-        "json",
-    ])?;
-
-    let ami_id = extract_json_value(&output, "ImageId").unwrap_or_default();
-    let ami = if !ami_id.is_empty() {
-        get_ami_name(&ami_id)
-    } else {
-        String::new()
-    };
-
-    let vpc_id = extract_json_value(&output, "VpcId").unwrap_or_default();
-    let vpc = if !vpc_id.is_empty() {
-        get_vpc_name(&vpc_id)
-    } else {
-        String::new()
-    };
+fn instance_state(instance: &Instance) -> String {
+    instance
+        .state()
+        .and_then(|state| state.name())
+        .map(|name| name.as_str().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
 
-    let subnet_id = extract_json_value(&output, "SubnetId").unwrap_or_default();
-    let subnet = if !subnet_id.is_empty() {
-        get_subnet_name(&subnet_id)
-    } else {
-        String::new()
-    };
-    let volumes = get_instance_volumes(instance_id);
-    let user_data = get_instance_user_data(instance_id);
+pub fn get_instance_detail(provider: &dyn AwsProvider, instance_id: &str) -> Option<Ec2Detail> {
+    let instances = provider.describe_instances(&Ec2Filter::id(instance_id));
+    let instance = instances.first()?;
 
-    Some(parse_instance_detail_output(
-        instance_id,
-        &output,
-        ami,
-        vpc,
-        subnet,
-        volumes,
-        user_data,
-    ))
+    let ami = instance
+        .image_id()
+        .map(|ami_id| get_ami_name(provider, ami_id))
+        .unwrap_or_default();
+    let vpc = instance
+        .vpc_id()
+        .map(|vpc_id| get_vpc_name(provider, vpc_id))
+        .unwrap_or_default();
+    let subnet = instance
+        .subnet_id()
+        .map(|subnet_id| get_subnet_name(provider, subnet_id))
+        .unwrap_or_default();
+    let volumes = get_instance_volumes(provider, instance);
+    let user_data = decode_user_data(provider.describe_instance_user_data(instance_id).as_deref());
+
+    let mut detail =
+        map_instance_detail(instance_id, instance, ami, vpc, subnet, volumes, user_data);
+    detail.iam_role_detail = detail
+        .iam_role
+        .as_deref()
+        .and_then(|role_name| get_iam_role_detail(provider, role_name));
+    detail.alarms = list_alarms_for(
+        provider,
+        &[(DIMENSION_INSTANCE_ID, instance_id.to_string())],
+    );
+    Some(detail)
 }
 
-fn parse_instance_detail_output(
+fn map_instance_detail(
     instance_id: &str,
-    json: &str,
+    instance: &Instance,
     ami: String,
     vpc: String,
     subnet: String,
     volumes: Vec<VolumeDetail>,
     user_data: Option<String>,
 ) -> Ec2Detail {
-    let instance_type = extract_json_value(json, "InstanceType").unwrap_or_default();
-    let platform = extract_json_value(json, "Platform").unwrap_or_else(|| "Linux".to_string());
-    let architecture =
-        extract_json_value(json, "Architecture").unwrap_or_else(|| "x86_64".to_string());
-    let key_pair = extract_json_value(json, "KeyName").unwrap_or_else(|| "-".to_string());
-    let az = extract_json_value(json, "AvailabilityZone").unwrap_or_default();
-    let public_ip = extract_json_value(json, "PublicIpAddress").unwrap_or_else(|| "-".to_string());
-    let private_ip = extract_json_value(json, "PrivateIpAddress").unwrap_or_default();
-    let state = extract_state(json);
-    let ebs_optimized = json.contains("\"EbsOptimized\": true");
-    let monitoring = if json.contains("\"State\": \"enabled\"") {
-        "Enabled".to_string()
-    } else {
-        "Disabled".to_string()
+    let tags = ec2_tags(instance.tags());
+    let name = ec2_name_tag(instance.tags());
+    let monitoring = match instance.monitoring().and_then(|m| m.state()) {
+        Some(aws_sdk_ec2::types::MonitoringState::Enabled) => "Enabled",
+        _ => "Disabled",
     };
-    let iam_role = extract_json_value(json, "Arn")
+    let iam_role = instance
+        .iam_instance_profile()
+        .and_then(|profile| profile.arn())
         .and_then(|arn| arn.split('/').next_back().map(|s| s.to_string()));
-    let iam_role_detail = iam_role
-        .as_ref()
-        .and_then(|role_name| iam_adapter::get(role_name));
-    let launch_time = extract_json_value(json, "LaunchTime").unwrap_or_default();
-    let tags = extract_tags(json);
-    let name = tags
+    let mut security_groups: Vec<String> = Vec::new();
+    for group_name in instance
+        .security_groups()
         .iter()
-        .find(|(k, _)| k == "Name")
-        .map(|(_, v)| v.clone())
-        .unwrap_or_default();
-    let security_groups = extract_security_groups(json);
+        .filter_map(|sg| sg.group_name())
+    {
+        if !security_groups.iter().any(|sg| sg == group_name) {
+            security_groups.push(group_name.to_string());
+        }
+    }
 
     Ec2Detail {
         name,
         instance_id: instance_id.to_string(),
-        instance_type,
+        instance_type: instance
+            .instance_type()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_default(),
         ami,
-        platform,
-        architecture,
-        key_pair,
+        platform: instance
+            .platform()
+            .map(|p| p.as_str())
+            .unwrap_or("Linux")
+            .to_string(),
+        architecture: instance
+            .architecture()
+            .map(|a| a.as_str())
+            .unwrap_or("x86_64")
+            .to_string(),
+        key_pair: instance.key_name().unwrap_or("-").to_string(),
         vpc,
         subnet,
-        az,
-        public_ip,
-        private_ip,
+        az: instance
+            .placement()
+            .and_then(|p| p.availability_zone())
+            .unwrap_or_default()
+            .to_string(),
+        public_ip: instance.public_ip_address().unwrap_or("-").to_string(),
+        private_ip: instance
+            .private_ip_address()
+            .unwrap_or_default()
+            .to_string(),
         security_groups,
-        state,
-        ebs_optimized,
-        monitoring,
+        state: instance_state(instance),
+        ebs_optimized: instance.ebs_optimized().unwrap_or(false),
+        monitoring: monitoring.to_string(),
         iam_role,
-        iam_role_detail,
-        launch_time,
+        iam_role_detail: None,
+        launch_time: instance
+            .launch_time()
+            .and_then(|t| {
+                t.fmt(aws_sdk_ec2::primitives::DateTimeFormat::DateTime)
+                    .ok()
+            })
+            .unwrap_or_default(),
         tags,
         volumes,
         user_data,
+        alarms: Vec::new(),
     }
 }
 
-fn get_instance_volumes(instance_id: &str) -> Vec<VolumeDetail> {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-instances",
-        "--instance-ids",
-        instance_id,
-        "--query",
-        "Reservations[0].Instances[0].BlockDeviceMappings",
-        "--output",
-        "json",
-    ]);
-
-    let mut volumes = Vec::new();
-
-    if let Some(json) = output {
-        for (device_name, volume_id, delete_on_term) in parse_instance_volume_mappings(&json) {
-            if let Some(vol_detail) = get_volume_detail(&volume_id, &device_name, delete_on_term) {
-                volumes.push(vol_detail);
-            }
-        }
+fn get_instance_volumes(provider: &dyn AwsProvider, instance: &Instance) -> Vec<VolumeDetail> {
+    let mappings = instance_volume_mappings(instance);
+    if mappings.is_empty() {
+        return Vec::new();
     }
-    volumes
-}
-
-fn parse_instance_volume_mappings(json: &str) -> Vec<(String, String, bool)> {
-    let mut mappings = Vec::new();
-    let mut search_start = 0;
-
-    while let Some(device_pos) = json[search_start..].find("\"DeviceName\": \"") {
-        let device_marker_start = search_start + device_pos;
-        let device_start = device_marker_start + 15;
-        if let Some(device_end) = json[device_start..].find('"') {
-            let device_name = json[device_start..device_start + device_end].to_string();
-            let next_device_start = json[device_start..]
-                .find("\"DeviceName\": \"")
-                .map(|offset| device_start + offset)
-                .unwrap_or(json.len());
-
-            if let Some(vol_pos) = json[device_start..next_device_start].find("\"VolumeId\": \"") {
-                let vol_start = device_start + vol_pos + 13;
-                if let Some(vol_end) = json[vol_start..].find('"') {
-                    let volume_id = json[vol_start..vol_start + vol_end].to_string();
-                    let delete_on_term = json[device_start..next_device_start]
-                        .contains("\"DeleteOnTermination\": true");
-                    mappings.push((device_name, volume_id, delete_on_term));
-                }
-            }
 
-            search_start = next_device_start;
-        } else {
-            break;
-        }
-    }
+    let volume_ids: Vec<String> = mappings.iter().map(|(_, id, _)| id.clone()).collect();
+    let volumes = provider.describe_volumes(&volume_ids);
 
     mappings
+        .into_iter()
+        .filter_map(|(device_name, volume_id, delete_on_termination)| {
+            volumes
+                .iter()
+                .find(|v| v.volume_id() == Some(volume_id.as_str()))
+                .map(|volume| map_volume_detail(volume, &device_name, delete_on_termination))
+        })
+        .collect()
 }
 
-fn get_volume_detail(
-    volume_id: &str,
-    device_name: &str,
-    delete_on_termination: bool,
-) -> Option<VolumeDetail> {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-volumes",
-        "--volume-ids",
-        volume_id,
-        "--output",
-        "json",
-    ])?;
-
-    Some(parse_volume_detail_output(
-        &output,
-        volume_id,
-        device_name,
-        delete_on_termination,
-    ))
+/// 블록 디바이스 매핑에서 (디바이스명, 볼륨 ID, 종료 시 삭제) 목록
+fn instance_volume_mappings(instance: &Instance) -> Vec<(String, String, bool)> {
+    instance
+        .block_device_mappings()
+        .iter()
+        .filter_map(|mapping| {
+            let ebs = mapping.ebs()?;
+            Some((
+                mapping.device_name().unwrap_or_default().to_string(),
+                ebs.volume_id()?.to_string(),
+                ebs.delete_on_termination().unwrap_or(false),
+            ))
+        })
+        .collect()
 }
 
-fn parse_volume_detail_output(
-    json: &str,
-    volume_id: &str,
+fn map_volume_detail(
+    volume: &Volume,
     device_name: &str,
     delete_on_termination: bool,
 ) -> VolumeDetail {
-    let size_str = extract_json_value(json, "Size").unwrap_or_default();
-    let size_gb = size_str.parse::<i64>().unwrap_or(0);
-    let volume_type =
-        extract_json_value(json, "VolumeType").unwrap_or_else(|| "unknown".to_string());
-    let iops_str = extract_json_value(json, "Iops");
-    let iops = iops_str.and_then(|s| s.parse::<i64>().ok());
-    let encrypted = json.contains("\"Encrypted\": true");
-
     VolumeDetail {
         device_name: device_name.to_string(),
-        volume_id: volume_id.to_string(),
-        size_gb,
-        volume_type,
-        iops,
-        encrypted,
+        volume_id: volume.volume_id().unwrap_or_default().to_string(),
+        size_gb: volume.size().unwrap_or(0) as i64,
+        volume_type: volume
+            .volume_type()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_else(|| "unknown".to_string()),
+        iops: volume.iops().map(|iops| iops as i64),
+        encrypted: volume.encrypted().unwrap_or(false),
         delete_on_termination,
     }
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct UserDataResponse {
-    user_data: Option<UserDataValue>,
+fn decode_user_data(base64_data: Option<&str>) -> Option<String> {
+    let base64_data = base64_data.filter(|data| !data.is_empty())?;
+    let decoded_bytes = general_purpose::STANDARD.decode(base64_data).ok()?;
+    let decoded = String::from_utf8_lossy(&decoded_bytes).to_string();
+    (!decoded.trim().is_empty()).then_some(decoded)
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct UserDataValue {
-    value: Option<String>,
+fn get_vpc_name(provider: &dyn AwsProvider, vpc_id: &str) -> String {
+    provider
+        .describe_vpcs(&Ec2Filter::id(vpc_id))
+        .first()
+        .map(|vpc| ec2_name_tag(vpc.tags()))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| vpc_id.to_string())
 }
 
-fn get_instance_user_data(instance_id: &str) -> Option<String> {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-instance-attribute",
-        "--instance-id",
-        instance_id,
-        "--attribute",
-        "userData",
-        "--output",
-        "json",
-    ])?;
-
-    parse_user_data_output(&output)
+pub fn get_subnet_name(provider: &dyn AwsProvider, subnet_id: &str) -> String {
+    provider
+        .describe_subnets(&Ec2Filter::id(subnet_id))
+        .first()
+        .map(|subnet| ec2_name_tag(subnet.tags()))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| subnet_id.to_string())
 }
 
-fn parse_user_data_output(output: &str) -> Option<String> {
-    if let Ok(response) = serde_json::from_str::<UserDataResponse>(output)
-        && let Some(user_data) = response.user_data
-        && let Some(base64_data) = user_data.value
-        && !base64_data.is_empty()
-        && let Ok(decoded_bytes) = general_purpose::STANDARD.decode(base64_data)
-    {
-        let decoded = String::from_utf8_lossy(&decoded_bytes).to_string();
-        if !decoded.trim().is_empty() {
-            return Some(decoded);
-        }
-    }
-    None
-}
-
-fn extract_security_groups(json: &str) -> Vec<String> {
-    let mut sgs = Vec::new();
-    let mut search_start = 0;
-
-    while let Some(pos) = json[search_start..].find("\"GroupName\": \"") {
-        let start = search_start + pos + 14;
-        if let Some(end) = json[start..].find('"') {
-            let sg = json[start..start + end].to_string();
-            if !sgs.contains(&sg) {
-                sgs.push(sg);
+fn get_ami_name(provider: &dyn AwsProvider, ami_id: &str) -> String {
+    provider
+        .describe_images(&[ami_id.to_string()])
+        .first()
+        .map(|image| {
+            let name = ec2_name_tag(image.tags());
+            if name.is_empty() {
+                image.name().unwrap_or_default().to_string()
+            } else {
+                name
             }
-            search_start = start + end;
-        } else {
-            break;
-        }
-    }
-    sgs
-}
-
-fn get_vpc_name(vpc_id: &str) -> String {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-vpcs",
-        "--vpc-ids",
-        vpc_id,
-        "--output",
-        "json",
-    ]);
-
-    if let Some(json) = output {
-        let name = parse_name_tag(&json);
-        if !name.is_empty() {
-            return name;
-        }
-    }
-    vpc_id.to_string()
-}
-
-pub fn get_subnet_name(subnet_id: &str) -> String {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-subnets",
-        "--subnet-ids",
-        subnet_id,
-        "--output",
-        "json",
-    ]);
-
-    if let Some(json) = output {
-        let name = parse_name_tag(&json);
-        if !name.is_empty() {
-            return name;
-        }
-    }
-    subnet_id.to_string()
-}
-
-fn get_ami_name(ami_id: &str) -> String {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-images",
-        "--image-ids",
-        ami_id,
-        "--output",
-        "json",
-    ]);
-
-    if let Some(json) = output {
-        let name = parse_name_tag(&json);
-        if !name.is_empty() {
-            return name;
-        }
-    }
-    ami_id.to_string()
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| ami_id.to_string())
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        Ec2Detail, VolumeDetail, cli_adapter, extract_security_groups, extract_state,
-        get_instance_detail, get_subnet_name, iam_adapter, list_instances,
-        parse_instance_detail_output, parse_instance_resources, parse_instance_volume_mappings,
-        parse_user_data_output, parse_volume_detail_output,
+        Ec2Detail, VolumeDetail, decode_user_data, get_instance_detail, get_subnet_name,
+        instance_state, instance_volume_mappings, list_instances, list_regions,
+        map_instance_detail, map_volume_detail,
     };
+    use crate::aws_cli::fake_provider::FakeProvider;
     use crate::aws_cli::iam::{AttachedPolicy, IamRoleDetail, InlinePolicy};
     use crate::i18n::Language;
-    use std::sync::{Mutex, OnceLock};
+    use aws_sdk_ec2::types::{
+        EbsInstanceBlockDevice, GroupIdentifier, IamInstanceProfile, Image, Instance,
+        InstanceBlockDeviceMapping, InstanceState, InstanceStateName, Monitoring, MonitoringState,
+        PlatformValues, Region, Subnet, Tag, Volume, VolumeType, Vpc,
+    };
 
-    fn test_lock() -> std::sync::MutexGuard<'static, ()> {
-        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-        match LOCK.get_or_init(|| Mutex::new(())).lock() {
-            Ok(guard) => guard,
-            Err(poisoned) => poisoned.into_inner(),
-        }
+    fn tag(key: &str, value: &str) -> Tag {
+        Tag::builder().key(key).value(value).build()
+    }
+
+    fn instance(id: &str, name: &str, state: InstanceStateName) -> Instance {
+        Instance::builder()
+            .instance_id(id)
+            .state(InstanceState::builder().name(state).build())
+            .tags(tag("Name", name))
+            .build()
+    }
+
+    fn block_device(
+        device: &str,
+        volume_id: &str,
+        delete_on_termination: bool,
+    ) -> InstanceBlockDeviceMapping {
+        InstanceBlockDeviceMapping::builder()
+            .device_name(device)
+            .ebs(
+                EbsInstanceBlockDevice::builder()
+                    .volume_id(volume_id)
+                    .delete_on_termination(delete_on_termination)
+                    .build(),
+            )
+            .build()
     }
 
     fn sample_detail() -> Ec2Detail {
@@ -766,44 +570,50 @@ mod tests {
                 delete_on_termination: true,
             }],
             user_data: Some("#!/bin/bash\necho hello".to_string()),
+            alarms: vec![],
         }
     }
 
     #[test]
-    fn parse_instance_resources_extracts_ids_and_names() {
-        let payload = r#"
-            [
-              [
-                ["i-aaa111", "running", [{"Key":"Name","Value":"web-a"}]],
-                ["i-bbb222", "stopped", [{"Key":"Name","Value":"web-b"}]]
-              ]
-            ]
-        "#;
-        let resources = parse_instance_resources(payload);
+    fn list_instances_sorts_and_deduplicates_ids() {
+        let provider = FakeProvider {
+            instances: vec![
+                instance("i-bbb222", "web-b", InstanceStateName::Stopped),
+                instance("i-aaa111", "web-a", InstanceStateName::Running),
+                instance("i-aaa111", "web-a", InstanceStateName::Running),
+                Instance::builder().build(),
+            ],
+            ..FakeProvider::default()
+        };
+
+        let resources = list_instances(&provider);
         assert_eq!(resources.len(), 2);
         assert_eq!(resources[0].id, "i-aaa111");
-        assert!(resources[0].name.contains("running"));
+        assert_eq!(resources[0].name, "web-a - i-aaa111 - running");
+        assert_eq!(resources[1].state, "stopped");
     }
 
     #[test]
-    fn extract_state_falls_back_to_unknown() {
-        assert_eq!(extract_state(r#"{"State":{"Name":"running"}}"#), "running");
-        assert_eq!(extract_state(r#"{"State":{"Name":"mystery"}}"#), "unknown");
+    fn list_regions_returns_sorted_region_codes() {
+        let region = |name: &str| Region::builder().region_name(name).build();
+        let provider = FakeProvider {
+            regions: vec![
+                region("sa-east-1"),
+                region("ap-northeast-2"),
+                Region::builder().build(),
+                region("ap-northeast-2"),
+            ],
+            ..FakeProvider::default()
+        };
+        assert_eq!(list_regions(&provider), vec!["ap-northeast-2", "sa-east-1"]);
+        assert!(list_regions(&FakeProvider::default()).is_empty());
     }
 
     #[test]
-    fn extract_security_groups_deduplicates_names() {
-        let payload = r#"
-            {
-              "SecurityGroups": [
-                {"GroupName": "web"},
-                {"GroupName": "db"},
-                {"GroupName": "web"}
-              ]
-            }
-        "#;
-        let names = extract_security_groups(payload);
-        assert_eq!(names, vec!["web".to_string(), "db".to_string()]);
+    fn instance_state_falls_back_to_unknown() {
+        let running = instance("i-1", "web", InstanceStateName::Running);
+        assert_eq!(instance_state(&running), "running");
+        assert_eq!(instance_state(&Instance::builder().build()), "unknown");
     }
 
     #[test]
@@ -815,29 +625,6 @@ mod tests {
         assert!(md.contains("User Data"));
     }
 
-    #[test]
-    fn parse_instance_resources_filters_invalid_and_deduplicates_ids() {
-        let payload = r#"
-            [
-              [
-                ["i-valid001", "running", [{"Key":"Name","Value":"api-a"}]],
-                ["i-valid001", "running", [{"Key":"Name","Value":"api-a"}]],
-                ["i-", "running", [{"Key":"Name","Value":"invalid"}]],
-                ["not-an-instance", "running", [{"Key":"Name","Value":"skip"}]]
-              ]
-            ]
-        "#;
-        let resources = parse_instance_resources(payload);
-        assert_eq!(resources.len(), 1);
-        assert_eq!(resources[0].id, "i-valid001");
-    }
-
-    #[test]
-    fn extract_security_groups_handles_empty_payload() {
-        let names = extract_security_groups("{}");
-        assert!(names.is_empty());
-    }
-
     #[test]
     fn ec2_markdown_omits_optional_sections_when_data_is_missing() {
         let detail = Ec2Detail {
@@ -863,6 +650,7 @@ mod tests {
             tags: vec![],
             volumes: vec![],
             user_data: None,
+            alarms: vec![],
         };
 
         let md = detail.to_markdown(Language::English);
@@ -874,28 +662,37 @@ mod tests {
     }
 
     #[test]
-    fn parse_instance_detail_output_maps_basic_fields_from_fixture_json() {
-        let payload = r#"
-        {
-          "InstanceType": "t3.micro",
-          "Platform": "Windows",
-          "Architecture": "x86_64",
-          "KeyName": "main-key",
-          "AvailabilityZone": "ap-northeast-2a",
-          "PublicIpAddress": "3.3.3.3",
-          "PrivateIpAddress": "10.0.0.20",
-          "EbsOptimized": true,
-          "LaunchTime": "2026-02-13T00:00:00Z",
-          "Tags": [{"Key": "Name", "Value": "web-a"}, {"Key": "Env", "Value": "prod"}],
-          "SecurityGroups": [{"GroupName": "sg-web"}],
-          "State": {"Name": "running"},
-          "Monitoring": {"State": "enabled"}
-        }
-        "#;
-
-        let detail = parse_instance_detail_output(
+    fn map_instance_detail_maps_basic_fields_and_defaults() {
+        let instance = Instance::builder()
+            .instance_type(aws_sdk_ec2::types::InstanceType::T3Micro)
+            .platform(PlatformValues::Windows)
+            .public_ip_address("3.3.3.3")
+            .private_ip_address("10.0.0.20")
+            .ebs_optimized(true)
+            .monitoring(
+                Monitoring::builder()
+                    .state(MonitoringState::Enabled)
+                    .build(),
+            )
+            .iam_instance_profile(
+                IamInstanceProfile::builder()
+                    .arn("arn:aws:iam::123456789012:instance-profile/role-web")
+                    .build(),
+            )
+            .tags(tag("Name", "web-a"))
+            .tags(tag("Env", "prod"))
+            .security_groups(GroupIdentifier::builder().group_name("sg-web").build())
+            .security_groups(GroupIdentifier::builder().group_name("sg-web").build())
+            .state(
+                InstanceState::builder()
+                    .name(InstanceStateName::Running)
+                    .build(),
+            )
+            .build();
+
+        let detail = map_instance_detail(
             "i-abc",
-            payload,
+            &instance,
             "ami-name".to_string(),
             "vpc-name".to_string(),
             "subnet-name".to_string(),
@@ -905,223 +702,108 @@ mod tests {
         assert_eq!(detail.instance_id, "i-abc");
         assert_eq!(detail.instance_type, "t3.micro");
         assert_eq!(detail.platform, "Windows");
+        assert_eq!(detail.architecture, "x86_64");
+        assert_eq!(detail.key_pair, "-");
         assert_eq!(detail.monitoring, "Enabled");
         assert_eq!(detail.name, "web-a");
+        assert_eq!(detail.iam_role.as_deref(), Some("role-web"));
         assert_eq!(detail.security_groups, vec!["sg-web".to_string()]);
+        assert_eq!(detail.tags.len(), 2);
     }
 
     #[test]
-    fn parse_instance_volume_mappings_and_volume_details_handle_fixture_json() {
-        let mappings_payload = r#"
-        [
-          {"DeviceName": "/dev/xvda", "Ebs": {"VolumeId": "vol-1", "DeleteOnTermination": true}},
-          {"DeviceName": "/dev/xvdb", "Ebs": {"VolumeId": "vol-2", "DeleteOnTermination": false}}
-        ]
-        "#;
-        let mappings = parse_instance_volume_mappings(mappings_payload);
+    fn instance_volume_mappings_and_volume_details_map_sdk_types() {
+        let instance = Instance::builder()
+            .block_device_mappings(block_device("/dev/xvda", "vol-1", true))
+            .block_device_mappings(block_device("/dev/xvdb", "vol-2", false))
+            .block_device_mappings(
+                InstanceBlockDeviceMapping::builder()
+                    .device_name("/dev/sdc")
+                    .build(),
+            )
+            .build();
+        let mappings = instance_volume_mappings(&instance);
         assert_eq!(mappings.len(), 2);
         assert_eq!(mappings[0].0, "/dev/xvda");
         assert!(mappings[0].2);
         assert!(!mappings[1].2);
 
-        let volume_payload = r#"{"VolumeId": "vol-1", "Size": "30", "VolumeType": "gp3", "Iops": "3000", "Encrypted": true}"#;
-        let volume = parse_volume_detail_output(volume_payload, "vol-1", "/dev/xvda", true);
-        assert_eq!(volume.size_gb, 30);
-        assert_eq!(volume.volume_type, "gp3");
-        assert_eq!(volume.iops, Some(3000));
-        assert!(volume.encrypted);
-        assert!(volume.delete_on_termination);
+        let volume = Volume::builder()
+            .volume_id("vol-1")
+            .size(30)
+            .volume_type(VolumeType::Gp3)
+            .iops(3000)
+            .encrypted(true)
+            .build();
+        let detail = map_volume_detail(&volume, "/dev/xvda", true);
+        assert_eq!(detail.size_gb, 30);
+        assert_eq!(detail.volume_type, "gp3");
+        assert_eq!(detail.iops, Some(3000));
+        assert!(detail.encrypted);
+        assert!(detail.delete_on_termination);
     }
 
     #[test]
-    fn parse_user_data_output_decodes_base64_and_rejects_empty_payloads() {
-        let payload = r#"{"UserData":{"Value":"IyEvYmluL2Jhc2gKZWNobyBoaQ=="}}"#;
-        let decoded = parse_user_data_output(payload).expect("decoded user data");
+    fn decode_user_data_decodes_base64_and_rejects_empty_payloads() {
+        let decoded = decode_user_data(Some("IyEvYmluL2Jhc2gKZWNobyBoaQ==")).expect("decoded");
         assert!(decoded.contains("#!/bin/bash"));
         assert!(decoded.contains("echo hi"));
 
-        let empty_payload = r#"{"UserData":{"Value":""}}"#;
-        assert_eq!(parse_user_data_output(empty_payload), None);
+        assert_eq!(decode_user_data(Some("")), None);
+        assert_eq!(decode_user_data(None), None);
     }
 
     #[test]
-    fn top_level_list_instances_uses_mocked_cli() {
-        let _guard = test_lock();
-        cli_adapter::clear();
-        iam_adapter::clear();
-
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-instances",
-                "--query",
-                "Reservations[*].Instances[*].[InstanceId,State.Name,Tags]",
-                "--output",
-                "json",
+    fn get_instance_detail_resolves_names_volumes_and_iam() {
+        let mut provider = FakeProvider {
+            instances: vec![
+                Instance::builder()
+                    .instance_id("i-abc")
+                    .image_id("ami-123")
+                    .vpc_id("vpc-123")
+                    .subnet_id("subnet-123")
+                    .iam_instance_profile(
+                        IamInstanceProfile::builder()
+                            .arn("arn:aws:iam::123456789012:instance-profile/role-web")
+                            .build(),
+                    )
+                    .block_device_mappings(block_device("/dev/xvda", "vol-1", true))
+                    .tags(tag("Name", "web-a"))
+                    .build(),
             ],
-            Some(
-                r#"
-                [
-                  [
-                    ["i-abc123", "running", [{"Key": "Name", "Value": "web-a"}]],
-                    ["i-def456", "stopped", [{"Key": "Name", "Value": "web-b"}]]
-                  ]
-                ]
-                "#,
-            ),
-        );
-
-        let resources = list_instances();
-        assert_eq!(resources.len(), 2);
-        assert_eq!(resources[0].id, "i-abc123");
-        assert!(resources[0].name.contains("running"));
-    }
-
-    #[test]
-    fn top_level_get_instance_detail_uses_mocked_cli_and_iam() {
-        let _guard = test_lock();
-        cli_adapter::clear();
-        iam_adapter::clear();
-
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-instances",
-                "--instance-ids",
-                "i-abc",
-                "--output",
-                "json",
+            volumes: vec![Volume::builder().volume_id("vol-1").size(30).build()],
+            images: vec![Image::builder().image_id("ami-123").name("ubuntu").build()],
+            vpcs: vec![
+                Vpc::builder()
+                    .vpc_id("vpc-123")
+                    .tags(tag("Name", "main-vpc"))
+                    .build(),
             ],
-            Some(
-                r#"
-                {
-                  "Reservations": [{
-                    "Instances": [{
-                      "InstanceId": "i-abc",
-                      "InstanceType": "t3.micro",
-                      "ImageId": "ami-123",
-                      "Platform": "Linux",
-                      "Architecture": "x86_64",
-                      "KeyName": "main-key",
-                      "VpcId": "vpc-123",
-                      "SubnetId": "subnet-123",
-                      "Placement": {"AvailabilityZone": "ap-northeast-2a"},
-                      "PublicIpAddress": "1.2.3.4",
-                      "PrivateIpAddress": "10.0.0.10",
-                      "State": {"Name": "running"},
-                      "Monitoring": {"State": "enabled"},
-                      "EbsOptimized": true,
-                      "IamInstanceProfile": {
-                        "Arn": "arn:aws:iam::123456789012:instance-profile/role-web"
-                      },
-                      "LaunchTime": "2026-02-13T00:00:00Z",
-                      "Tags": [
-                        {"Key": "Name", "Value": "web-a"},
-                        {"Key": "Env", "Value": "prod"}
-                      ],
-                      "SecurityGroups": [{"GroupName": "sg-web"}]
-                    }]
-                  }]
-                }
-                "#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-images",
-                "--image-ids",
-                "ami-123",
-                "--output",
-                "json",
+            subnets: vec![
+                Subnet::builder()
+                    .subnet_id("subnet-123")
+                    .tags(tag("Name", "public-a"))
+                    .build(),
             ],
-            Some(
-                r#"{"Images":[{"ImageId":"ami-123","Tags":[{"Key": "Name", "Value": "ubuntu"}]}]}"#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-vpcs",
-                "--vpc-ids",
-                "vpc-123",
-                "--output",
-                "json",
-            ],
-            Some(r#"{"Vpcs":[{"VpcId":"vpc-123","Tags":[{"Key": "Name", "Value": "main-vpc"}]}]}"#),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-subnets",
-                "--subnet-ids",
-                "subnet-123",
-                "--output",
-                "json",
-            ],
-            Some(
-                r#"{"Subnets":[{"SubnetId":"subnet-123","Tags":[{"Key": "Name", "Value": "public-a"}]}]}"#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-instances",
-                "--instance-ids",
-                "i-abc",
-                "--query",
-                "Reservations[0].Instances[0].BlockDeviceMappings",
-                "--output",
-                "json",
-            ],
-            Some(
-                r#"
-                [
-                  {"DeviceName": "/dev/xvda", "Ebs": {"VolumeId": "vol-1", "DeleteOnTermination": true}}
-                ]
-                "#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-volumes",
-                "--volume-ids",
-                "vol-1",
-                "--output",
-                "json",
-            ],
-            Some(
-                r#"{"Volumes":[{"VolumeId": "vol-1", "Size": "30", "VolumeType": "gp3", "Iops": "3000", "Encrypted": true}]}"#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-instance-attribute",
-                "--instance-id",
-                "i-abc",
-                "--attribute",
-                "userData",
-                "--output",
-                "json",
-            ],
-            Some(r#"{"UserData":{"Value":"IyEvYmluL2Jhc2gKZWNobyBoaQ=="}}"#),
-        );
-
-        iam_adapter::set(
-            "role-web",
-            Some(IamRoleDetail {
-                name: "role-web".to_string(),
-                arn: "arn:aws:iam::123456789012:role/role-web".to_string(),
-                assume_role_policy: "{\"Version\":\"2012-10-17\"}".to_string(),
-                attached_policies: vec![],
-                inline_policies: vec![],
-            }),
+            user_data: [(
+                "i-abc".to_string(),
+                "IyEvYmluL2Jhc2gKZWNobyBoaQ==".to_string(),
+            )]
+            .into(),
+            ..FakeProvider::default()
+        };
+        provider.roles.push(
+            aws_sdk_iam::types::Role::builder()
+                .path("/")
+                .role_name("role-web")
+                .role_id("AROAEXAMPLE")
+                .arn("arn:aws:iam::123456789012:role/role-web")
+                .create_date(aws_sdk_iam::primitives::DateTime::from_secs(0))
+                .build()
+                .expect("role"),
         );
 
-        let detail = get_instance_detail("i-abc").expect("instance detail");
-        assert_eq!(detail.instance_id, "i-abc");
+        let detail = get_instance_detail(&provider, "i-abc").expect("instance detail");
         assert_eq!(detail.name, "web-a");
         assert_eq!(detail.ami, "ubuntu");
         assert_eq!(detail.vpc, "main-vpc");
@@ -1137,28 +819,21 @@ mod tests {
         );
         assert_eq!(detail.iam_role.as_deref(), Some("role-web"));
         assert!(detail.iam_role_detail.is_some());
+        assert!(get_instance_detail(&provider, "i-missing").is_none());
     }
 
     #[test]
-    fn top_level_get_subnet_name_handles_found_and_missing_cases() {
-        let _guard = test_lock();
-        cli_adapter::clear();
-        iam_adapter::clear();
-
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-subnets",
-                "--subnet-ids",
-                "subnet-777",
-                "--output",
-                "json",
+    fn get_subnet_name_handles_found_and_missing_cases() {
+        let provider = FakeProvider {
+            subnets: vec![
+                Subnet::builder()
+                    .subnet_id("subnet-777")
+                    .tags(tag("Name", "app-a"))
+                    .build(),
             ],
-            Some(
-                r#"{"Subnets":[{"SubnetId":"subnet-777","Tags":[{"Key": "Name", "Value": "app-a"}]}]}"#,
-            ),
-        );
-        assert_eq!(get_subnet_name("subnet-777"), "app-a");
-        assert_eq!(get_subnet_name("subnet-999"), "subnet-999");
+            ..FakeProvider::default()
+        };
+        assert_eq!(get_subnet_name(&provider, "subnet-777"), "app-a");
+        assert_eq!(get_subnet_name(&provider, "subnet-999"), "subnet-999");
     }
 }
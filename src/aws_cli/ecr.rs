@@ -1,47 +1,7 @@
 use crate::i18n::{I18n, Language};
-use serde::Deserialize;
 
 pub use crate::aws_cli::ecr_sdk::{get_ecr_detail, list_ecr_repositories};
 
-#[derive(Debug, Deserialize)]
-pub(super) struct EcrRepositoriesResponse {
-    pub(super) repositories: Vec<EcrRepository>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub(super) struct EcrRepository {
-    pub(super) repository_name: String,
-    pub(super) repository_uri: String,
-    #[serde(default)]
-    pub(super) image_tag_mutability: String,
-    #[serde(default)]
-    pub(super) encryption_configuration: Option<EcrEncryptionConfiguration>,
-    #[serde(default)]
-    pub(super) created_at: Option<f64>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub(super) struct EcrEncryptionConfiguration {
-    pub(super) encryption_type: String,
-    #[serde(default)]
-    pub(super) kms_key: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub(super) struct EcrImagesResponse {
-    pub(super) image_details: Vec<EcrImageDetail>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub(super) struct EcrImageDetail {
-    #[allow(dead_code)]
-    pub(super) image_digest: String,
-}
-
 #[derive(Debug)]
 pub struct EcrDetail {
     pub name: String,
@@ -1,144 +1,150 @@
-use crate::aws_cli::common::{AwsResource, run_aws_cli};
-use crate::aws_cli::ecr::{
-    EcrDetail, EcrImagesResponse, EcrRepositoriesResponse, format_created_at, mutability_label,
-};
-
-pub fn list_ecr_repositories() -> Vec<AwsResource> {
-    let output = match run_aws_cli(&["ecr", "describe-repositories", "--output", "json"]) {
-        Some(o) => o,
-        None => return Vec::new(),
-    };
-
-    parse_repository_list_output(&output).unwrap_or_default()
+use crate::aws_cli::common::AwsResource;
+use crate::aws_cli::ecr::{EcrDetail, format_created_at, mutability_label};
+use crate::aws_cli::provider::AwsProvider;
+use aws_sdk_ecr::types::Repository;
+
+pub fn list_ecr_repositories(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut repositories = provider.describe_repositories(&[]);
+    repositories.sort_by(|a, b| a.repository_name().cmp(&b.repository_name()));
+    repositories.iter().map(map_repository_resource).collect()
 }
 
-pub fn get_ecr_detail(repo_name: &str) -> Option<EcrDetail> {
-    let output = run_aws_cli(&[
-        "ecr",
-        "describe-repositories",
-        "--repository-names",
-        repo_name,
-        "--output",
-        "json",
-    ])?;
-
-    let images_output = run_aws_cli(&[
-        "ecr",
-        "describe-images",
-        "--repository-name",
-        repo_name,
-        "--output",
-        "json",
-    ]);
-
-    build_ecr_detail_from_outputs(&output, images_output.as_deref())
+pub fn get_ecr_detail(provider: &dyn AwsProvider, repo_name: &str) -> Option<EcrDetail> {
+    let repositories = provider.describe_repositories(&[repo_name.to_string()]);
+    let repo = repositories.first()?;
+    let image_count = provider.describe_ecr_images(repo_name).len() as i32;
+    Some(map_ecr_detail(repo, image_count))
 }
 
-fn parse_repository_list_output(output: &str) -> Option<Vec<AwsResource>> {
-    let response: EcrRepositoriesResponse = serde_json::from_str(output).ok()?;
-
-    Some(
-        response
-            .repositories
-            .into_iter()
-            .map(|repo| {
-                let mutability = mutability_label(&repo.image_tag_mutability);
-
-                AwsResource {
-                    name: format!("{} ({})", repo.repository_name, mutability),
-                    id: repo.repository_name,
-                    state: repo.image_tag_mutability,
-                    az: String::new(),
-                    cidr: repo.repository_uri,
-                }
-            })
-            .collect(),
-    )
+fn map_repository_resource(repo: &Repository) -> AwsResource {
+    let name = repo.repository_name().unwrap_or_default();
+    let tag_mutability = repo
+        .image_tag_mutability()
+        .map(|m| m.as_str())
+        .unwrap_or_default();
+
+    AwsResource {
+        name: format!("{} ({})", name, mutability_label(tag_mutability)),
+        id: name.to_string(),
+        state: tag_mutability.to_string(),
+        az: String::new(),
+        cidr: repo.repository_uri().unwrap_or_default().to_string(),
+        region: None,
+    }
 }
 
-fn build_ecr_detail_from_outputs(
-    repo_output: &str,
-    image_output: Option<&str>,
-) -> Option<EcrDetail> {
-    let response: EcrRepositoriesResponse = serde_json::from_str(repo_output).ok()?;
-    let repo = response.repositories.first()?;
-
-    let image_count = image_output
-        .and_then(|o| serde_json::from_str::<EcrImagesResponse>(o).ok())
-        .map(|r| r.image_details.len() as i32)
-        .unwrap_or(0);
-
-    let created_at = format_created_at(repo.created_at);
-
+fn map_ecr_detail(repo: &Repository, image_count: i32) -> EcrDetail {
     let (encryption_type, kms_key) = repo
-        .encryption_configuration
-        .as_ref()
-        .map(|enc| (enc.encryption_type.clone(), enc.kms_key.clone()))
+        .encryption_configuration()
+        .map(|enc| {
+            (
+                enc.encryption_type().as_str().to_string(),
+                enc.kms_key().map(|s| s.to_string()),
+            )
+        })
         .unwrap_or_else(|| ("AES256".to_string(), None));
 
-    Some(EcrDetail {
-        name: repo.repository_name.clone(),
-        uri: repo.repository_uri.clone(),
-        tag_mutability: repo.image_tag_mutability.clone(),
+    EcrDetail {
+        name: repo.repository_name().unwrap_or_default().to_string(),
+        uri: repo.repository_uri().unwrap_or_default().to_string(),
+        tag_mutability: repo
+            .image_tag_mutability()
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_default(),
         encryption_type,
         kms_key,
-        created_at,
+        created_at: format_created_at(repo.created_at().map(|dt| dt.as_secs_f64())),
         image_count,
-    })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{build_ecr_detail_from_outputs, parse_repository_list_output};
-
-    #[test]
-    fn parse_repository_list_output_maps_mutability_and_name() {
-        let payload = r#"
-            {
-              "repositories": [
-                {
-                  "repositoryName": "repo-a",
-                  "repositoryUri": "123456789012.dkr.ecr.ap-northeast-2.amazonaws.com/repo-a",
-                  "imageTagMutability": "IMMUTABLE",
-                  "createdAt": 1700000000.0
-                }
-              ]
-            }
-        "#;
+    use super::{get_ecr_detail, list_ecr_repositories, map_ecr_detail, map_repository_resource};
+    use crate::aws_cli::fake_provider::FakeProvider;
+    use aws_sdk_ecr::primitives::DateTime;
+    use aws_sdk_ecr::types::{
+        EncryptionConfiguration, EncryptionType, ImageDetail, ImageTagMutability, Repository,
+    };
 
-        let resources = parse_repository_list_output(payload).expect("parse list");
-        assert_eq!(resources.len(), 1);
-        assert_eq!(resources[0].id, "repo-a");
-        assert!(resources[0].name.contains("Immutable"));
+    fn repository(name: &str, mutability: ImageTagMutability) -> Repository {
+        Repository::builder()
+            .repository_name(name)
+            .repository_uri(format!(
+                "123456789012.dkr.ecr.ap-northeast-2.amazonaws.com/{}",
+                name
+            ))
+            .image_tag_mutability(mutability)
+            .created_at(DateTime::from_secs(1_700_000_000))
+            .build()
     }
 
     #[test]
-    fn build_ecr_detail_from_outputs_handles_defaults() {
-        let repo_payload = r#"
-            {
-              "repositories": [
-                {
-                  "repositoryName": "repo-b",
-                  "repositoryUri": "123456789012.dkr.ecr.ap-northeast-2.amazonaws.com/repo-b",
-                  "imageTagMutability": "MUTABLE",
-                  "createdAt": 1700000000.0
-                }
-              ]
-            }
-        "#;
-        let image_payload = r#"
-            {
-              "imageDetails": [
-                {"imageDigest": "sha256:1"},
-                {"imageDigest": "sha256:2"}
-              ]
-            }
-        "#;
+    fn map_repository_resource_maps_mutability_and_name() {
+        let resource =
+            map_repository_resource(&repository("repo-a", ImageTagMutability::Immutable));
+        assert_eq!(resource.id, "repo-a");
+        assert!(resource.name.contains("Immutable"));
+        assert!(resource.cidr.ends_with("/repo-a"));
+    }
 
-        let detail =
-            build_ecr_detail_from_outputs(repo_payload, Some(image_payload)).expect("detail");
+    #[test]
+    fn map_ecr_detail_handles_defaults() {
+        let detail = map_ecr_detail(&repository("repo-b", ImageTagMutability::Mutable), 2);
         assert_eq!(detail.name, "repo-b");
         assert_eq!(detail.image_count, 2);
         assert_eq!(detail.encryption_type, "AES256");
+        assert_eq!(detail.kms_key, None);
+        assert_eq!(detail.created_at, "2023-11-14");
+    }
+
+    #[test]
+    fn map_ecr_detail_reads_kms_encryption() {
+        let repo = Repository::builder()
+            .repository_name("repo-kms")
+            .encryption_configuration(
+                EncryptionConfiguration::builder()
+                    .encryption_type(EncryptionType::Kms)
+                    .kms_key("arn:aws:kms:ap-northeast-2:123456789012:key/abcd")
+                    .build()
+                    .expect("encryption configuration"),
+            )
+            .build();
+        let detail = map_ecr_detail(&repo, 0);
+        assert_eq!(detail.encryption_type, "KMS");
+        assert_eq!(
+            detail.kms_key.as_deref(),
+            Some("arn:aws:kms:ap-northeast-2:123456789012:key/abcd")
+        );
+        assert_eq!(detail.created_at, "-");
+    }
+
+    #[test]
+    fn ecr_queries_use_provider_repositories_and_images() {
+        let provider = FakeProvider {
+            repositories: vec![
+                repository("repo-b", ImageTagMutability::Mutable),
+                repository("repo-a", ImageTagMutability::Immutable),
+            ],
+            ecr_images: [(
+                "repo-b".to_string(),
+                vec![
+                    ImageDetail::builder().image_digest("sha256:1").build(),
+                    ImageDetail::builder().image_digest("sha256:2").build(),
+                ],
+            )]
+            .into(),
+            ..FakeProvider::default()
+        };
+
+        let ids: Vec<String> = list_ecr_repositories(&provider)
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, vec!["repo-a".to_string(), "repo-b".to_string()]);
+
+        let detail = get_ecr_detail(&provider, "repo-b").expect("detail");
+        assert_eq!(detail.image_count, 2);
+        assert!(get_ecr_detail(&provider, "missing").is_none());
     }
 }
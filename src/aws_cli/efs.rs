@@ -0,0 +1,358 @@
+pub use crate::aws_cli::efs_sdk::{get_efs_detail, list_efs_file_systems};
+use crate::i18n::{I18n, Language};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EfsTransition {
+    Ia,
+    Archive,
+    Primary,
+}
+
+#[derive(Debug, Clone)]
+pub struct EfsLifecyclePolicy {
+    pub transition: EfsTransition,
+    pub rule: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct EfsAccessPoint {
+    pub id: String,
+    pub name: String,
+    pub root_directory: String,
+    pub posix_user: Option<String>,
+    pub state: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct EfsMountTarget {
+    pub id: String,
+    pub availability_zone: String,
+    pub subnet_id: String,
+    /// VPC NetworkDetail 서브넷 목록에서 찾은 이름/CIDR (없으면 빈 문자열)
+    pub subnet_name: String,
+    pub subnet_cidr: String,
+    pub ip_address: String,
+    pub security_groups: Vec<String>,
+    pub state: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct EfsDetail {
+    pub id: String,
+    pub name: String,
+    pub arn: String,
+    pub vpc_id: Option<String>,
+    pub state: String,
+    pub performance_mode: String,
+    pub throughput_mode: String,
+    pub provisioned_throughput_mibps: Option<f64>,
+    pub encrypted: bool,
+    pub kms_key_id: Option<String>,
+    pub size_bytes: i64,
+    pub availability_zone: Option<String>,
+    pub creation_time: String,
+    pub backup_enabled: Option<bool>,
+    pub lifecycle_policies: Vec<EfsLifecyclePolicy>,
+    pub access_points: Vec<EfsAccessPoint>,
+    pub mount_targets: Vec<EfsMountTarget>,
+    pub tags: Vec<(String, String)>,
+}
+
+fn format_size(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+impl EfsDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        self.to_markdown_with_links(lang, &HashMap::new())
+    }
+
+    /// `network_links`: VPC ID -> 같은 블루프린트 문서 내 Network 섹션 앵커
+    pub fn to_markdown_with_links(
+        &self,
+        lang: Language,
+        network_links: &HashMap<String, String>,
+    ) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = if self.name.is_empty() {
+            &self.id
+        } else {
+            &self.name
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.efs(), display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| ID | `{}` |", self.id),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+        ];
+
+        if !self.arn.is_empty() {
+            lines.push(format!("| ARN | {} |", self.arn));
+        }
+        if let Some(ref vpc_id) = self.vpc_id {
+            let vpc = match network_links.get(vpc_id) {
+                Some(anchor) => format!("[{}](#{})", vpc_id, anchor),
+                None => vpc_id.clone(),
+            };
+            lines.push(format!("| VPC | {} |", vpc));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_performance_mode(),
+            self.performance_mode
+        ));
+        let throughput = match self.provisioned_throughput_mibps {
+            Some(mibps) => format!("{} ({} MiB/s)", self.throughput_mode, mibps),
+            None => self.throughput_mode.clone(),
+        };
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_throughput_mode(),
+            throughput
+        ));
+
+        let encryption = match (self.encrypted, &self.kms_key_id) {
+            (true, Some(key)) => format!("✓ ({})", key),
+            (true, None) => "✓".to_string(),
+            (false, _) => "-".to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_encrypted(), encryption));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_size(),
+            format_size(self.size_bytes)
+        ));
+        if let Some(ref az) = self.availability_zone {
+            lines.push(format!("| {} | {} |", i18n.md_availability_zone(), az));
+        }
+        let backup = match self.backup_enabled {
+            Some(true) => i18n.md_enabled(),
+            Some(false) => i18n.md_disabled(),
+            None => "-",
+        };
+        lines.push(format!("| {} | {} |", i18n.md_backup_policy(), backup));
+        if !self.creation_time.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                self.creation_time
+            ));
+        }
+
+        // Lifecycle Policies
+        if !self.lifecycle_policies.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_lifecycle_policies()));
+            for policy in &self.lifecycle_policies {
+                let label = match policy.transition {
+                    EfsTransition::Ia => i18n.md_transition_to_ia(),
+                    EfsTransition::Archive => i18n.md_transition_to_archive(),
+                    EfsTransition::Primary => i18n.md_transition_to_primary(),
+                };
+                lines.push(format!("- {}: {}", label, policy.rule));
+            }
+        }
+
+        // Mount Targets
+        if !self.mount_targets.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_mount_targets()));
+            lines.push(format!(
+                "| ID | AZ | {} | CIDR | {} | {} | {} |",
+                i18n.md_subnet(),
+                i18n.md_ip_address(),
+                i18n.md_security_groups(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|:---|:---|".to_string());
+            for target in &self.mount_targets {
+                let subnet = if target.subnet_name.is_empty() {
+                    format!("`{}`", target.subnet_id)
+                } else {
+                    format!("{} (`{}`)", target.subnet_name, target.subnet_id)
+                };
+                let security_groups = if target.security_groups.is_empty() {
+                    "-".to_string()
+                } else {
+                    target.security_groups.join(", ")
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} | {} |",
+                    target.id,
+                    target.availability_zone,
+                    subnet,
+                    if target.subnet_cidr.is_empty() {
+                        "-"
+                    } else {
+                        &target.subnet_cidr
+                    },
+                    if target.ip_address.is_empty() {
+                        "-"
+                    } else {
+                        &target.ip_address
+                    },
+                    security_groups,
+                    target.state
+                ));
+            }
+        }
+
+        // Access Points
+        if !self.access_points.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_access_points()));
+            lines.push(format!(
+                "| {} | ID | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_root_directory(),
+                i18n.md_posix_user(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for ap in &self.access_points {
+                lines.push(format!(
+                    "| {} | `{}` | {} | {} | {} |",
+                    if ap.name.is_empty() { "-" } else { &ap.name },
+                    ap.id,
+                    ap.root_directory,
+                    ap.posix_user.as_deref().unwrap_or("-"),
+                    ap.state
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_tags()));
+            lines.push(format!("| {} | {} |", i18n.md_tag_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                if key != "Name" {
+                    lines.push(format!("| {} | {} |", key, value));
+                }
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        EfsAccessPoint, EfsDetail, EfsLifecyclePolicy, EfsMountTarget, EfsTransition, format_size,
+    };
+    use crate::i18n::Language;
+    use std::collections::HashMap;
+
+    fn sample_efs() -> EfsDetail {
+        EfsDetail {
+            id: "fs-0123456789abcdef0".to_string(),
+            name: "shared-data".to_string(),
+            arn: "arn:aws:elasticfilesystem:ap-northeast-2:123456789012:file-system/fs-0123456789abcdef0".to_string(),
+            vpc_id: Some("vpc-1234".to_string()),
+            state: "available".to_string(),
+            performance_mode: "generalPurpose".to_string(),
+            throughput_mode: "provisioned".to_string(),
+            provisioned_throughput_mibps: Some(128.0),
+            encrypted: true,
+            kms_key_id: Some("alias/aws/elasticfilesystem".to_string()),
+            size_bytes: 6 * 1024 * 1024 * 1024,
+            availability_zone: None,
+            creation_time: "2026-03-01".to_string(),
+            backup_enabled: Some(true),
+            lifecycle_policies: vec![
+                EfsLifecyclePolicy {
+                    transition: EfsTransition::Ia,
+                    rule: "AFTER_30_DAYS".to_string(),
+                },
+                EfsLifecyclePolicy {
+                    transition: EfsTransition::Primary,
+                    rule: "AFTER_1_ACCESS".to_string(),
+                },
+            ],
+            access_points: vec![EfsAccessPoint {
+                id: "fsap-0123".to_string(),
+                name: "app".to_string(),
+                root_directory: "/app".to_string(),
+                posix_user: Some("1000:1000".to_string()),
+                state: "available".to_string(),
+            }],
+            mount_targets: vec![EfsMountTarget {
+                id: "fsmt-a".to_string(),
+                availability_zone: "ap-northeast-2a".to_string(),
+                subnet_id: "subnet-a".to_string(),
+                subnet_name: "private-a".to_string(),
+                subnet_cidr: "10.0.1.0/24".to_string(),
+                ip_address: "10.0.1.25".to_string(),
+                security_groups: vec!["sg-efs".to_string()],
+                state: "available".to_string(),
+            }],
+            tags: vec![
+                ("Name".to_string(), "shared-data".to_string()),
+                ("Team".to_string(), "platform".to_string()),
+            ],
+        }
+    }
+
+    #[test]
+    fn efs_markdown_renders_modes_policies_and_mount_targets() {
+        let md = sample_efs().to_markdown(Language::English);
+        assert!(md.contains("## EFS File System (shared-data)"));
+        assert!(md.contains("| VPC | vpc-1234 |"));
+        assert!(md.contains("| Throughput Mode | provisioned (128 MiB/s) |"));
+        assert!(md.contains("| Encrypted | ✓ (alias/aws/elasticfilesystem) |"));
+        assert!(md.contains("| Size | 6.0 GiB |"));
+        assert!(md.contains("| Automatic Backups | Enabled |"));
+        assert!(md.contains("- Transition into IA: AFTER_30_DAYS"));
+        assert!(md.contains("- Transition into Standard: AFTER_1_ACCESS"));
+        assert!(md.contains(
+            "| ap-northeast-2a | private-a (`subnet-a`) | 10.0.1.0/24 | 10.0.1.25 | sg-efs | available |"
+        ));
+        assert!(md.contains("| app | `fsap-0123` | /app | 1000:1000 | available |"));
+        assert!(md.contains("| Team | platform |"));
+        // Name 태그는 제목/이름 행에만 표시
+        assert_eq!(md.matches("| Name | shared-data |").count(), 1);
+    }
+
+    #[test]
+    fn efs_markdown_links_vpc_to_network_section() {
+        let links = HashMap::from([("vpc-1234".to_string(), "network-main-vpc".to_string())]);
+        let md = sample_efs().to_markdown_with_links(Language::English, &links);
+        assert!(md.contains("| VPC | [vpc-1234](#network-main-vpc) |"));
+    }
+
+    #[test]
+    fn efs_markdown_falls_back_to_subnet_id_when_not_in_network_detail() {
+        let mut efs = sample_efs();
+        efs.mount_targets[0].subnet_name.clear();
+        efs.mount_targets[0].subnet_cidr.clear();
+        efs.mount_targets[0].security_groups.clear();
+        let md = efs.to_markdown(Language::English);
+        assert!(md.contains("| ap-northeast-2a | `subnet-a` | - | 10.0.1.25 | - | available |"));
+    }
+
+    #[test]
+    fn format_size_uses_binary_units() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(6144), "6.0 KiB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
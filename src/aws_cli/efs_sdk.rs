@@ -0,0 +1,320 @@
+use crate::aws_cli::common::{AwsResource, format_date};
+use crate::aws_cli::efs::{
+    EfsAccessPoint, EfsDetail, EfsLifecyclePolicy, EfsMountTarget, EfsTransition,
+};
+use crate::aws_cli::provider::AwsProvider;
+use crate::aws_cli::vpc::list_subnets;
+use aws_sdk_efs::types::{
+    AccessPointDescription, FileSystemDescription, LifecyclePolicy, MountTargetDescription, Status,
+};
+
+/// List EFS file systems
+pub fn list_efs_file_systems(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut resources: Vec<AwsResource> = provider
+        .describe_file_systems(None)
+        .iter()
+        .map(map_file_system_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    resources
+}
+
+/// Get EFS file system detail
+pub fn get_efs_detail(provider: &dyn AwsProvider, file_system_id: &str) -> Option<EfsDetail> {
+    let file_systems = provider.describe_file_systems(Some(file_system_id));
+    let file_system = file_systems.first()?;
+
+    let lifecycle_policies =
+        map_lifecycle_policies(&provider.describe_lifecycle_configuration(file_system_id));
+
+    // 백업 정책이 한 번도 설정되지 않은 파일 시스템은 Disabled로 취급
+    let backup_enabled = provider
+        .describe_backup_policy(file_system_id)
+        .map(|policy| matches!(policy.status(), Status::Enabled | Status::Enabling));
+
+    let access_points = provider.describe_access_points(file_system_id);
+    let mount_target_descriptions = provider.describe_mount_targets(file_system_id);
+
+    let mut mount_targets: Vec<EfsMountTarget> = mount_target_descriptions
+        .iter()
+        .map(|target| {
+            let security_groups =
+                provider.describe_mount_target_security_groups(target.mount_target_id());
+            map_mount_target(target, security_groups)
+        })
+        .collect();
+    mount_targets.sort_by(|a, b| a.availability_zone.cmp(&b.availability_zone));
+
+    let vpc_id = mount_target_descriptions
+        .iter()
+        .find_map(|target| target.vpc_id())
+        .map(|s| s.to_string());
+
+    // NetworkDetail과 같은 서브넷 목록으로 탑재 대상의 서브넷 이름/CIDR 연결
+    if let Some(ref vpc_id) = vpc_id {
+        link_subnets(&mut mount_targets, &list_subnets(provider, vpc_id));
+    }
+
+    Some(EfsDetail {
+        vpc_id,
+        backup_enabled,
+        lifecycle_policies,
+        access_points: access_points.iter().map(map_access_point).collect(),
+        mount_targets,
+        ..map_file_system(file_system)
+    })
+}
+
+fn map_file_system_resource(file_system: &FileSystemDescription) -> AwsResource {
+    AwsResource {
+        name: file_system.name().unwrap_or_default().to_string(),
+        id: file_system.file_system_id().to_string(),
+        state: file_system.life_cycle_state().as_str().to_string(),
+        az: file_system
+            .availability_zone_name()
+            .unwrap_or_default()
+            .to_string(),
+        cidr: file_system.performance_mode().as_str().to_string(),
+        region: None,
+    }
+}
+
+fn map_file_system(file_system: &FileSystemDescription) -> EfsDetail {
+    EfsDetail {
+        id: file_system.file_system_id().to_string(),
+        name: file_system.name().unwrap_or_default().to_string(),
+        arn: file_system
+            .file_system_arn()
+            .unwrap_or_default()
+            .to_string(),
+        vpc_id: None,
+        state: file_system.life_cycle_state().as_str().to_string(),
+        performance_mode: file_system.performance_mode().as_str().to_string(),
+        throughput_mode: file_system
+            .throughput_mode()
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_else(|| "bursting".to_string()),
+        provisioned_throughput_mibps: file_system.provisioned_throughput_in_mibps(),
+        encrypted: file_system.encrypted().unwrap_or(false),
+        kms_key_id: file_system.kms_key_id().map(|s| s.to_string()),
+        size_bytes: file_system.size_in_bytes().map(|s| s.value()).unwrap_or(0),
+        availability_zone: file_system.availability_zone_name().map(|s| s.to_string()),
+        creation_time: format_date(Some(file_system.creation_time())),
+        backup_enabled: None,
+        lifecycle_policies: Vec::new(),
+        access_points: Vec::new(),
+        mount_targets: Vec::new(),
+        tags: file_system
+            .tags()
+            .iter()
+            .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+            .collect(),
+    }
+}
+
+fn map_lifecycle_policies(policies: &[LifecyclePolicy]) -> Vec<EfsLifecyclePolicy> {
+    let mut result = Vec::new();
+    for policy in policies {
+        if let Some(rule) = policy.transition_to_ia() {
+            result.push(EfsLifecyclePolicy {
+                transition: EfsTransition::Ia,
+                rule: rule.as_str().to_string(),
+            });
+        }
+        if let Some(rule) = policy.transition_to_archive() {
+            result.push(EfsLifecyclePolicy {
+                transition: EfsTransition::Archive,
+                rule: rule.as_str().to_string(),
+            });
+        }
+        if let Some(rule) = policy.transition_to_primary_storage_class() {
+            result.push(EfsLifecyclePolicy {
+                transition: EfsTransition::Primary,
+                rule: rule.as_str().to_string(),
+            });
+        }
+    }
+    result
+}
+
+fn map_access_point(access_point: &AccessPointDescription) -> EfsAccessPoint {
+    EfsAccessPoint {
+        id: access_point
+            .access_point_id()
+            .unwrap_or_default()
+            .to_string(),
+        name: access_point.name().unwrap_or_default().to_string(),
+        root_directory: access_point
+            .root_directory()
+            .and_then(|dir| dir.path())
+            .unwrap_or("/")
+            .to_string(),
+        posix_user: access_point
+            .posix_user()
+            .map(|user| format!("{}:{}", user.uid(), user.gid())),
+        state: access_point
+            .life_cycle_state()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn map_mount_target(
+    target: &MountTargetDescription,
+    security_groups: Vec<String>,
+) -> EfsMountTarget {
+    EfsMountTarget {
+        id: target.mount_target_id().to_string(),
+        availability_zone: target
+            .availability_zone_name()
+            .unwrap_or_default()
+            .to_string(),
+        subnet_id: target.subnet_id().to_string(),
+        subnet_name: String::new(),
+        subnet_cidr: String::new(),
+        ip_address: target.ip_address().unwrap_or_default().to_string(),
+        security_groups,
+        state: target.life_cycle_state().as_str().to_string(),
+    }
+}
+
+fn link_subnets(mount_targets: &mut [EfsMountTarget], subnets: &[AwsResource]) {
+    for target in mount_targets {
+        if let Some(subnet) = subnets.iter().find(|s| s.id == target.subnet_id) {
+            target.subnet_name = subnet.name.clone();
+            target.subnet_cidr = subnet.cidr.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        link_subnets, map_access_point, map_file_system, map_file_system_resource,
+        map_lifecycle_policies, map_mount_target,
+    };
+    use crate::aws_cli::common::AwsResource;
+    use crate::aws_cli::efs::EfsTransition;
+    use aws_sdk_efs::primitives::DateTime;
+    use aws_sdk_efs::types::{
+        AccessPointDescription, FileSystemDescription, FileSystemSize, LifeCycleState,
+        LifecyclePolicy, MountTargetDescription, PerformanceMode, PosixUser, RootDirectory,
+        ThroughputMode, TransitionToIaRules, TransitionToPrimaryStorageClassRules,
+    };
+
+    fn file_system() -> FileSystemDescription {
+        FileSystemDescription::builder()
+            .owner_id("123456789012")
+            .creation_token("token")
+            .file_system_id("fs-0123")
+            .name("shared-data")
+            .creation_time(DateTime::from_secs(1_767_225_600))
+            .life_cycle_state(LifeCycleState::Available)
+            .number_of_mount_targets(2)
+            .size_in_bytes(FileSystemSize::builder().value(4096).build())
+            .performance_mode(PerformanceMode::GeneralPurpose)
+            .throughput_mode(ThroughputMode::Elastic)
+            .encrypted(true)
+            .set_tags(Some(vec![]))
+            .build()
+            .expect("file system")
+    }
+
+    fn mount_target(id: &str, subnet_id: &str, az: &str) -> MountTargetDescription {
+        MountTargetDescription::builder()
+            .mount_target_id(id)
+            .file_system_id("fs-0123")
+            .subnet_id(subnet_id)
+            .life_cycle_state(LifeCycleState::Available)
+            .availability_zone_name(az)
+            .ip_address("10.0.1.25")
+            .vpc_id("vpc-1234")
+            .build()
+            .expect("mount target")
+    }
+
+    #[test]
+    fn map_file_system_reads_modes_encryption_and_size() {
+        let resource = map_file_system_resource(&file_system());
+        assert_eq!(resource.id, "fs-0123");
+        assert_eq!(resource.state, "available");
+        assert_eq!(resource.cidr, "generalPurpose");
+
+        let detail = map_file_system(&file_system());
+        assert_eq!(detail.throughput_mode, "elastic");
+        assert!(detail.encrypted);
+        assert_eq!(detail.size_bytes, 4096);
+        assert_eq!(detail.creation_time, "2026-01-01");
+        assert_eq!(detail.availability_zone, None);
+    }
+
+    #[test]
+    fn map_lifecycle_policies_flattens_transitions() {
+        let policies = map_lifecycle_policies(&[
+            LifecyclePolicy::builder()
+                .transition_to_ia(TransitionToIaRules::After30Days)
+                .build(),
+            LifecyclePolicy::builder()
+                .transition_to_primary_storage_class(
+                    TransitionToPrimaryStorageClassRules::After1Access,
+                )
+                .build(),
+        ]);
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[0].transition, EfsTransition::Ia);
+        assert_eq!(policies[0].rule, "AFTER_30_DAYS");
+        assert_eq!(policies[1].transition, EfsTransition::Primary);
+    }
+
+    #[test]
+    fn map_access_point_formats_posix_user_and_root() {
+        let access_point = AccessPointDescription::builder()
+            .access_point_id("fsap-0123")
+            .name("app")
+            .posix_user(
+                PosixUser::builder()
+                    .uid(1000)
+                    .gid(1000)
+                    .build()
+                    .expect("posix"),
+            )
+            .root_directory(RootDirectory::builder().path("/app").build())
+            .life_cycle_state(LifeCycleState::Available)
+            .build();
+        let mapped = map_access_point(&access_point);
+        assert_eq!(mapped.posix_user.as_deref(), Some("1000:1000"));
+        assert_eq!(mapped.root_directory, "/app");
+
+        let mapped = map_access_point(&AccessPointDescription::builder().build());
+        assert_eq!(mapped.root_directory, "/");
+        assert_eq!(mapped.posix_user, None);
+    }
+
+    #[test]
+    fn link_subnets_fills_name_and_cidr_from_network_subnets() {
+        let mut targets = vec![
+            map_mount_target(
+                &mount_target("fsmt-a", "subnet-a", "ap-northeast-2a"),
+                vec!["sg-efs".to_string()],
+            ),
+            map_mount_target(
+                &mount_target("fsmt-c", "subnet-c", "ap-northeast-2c"),
+                vec![],
+            ),
+        ];
+        let subnets = vec![AwsResource {
+            name: "private-a".to_string(),
+            id: "subnet-a".to_string(),
+            state: "available".to_string(),
+            az: "ap-northeast-2a".to_string(),
+            cidr: "10.0.1.0/24".to_string(),
+            region: None,
+        }];
+
+        link_subnets(&mut targets, &subnets);
+        assert_eq!(targets[0].subnet_name, "private-a");
+        assert_eq!(targets[0].subnet_cidr, "10.0.1.0/24");
+        assert_eq!(targets[0].security_groups, vec!["sg-efs"]);
+        assert_eq!(targets[1].subnet_name, "");
+    }
+}
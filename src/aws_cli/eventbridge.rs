@@ -0,0 +1,203 @@
+pub use crate::aws_cli::eventbridge_sdk::{get_event_rule_detail, list_event_rules};
+use crate::i18n::{I18n, Language};
+use std::collections::HashMap;
+
+pub const DEFAULT_EVENT_BUS: &str = "default";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventTarget {
+    pub id: String,
+    pub arn: String,
+    pub role_arn: Option<String>,
+    /// Input / InputPath / InputTransformer 요약
+    pub input: Option<String>,
+    pub dead_letter_arn: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EventRuleDetail {
+    pub name: String,
+    pub arn: String,
+    pub event_bus: String,
+    pub description: String,
+    pub state: String,
+    pub event_pattern: Option<String>,
+    pub schedule: Option<String>,
+    pub role_arn: Option<String>,
+    pub managed_by: Option<String>,
+    pub targets: Vec<EventTarget>,
+}
+
+/// 규칙 ARN에서 (이벤트 버스, 규칙 이름) 추출
+/// (`...:rule/name` -> default 버스, `...:rule/bus/name` -> 사용자 지정 버스)
+pub fn parse_rule_arn(arn: &str) -> Option<(String, String)> {
+    let (_, resource) = arn.split_once(":rule/")?;
+    match resource.split_once('/') {
+        Some((bus, name)) => Some((bus.to_string(), name.to_string())),
+        None => Some((DEFAULT_EVENT_BUS.to_string(), resource.to_string())),
+    }
+}
+
+/// JSON 문자열을 보기 좋게 정렬 (파싱 실패 시 원문 유지)
+fn pretty_json(raw: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(raw)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| raw.to_string())
+}
+
+impl EventRuleDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        self.to_markdown_with_links(lang, &HashMap::new())
+    }
+
+    /// `arn_links`: 대상 ARN -> 같은 블루프린트 문서 내 섹션 앵커
+    pub fn to_markdown_with_links(
+        &self,
+        lang: Language,
+        arn_links: &HashMap<String, String>,
+    ) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.event_rule(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_event_bus(), self.event_bus),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+        ];
+
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if let Some(ref schedule) = self.schedule {
+            lines.push(format!("| {} | `{}` |", i18n.md_schedule(), schedule));
+        }
+        if let Some(ref role) = self.role_arn {
+            lines.push(format!("| {} | {} |", i18n.md_iam_role(), role));
+        }
+        if let Some(ref managed_by) = self.managed_by {
+            lines.push(format!("| {} | {} |", i18n.md_managed_by(), managed_by));
+        }
+
+        // Event Pattern
+        if let Some(ref pattern) = self.event_pattern {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_event_pattern()));
+            lines.push("```json".to_string());
+            lines.push(pretty_json(pattern));
+            lines.push("```".to_string());
+        }
+
+        // Targets
+        if !self.targets.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_rule_targets()));
+            lines.push(format!(
+                "| ID | {} | {} | {} | {} |",
+                i18n.md_target(),
+                i18n.md_iam_role(),
+                i18n.md_input(),
+                i18n.md_dead_letter_queue()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for target in &self.targets {
+                let arn = match arn_links.get(&target.arn) {
+                    Some(anchor) => format!("[{}](#{})", target.arn, anchor),
+                    None => target.arn.clone(),
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    target.id,
+                    arn,
+                    target.role_arn.as_deref().unwrap_or("-"),
+                    target.input.as_deref().unwrap_or("-"),
+                    target.dead_letter_arn.as_deref().unwrap_or("-")
+                ));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventRuleDetail, EventTarget, parse_rule_arn};
+    use crate::i18n::Language;
+    use std::collections::HashMap;
+
+    const STATE_MACHINE_ARN: &str =
+        "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow";
+
+    fn sample_rule() -> EventRuleDetail {
+        EventRuleDetail {
+            name: "order-created".to_string(),
+            arn: "arn:aws:events:ap-northeast-2:123456789012:rule/orders/order-created".to_string(),
+            event_bus: "orders".to_string(),
+            description: String::new(),
+            state: "ENABLED".to_string(),
+            event_pattern: Some(r#"{"source":["app.orders"]}"#.to_string()),
+            schedule: None,
+            role_arn: None,
+            managed_by: None,
+            targets: vec![EventTarget {
+                id: "workflow".to_string(),
+                arn: STATE_MACHINE_ARN.to_string(),
+                role_arn: Some("arn:aws:iam::123456789012:role/events-invoke".to_string()),
+                input: Some("InputPath: $.detail".to_string()),
+                dead_letter_arn: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn parse_rule_arn_handles_default_and_custom_buses() {
+        assert_eq!(
+            parse_rule_arn("arn:aws:events:ap-northeast-2:123456789012:rule/nightly"),
+            Some(("default".to_string(), "nightly".to_string()))
+        );
+        assert_eq!(
+            parse_rule_arn("arn:aws:events:ap-northeast-2:123456789012:rule/orders/order-created"),
+            Some(("orders".to_string(), "order-created".to_string()))
+        );
+        assert_eq!(parse_rule_arn("order-created"), None);
+    }
+
+    #[test]
+    fn event_rule_markdown_renders_pattern_and_targets() {
+        let md = sample_rule().to_markdown(Language::English);
+        assert!(md.contains("## EventBridge Rule (order-created)"));
+        assert!(md.contains("| Event Bus | orders |"));
+        assert!(md.contains("```json\n{\n  \"source\": [\n    \"app.orders\"\n  ]\n}\n```"));
+        assert!(md.contains(
+            "| workflow | arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow | arn:aws:iam::123456789012:role/events-invoke | InputPath: $.detail | - |"
+        ));
+        assert!(!md.contains("Schedule"));
+    }
+
+    #[test]
+    fn event_rule_markdown_renders_schedule_and_links_targets() {
+        let mut rule = sample_rule();
+        rule.event_pattern = None;
+        rule.schedule = Some("rate(5 minutes)".to_string());
+        let links = HashMap::from([(
+            STATE_MACHINE_ARN.to_string(),
+            "step-functions-order-workflow".to_string(),
+        )]);
+
+        let md = rule.to_markdown_with_links(Language::English, &links);
+        assert!(md.contains("| Schedule | `rate(5 minutes)` |"));
+        assert!(md.contains(&format!(
+            "[{}](#step-functions-order-workflow)",
+            STATE_MACHINE_ARN
+        )));
+        assert!(!md.contains("### Event Pattern"));
+    }
+}
@@ -0,0 +1,197 @@
+use crate::aws_cli::common::AwsResource;
+use crate::aws_cli::eventbridge::{
+    DEFAULT_EVENT_BUS, EventRuleDetail, EventTarget, parse_rule_arn,
+};
+use crate::aws_cli::provider::AwsProvider;
+use aws_sdk_eventbridge::operation::describe_rule::DescribeRuleOutput;
+use aws_sdk_eventbridge::types::{Rule, Target};
+
+/// List EventBridge rules on every event bus
+pub fn list_event_rules(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut bus_names: Vec<String> = provider
+        .list_event_buses()
+        .iter()
+        .filter_map(|bus| bus.name().map(|s| s.to_string()))
+        .collect();
+    if bus_names.is_empty() {
+        bus_names.push(DEFAULT_EVENT_BUS.to_string());
+    }
+
+    let mut resources: Vec<AwsResource> = bus_names
+        .iter()
+        .flat_map(|bus| provider.list_rules(bus))
+        .map(|rule| map_rule_resource(&rule))
+        .collect();
+
+    resources.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    resources
+}
+
+/// Get EventBridge rule detail (rule + targets) by rule ARN
+pub fn get_event_rule_detail(
+    provider: &dyn AwsProvider,
+    rule_arn: &str,
+) -> Option<EventRuleDetail> {
+    let (bus, name) = parse_rule_arn(rule_arn)?;
+    let rule = provider.describe_rule(&bus, &name)?;
+    let targets = provider.list_targets_by_rule(&bus, &name);
+    Some(map_rule_detail(&rule, &targets))
+}
+
+fn map_rule_resource(rule: &Rule) -> AwsResource {
+    let bus = rule.event_bus_name().unwrap_or(DEFAULT_EVENT_BUS);
+    let name = rule.name().unwrap_or_default();
+    AwsResource {
+        name: if bus == DEFAULT_EVENT_BUS {
+            name.to_string()
+        } else {
+            format!("{}/{}", bus, name)
+        },
+        id: rule.arn().unwrap_or_default().to_string(),
+        state: rule
+            .state()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        az: bus.to_string(),
+        cidr: rule.schedule_expression().unwrap_or_default().to_string(),
+        region: None,
+    }
+}
+
+fn map_target(target: &Target) -> EventTarget {
+    let input = if let Some(transformer) = target.input_transformer() {
+        Some(format!(
+            "InputTransformer: `{}`",
+            transformer.input_template()
+        ))
+    } else if let Some(path) = target.input_path() {
+        Some(format!("InputPath: {}", path))
+    } else {
+        target.input().map(|input| format!("Input: `{}`", input))
+    };
+
+    EventTarget {
+        id: target.id().to_string(),
+        arn: target.arn().to_string(),
+        role_arn: target.role_arn().map(|s| s.to_string()),
+        input,
+        dead_letter_arn: target
+            .dead_letter_config()
+            .and_then(|dlq| dlq.arn())
+            .map(|s| s.to_string()),
+    }
+}
+
+fn map_rule_detail(rule: &DescribeRuleOutput, targets: &[Target]) -> EventRuleDetail {
+    EventRuleDetail {
+        name: rule.name().unwrap_or_default().to_string(),
+        arn: rule.arn().unwrap_or_default().to_string(),
+        event_bus: rule
+            .event_bus_name()
+            .unwrap_or(DEFAULT_EVENT_BUS)
+            .to_string(),
+        description: rule.description().unwrap_or_default().to_string(),
+        state: rule
+            .state()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        event_pattern: rule.event_pattern().map(|s| s.to_string()),
+        schedule: rule.schedule_expression().map(|s| s.to_string()),
+        role_arn: rule.role_arn().map(|s| s.to_string()),
+        managed_by: rule.managed_by().map(|s| s.to_string()),
+        targets: targets.iter().map(map_target).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_rule_detail, map_rule_resource, map_target};
+    use aws_sdk_eventbridge::operation::describe_rule::DescribeRuleOutput;
+    use aws_sdk_eventbridge::types::{DeadLetterConfig, InputTransformer, Rule, RuleState, Target};
+
+    #[test]
+    fn map_rule_resource_prefixes_custom_bus() {
+        let rule = Rule::builder()
+            .name("order-created")
+            .arn("arn:aws:events:ap-northeast-2:123456789012:rule/orders/order-created")
+            .event_bus_name("orders")
+            .state(RuleState::Enabled)
+            .build();
+        let resource = map_rule_resource(&rule);
+        assert_eq!(resource.name, "orders/order-created");
+        assert_eq!(resource.state, "ENABLED");
+        assert_eq!(resource.az, "orders");
+
+        let scheduled = Rule::builder()
+            .name("nightly")
+            .event_bus_name("default")
+            .schedule_expression("cron(0 18 * * ? *)")
+            .build();
+        let resource = map_rule_resource(&scheduled);
+        assert_eq!(resource.name, "nightly");
+        assert_eq!(resource.cidr, "cron(0 18 * * ? *)");
+    }
+
+    #[test]
+    fn map_target_summarizes_input_and_dead_letter_queue() {
+        let target = Target::builder()
+            .id("workflow")
+            .arn("arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow")
+            .input_transformer(
+                InputTransformer::builder()
+                    .input_template(r#"{"id": <id>}"#)
+                    .build()
+                    .expect("input transformer"),
+            )
+            .dead_letter_config(
+                DeadLetterConfig::builder()
+                    .arn("arn:aws:sqs:ap-northeast-2:123456789012:events-dlq")
+                    .build(),
+            )
+            .build()
+            .expect("target");
+        let mapped = map_target(&target);
+        assert_eq!(
+            mapped.input.as_deref(),
+            Some(r#"InputTransformer: `{"id": <id>}`"#)
+        );
+        assert_eq!(
+            mapped.dead_letter_arn.as_deref(),
+            Some("arn:aws:sqs:ap-northeast-2:123456789012:events-dlq")
+        );
+
+        let plain = Target::builder()
+            .id("fn")
+            .arn("arn:aws:lambda:ap-northeast-2:123456789012:function:handler")
+            .build()
+            .expect("target");
+        assert_eq!(map_target(&plain).input, None);
+    }
+
+    #[test]
+    fn map_rule_detail_defaults_bus_and_maps_targets() {
+        let rule = DescribeRuleOutput::builder()
+            .name("nightly")
+            .arn("arn:aws:events:ap-northeast-2:123456789012:rule/nightly")
+            .schedule_expression("rate(1 day)")
+            .state(RuleState::Disabled)
+            .build();
+        let targets = vec![
+            Target::builder()
+                .id("fn")
+                .arn("arn:aws:lambda:ap-northeast-2:123456789012:function:handler")
+                .input_path("$.detail")
+                .build()
+                .expect("target"),
+        ];
+        let detail = map_rule_detail(&rule, &targets);
+        assert_eq!(detail.event_bus, "default");
+        assert_eq!(detail.state, "DISABLED");
+        assert_eq!(detail.schedule.as_deref(), Some("rate(1 day)"));
+        assert_eq!(detail.targets.len(), 1);
+        assert_eq!(
+            detail.targets[0].input.as_deref(),
+            Some("InputPath: $.detail")
+        );
+    }
+}
@@ -0,0 +1,1034 @@
+use crate::aws_cli::common::{
+    AwsQueryError, AwsQueryErrorCode, record_partial_failures, record_query_errors,
+};
+use crate::aws_cli::provider::{AwsProvider, Ec2Filter, PartialFailure};
+use aws_sdk_apigateway::operation::get_rest_api::GetRestApiOutput;
+use aws_sdk_apigatewayv2::operation::get_api::GetApiOutput;
+use aws_sdk_eventbridge::operation::describe_rule::DescribeRuleOutput;
+use aws_sdk_kms::operation::get_key_rotation_status::GetKeyRotationStatusOutput;
+use aws_sdk_lambda::operation::get_function_configuration::GetFunctionConfigurationOutput;
+use aws_sdk_secretsmanager::operation::describe_secret::DescribeSecretOutput;
+use aws_sdk_sfn::operation::describe_state_machine::DescribeStateMachineOutput;
+use aws_sdk_sts::operation::get_caller_identity::GetCallerIdentityOutput;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// 테스트용 인메모리 `AwsProvider`.
+///
+/// 필드에 넣은 SDK 타입을 그대로 돌려주며, ID/이름 필터는 실제 API처럼 적용한다.
+/// 목록 키가 없는 하위 리소스(리스너, 대상 상태 등)는 상위 리소스 ID로 조회한다.
+#[derive(Default)]
+pub struct FakeProvider {
+    // EC2
+    pub regions: Vec<aws_sdk_ec2::types::Region>,
+    pub instances: Vec<aws_sdk_ec2::types::Instance>,
+    pub volumes: Vec<aws_sdk_ec2::types::Volume>,
+    pub user_data: HashMap<String, String>,
+    pub images: Vec<aws_sdk_ec2::types::Image>,
+    pub vpcs: Vec<aws_sdk_ec2::types::Vpc>,
+    pub vpc_attributes: HashMap<(String, aws_sdk_ec2::types::VpcAttributeName), bool>,
+    pub subnets: Vec<aws_sdk_ec2::types::Subnet>,
+    pub internet_gateways: Vec<aws_sdk_ec2::types::InternetGateway>,
+    pub nat_gateways: Vec<aws_sdk_ec2::types::NatGateway>,
+    pub route_tables: Vec<aws_sdk_ec2::types::RouteTable>,
+    pub addresses: Vec<aws_sdk_ec2::types::Address>,
+    pub security_groups: Vec<aws_sdk_ec2::types::SecurityGroup>,
+
+    // Elastic Load Balancing v2
+    pub load_balancers: Vec<aws_sdk_elasticloadbalancingv2::types::LoadBalancer>,
+    pub listeners: HashMap<String, Vec<aws_sdk_elasticloadbalancingv2::types::Listener>>,
+    pub target_groups: Vec<aws_sdk_elasticloadbalancingv2::types::TargetGroup>,
+    pub target_health:
+        HashMap<String, Vec<aws_sdk_elasticloadbalancingv2::types::TargetHealthDescription>>,
+
+    // IAM
+    pub roles: Vec<aws_sdk_iam::types::Role>,
+    pub attached_role_policies: HashMap<String, Vec<aws_sdk_iam::types::AttachedPolicy>>,
+    /// 역할 이름 → (정책 이름, URL 인코딩된 정책 문서)
+    pub role_policies: HashMap<String, Vec<(String, String)>>,
+    pub account_aliases: Vec<String>,
+
+    // STS
+    pub caller_identity: Option<GetCallerIdentityOutput>,
+
+    // Organizations
+    pub org_roots: Vec<aws_sdk_organizations::types::Root>,
+    /// 상위(루트/OU) ID → 하위 OU
+    pub org_units: HashMap<String, Vec<aws_sdk_organizations::types::OrganizationalUnit>>,
+    /// 상위(루트/OU) ID → 바로 아래 계정
+    pub org_accounts: HashMap<String, Vec<aws_sdk_organizations::types::Account>>,
+
+    // ECR
+    pub repositories: Vec<aws_sdk_ecr::types::Repository>,
+    pub ecr_images: HashMap<String, Vec<aws_sdk_ecr::types::ImageDetail>>,
+
+    // Auto Scaling
+    pub auto_scaling_groups: Vec<aws_sdk_autoscaling::types::AutoScalingGroup>,
+    pub scaling_policies: HashMap<String, Vec<aws_sdk_autoscaling::types::ScalingPolicy>>,
+
+    // API Gateway (REST v1)
+    pub rest_apis: Vec<aws_sdk_apigateway::types::RestApi>,
+    pub rest_stages: HashMap<String, Vec<aws_sdk_apigateway::types::Stage>>,
+    pub rest_resources: HashMap<String, Vec<aws_sdk_apigateway::types::Resource>>,
+    pub rest_authorizers: HashMap<String, Vec<aws_sdk_apigateway::types::Authorizer>>,
+    pub rest_domain_names: Vec<aws_sdk_apigateway::types::DomainName>,
+    pub base_path_mappings: HashMap<String, Vec<aws_sdk_apigateway::types::BasePathMapping>>,
+
+    // API Gateway (HTTP/WebSocket v2)
+    pub http_apis: Vec<aws_sdk_apigatewayv2::types::Api>,
+    pub http_stages: HashMap<String, Vec<aws_sdk_apigatewayv2::types::Stage>>,
+    pub http_routes: HashMap<String, Vec<aws_sdk_apigatewayv2::types::Route>>,
+    pub http_integrations: HashMap<String, Vec<aws_sdk_apigatewayv2::types::Integration>>,
+    pub http_authorizers: HashMap<String, Vec<aws_sdk_apigatewayv2::types::Authorizer>>,
+    pub http_domain_names: Vec<aws_sdk_apigatewayv2::types::DomainName>,
+    pub api_mappings: HashMap<String, Vec<aws_sdk_apigatewayv2::types::ApiMapping>>,
+
+    // KMS
+    pub kms_keys: Vec<aws_sdk_kms::types::KeyMetadata>,
+    pub kms_aliases: Vec<aws_sdk_kms::types::AliasListEntry>,
+    pub key_rotations: HashMap<String, GetKeyRotationStatusOutput>,
+    pub key_policies: HashMap<String, String>,
+    pub grants: HashMap<String, Vec<aws_sdk_kms::types::GrantListEntry>>,
+    pub kms_tags: HashMap<String, Vec<aws_sdk_kms::types::Tag>>,
+
+    // Secrets Manager (메타데이터만 보관)
+    pub secrets: Vec<aws_sdk_secretsmanager::types::SecretListEntry>,
+
+    // SSM Parameter Store (메타데이터만 보관)
+    pub parameters: Vec<aws_sdk_ssm::types::ParameterMetadata>,
+    pub parameter_tags: HashMap<String, Vec<aws_sdk_ssm::types::Tag>>,
+
+    // EFS
+    pub file_systems: Vec<aws_sdk_efs::types::FileSystemDescription>,
+    pub lifecycle_policies: HashMap<String, Vec<aws_sdk_efs::types::LifecyclePolicy>>,
+    pub backup_policies: HashMap<String, aws_sdk_efs::types::BackupPolicy>,
+    pub access_points: HashMap<String, Vec<aws_sdk_efs::types::AccessPointDescription>>,
+    pub mount_targets: HashMap<String, Vec<aws_sdk_efs::types::MountTargetDescription>>,
+    pub mount_target_security_groups: HashMap<String, Vec<String>>,
+
+    // CloudWatch
+    pub metric_alarms: Vec<aws_sdk_cloudwatch::types::MetricAlarm>,
+
+    // EventBridge
+    pub event_buses: Vec<aws_sdk_eventbridge::types::EventBus>,
+    /// 이벤트 버스 이름 → 규칙
+    pub rules: HashMap<String, Vec<aws_sdk_eventbridge::types::Rule>>,
+    /// (이벤트 버스, 규칙 이름) → 대상
+    pub rule_targets: HashMap<(String, String), Vec<aws_sdk_eventbridge::types::Target>>,
+
+    // Step Functions
+    pub state_machines: Vec<DescribeStateMachineOutput>,
+
+    // Lambda
+    pub functions: Vec<GetFunctionConfigurationOutput>,
+
+    /// 다음 목록 조회에서 현재 범위에 기록할 부분 결과 (한 번 기록하면 비워짐,
+    /// EC2 인스턴스/EFS 탑재 대상 조회에 적용)
+    pub partial_failures: Mutex<Vec<PartialFailure>>,
+    /// 실패시킬 API → 실패 원인 (EC2 인스턴스/VPC/라우팅 테이블/보안 그룹 조회에 적용)
+    pub failures: HashMap<&'static str, AwsQueryErrorCode>,
+}
+
+impl FakeProvider {
+    /// `failures`에 등록된 API면 실패를 기록하고 `true`
+    fn fails(&self, operation: &'static str) -> bool {
+        let Some(&code) = self.failures.get(operation) else {
+            return false;
+        };
+        record_query_errors([AwsQueryError::new(code, operation, "injected failure")]);
+        true
+    }
+
+    /// 등록된 부분 결과를 현재 범위에 기록 (페이지 조회가 중간에 실패한 것처럼)
+    fn truncate(&self) {
+        if let Ok(mut failures) = self.partial_failures.lock() {
+            record_partial_failures(std::mem::take(&mut *failures));
+        }
+    }
+}
+
+fn selected<T: Clone>(items: &[T], keys: &[String], key: impl Fn(&T) -> Option<&str>) -> Vec<T> {
+    items
+        .iter()
+        .filter(|item| keys.is_empty() || key(item).is_some_and(|k| keys.iter().any(|v| v == k)))
+        .cloned()
+        .collect()
+}
+
+fn lookup<T: Clone>(map: &HashMap<String, Vec<T>>, key: &str) -> Vec<T> {
+    map.get(key).cloned().unwrap_or_default()
+}
+
+impl AwsProvider for FakeProvider {
+    fn describe_regions(&self) -> Vec<aws_sdk_ec2::types::Region> {
+        if self.fails("ec2:DescribeRegions") {
+            return Vec::new();
+        }
+        self.regions.clone()
+    }
+
+    fn describe_instances(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Instance> {
+        if self.fails("ec2:DescribeInstances") {
+            return Vec::new();
+        }
+        self.truncate();
+        self.instances
+            .iter()
+            .filter(|i| filter.matches(i.instance_id(), i.vpc_id()))
+            .cloned()
+            .collect()
+    }
+
+    fn describe_volumes(&self, volume_ids: &[String]) -> Vec<aws_sdk_ec2::types::Volume> {
+        selected(&self.volumes, volume_ids, |v| v.volume_id())
+    }
+
+    fn describe_instance_user_data(&self, instance_id: &str) -> Option<String> {
+        self.user_data.get(instance_id).cloned()
+    }
+
+    fn describe_images(&self, image_ids: &[String]) -> Vec<aws_sdk_ec2::types::Image> {
+        selected(&self.images, image_ids, |i| i.image_id())
+    }
+
+    fn describe_vpcs(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Vpc> {
+        if self.fails("ec2:DescribeVpcs") {
+            return Vec::new();
+        }
+        self.vpcs
+            .iter()
+            .filter(|v| filter.matches(v.vpc_id(), v.vpc_id()))
+            .cloned()
+            .collect()
+    }
+
+    fn describe_vpc_attribute(
+        &self,
+        vpc_id: &str,
+        attribute: aws_sdk_ec2::types::VpcAttributeName,
+    ) -> Option<bool> {
+        self.vpc_attributes
+            .get(&(vpc_id.to_string(), attribute))
+            .copied()
+    }
+
+    fn describe_subnets(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Subnet> {
+        self.subnets
+            .iter()
+            .filter(|s| filter.matches(s.subnet_id(), s.vpc_id()))
+            .cloned()
+            .collect()
+    }
+
+    fn describe_internet_gateways(
+        &self,
+        filter: &Ec2Filter,
+    ) -> Vec<aws_sdk_ec2::types::InternetGateway> {
+        self.internet_gateways
+            .iter()
+            .filter(|igw| {
+                let id = igw.internet_gateway_id();
+                if filter.vpc_id.is_none() {
+                    return filter.matches(id, None);
+                }
+                igw.attachments()
+                    .iter()
+                    .any(|a| filter.matches(id, a.vpc_id()))
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn describe_nat_gateways(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::NatGateway> {
+        self.nat_gateways
+            .iter()
+            .filter(|n| filter.matches(n.nat_gateway_id(), n.vpc_id()))
+            .cloned()
+            .collect()
+    }
+
+    fn describe_route_tables(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::RouteTable> {
+        if self.fails("ec2:DescribeRouteTables") {
+            return Vec::new();
+        }
+        self.route_tables
+            .iter()
+            .filter(|rt| filter.matches(rt.route_table_id(), rt.vpc_id()))
+            .cloned()
+            .collect()
+    }
+
+    fn describe_addresses(&self) -> Vec<aws_sdk_ec2::types::Address> {
+        self.addresses.clone()
+    }
+
+    fn describe_security_groups(
+        &self,
+        filter: &Ec2Filter,
+    ) -> Vec<aws_sdk_ec2::types::SecurityGroup> {
+        if self.fails("ec2:DescribeSecurityGroups") {
+            return Vec::new();
+        }
+        self.security_groups
+            .iter()
+            .filter(|sg| filter.matches(sg.group_id(), sg.vpc_id()))
+            .cloned()
+            .collect()
+    }
+
+    fn describe_load_balancers(
+        &self,
+        arns: &[String],
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::LoadBalancer> {
+        selected(&self.load_balancers, arns, |lb| lb.load_balancer_arn())
+    }
+
+    fn describe_listeners(
+        &self,
+        load_balancer_arn: &str,
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::Listener> {
+        lookup(&self.listeners, load_balancer_arn)
+    }
+
+    fn describe_target_groups(
+        &self,
+        arns: &[String],
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::TargetGroup> {
+        selected(&self.target_groups, arns, |tg| tg.target_group_arn())
+    }
+
+    fn describe_target_health(
+        &self,
+        target_group_arn: &str,
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::TargetHealthDescription> {
+        lookup(&self.target_health, target_group_arn)
+    }
+
+    fn get_role(&self, role_name: &str) -> Option<aws_sdk_iam::types::Role> {
+        self.roles
+            .iter()
+            .find(|r| r.role_name() == role_name)
+            .cloned()
+    }
+
+    fn list_attached_role_policies(
+        &self,
+        role_name: &str,
+    ) -> Vec<aws_sdk_iam::types::AttachedPolicy> {
+        lookup(&self.attached_role_policies, role_name)
+    }
+
+    fn list_role_policies(&self, role_name: &str) -> Vec<String> {
+        lookup(&self.role_policies, role_name)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    fn get_role_policy(&self, role_name: &str, policy_name: &str) -> Option<String> {
+        lookup(&self.role_policies, role_name)
+            .into_iter()
+            .find(|(name, _)| name == policy_name)
+            .map(|(_, document)| document)
+    }
+
+    fn list_account_aliases(&self) -> Vec<String> {
+        self.account_aliases.clone()
+    }
+
+    fn get_caller_identity(&self) -> Option<GetCallerIdentityOutput> {
+        if self.fails("sts:GetCallerIdentity") {
+            return None;
+        }
+        self.caller_identity.clone()
+    }
+
+    fn list_roots(&self) -> Vec<aws_sdk_organizations::types::Root> {
+        if self.fails("organizations:ListRoots") {
+            return Vec::new();
+        }
+        self.org_roots.clone()
+    }
+
+    fn list_organizational_units_for_parent(
+        &self,
+        parent_id: &str,
+    ) -> Vec<aws_sdk_organizations::types::OrganizationalUnit> {
+        lookup(&self.org_units, parent_id)
+    }
+
+    fn list_accounts_for_parent(
+        &self,
+        parent_id: &str,
+    ) -> Vec<aws_sdk_organizations::types::Account> {
+        lookup(&self.org_accounts, parent_id)
+    }
+
+    fn describe_repositories(&self, names: &[String]) -> Vec<aws_sdk_ecr::types::Repository> {
+        selected(&self.repositories, names, |r| r.repository_name())
+    }
+
+    fn describe_ecr_images(&self, repository_name: &str) -> Vec<aws_sdk_ecr::types::ImageDetail> {
+        lookup(&self.ecr_images, repository_name)
+    }
+
+    fn describe_auto_scaling_groups(
+        &self,
+        names: &[String],
+    ) -> Vec<aws_sdk_autoscaling::types::AutoScalingGroup> {
+        selected(&self.auto_scaling_groups, names, |asg| {
+            asg.auto_scaling_group_name()
+        })
+    }
+
+    fn describe_policies(&self, asg_name: &str) -> Vec<aws_sdk_autoscaling::types::ScalingPolicy> {
+        lookup(&self.scaling_policies, asg_name)
+    }
+
+    fn get_rest_apis(&self) -> Vec<aws_sdk_apigateway::types::RestApi> {
+        self.rest_apis.clone()
+    }
+
+    fn get_rest_api(&self, api_id: &str) -> Option<GetRestApiOutput> {
+        let api = self.rest_apis.iter().find(|a| a.id() == Some(api_id))?;
+        Some(
+            GetRestApiOutput::builder()
+                .set_id(api.id().map(|s| s.to_string()))
+                .set_name(api.name().map(|s| s.to_string()))
+                .set_description(api.description().map(|s| s.to_string()))
+                .set_created_date(api.created_date().cloned())
+                .set_endpoint_configuration(api.endpoint_configuration().cloned())
+                .build(),
+        )
+    }
+
+    fn get_rest_stages(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Stage> {
+        lookup(&self.rest_stages, api_id)
+    }
+
+    fn get_rest_resources(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Resource> {
+        lookup(&self.rest_resources, api_id)
+    }
+
+    fn get_rest_authorizers(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Authorizer> {
+        lookup(&self.rest_authorizers, api_id)
+    }
+
+    fn get_rest_domain_names(&self) -> Vec<aws_sdk_apigateway::types::DomainName> {
+        self.rest_domain_names.clone()
+    }
+
+    fn get_base_path_mappings(
+        &self,
+        domain_name: &str,
+    ) -> Vec<aws_sdk_apigateway::types::BasePathMapping> {
+        lookup(&self.base_path_mappings, domain_name)
+    }
+
+    fn get_http_apis(&self) -> Vec<aws_sdk_apigatewayv2::types::Api> {
+        self.http_apis.clone()
+    }
+
+    fn get_http_api(&self, api_id: &str) -> Option<GetApiOutput> {
+        let api = self.http_apis.iter().find(|a| a.api_id() == Some(api_id))?;
+        Some(
+            GetApiOutput::builder()
+                .set_api_id(api.api_id().map(|s| s.to_string()))
+                .set_name(api.name().map(|s| s.to_string()))
+                .set_protocol_type(api.protocol_type().cloned())
+                .set_api_endpoint(api.api_endpoint().map(|s| s.to_string()))
+                .set_description(api.description().map(|s| s.to_string()))
+                .set_created_date(api.created_date().cloned())
+                .build(),
+        )
+    }
+
+    fn get_http_stages(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Stage> {
+        lookup(&self.http_stages, api_id)
+    }
+
+    fn get_http_routes(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Route> {
+        lookup(&self.http_routes, api_id)
+    }
+
+    fn get_http_integrations(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Integration> {
+        lookup(&self.http_integrations, api_id)
+    }
+
+    fn get_http_authorizers(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Authorizer> {
+        lookup(&self.http_authorizers, api_id)
+    }
+
+    fn get_http_domain_names(&self) -> Vec<aws_sdk_apigatewayv2::types::DomainName> {
+        self.http_domain_names.clone()
+    }
+
+    fn get_api_mappings(&self, domain_name: &str) -> Vec<aws_sdk_apigatewayv2::types::ApiMapping> {
+        lookup(&self.api_mappings, domain_name)
+    }
+
+    fn list_keys(&self) -> Vec<aws_sdk_kms::types::KeyListEntry> {
+        self.kms_keys
+            .iter()
+            .map(|key| {
+                aws_sdk_kms::types::KeyListEntry::builder()
+                    .key_id(key.key_id())
+                    .set_key_arn(key.arn().map(|s| s.to_string()))
+                    .build()
+            })
+            .collect()
+    }
+
+    fn describe_key(&self, key_id: &str) -> Option<aws_sdk_kms::types::KeyMetadata> {
+        self.kms_keys
+            .iter()
+            .find(|key| key.key_id() == key_id || key.arn() == Some(key_id))
+            .cloned()
+    }
+
+    fn list_aliases(&self, key_id: Option<&str>) -> Vec<aws_sdk_kms::types::AliasListEntry> {
+        self.kms_aliases
+            .iter()
+            .filter(|alias| key_id.is_none() || alias.target_key_id() == key_id)
+            .cloned()
+            .collect()
+    }
+
+    fn get_key_rotation_status(&self, key_id: &str) -> Option<GetKeyRotationStatusOutput> {
+        self.key_rotations.get(key_id).cloned()
+    }
+
+    fn get_key_policy(&self, key_id: &str) -> Option<String> {
+        self.key_policies.get(key_id).cloned()
+    }
+
+    fn list_grants(&self, key_id: &str) -> Vec<aws_sdk_kms::types::GrantListEntry> {
+        lookup(&self.grants, key_id)
+    }
+
+    fn list_resource_tags(&self, key_id: &str) -> Vec<aws_sdk_kms::types::Tag> {
+        lookup(&self.kms_tags, key_id)
+    }
+
+    fn list_secrets(&self) -> Vec<aws_sdk_secretsmanager::types::SecretListEntry> {
+        self.secrets.clone()
+    }
+
+    fn describe_secret(&self, secret_id: &str) -> Option<DescribeSecretOutput> {
+        let secret = self
+            .secrets
+            .iter()
+            .find(|s| s.arn() == Some(secret_id) || s.name() == Some(secret_id))?;
+        Some(
+            DescribeSecretOutput::builder()
+                .set_arn(secret.arn().map(|s| s.to_string()))
+                .set_name(secret.name().map(|s| s.to_string()))
+                .set_description(secret.description().map(|s| s.to_string()))
+                .set_kms_key_id(secret.kms_key_id().map(|s| s.to_string()))
+                .set_rotation_enabled(secret.rotation_enabled())
+                .set_rotation_lambda_arn(secret.rotation_lambda_arn().map(|s| s.to_string()))
+                .set_rotation_rules(secret.rotation_rules().cloned())
+                .set_last_rotated_date(secret.last_rotated_date().cloned())
+                .set_last_changed_date(secret.last_changed_date().cloned())
+                .set_last_accessed_date(secret.last_accessed_date().cloned())
+                .set_next_rotation_date(secret.next_rotation_date().cloned())
+                .set_created_date(secret.created_date().cloned())
+                .set_owning_service(secret.owning_service().map(|s| s.to_string()))
+                .set_primary_region(secret.primary_region().map(|s| s.to_string()))
+                .set_tags(Some(secret.tags().to_vec()))
+                .build(),
+        )
+    }
+
+    fn describe_parameters(
+        &self,
+        name: Option<&str>,
+    ) -> Vec<aws_sdk_ssm::types::ParameterMetadata> {
+        self.parameters
+            .iter()
+            .filter(|p| name.is_none() || p.name() == name)
+            .cloned()
+            .collect()
+    }
+
+    fn list_parameter_tags(&self, name: &str) -> Vec<aws_sdk_ssm::types::Tag> {
+        lookup(&self.parameter_tags, name)
+    }
+
+    fn describe_file_systems(
+        &self,
+        file_system_id: Option<&str>,
+    ) -> Vec<aws_sdk_efs::types::FileSystemDescription> {
+        self.file_systems
+            .iter()
+            .filter(|fs| file_system_id.is_none_or(|id| fs.file_system_id() == id))
+            .cloned()
+            .collect()
+    }
+
+    fn describe_lifecycle_configuration(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::LifecyclePolicy> {
+        lookup(&self.lifecycle_policies, file_system_id)
+    }
+
+    fn describe_backup_policy(
+        &self,
+        file_system_id: &str,
+    ) -> Option<aws_sdk_efs::types::BackupPolicy> {
+        self.backup_policies
+            .get(file_system_id)
+            .cloned()
+            .or_else(|| {
+                aws_sdk_efs::types::BackupPolicy::builder()
+                    .status(aws_sdk_efs::types::Status::Disabled)
+                    .build()
+                    .ok()
+            })
+    }
+
+    fn describe_access_points(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::AccessPointDescription> {
+        lookup(&self.access_points, file_system_id)
+    }
+
+    fn describe_mount_targets(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::MountTargetDescription> {
+        self.truncate();
+        lookup(&self.mount_targets, file_system_id)
+    }
+
+    fn describe_mount_target_security_groups(&self, mount_target_id: &str) -> Vec<String> {
+        lookup(&self.mount_target_security_groups, mount_target_id)
+    }
+
+    fn describe_metric_alarms(
+        &self,
+        names: &[String],
+    ) -> Vec<aws_sdk_cloudwatch::types::MetricAlarm> {
+        selected(&self.metric_alarms, names, |a| a.alarm_name())
+    }
+
+    fn list_event_buses(&self) -> Vec<aws_sdk_eventbridge::types::EventBus> {
+        self.event_buses.clone()
+    }
+
+    fn list_rules(&self, event_bus: &str) -> Vec<aws_sdk_eventbridge::types::Rule> {
+        lookup(&self.rules, event_bus)
+    }
+
+    fn describe_rule(&self, event_bus: &str, name: &str) -> Option<DescribeRuleOutput> {
+        let rule = lookup(&self.rules, event_bus)
+            .into_iter()
+            .find(|r| r.name() == Some(name))?;
+        Some(
+            DescribeRuleOutput::builder()
+                .set_name(rule.name().map(|s| s.to_string()))
+                .set_arn(rule.arn().map(|s| s.to_string()))
+                .set_event_pattern(rule.event_pattern().map(|s| s.to_string()))
+                .set_schedule_expression(rule.schedule_expression().map(|s| s.to_string()))
+                .set_state(rule.state().cloned())
+                .set_description(rule.description().map(|s| s.to_string()))
+                .set_role_arn(rule.role_arn().map(|s| s.to_string()))
+                .set_managed_by(rule.managed_by().map(|s| s.to_string()))
+                .set_event_bus_name(rule.event_bus_name().map(|s| s.to_string()))
+                .build(),
+        )
+    }
+
+    fn list_targets_by_rule(
+        &self,
+        event_bus: &str,
+        rule: &str,
+    ) -> Vec<aws_sdk_eventbridge::types::Target> {
+        self.rule_targets
+            .get(&(event_bus.to_string(), rule.to_string()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn list_state_machines(&self) -> Vec<aws_sdk_sfn::types::StateMachineListItem> {
+        self.state_machines
+            .iter()
+            .filter_map(|machine| {
+                aws_sdk_sfn::types::StateMachineListItem::builder()
+                    .state_machine_arn(machine.state_machine_arn())
+                    .name(machine.name())
+                    .r#type(machine.r#type().clone())
+                    .creation_date(*machine.creation_date())
+                    .build()
+                    .ok()
+            })
+            .collect()
+    }
+
+    fn describe_state_machine(&self, arn: &str) -> Option<DescribeStateMachineOutput> {
+        self.state_machines
+            .iter()
+            .find(|machine| machine.state_machine_arn() == arn)
+            .cloned()
+    }
+
+    fn list_functions(&self) -> Vec<aws_sdk_lambda::types::FunctionConfiguration> {
+        self.functions
+            .iter()
+            .map(|function| {
+                aws_sdk_lambda::types::FunctionConfiguration::builder()
+                    .set_function_name(function.function_name().map(str::to_string))
+                    .set_function_arn(function.function_arn().map(str::to_string))
+                    .set_runtime(function.runtime().cloned())
+                    .set_package_type(function.package_type().cloned())
+                    .set_last_modified(function.last_modified().map(str::to_string))
+                    .build()
+            })
+            .collect()
+    }
+
+    fn get_function_configuration(&self, function: &str) -> Option<GetFunctionConfigurationOutput> {
+        self.functions
+            .iter()
+            .find(|f| f.function_arn() == Some(function) || f.function_name() == Some(function))
+            .cloned()
+    }
+}
+
+impl FakeProvider {
+    /// 화면/핸들러 테스트용 샘플 계정 (서비스마다 리소스 하나씩, `vpc-1234` 기준으로 연결)
+    pub fn sample() -> Self {
+        use aws_sdk_ec2::types as ec2;
+
+        const VPC_ID: &str = "vpc-1234";
+        const SUBNET_ID: &str = "subnet-1234";
+        const AZ: &str = "ap-northeast-2a";
+        let name_tag = |value: &str| ec2::Tag::builder().key("Name").value(value).build();
+        let created = aws_sdk_ec2::primitives::DateTime::from_secs(1_767_225_600);
+
+        let mut provider = FakeProvider {
+            instances: vec![
+                ec2::Instance::builder()
+                    .instance_id("i-1234")
+                    .instance_type(ec2::InstanceType::T3Micro)
+                    .image_id("ami-1234")
+                    .key_name("kp")
+                    .vpc_id(VPC_ID)
+                    .subnet_id(SUBNET_ID)
+                    .private_ip_address("10.0.0.10")
+                    .placement(ec2::Placement::builder().availability_zone(AZ).build())
+                    .state(
+                        ec2::InstanceState::builder()
+                            .name(ec2::InstanceStateName::Running)
+                            .build(),
+                    )
+                    .security_groups(
+                        ec2::GroupIdentifier::builder()
+                            .group_id("sg-1234")
+                            .group_name("web-sg")
+                            .build(),
+                    )
+                    .tags(name_tag("web-1"))
+                    .build(),
+            ],
+            images: vec![
+                ec2::Image::builder()
+                    .image_id("ami-1234")
+                    .name("al2023-ami")
+                    .build(),
+            ],
+            vpcs: vec![
+                ec2::Vpc::builder()
+                    .vpc_id(VPC_ID)
+                    .cidr_block("10.0.0.0/16")
+                    .state(ec2::VpcState::Available)
+                    .tags(name_tag("main-vpc"))
+                    .build(),
+            ],
+            subnets: vec![
+                ec2::Subnet::builder()
+                    .subnet_id(SUBNET_ID)
+                    .vpc_id(VPC_ID)
+                    .cidr_block("10.0.0.0/24")
+                    .availability_zone(AZ)
+                    .state(ec2::SubnetState::Available)
+                    .tags(name_tag("subnet-a"))
+                    .build(),
+            ],
+            internet_gateways: vec![
+                ec2::InternetGateway::builder()
+                    .internet_gateway_id("igw-1234")
+                    .attachments(
+                        ec2::InternetGatewayAttachment::builder()
+                            .vpc_id(VPC_ID)
+                            .state(ec2::AttachmentStatus::Attached)
+                            .build(),
+                    )
+                    .tags(name_tag("main-igw"))
+                    .build(),
+            ],
+            security_groups: vec![
+                ec2::SecurityGroup::builder()
+                    .group_id("sg-1234")
+                    .group_name("web-sg")
+                    .description("web")
+                    .vpc_id(VPC_ID)
+                    .build(),
+            ],
+            ..FakeProvider::default()
+        };
+        provider.vpc_attributes = [
+            (
+                (VPC_ID.to_string(), ec2::VpcAttributeName::EnableDnsSupport),
+                true,
+            ),
+            (
+                (
+                    VPC_ID.to_string(),
+                    ec2::VpcAttributeName::EnableDnsHostnames,
+                ),
+                true,
+            ),
+        ]
+        .into();
+
+        provider.load_balancers = vec![
+            aws_sdk_elasticloadbalancingv2::types::LoadBalancer::builder()
+                .load_balancer_arn(SAMPLE_LOAD_BALANCER_ARN)
+                .load_balancer_name("web-alb")
+                .dns_name("web-alb.example.com")
+                .r#type(aws_sdk_elasticloadbalancingv2::types::LoadBalancerTypeEnum::Application)
+                .scheme(aws_sdk_elasticloadbalancingv2::types::LoadBalancerSchemeEnum::Internal)
+                .vpc_id(VPC_ID)
+                .build(),
+        ];
+
+        provider.repositories = vec![
+            aws_sdk_ecr::types::Repository::builder()
+                .repository_name("repo-a")
+                .repository_uri("123456789012.dkr.ecr.ap-northeast-2.amazonaws.com/repo-a")
+                .image_tag_mutability(aws_sdk_ecr::types::ImageTagMutability::Mutable)
+                .created_at(created)
+                .build(),
+        ];
+
+        provider.auto_scaling_groups = vec![
+            aws_sdk_autoscaling::types::AutoScalingGroup::builder()
+                .auto_scaling_group_name("asg-a")
+                .auto_scaling_group_arn(
+                    "arn:aws:autoscaling:ap-northeast-2:123456789012:autoScalingGroup:asg-a",
+                )
+                .min_size(1)
+                .max_size(2)
+                .desired_capacity(1)
+                .health_check_type("EC2")
+                .created_time(created)
+                .build(),
+        ];
+
+        let api_id = "a1b2c3d4e5";
+        provider.rest_apis = vec![
+            aws_sdk_apigateway::types::RestApi::builder()
+                .id(api_id)
+                .name("orders-api")
+                .created_date(created)
+                .build(),
+        ];
+        provider.rest_resources.insert(
+            api_id.to_string(),
+            vec![
+                aws_sdk_apigateway::types::Resource::builder()
+                    .path("/orders")
+                    .resource_methods(
+                        "GET",
+                        aws_sdk_apigateway::types::Method::builder()
+                            .http_method("GET")
+                            .authorization_type("NONE")
+                            .method_integration(
+                                aws_sdk_apigateway::types::Integration::builder()
+                                    .r#type(aws_sdk_apigateway::types::IntegrationType::AwsProxy)
+                                    .uri("arn:aws:lambda:ap-northeast-2:123456789012:function:list-orders")
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .build(),
+            ],
+        );
+
+        let key_id = "1234abcd-12ab-34cd-56ef-1234567890ab";
+        provider.kms_keys = vec![
+            aws_sdk_kms::types::KeyMetadata::builder()
+                .key_id(key_id)
+                .arn(format!(
+                    "arn:aws:kms:ap-northeast-2:123456789012:key/{key_id}"
+                ))
+                .key_manager(aws_sdk_kms::types::KeyManagerType::Customer)
+                .key_spec(aws_sdk_kms::types::KeySpec::SymmetricDefault)
+                .key_usage(aws_sdk_kms::types::KeyUsageType::EncryptDecrypt)
+                .key_state(aws_sdk_kms::types::KeyState::Enabled)
+                .build()
+                .expect("key metadata"),
+        ];
+        provider.kms_aliases = vec![
+            aws_sdk_kms::types::AliasListEntry::builder()
+                .alias_name("alias/app-data")
+                .target_key_id(key_id)
+                .build(),
+        ];
+        provider.key_rotations.insert(
+            key_id.to_string(),
+            GetKeyRotationStatusOutput::builder()
+                .key_rotation_enabled(true)
+                .rotation_period_in_days(365)
+                .build(),
+        );
+
+        provider.secrets = vec![
+            aws_sdk_secretsmanager::types::SecretListEntry::builder()
+                .name("prod/db/password")
+                .arn("arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/db/password-AbCdEf")
+                .last_changed_date(created)
+                .build(),
+        ];
+
+        provider.parameters = vec![
+            aws_sdk_ssm::types::ParameterMetadata::builder()
+                .name("/app/prod/db-url")
+                .r#type(aws_sdk_ssm::types::ParameterType::SecureString)
+                .key_id("alias/aws/ssm")
+                .tier(aws_sdk_ssm::types::ParameterTier::Standard)
+                .data_type("text")
+                .version(1)
+                .build(),
+        ];
+
+        let fs_id = "fs-0123456789abcdef0";
+        provider.file_systems = vec![
+            aws_sdk_efs::types::FileSystemDescription::builder()
+                .owner_id("123456789012")
+                .creation_token("shared-data")
+                .file_system_id(fs_id)
+                .name("shared-data")
+                .creation_time(created)
+                .life_cycle_state(aws_sdk_efs::types::LifeCycleState::Available)
+                .number_of_mount_targets(1)
+                .size_in_bytes(
+                    aws_sdk_efs::types::FileSystemSize::builder()
+                        .value(6144)
+                        .build(),
+                )
+                .performance_mode(aws_sdk_efs::types::PerformanceMode::GeneralPurpose)
+                .throughput_mode(aws_sdk_efs::types::ThroughputMode::Elastic)
+                .encrypted(true)
+                .set_tags(Some(vec![]))
+                .build()
+                .expect("file system"),
+        ];
+        provider.mount_targets.insert(
+            fs_id.to_string(),
+            vec![
+                aws_sdk_efs::types::MountTargetDescription::builder()
+                    .mount_target_id("fsmt-a")
+                    .file_system_id(fs_id)
+                    .subnet_id(SUBNET_ID)
+                    .vpc_id(VPC_ID)
+                    .availability_zone_name(AZ)
+                    .ip_address("10.0.0.25")
+                    .life_cycle_state(aws_sdk_efs::types::LifeCycleState::Available)
+                    .build()
+                    .expect("mount target"),
+            ],
+        );
+        provider
+            .mount_target_security_groups
+            .insert("fsmt-a".to_string(), vec!["sg-1234".to_string()]);
+
+        provider.metric_alarms = vec![
+            aws_sdk_cloudwatch::types::MetricAlarm::builder()
+                .alarm_name("web-cpu-high")
+                .namespace("AWS/EC2")
+                .metric_name("CPUUtilization")
+                .statistic(aws_sdk_cloudwatch::types::Statistic::Average)
+                .period(300)
+                .evaluation_periods(2)
+                .threshold(80.0)
+                .comparison_operator(
+                    aws_sdk_cloudwatch::types::ComparisonOperator::GreaterThanOrEqualToThreshold,
+                )
+                .state_value(aws_sdk_cloudwatch::types::StateValue::Ok)
+                .dimensions(
+                    aws_sdk_cloudwatch::types::Dimension::builder()
+                        .name("InstanceId")
+                        .value("i-1234")
+                        .build(),
+                )
+                .build(),
+        ];
+
+        let state_machine_arn =
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow";
+        provider.rules.insert(
+            "default".to_string(),
+            vec![
+                aws_sdk_eventbridge::types::Rule::builder()
+                    .name("nightly-report")
+                    .arn("arn:aws:events:ap-northeast-2:123456789012:rule/nightly-report")
+                    .event_bus_name("default")
+                    .state(aws_sdk_eventbridge::types::RuleState::Enabled)
+                    .schedule_expression("cron(0 18 * * ? *)")
+                    .build(),
+            ],
+        );
+        provider.rule_targets.insert(
+            ("default".to_string(), "nightly-report".to_string()),
+            vec![
+                aws_sdk_eventbridge::types::Target::builder()
+                    .id("workflow")
+                    .arn(state_machine_arn)
+                    .build()
+                    .expect("event target"),
+            ],
+        );
+
+        provider.state_machines = vec![
+            DescribeStateMachineOutput::builder()
+                .state_machine_arn(state_machine_arn)
+                .name("order-workflow")
+                .definition(
+                    r#"{"StartAt":"Report","States":{"Report":{"Type":"Pass","End":true}}}"#,
+                )
+                .role_arn("arn:aws:iam::123456789012:role/sfn-orders")
+                .r#type(aws_sdk_sfn::types::StateMachineType::Standard)
+                .status(aws_sdk_sfn::types::StateMachineStatus::Active)
+                .creation_date(created)
+                .build()
+                .expect("state machine"),
+        ];
+
+        provider.functions = vec![
+            GetFunctionConfigurationOutput::builder()
+                .function_name("list-orders")
+                .function_arn(SAMPLE_LAMBDA_ARN)
+                .runtime(aws_sdk_lambda::types::Runtime::Python312)
+                .handler("app.handler")
+                .memory_size(128)
+                .timeout(3)
+                .role("arn:aws:iam::123456789012:role/list-orders")
+                .last_modified("2026-01-01T00:00:00.000+0000")
+                .build(),
+        ];
+
+        provider
+    }
+}
+
+/// `FakeProvider::sample()`의 Lambda 함수 ARN (API Gateway 통합 대상)
+pub const SAMPLE_LAMBDA_ARN: &str =
+    "arn:aws:lambda:ap-northeast-2:123456789012:function:list-orders";
+
+/// `FakeProvider::sample()`의 로드 밸런서 ARN
+pub const SAMPLE_LOAD_BALANCER_ARN: &str =
+    "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/web-alb/1234";
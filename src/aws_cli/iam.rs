@@ -1,5 +1,4 @@
-use crate::aws_cli::common::{extract_json_value, run_aws_cli};
-use serde::Deserialize;
+use crate::aws_cli::provider::AwsProvider;
 
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -23,143 +22,48 @@ pub struct InlinePolicy {
     pub document: String,
 }
 
-pub fn get_iam_role_detail(role_name: &str) -> Option<IamRoleDetail> {
+pub fn get_iam_role_detail(provider: &dyn AwsProvider, role_name: &str) -> Option<IamRoleDetail> {
     // 역할 기본 정보
-    let output = run_aws_cli(&[
-        "iam",
-        "get-role",
-        "--role-name",
-        role_name,
-        "--output",
-        "json",
-    ])?;
-    let attached_policies = get_attached_policies(role_name);
-    let inline_policies = get_inline_policies(role_name);
-    build_iam_role_detail_from_output(&output, attached_policies, inline_policies)
-}
-
-fn extract_assume_role_policy(json: &str) -> String {
-    let v: serde_json::Value = match serde_json::from_str(json) {
-        Ok(v) => v,
-        Err(_) => return String::new(),
-    };
-    if let Some(policy) = v
-        .get("Role")
-        .and_then(|r| r.get("AssumeRolePolicyDocument"))
-    {
-        serde_json::to_string_pretty(policy).unwrap_or_default()
-    } else {
-        String::new()
-    }
-}
-
-fn get_attached_policies(role_name: &str) -> Vec<AttachedPolicy> {
-    let output = match run_aws_cli(&[
-        "iam",
-        "list-attached-role-policies",
-        "--role-name",
-        role_name,
-        "--output",
-        "json",
-    ]) {
-        Some(o) => o,
-        None => return Vec::new(),
-    };
-
-    parse_attached_policies_response(&output).unwrap_or_default()
-}
-
-fn get_inline_policies(role_name: &str) -> Vec<InlinePolicy> {
-    let output = match run_aws_cli(&[
-        "iam",
-        "list-role-policies",
-        "--role-name",
-        role_name,
-        "--output",
-        "json",
-    ]) {
-        Some(o) => o,
-        None => return Vec::new(),
-    };
-
-    let policy_names = match parse_role_policy_names_response(&output) {
-        Some(names) => names,
-        None => return Vec::new(),
-    };
-    build_inline_policies_from_names(policy_names, |policy_name| {
-        get_inline_policy_document(role_name, policy_name)
-    })
-}
-
-fn get_inline_policy_document(role_name: &str, policy_name: &str) -> Option<String> {
-    let output = run_aws_cli(&[
-        "iam",
-        "get-role-policy",
-        "--role-name",
-        role_name,
-        "--policy-name",
-        policy_name,
-        "--output",
-        "json",
-    ])?;
-
-    parse_inline_policy_document_response(&output)
-}
-
-fn parse_attached_policies_response(output: &str) -> Option<Vec<AttachedPolicy>> {
-    #[derive(Deserialize)]
-    struct Response {
-        #[serde(rename = "AttachedPolicies")]
-        attached_policies: Vec<Policy>,
-    }
-
-    #[derive(Deserialize)]
-    struct Policy {
-        #[serde(rename = "PolicyName")]
-        policy_name: String,
-        #[serde(rename = "PolicyArn")]
-        policy_arn: String,
-    }
-
-    let response: Response = serde_json::from_str(output).ok()?;
-    Some(
-        response
-            .attached_policies
-            .into_iter()
-            .map(|p| AttachedPolicy {
-                name: p.policy_name,
-                arn: p.policy_arn,
-            })
-            .collect(),
-    )
+    let role = provider.get_role(role_name)?;
+    let attached_policies = map_attached_policies(&provider.list_attached_role_policies(role_name));
+    let inline_policies =
+        build_inline_policies_from_names(provider.list_role_policies(role_name), |policy_name| {
+            provider
+                .get_role_policy(role_name, policy_name)
+                .map(|document| format_policy_document(&document))
+        });
+    map_iam_role_detail(&role, attached_policies, inline_policies)
 }
 
-fn parse_role_policy_names_response(output: &str) -> Option<Vec<String>> {
-    #[derive(Deserialize)]
-    struct Response {
-        #[serde(rename = "PolicyNames")]
-        policy_names: Vec<String>,
-    }
-
-    serde_json::from_str::<Response>(output)
+/// URL 인코딩된 정책 문서를 보기 좋은 JSON으로 변환 (파싱 실패 시 원문 유지)
+fn format_policy_document(document: &str) -> String {
+    percent_encoding::percent_decode_str(document)
+        .decode_utf8()
         .ok()
-        .map(|r| r.policy_names)
+        .and_then(|decoded| serde_json::from_str::<serde_json::Value>(&decoded).ok())
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| document.to_string())
 }
 
-fn parse_inline_policy_document_response(output: &str) -> Option<String> {
-    let v: serde_json::Value = serde_json::from_str(output).ok()?;
-    let policy = v.get("PolicyDocument")?;
-    serde_json::to_string_pretty(policy).ok()
+fn map_attached_policies(policies: &[aws_sdk_iam::types::AttachedPolicy]) -> Vec<AttachedPolicy> {
+    let mut policies: Vec<AttachedPolicy> = policies
+        .iter()
+        .map(|p| AttachedPolicy {
+            name: p.policy_name().unwrap_or_default().to_string(),
+            arn: p.policy_arn().unwrap_or_default().to_string(),
+        })
+        .collect();
+    policies.sort_by(|a, b| a.name.cmp(&b.name));
+    policies
 }
 
-fn build_iam_role_detail_from_output(
-    output: &str,
+fn map_iam_role_detail(
+    role: &aws_sdk_iam::types::Role,
     attached_policies: Vec<AttachedPolicy>,
     inline_policies: Vec<InlinePolicy>,
 ) -> Option<IamRoleDetail> {
-    let name = extract_json_value(output, "RoleName").unwrap_or_default();
-    let arn = extract_json_value(output, "Arn").unwrap_or_default();
-    let assume_role_policy = extract_assume_role_policy(output);
+    let name = role.role_name().to_string();
+    let arn = role.arn().to_string();
 
     if name.is_empty() || arn.is_empty() {
         return None;
@@ -168,7 +72,10 @@ fn build_iam_role_detail_from_output(
     Some(IamRoleDetail {
         name,
         arn,
-        assume_role_policy,
+        assume_role_policy: role
+            .assume_role_policy_document()
+            .map(format_policy_document)
+            .unwrap_or_default(),
         attached_policies,
         inline_policies,
     })
@@ -181,6 +88,8 @@ fn build_inline_policies_from_names<F>(
 where
     F: FnMut(&str) -> Option<String>,
 {
+    let mut policy_names = policy_names;
+    policy_names.sort();
     let mut policies = Vec::new();
 
     for policy_name in policy_names {
@@ -198,97 +107,83 @@ where
 #[cfg(test)]
 mod tests {
     use super::{
-        build_iam_role_detail_from_output, build_inline_policies_from_names,
-        extract_assume_role_policy, parse_attached_policies_response,
-        parse_inline_policy_document_response, parse_role_policy_names_response,
+        build_inline_policies_from_names, format_policy_document, get_iam_role_detail,
+        map_attached_policies, map_iam_role_detail,
     };
+    use crate::aws_cli::fake_provider::FakeProvider;
+    use aws_sdk_iam::primitives::DateTime;
+    use aws_sdk_iam::types::{AttachedPolicy, Role};
+
+    const ASSUME_ROLE_POLICY: &str =
+        "%7B%22Version%22%3A%222012-10-17%22%2C%22Statement%22%3A%5B%5D%7D";
+
+    fn role(name: &str) -> Role {
+        Role::builder()
+            .path("/")
+            .role_name(name)
+            .role_id("AROAEXAMPLE")
+            .arn(format!("arn:aws:iam::123456789012:role/{}", name))
+            .create_date(DateTime::from_secs(0))
+            .assume_role_policy_document(ASSUME_ROLE_POLICY)
+            .build()
+            .expect("role")
+    }
 
     #[test]
-    fn extract_assume_role_policy_returns_pretty_json() {
-        let payload = r#"
-            {
-              "Role": {
-                "AssumeRolePolicyDocument": {
-                  "Version": "2012-10-17",
-                  "Statement": []
-                }
-              }
-            }
-        "#;
-        let policy = extract_assume_role_policy(payload);
+    fn format_policy_document_decodes_and_pretty_prints() {
+        let policy = format_policy_document(ASSUME_ROLE_POLICY);
         assert!(policy.contains("\"Version\""));
         assert!(policy.contains("2012-10-17"));
+        assert!(policy.contains('\n'));
     }
 
     #[test]
-    fn extract_assume_role_policy_returns_empty_on_invalid_payload() {
-        assert_eq!(extract_assume_role_policy("not-json"), "");
-        assert_eq!(extract_assume_role_policy(r#"{"Role":{}}"#), "");
+    fn format_policy_document_keeps_invalid_payload() {
+        assert_eq!(format_policy_document("%FF"), "%FF");
+        assert_eq!(format_policy_document("not-json"), "not-json");
     }
 
     #[test]
-    fn parse_attached_policies_response_maps_policy_fields() {
-        let payload = r#"
-            {
-              "AttachedPolicies": [
-                {"PolicyName": "ReadOnlyAccess", "PolicyArn": "arn:aws:iam::aws:policy/ReadOnlyAccess"}
-              ]
-            }
-        "#;
-
-        let policies = parse_attached_policies_response(payload).expect("parse policies");
-        assert_eq!(policies.len(), 1);
-        assert_eq!(policies[0].name, "ReadOnlyAccess");
-    }
-
-    #[test]
-    fn parse_role_policy_names_response_reads_names() {
-        let payload = r#"{"PolicyNames":["inline-a","inline-b"]}"#;
-        let names = parse_role_policy_names_response(payload).expect("parse policy names");
-        assert_eq!(names, vec!["inline-a".to_string(), "inline-b".to_string()]);
-    }
-
-    #[test]
-    fn parse_inline_policy_document_response_returns_json_string() {
-        let payload = r#"
-            {
-              "PolicyDocument": {
-                "Version": "2012-10-17",
-                "Statement": []
-              }
-            }
-        "#;
-        let doc = parse_inline_policy_document_response(payload).expect("parse inline doc");
-        assert!(doc.contains("\"Version\""));
-        assert!(doc.contains("2012-10-17"));
+    fn map_attached_policies_maps_policy_fields_sorted() {
+        let policies = map_attached_policies(&[
+            AttachedPolicy::builder()
+                .policy_name("ReadOnlyAccess")
+                .policy_arn("arn:aws:iam::aws:policy/ReadOnlyAccess")
+                .build(),
+            AttachedPolicy::builder()
+                .policy_name("AmazonSSMManagedInstanceCore")
+                .policy_arn("arn:aws:iam::aws:policy/AmazonSSMManagedInstanceCore")
+                .build(),
+        ]);
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[0].name, "AmazonSSMManagedInstanceCore");
+        assert_eq!(policies[1].arn, "arn:aws:iam::aws:policy/ReadOnlyAccess");
     }
 
     #[test]
-    fn build_iam_role_detail_from_output_requires_name_and_arn() {
-        let payload = r#"
-            {
-              "Role": {
-                "RoleName": "role-a",
-                "Arn": "arn:aws:iam::123456789012:role/role-a",
-                "AssumeRolePolicyDocument": {"Version":"2012-10-17"}
-              }
-            }
-        "#;
-        let detail = build_iam_role_detail_from_output(payload, vec![], vec![]).expect("detail");
+    fn map_iam_role_detail_requires_name_and_arn() {
+        let detail = map_iam_role_detail(&role("role-a"), vec![], vec![]).expect("detail");
         assert_eq!(detail.name, "role-a");
         assert_eq!(detail.arn, "arn:aws:iam::123456789012:role/role-a");
         assert!(detail.assume_role_policy.contains("Version"));
 
-        let invalid = build_iam_role_detail_from_output(r#"{"Role":{}}"#, vec![], vec![]);
-        assert!(invalid.is_none());
+        let unnamed = Role::builder()
+            .path("/")
+            .role_name("")
+            .role_id("AROAEXAMPLE")
+            .arn("")
+            .create_date(DateTime::from_secs(0))
+            .build()
+            .expect("role");
+        assert!(map_iam_role_detail(&unnamed, vec![], vec![]).is_none());
     }
 
     #[test]
     fn build_inline_policies_from_names_skips_missing_documents() {
         let names = vec![
-            "inline-a".to_string(),
-            "inline-b".to_string(),
             "inline-c".to_string(),
+            "inline-b".to_string(),
+            "inline-a".to_string(),
         ];
         let policies = build_inline_policies_from_names(names, |name| match name {
             "inline-a" => Some("{\"Statement\":[]}".to_string()),
@@ -299,4 +194,34 @@ mod tests {
         assert_eq!(policies[0].name, "inline-a");
         assert_eq!(policies[1].name, "inline-c");
     }
+
+    #[test]
+    fn get_iam_role_detail_collects_attached_and_inline_policies() {
+        let provider = FakeProvider {
+            roles: vec![role("role-web")],
+            attached_role_policies: [(
+                "role-web".to_string(),
+                vec![
+                    AttachedPolicy::builder()
+                        .policy_name("ReadOnlyAccess")
+                        .policy_arn("arn:aws:iam::aws:policy/ReadOnlyAccess")
+                        .build(),
+                ],
+            )]
+            .into(),
+            role_policies: [(
+                "role-web".to_string(),
+                vec![("inline-s3".to_string(), ASSUME_ROLE_POLICY.to_string())],
+            )]
+            .into(),
+            ..FakeProvider::default()
+        };
+
+        let detail = get_iam_role_detail(&provider, "role-web").expect("detail");
+        assert_eq!(detail.attached_policies.len(), 1);
+        assert_eq!(detail.inline_policies.len(), 1);
+        assert_eq!(detail.inline_policies[0].name, "inline-s3");
+        assert!(detail.inline_policies[0].document.contains("2012-10-17"));
+        assert!(get_iam_role_detail(&provider, "missing").is_none());
+    }
 }
@@ -0,0 +1,110 @@
+//! 프로필이 가리키는 계정: STS 호출자와 IAM 계정 별칭
+
+use crate::aws_cli::account::{AccountTarget, with_account};
+use crate::aws_cli::common::{capture_query_errors, map_concurrent};
+use crate::aws_cli::provider::AwsProvider;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountIdentity {
+    pub account_id: String,
+    pub alias: Option<String>,
+    pub arn: String,
+}
+
+impl AccountIdentity {
+    /// `별칭 (계정 ID)`, 별칭이 없으면 계정 ID
+    pub fn label(&self) -> String {
+        match &self.alias {
+            Some(alias) => format!("{} ({})", alias, self.account_id),
+            None => self.account_id.clone(),
+        }
+    }
+
+    /// 공유용 사본: 계정 ID는 끝 4자리만 남기고 (ARN 안의 ID 포함) 별칭은 뺌
+    pub fn redacted(&self) -> Self {
+        let visible = self.account_id.len().saturating_sub(4);
+        let masked = format!("{}{}", "*".repeat(visible), &self.account_id[visible..]);
+        Self {
+            account_id: masked.clone(),
+            alias: None,
+            arn: if self.account_id.is_empty() {
+                self.arn.clone()
+            } else {
+                self.arn.replace(&self.account_id, &masked)
+            },
+        }
+    }
+}
+
+/// 현재 자격 증명의 호출자와 계정 별칭 (호출자를 확인하지 못하면 `None`)
+pub fn caller_identity(provider: &dyn AwsProvider) -> Option<AccountIdentity> {
+    let caller = provider.get_caller_identity()?;
+    Some(AccountIdentity {
+        account_id: caller.account().unwrap_or_default().to_string(),
+        alias: provider.list_account_aliases().into_iter().next(),
+        arn: caller.arn().unwrap_or_default().to_string(),
+    })
+}
+
+/// 프로필마다 계정 정보를 동시에 조회. 로그인되지 않은 프로필은 `None`이며
+/// 조회 실패로 기록하지 않음
+pub fn profile_identities(
+    provider: &dyn AwsProvider,
+    profiles: &[String],
+) -> Vec<(String, Option<AccountIdentity>)> {
+    map_concurrent(profiles, |profile| {
+        let target = AccountTarget {
+            profile: Some(profile.clone()),
+            role_chain: Vec::new(),
+        };
+        let (identity, _) =
+            capture_query_errors(|| with_account(Some(&target), || caller_identity(provider)));
+        (profile.clone(), identity)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountIdentity, caller_identity};
+    use crate::aws_cli::AwsQueryErrorCode;
+    use crate::aws_cli::fake_provider::FakeProvider;
+    use aws_sdk_sts::operation::get_caller_identity::GetCallerIdentityOutput;
+
+    #[test]
+    fn caller_identity_combines_sts_caller_and_account_alias() {
+        let mut provider = FakeProvider {
+            caller_identity: Some(
+                GetCallerIdentityOutput::builder()
+                    .account("111122223333")
+                    .arn("arn:aws:sts::111122223333:assumed-role/ReadOnly/emd")
+                    .build(),
+            ),
+            account_aliases: vec!["acme-prod".to_string()],
+            ..Default::default()
+        };
+        let identity = caller_identity(&provider).expect("identity");
+        assert_eq!(identity.label(), "acme-prod (111122223333)");
+        assert_eq!(
+            identity.arn,
+            "arn:aws:sts::111122223333:assumed-role/ReadOnly/emd"
+        );
+
+        let redacted = identity.redacted();
+        assert_eq!(redacted.label(), "********3333");
+        assert_eq!(
+            redacted.arn,
+            "arn:aws:sts::********3333:assumed-role/ReadOnly/emd"
+        );
+
+        provider.account_aliases.clear();
+        assert_eq!(
+            caller_identity(&provider).map(|identity| identity.label()),
+            Some("111122223333".to_string())
+        );
+
+        provider
+            .failures
+            .insert("sts:GetCallerIdentity", AwsQueryErrorCode::Credentials);
+        assert_eq!(caller_identity(&provider), None::<AccountIdentity>);
+    }
+}
@@ -0,0 +1,179 @@
+pub use crate::aws_cli::kms_sdk::{get_kms_key_detail, list_kms_keys};
+use crate::i18n::{I18n, Language};
+
+#[derive(Debug, Clone)]
+pub struct KmsKeyDetail {
+    pub key_id: String,
+    pub arn: String,
+    pub aliases: Vec<String>,
+    pub description: String,
+    pub key_spec: String,
+    pub key_usage: String,
+    pub key_state: String,
+    pub origin: String,
+    pub multi_region: bool,
+    pub creation_date: String,
+    pub rotation_enabled: Option<bool>,
+    pub rotation_period_days: Option<i32>,
+    pub next_rotation_date: String,
+    pub key_policy: String,
+    pub grants_count: usize,
+    pub tags: Vec<(String, String)>,
+}
+
+impl KmsKeyDetail {
+    /// 첫 번째 별칭(alias/ 접두사 제외), 없으면 키 ID
+    pub fn display_name(&self) -> &str {
+        self.aliases
+            .first()
+            .map(|alias| alias.strip_prefix("alias/").unwrap_or(alias))
+            .unwrap_or(&self.key_id)
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.kms_key(), self.display_name()),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| Key ID | `{}` |", self.key_id),
+            format!("| ARN | {} |", self.arn),
+        ];
+
+        if !self.aliases.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_aliases(),
+                self.aliases.join(", ")
+            ));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_key_spec(), self.key_spec));
+        lines.push(format!("| {} | {} |", i18n.md_key_usage(), self.key_usage));
+        lines.push(format!("| {} | {} |", i18n.md_state(), self.key_state));
+        lines.push(format!("| {} | {} |", i18n.md_origin(), self.origin));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_multi_region(),
+            if self.multi_region { "✓" } else { "-" }
+        ));
+
+        let rotation = match self.rotation_enabled {
+            Some(true) => i18n.md_enabled(),
+            Some(false) => i18n.md_disabled(),
+            None => "-",
+        };
+        lines.push(format!("| {} | {} |", i18n.md_key_rotation(), rotation));
+        if self.rotation_enabled == Some(true) {
+            if let Some(days) = self.rotation_period_days {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_rotation_period(),
+                    i18n.md_days(i64::from(days))
+                ));
+            }
+            if !self.next_rotation_date.is_empty() {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_next_rotation(),
+                    self.next_rotation_date
+                ));
+            }
+        }
+        lines.push(format!("| {} | {} |", i18n.md_grants(), self.grants_count));
+        if !self.creation_date.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                self.creation_date
+            ));
+        }
+
+        // Key Policy
+        if !self.key_policy.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_key_policy()));
+            lines.push("```json".to_string());
+            lines.push(self.key_policy.clone());
+            lines.push("```".to_string());
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_tags()));
+            lines.push(format!("| {} | {} |", i18n.md_tag_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KmsKeyDetail;
+    use crate::i18n::Language;
+
+    fn sample_key() -> KmsKeyDetail {
+        KmsKeyDetail {
+            key_id: "1234abcd-12ab-34cd-56ef-1234567890ab".to_string(),
+            arn: "arn:aws:kms:ap-northeast-2:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab"
+                .to_string(),
+            aliases: vec!["alias/app-data".to_string()],
+            description: "Application data key".to_string(),
+            key_spec: "SYMMETRIC_DEFAULT".to_string(),
+            key_usage: "ENCRYPT_DECRYPT".to_string(),
+            key_state: "Enabled".to_string(),
+            origin: "AWS_KMS".to_string(),
+            multi_region: false,
+            creation_date: "2026-01-15".to_string(),
+            rotation_enabled: Some(true),
+            rotation_period_days: Some(365),
+            next_rotation_date: "2027-01-15".to_string(),
+            key_policy: "{\n  \"Version\": \"2012-10-17\"\n}".to_string(),
+            grants_count: 2,
+            tags: vec![("Team".to_string(), "platform".to_string())],
+        }
+    }
+
+    #[test]
+    fn kms_key_markdown_includes_rotation_policy_and_grants() {
+        let md = sample_key().to_markdown(Language::English);
+        assert!(md.contains("## KMS Key (app-data)"));
+        assert!(md.contains("| Aliases | alias/app-data |"));
+        assert!(md.contains("| Key Spec | SYMMETRIC_DEFAULT |"));
+        assert!(md.contains("| Automatic Key Rotation | Enabled |"));
+        assert!(md.contains("| Rotation Period | 365 days |"));
+        assert!(md.contains("| Next Rotation | 2027-01-15 |"));
+        assert!(md.contains("| Grants | 2 |"));
+        assert!(md.contains("```json\n{\n  \"Version\": \"2012-10-17\"\n}\n```"));
+        assert!(md.contains("| Team | platform |"));
+    }
+
+    #[test]
+    fn kms_key_markdown_falls_back_to_key_id_and_hides_rotation_details() {
+        let mut key = sample_key();
+        key.aliases.clear();
+        key.rotation_enabled = None;
+        key.key_policy.clear();
+        key.tags.clear();
+
+        let md = key.to_markdown(Language::Korean);
+        assert!(md.contains("(1234abcd-12ab-34cd-56ef-1234567890ab)"));
+        assert!(md.contains("| 자동 키 교체 | - |"));
+        assert!(!md.contains("교체 주기"));
+        assert!(!md.contains("```json"));
+        assert!(!md.contains("### 태그"));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::aws_cli::common::{AwsResource, format_date};
+use crate::aws_cli::kms::KmsKeyDetail;
+use crate::aws_cli::provider::AwsProvider;
+use aws_sdk_kms::operation::get_key_rotation_status::GetKeyRotationStatusOutput;
+use aws_sdk_kms::types::{AliasListEntry, KeyManagerType, KeyMetadata, Tag};
+
+/// List customer managed KMS keys
+pub fn list_kms_keys(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let keys = provider.list_keys();
+    let aliases = provider.list_aliases(None);
+
+    let mut resources = Vec::new();
+    for key in &keys {
+        let Some(key_id) = key.key_id() else {
+            continue;
+        };
+        // ListKeys에는 관리 주체가 없으므로 DescribeKey로 AWS 관리형 키를 걸러냄
+        if let Some(metadata) = provider.describe_key(key_id)
+            && metadata.key_manager() == Some(&KeyManagerType::Customer)
+        {
+            resources.push(map_kms_key_resource(&metadata, &aliases));
+        }
+    }
+
+    resources.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    resources
+}
+
+/// Get KMS key detail (metadata, rotation, key policy, grants)
+pub fn get_kms_key_detail(provider: &dyn AwsProvider, key_id: &str) -> Option<KmsKeyDetail> {
+    let metadata = provider.describe_key(key_id)?;
+    let key_id = metadata.key_id();
+
+    let aliases = provider.list_aliases(Some(key_id));
+    // 비대칭/HMAC 키 등 자동 교체를 지원하지 않는 키는 None
+    let rotation = provider.get_key_rotation_status(key_id);
+    let key_policy = provider
+        .get_key_policy(key_id)
+        .map(|policy| pretty_policy(&policy))
+        .unwrap_or_default();
+    let grants_count = provider.list_grants(key_id).len();
+    let tags = provider.list_resource_tags(key_id);
+
+    Some(map_kms_key_detail(
+        &metadata,
+        &aliases,
+        rotation.as_ref(),
+        key_policy,
+        grants_count,
+        &tags,
+    ))
+}
+
+fn pretty_policy(policy: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(policy)
+        .ok()
+        .and_then(|v| serde_json::to_string_pretty(&v).ok())
+        .unwrap_or_else(|| policy.to_string())
+}
+
+fn aliases_for_key(key_id: &str, aliases: &[AliasListEntry]) -> Vec<String> {
+    let mut names: Vec<String> = aliases
+        .iter()
+        .filter(|alias| alias.target_key_id() == Some(key_id))
+        .filter_map(|alias| alias.alias_name().map(|s| s.to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+fn map_kms_key_resource(metadata: &KeyMetadata, aliases: &[AliasListEntry]) -> AwsResource {
+    let key_id = metadata.key_id().to_string();
+    AwsResource {
+        name: aliases_for_key(&key_id, aliases)
+            .into_iter()
+            .next()
+            .unwrap_or_default(),
+        id: key_id,
+        state: metadata
+            .key_state()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        az: metadata
+            .key_spec()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        cidr: metadata.arn().unwrap_or_default().to_string(),
+        region: None,
+    }
+}
+
+fn map_kms_key_detail(
+    metadata: &KeyMetadata,
+    aliases: &[AliasListEntry],
+    rotation: Option<&GetKeyRotationStatusOutput>,
+    key_policy: String,
+    grants_count: usize,
+    tags: &[Tag],
+) -> KmsKeyDetail {
+    let key_id = metadata.key_id().to_string();
+    let rotation_enabled = rotation.map(|r| r.key_rotation_enabled());
+
+    KmsKeyDetail {
+        aliases: aliases_for_key(&key_id, aliases),
+        arn: metadata.arn().unwrap_or_default().to_string(),
+        description: metadata.description().unwrap_or_default().to_string(),
+        key_spec: metadata
+            .key_spec()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        key_usage: metadata
+            .key_usage()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        key_state: metadata
+            .key_state()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        origin: metadata
+            .origin()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        multi_region: metadata.multi_region().unwrap_or(false),
+        creation_date: format_date(metadata.creation_date()),
+        rotation_enabled,
+        rotation_period_days: rotation.and_then(|r| r.rotation_period_in_days()),
+        next_rotation_date: format_date(rotation.and_then(|r| r.next_rotation_date())),
+        key_policy,
+        grants_count,
+        tags: tags
+            .iter()
+            .map(|tag| (tag.tag_key().to_string(), tag.tag_value().to_string()))
+            .collect(),
+        key_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_kms_key_detail, map_kms_key_resource, pretty_policy};
+    use aws_sdk_kms::operation::get_key_rotation_status::GetKeyRotationStatusOutput;
+    use aws_sdk_kms::types::{AliasListEntry, KeyMetadata, KeySpec, KeyState, KeyUsageType, Tag};
+
+    fn metadata() -> KeyMetadata {
+        KeyMetadata::builder()
+            .key_id("key-1")
+            .arn("arn:aws:kms:ap-northeast-2:123456789012:key/key-1")
+            .key_spec(KeySpec::SymmetricDefault)
+            .key_usage(KeyUsageType::EncryptDecrypt)
+            .key_state(KeyState::Enabled)
+            .build()
+            .expect("key metadata")
+    }
+
+    fn aliases() -> Vec<AliasListEntry> {
+        vec![
+            AliasListEntry::builder()
+                .alias_name("alias/zeta")
+                .target_key_id("key-1")
+                .build(),
+            AliasListEntry::builder()
+                .alias_name("alias/app-data")
+                .target_key_id("key-1")
+                .build(),
+            AliasListEntry::builder()
+                .alias_name("alias/other")
+                .target_key_id("key-2")
+                .build(),
+        ]
+    }
+
+    #[test]
+    fn map_kms_key_resource_uses_first_alias_and_state() {
+        let resource = map_kms_key_resource(&metadata(), &aliases());
+        assert_eq!(resource.name, "alias/app-data");
+        assert_eq!(resource.id, "key-1");
+        assert_eq!(resource.state, "Enabled");
+        assert_eq!(resource.az, "SYMMETRIC_DEFAULT");
+
+        let resource = map_kms_key_resource(&metadata(), &[]);
+        assert_eq!(resource.name, "");
+        assert_eq!(resource.display(), "key-1");
+    }
+
+    #[test]
+    fn map_kms_key_detail_reads_rotation_grants_and_tags() {
+        let rotation = GetKeyRotationStatusOutput::builder()
+            .key_rotation_enabled(true)
+            .rotation_period_in_days(180)
+            .build();
+        let tags = vec![
+            Tag::builder()
+                .tag_key("Team")
+                .tag_value("platform")
+                .build()
+                .expect("tag"),
+        ];
+
+        let detail = map_kms_key_detail(
+            &metadata(),
+            &aliases(),
+            Some(&rotation),
+            "{}".to_string(),
+            3,
+            &tags,
+        );
+        assert_eq!(detail.aliases, vec!["alias/app-data", "alias/zeta"]);
+        assert_eq!(detail.key_usage, "ENCRYPT_DECRYPT");
+        assert_eq!(detail.rotation_enabled, Some(true));
+        assert_eq!(detail.rotation_period_days, Some(180));
+        assert_eq!(detail.grants_count, 3);
+        assert_eq!(
+            detail.tags,
+            vec![("Team".to_string(), "platform".to_string())]
+        );
+
+        let detail = map_kms_key_detail(&metadata(), &[], None, String::new(), 0, &[]);
+        assert_eq!(detail.rotation_enabled, None);
+        assert_eq!(detail.next_rotation_date, "");
+    }
+
+    #[test]
+    fn pretty_policy_formats_json_and_keeps_invalid_input() {
+        assert_eq!(
+            pretty_policy(r#"{"Version":"2012-10-17"}"#),
+            "{\n  \"Version\": \"2012-10-17\"\n}"
+        );
+        assert_eq!(pretty_policy("not-json"), "not-json");
+    }
+}
@@ -0,0 +1,171 @@
+pub use crate::aws_cli::lambda_sdk::{get_lambda_function_detail, list_lambda_functions};
+use crate::i18n::{I18n, Language};
+
+#[derive(Debug, Clone)]
+pub struct LambdaFunctionDetail {
+    pub name: String,
+    pub arn: String,
+    pub runtime: String,
+    pub handler: String,
+    pub package_type: String,
+    pub architectures: Vec<String>,
+    pub memory_size: Option<i32>,
+    pub timeout: Option<i32>,
+    pub role: String,
+    pub state: String,
+    pub description: String,
+    pub last_modified: String,
+    pub vpc_id: Option<String>,
+    pub subnet_ids: Vec<String>,
+    pub security_group_ids: Vec<String>,
+    /// 환경 변수 이름 (값에 비밀이 들어 있을 수 있어 이름만 보관)
+    pub environment_variables: Vec<String>,
+    pub layers: Vec<String>,
+}
+
+impl LambdaFunctionDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.lambda_function(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+        ];
+
+        // 컨테이너 이미지 함수는 런타임과 핸들러가 없음
+        if !self.runtime.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_runtime(), self.runtime));
+        }
+        if !self.handler.is_empty() {
+            lines.push(format!("| {} | `{}` |", i18n.md_handler(), self.handler));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_type(), self.package_type));
+        if !self.architectures.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_architecture(),
+                self.architectures.join(", ")
+            ));
+        }
+        if let Some(memory_size) = self.memory_size {
+            lines.push(format!("| {} | {} MB |", i18n.md_memory(), memory_size));
+        }
+        if let Some(timeout) = self.timeout {
+            lines.push(format!("| {} | {}s |", i18n.md_timeout(), timeout));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_iam_role(), self.role));
+        if !self.state.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_state(), self.state));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.last_modified.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_last_modified(),
+                self.last_modified
+            ));
+        }
+        if let Some(vpc_id) = &self.vpc_id {
+            lines.push(format!("| VPC | {} |", vpc_id));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_subnets(),
+                self.subnet_ids.join(", ")
+            ));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_security_groups(),
+                self.security_group_ids.join(", ")
+            ));
+        }
+        if !self.layers.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_layers(),
+                self.layers.join("<br>")
+            ));
+        }
+        if !self.environment_variables.is_empty() {
+            let names: Vec<String> = self
+                .environment_variables
+                .iter()
+                .map(|name| format!("`{}`", name))
+                .collect();
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_environment_variables(),
+                names.join(", ")
+            ));
+            lines.push(String::new());
+            lines.push(format!("> {}", i18n.md_env_values_omitted()));
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LambdaFunctionDetail;
+    use crate::i18n::Language;
+
+    fn sample_detail() -> LambdaFunctionDetail {
+        LambdaFunctionDetail {
+            name: "list-orders".to_string(),
+            arn: "arn:aws:lambda:ap-northeast-2:123456789012:function:list-orders".to_string(),
+            runtime: "python3.12".to_string(),
+            handler: "app.handler".to_string(),
+            package_type: "Zip".to_string(),
+            architectures: vec!["arm64".to_string()],
+            memory_size: Some(256),
+            timeout: Some(30),
+            role: "arn:aws:iam::123456789012:role/list-orders".to_string(),
+            state: "Active".to_string(),
+            description: String::new(),
+            last_modified: "2026-01-01".to_string(),
+            vpc_id: Some("vpc-1234".to_string()),
+            subnet_ids: vec!["subnet-1234".to_string()],
+            security_group_ids: vec!["sg-1234".to_string()],
+            environment_variables: vec!["DB_HOST".to_string(), "STAGE".to_string()],
+            layers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn to_markdown_lists_configuration_and_only_environment_variable_names() {
+        let md = sample_detail().to_markdown(Language::English);
+        assert!(md.contains("## Lambda Function (list-orders)"));
+        assert!(md.contains("| Runtime | python3.12 |"));
+        assert!(md.contains("| Memory | 256 MB |"));
+        assert!(md.contains("| Timeout | 30s |"));
+        assert!(md.contains("| VPC | vpc-1234 |"));
+        assert!(md.contains("| Environment Variables | `DB_HOST`, `STAGE` |"));
+        assert!(md.contains("> Values are not written to the document (names only)"));
+    }
+
+    #[test]
+    fn to_markdown_skips_runtime_rows_for_container_images() {
+        let detail = LambdaFunctionDetail {
+            runtime: String::new(),
+            handler: String::new(),
+            package_type: "Image".to_string(),
+            vpc_id: None,
+            environment_variables: Vec::new(),
+            ..sample_detail()
+        };
+        let md = detail.to_markdown(Language::Korean);
+        assert!(md.contains("| 타입 | Image |"));
+        assert!(!md.contains("런타임"));
+        assert!(!md.contains("VPC"));
+        assert!(!md.contains("환경 변수"));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::aws_cli::common::AwsResource;
+use crate::aws_cli::lambda::LambdaFunctionDetail;
+use crate::aws_cli::provider::AwsProvider;
+use aws_sdk_lambda::operation::get_function_configuration::GetFunctionConfigurationOutput;
+use aws_sdk_lambda::types::FunctionConfiguration;
+
+/// List Lambda functions
+pub fn list_lambda_functions(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut resources: Vec<AwsResource> = provider
+        .list_functions()
+        .iter()
+        .map(map_function_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get Lambda function detail by ARN (or name)
+pub fn get_lambda_function_detail(
+    provider: &dyn AwsProvider,
+    function: &str,
+) -> Option<LambdaFunctionDetail> {
+    provider
+        .get_function_configuration(function)
+        .map(|output| map_function_detail(&output))
+}
+
+/// `2026-01-01T00:00:00.000+0000` 형식의 수정 시각에서 날짜만
+fn last_modified_date(last_modified: Option<&str>) -> String {
+    last_modified
+        .and_then(|value| value.split('T').next())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn map_function_resource(function: &FunctionConfiguration) -> AwsResource {
+    // 컨테이너 이미지 함수는 런타임 대신 패키지 유형 표시
+    let state = match (function.runtime(), function.package_type()) {
+        (Some(runtime), _) => runtime.as_str().to_string(),
+        (None, Some(package_type)) => package_type.as_str().to_string(),
+        (None, None) => String::new(),
+    };
+    AwsResource {
+        name: function.function_name().unwrap_or_default().to_string(),
+        id: function.function_arn().unwrap_or_default().to_string(),
+        state,
+        az: String::new(),
+        cidr: last_modified_date(function.last_modified()),
+        region: None,
+    }
+}
+
+fn map_function_detail(output: &GetFunctionConfigurationOutput) -> LambdaFunctionDetail {
+    let vpc = output
+        .vpc_config()
+        .filter(|vpc| vpc.vpc_id().is_some_and(|id| !id.is_empty()));
+    // 환경 변수 값은 버리고 이름만 남김
+    let mut environment_variables: Vec<String> = output
+        .environment()
+        .and_then(|environment| environment.variables())
+        .map(|variables| variables.keys().cloned().collect())
+        .unwrap_or_default();
+    environment_variables.sort();
+
+    LambdaFunctionDetail {
+        name: output.function_name().unwrap_or_default().to_string(),
+        arn: output.function_arn().unwrap_or_default().to_string(),
+        runtime: output
+            .runtime()
+            .map(|runtime| runtime.as_str().to_string())
+            .unwrap_or_default(),
+        handler: output.handler().unwrap_or_default().to_string(),
+        package_type: output
+            .package_type()
+            .map(|package_type| package_type.as_str().to_string())
+            .unwrap_or_else(|| "Zip".to_string()),
+        architectures: output
+            .architectures()
+            .iter()
+            .map(|architecture| architecture.as_str().to_string())
+            .collect(),
+        memory_size: output.memory_size(),
+        timeout: output.timeout(),
+        role: output.role().unwrap_or_default().to_string(),
+        state: output
+            .state()
+            .map(|state| state.as_str().to_string())
+            .unwrap_or_default(),
+        description: output.description().unwrap_or_default().to_string(),
+        last_modified: last_modified_date(output.last_modified()),
+        vpc_id: vpc.and_then(|vpc| vpc.vpc_id()).map(str::to_string),
+        subnet_ids: vpc.map(|vpc| vpc.subnet_ids().to_vec()).unwrap_or_default(),
+        security_group_ids: vpc
+            .map(|vpc| vpc.security_group_ids().to_vec())
+            .unwrap_or_default(),
+        environment_variables,
+        layers: output
+            .layers()
+            .iter()
+            .filter_map(|layer| layer.arn())
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_function_detail, map_function_resource};
+    use aws_sdk_lambda::operation::get_function_configuration::GetFunctionConfigurationOutput;
+    use aws_sdk_lambda::types::{
+        EnvironmentResponse, FunctionConfiguration, PackageType, Runtime, VpcConfigResponse,
+    };
+
+    const ARN: &str = "arn:aws:lambda:ap-northeast-2:123456789012:function:list-orders";
+
+    #[test]
+    fn map_function_resource_shows_runtime_or_package_type() {
+        let zip = FunctionConfiguration::builder()
+            .function_name("list-orders")
+            .function_arn(ARN)
+            .runtime(Runtime::Python312)
+            .last_modified("2026-01-01T00:00:00.000+0000")
+            .build();
+        let resource = map_function_resource(&zip);
+        assert_eq!(resource.id, ARN);
+        assert_eq!(resource.state, "python3.12");
+        assert_eq!(resource.cidr, "2026-01-01");
+
+        let image = FunctionConfiguration::builder()
+            .function_name("render")
+            .package_type(PackageType::Image)
+            .build();
+        assert_eq!(map_function_resource(&image).state, "Image");
+    }
+
+    #[test]
+    fn map_function_detail_keeps_only_environment_variable_names() {
+        let output = GetFunctionConfigurationOutput::builder()
+            .function_name("list-orders")
+            .function_arn(ARN)
+            .runtime(Runtime::Python312)
+            .memory_size(256)
+            .environment(
+                EnvironmentResponse::builder()
+                    .variables("STAGE", "prod")
+                    .variables("DB_PASSWORD", "hunter2")
+                    .build(),
+            )
+            .vpc_config(
+                VpcConfigResponse::builder()
+                    .vpc_id("vpc-1234")
+                    .subnet_ids("subnet-1234")
+                    .security_group_ids("sg-1234")
+                    .build(),
+            )
+            .build();
+
+        let detail = map_function_detail(&output);
+        assert_eq!(detail.environment_variables, vec!["DB_PASSWORD", "STAGE"]);
+        assert!(!format!("{:?}", detail).contains("hunter2"));
+        assert_eq!(detail.package_type, "Zip");
+        assert_eq!(detail.memory_size, Some(256));
+        assert_eq!(detail.vpc_id.as_deref(), Some("vpc-1234"));
+        assert_eq!(detail.subnet_ids, vec!["subnet-1234"]);
+    }
+}
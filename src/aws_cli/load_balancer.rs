@@ -1,118 +1,14 @@
-use crate::aws_cli::common::{AwsResource, run_aws_cli};
+use crate::aws_cli::cloudwatch::{
+    CloudWatchAlarm, DIMENSION_LOAD_BALANCER, DIMENSION_TARGET_GROUP, alarms_section,
+    elb_dimension_value, list_alarms_for,
+};
+use crate::aws_cli::common::{AwsResource, map_concurrent};
+use crate::aws_cli::partition::resource_id_from_arn;
+use crate::aws_cli::provider::AwsProvider;
 use crate::i18n::{I18n, Language};
-use serde::Deserialize;
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct LoadBalancersResponse {
-    load_balancers: Vec<LoadBalancerInfo>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct LoadBalancerInfo {
-    load_balancer_name: String,
-    load_balancer_arn: String,
-    #[serde(rename = "DNSName")]
-    dns_name: String,
-    #[serde(rename = "Type")]
-    lb_type: String,
-    scheme: String,
-    #[serde(rename = "VpcId")]
-    vpc_id: String,
-    #[serde(default)]
-    ip_address_type: String,
-    #[serde(default)]
-    availability_zones: Vec<AvailabilityZone>,
-    #[serde(default)]
-    security_groups: Vec<String>,
-    #[serde(default)]
-    state: Option<LoadBalancerState>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct AvailabilityZone {
-    zone_name: String,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct LoadBalancerState {
-    code: String,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct ListenersResponse {
-    listeners: Vec<ListenerData>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct ListenerData {
-    port: i32,
-    protocol: String,
-    #[serde(default)]
-    default_actions: Vec<DefaultAction>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct DefaultAction {
-    #[serde(rename = "Type")]
-    action_type: String,
-    #[serde(default)]
-    target_group_arn: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct TargetGroupsResponse {
-    target_groups: Vec<TargetGroupData>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct TargetGroupData {
-    target_group_name: String,
-    target_group_arn: String,
-    protocol: String,
-    port: i32,
-    target_type: String,
-    health_check_protocol: String,
-    health_check_path: String,
-    healthy_threshold_count: i32,
-    unhealthy_threshold_count: i32,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct TargetHealthResponse {
-    target_health_descriptions: Vec<TargetHealthDescription>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct TargetHealthDescription {
-    target: Target,
-    #[allow(dead_code)]
-    health_check_port: String,
-    target_health: TargetHealth,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct Target {
-    id: String,
-    port: i32,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct TargetHealth {
-    state: String,
-}
+use aws_sdk_elasticloadbalancingv2::types::{
+    Listener, LoadBalancer, LoadBalancerSchemeEnum, TargetGroup, TargetHealthDescription,
+};
 
 #[derive(Debug)]
 pub struct LoadBalancerDetail {
@@ -128,6 +24,7 @@ pub struct LoadBalancerDetail {
     pub security_groups: Vec<String>,
     pub listeners: Vec<ListenerInfo>,
     pub target_groups: Vec<TargetGroupInfo>,
+    pub alarms: Vec<CloudWatchAlarm>,
 }
 
 #[derive(Debug)]
@@ -140,7 +37,6 @@ pub struct ListenerInfo {
 #[derive(Debug)]
 pub struct TargetGroupInfo {
     pub name: String,
-    #[allow(dead_code)]
     pub arn: String,
     pub protocol: String,
     pub port: i32,
@@ -162,8 +58,8 @@ pub struct TargetInfo {
 impl LoadBalancerDetail {
     pub fn to_markdown(&self, lang: Language) -> String {
         let i18n = I18n::new(lang);
-        // Extract LB ID from ARN (last part after the last /)
-        let lb_id = self.arn.rsplit('/').next().unwrap_or(&self.arn);
+        // ARN 리소스 경로의 마지막 구간이 LB ID (파티션과 무관)
+        let lb_id = resource_id_from_arn(&self.arn);
         let display_name = if self.name.is_empty() {
             format!("NULL - {}", lb_id)
         } else {
@@ -266,212 +162,265 @@ impl LoadBalancerDetail {
             }
         }
 
+        lines.extend(alarms_section(&self.alarms, &i18n));
+
         lines.join("\n") + "\n"
     }
+
+    /// 이 로드 밸런서와 대상 그룹을 참조하는 CloudWatch 경보 차원
+    pub fn alarm_dimensions(&self) -> Vec<(&'static str, String)> {
+        let mut dimensions = vec![(DIMENSION_LOAD_BALANCER, elb_dimension_value(&self.arn))];
+        dimensions.extend(
+            self.target_groups
+                .iter()
+                .map(|tg| (DIMENSION_TARGET_GROUP, elb_dimension_value(&tg.arn))),
+        );
+        dimensions
+    }
 }
 
-pub fn list_load_balancers() -> Vec<AwsResource> {
-    let output = match run_aws_cli(&["elbv2", "describe-load-balancers", "--output", "json"]) {
-        Some(o) => o,
-        None => return Vec::new(),
-    };
+pub fn list_load_balancers(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut load_balancers = provider.describe_load_balancers(&[]);
+    load_balancers.sort_by(|a, b| a.load_balancer_name().cmp(&b.load_balancer_name()));
 
     let i18n = I18n::new(Language::English);
-    parse_load_balancers_output(&output, &i18n).unwrap_or_default()
+    load_balancers
+        .iter()
+        .map(|lb| map_load_balancer_resource(lb, &i18n))
+        .collect()
 }
 
-pub fn get_load_balancer_detail(lb_arn: &str) -> Option<LoadBalancerDetail> {
-    let output = run_aws_cli(&[
-        "elbv2",
-        "describe-load-balancers",
-        "--load-balancer-arns",
-        lb_arn,
-        "--output",
-        "json",
-    ])?;
-
-    let listeners_output = run_aws_cli(&[
-        "elbv2",
-        "describe-listeners",
-        "--load-balancer-arn",
-        lb_arn,
-        "--output",
-        "json",
-    ])?;
-
-    let (listeners, target_group_arns) = parse_listener_infos(&listeners_output)?;
-
-    let mut target_groups = Vec::new();
-
-    for tg_arn in &target_group_arns {
-        if let Some(tg_info) = get_target_group_info(tg_arn) {
-            target_groups.push(tg_info);
-        }
-    }
-    parse_load_balancer_detail_output(&output, listeners, target_groups)
-}
+pub fn get_load_balancer_detail(
+    provider: &dyn AwsProvider,
+    lb_arn: &str,
+) -> Option<LoadBalancerDetail> {
+    let load_balancers = provider.describe_load_balancers(&[lb_arn.to_string()]);
+    let lb = load_balancers.first()?;
 
-fn get_target_group_info(tg_arn: &str) -> Option<TargetGroupInfo> {
-    let output = run_aws_cli(&[
-        "elbv2",
-        "describe-target-groups",
-        "--target-group-arns",
-        tg_arn,
-        "--output",
-        "json",
-    ])?;
-
-    let health_output = run_aws_cli(&[
-        "elbv2",
-        "describe-target-health",
-        "--target-group-arn",
-        tg_arn,
-        "--output",
-        "json",
-    ])?;
-
-    parse_target_group_info_outputs(&output, &health_output)
-}
+    let (listeners, target_group_arns) = map_listener_infos(&provider.describe_listeners(lb_arn));
 
-fn parse_load_balancers_output(output: &str, i18n: &I18n) -> Option<Vec<AwsResource>> {
-    let response: LoadBalancersResponse = serde_json::from_str(output).ok()?;
-    Some(
-        response
-            .load_balancers
-            .into_iter()
-            .map(|lb| {
-                let scheme_text = match lb.scheme.as_str() {
-                    "internet-facing" => i18n.md_public().to_string(),
-                    "internal" => i18n.md_private().to_string(),
-                    _ => lb.scheme.clone(),
-                };
-
-                AwsResource {
-                    name: format!("{} ({})", lb.load_balancer_name, scheme_text),
-                    id: lb.load_balancer_arn,
-                    state: lb.lb_type,
-                    az: lb.vpc_id,
-                    cidr: lb.dns_name,
-                }
-            })
-            .collect(),
-    )
+    let mut target_groups: Vec<TargetGroupInfo> = if target_group_arns.is_empty() {
+        Vec::new()
+    } else {
+        // 대상 그룹별 상태 조회는 서로 독립적이라 동시에 수행
+        map_concurrent(&provider.describe_target_groups(&target_group_arns), |tg| {
+            let health = provider.describe_target_health(tg.target_group_arn().unwrap_or_default());
+            map_target_group_info(tg, &health)
+        })
+    };
+    target_groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut detail = map_load_balancer_detail(lb, listeners, target_groups);
+    detail.alarms = list_alarms_for(provider, &detail.alarm_dimensions());
+    Some(detail)
 }
 
-fn parse_target_group_info_outputs(
-    target_group_output: &str,
-    target_health_output: &str,
-) -> Option<TargetGroupInfo> {
-    let response: TargetGroupsResponse = serde_json::from_str(target_group_output).ok()?;
-    let tg = response.target_groups.first()?;
+fn map_load_balancer_resource(lb: &LoadBalancer, i18n: &I18n) -> AwsResource {
+    let scheme_text = match lb.scheme() {
+        Some(LoadBalancerSchemeEnum::InternetFacing) => i18n.md_public().to_string(),
+        Some(LoadBalancerSchemeEnum::Internal) => i18n.md_private().to_string(),
+        other => other.map(|s| s.as_str().to_string()).unwrap_or_default(),
+    };
+
+    AwsResource {
+        name: format!(
+            "{} ({})",
+            lb.load_balancer_name().unwrap_or_default(),
+            scheme_text
+        ),
+        id: lb.load_balancer_arn().unwrap_or_default().to_string(),
+        state: lb
+            .r#type()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_default(),
+        az: lb.vpc_id().unwrap_or_default().to_string(),
+        cidr: lb.dns_name().unwrap_or_default().to_string(),
+        region: None,
+    }
+}
 
-    let health_response: TargetHealthResponse = serde_json::from_str(target_health_output).ok()?;
-    let targets: Vec<TargetInfo> = health_response
-        .target_health_descriptions
+fn map_target_group_info(tg: &TargetGroup, health: &[TargetHealthDescription]) -> TargetGroupInfo {
+    let mut targets: Vec<TargetInfo> = health
         .iter()
         .map(|thd| TargetInfo {
-            id: thd.target.id.clone(),
-            port: thd.target.port,
-            health_status: thd.target_health.state.clone(),
+            id: thd
+                .target()
+                .and_then(|t| t.id())
+                .unwrap_or_default()
+                .to_string(),
+            port: thd.target().and_then(|t| t.port()).unwrap_or(0),
+            health_status: thd
+                .target_health()
+                .and_then(|h| h.state())
+                .map(|s| s.as_str().to_string())
+                .unwrap_or_default(),
         })
         .collect();
-
-    Some(TargetGroupInfo {
-        name: tg.target_group_name.clone(),
-        arn: tg.target_group_arn.clone(),
-        protocol: tg.protocol.clone(),
-        port: tg.port,
-        target_type: tg.target_type.clone(),
-        health_check_protocol: tg.health_check_protocol.clone(),
-        health_check_path: tg.health_check_path.clone(),
-        healthy_threshold: tg.healthy_threshold_count,
-        unhealthy_threshold: tg.unhealthy_threshold_count,
+    targets.sort_by(|a, b| a.id.cmp(&b.id));
+
+    TargetGroupInfo {
+        name: tg.target_group_name().unwrap_or_default().to_string(),
+        arn: tg.target_group_arn().unwrap_or_default().to_string(),
+        protocol: tg
+            .protocol()
+            .map(|p| p.as_str().to_string())
+            .unwrap_or_default(),
+        port: tg.port().unwrap_or(0),
+        target_type: tg
+            .target_type()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_default(),
+        health_check_protocol: tg
+            .health_check_protocol()
+            .map(|p| p.as_str().to_string())
+            .unwrap_or_default(),
+        health_check_path: tg.health_check_path().unwrap_or_default().to_string(),
+        healthy_threshold: tg.healthy_threshold_count().unwrap_or(0),
+        unhealthy_threshold: tg.unhealthy_threshold_count().unwrap_or(0),
         targets,
-    })
+    }
 }
 
-fn parse_listener_infos(listeners_output: &str) -> Option<(Vec<ListenerInfo>, Vec<String>)> {
-    let listeners_response: ListenersResponse = serde_json::from_str(listeners_output).ok()?;
-    let listeners: Vec<ListenerInfo> = listeners_response
-        .listeners
+/// 리스너 목록(포트 순)과 기본 동작이 가리키는 대상 그룹 ARN 목록(중복 제거)
+fn map_listener_infos(listeners: &[Listener]) -> (Vec<ListenerInfo>, Vec<String>) {
+    let mut listener_infos: Vec<ListenerInfo> = listeners
         .iter()
-        .map(|l| {
-            let action = l
-                .default_actions
+        .map(|l| ListenerInfo {
+            port: l.port().unwrap_or(0),
+            protocol: l
+                .protocol()
+                .map(|p| p.as_str().to_string())
+                .unwrap_or_default(),
+            default_action: l
+                .default_actions()
                 .first()
-                .map(|a| a.action_type.clone())
-                .unwrap_or_else(|| "-".to_string());
-
-            ListenerInfo {
-                port: l.port,
-                protocol: l.protocol.clone(),
-                default_action: action,
-            }
+                .and_then(|a| a.r#type())
+                .map(|t| t.as_str().to_string())
+                .unwrap_or_else(|| "-".to_string()),
         })
         .collect();
+    listener_infos.sort_by_key(|l| l.port);
 
-    let mut target_group_arns: Vec<String> = listeners_response
-        .listeners
+    let mut target_group_arns: Vec<String> = listeners
         .iter()
-        .flat_map(|l| &l.default_actions)
-        .filter_map(|a| a.target_group_arn.clone())
+        .flat_map(|l| l.default_actions())
+        .filter_map(|a| a.target_group_arn().map(|s| s.to_string()))
         .collect();
     target_group_arns.sort();
     target_group_arns.dedup();
 
-    Some((listeners, target_group_arns))
+    (listener_infos, target_group_arns)
 }
 
-fn parse_load_balancer_detail_output(
-    output: &str,
+fn map_load_balancer_detail(
+    lb: &LoadBalancer,
     listeners: Vec<ListenerInfo>,
     target_groups: Vec<TargetGroupInfo>,
-) -> Option<LoadBalancerDetail> {
-    let response: LoadBalancersResponse = serde_json::from_str(output).ok()?;
-    let lb = response.load_balancers.first()?;
-
+) -> LoadBalancerDetail {
     let availability_zones: Vec<String> = lb
-        .availability_zones
+        .availability_zones()
         .iter()
-        .map(|az| az.zone_name.clone())
+        .filter_map(|az| az.zone_name().map(|s| s.to_string()))
         .collect();
 
-    let ip_address_type = if lb.ip_address_type.is_empty() {
-        "ipv4".to_string()
-    } else {
-        lb.ip_address_type.clone()
-    };
+    let ip_address_type = lb
+        .ip_address_type()
+        .map(|t| t.as_str().to_string())
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| "ipv4".to_string());
 
     let state = lb
-        .state
-        .as_ref()
-        .map(|s| s.code.clone())
+        .state()
+        .and_then(|s| s.code())
+        .map(|c| c.as_str().to_string())
         .unwrap_or_else(|| "unknown".to_string());
 
-    Some(LoadBalancerDetail {
-        name: lb.load_balancer_name.clone(),
-        arn: lb.load_balancer_arn.clone(),
-        dns_name: lb.dns_name.clone(),
-        lb_type: lb.lb_type.clone(),
-        scheme: lb.scheme.clone(),
-        vpc_id: lb.vpc_id.clone(),
+    LoadBalancerDetail {
+        name: lb.load_balancer_name().unwrap_or_default().to_string(),
+        arn: lb.load_balancer_arn().unwrap_or_default().to_string(),
+        dns_name: lb.dns_name().unwrap_or_default().to_string(),
+        lb_type: lb
+            .r#type()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_default(),
+        scheme: lb
+            .scheme()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        vpc_id: lb.vpc_id().unwrap_or_default().to_string(),
         ip_address_type,
         state,
         availability_zones,
-        security_groups: lb.security_groups.clone(),
+        security_groups: lb.security_groups().to_vec(),
         listeners,
         target_groups,
-    })
+        alarms: Vec::new(),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        ListenerInfo, LoadBalancerDetail, TargetGroupInfo, TargetInfo, parse_listener_infos,
-        parse_load_balancer_detail_output, parse_load_balancers_output,
-        parse_target_group_info_outputs,
+        ListenerInfo, LoadBalancerDetail, TargetGroupInfo, TargetInfo, get_load_balancer_detail,
+        list_load_balancers, map_listener_infos, map_load_balancer_detail,
+        map_load_balancer_resource, map_target_group_info,
     };
+    use crate::aws_cli::fake_provider::FakeProvider;
     use crate::i18n::{I18n, Language};
+    use aws_sdk_elasticloadbalancingv2::types::{
+        Action, ActionTypeEnum, Listener, LoadBalancer, LoadBalancerSchemeEnum,
+        LoadBalancerTypeEnum, ProtocolEnum, TargetDescription, TargetGroup, TargetHealth,
+        TargetHealthDescription, TargetHealthStateEnum,
+    };
+
+    const LB_ARN: &str =
+        "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/alb-main/1234";
+    const TG_ARN: &str =
+        "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:targetgroup/tg-main/abcd";
+
+    fn load_balancer(name: &str, arn: &str, scheme: LoadBalancerSchemeEnum) -> LoadBalancer {
+        LoadBalancer::builder()
+            .load_balancer_name(name)
+            .load_balancer_arn(arn)
+            .dns_name(format!("{}.example.com", name))
+            .r#type(LoadBalancerTypeEnum::Application)
+            .scheme(scheme)
+            .vpc_id("vpc-1111")
+            .build()
+    }
+
+    fn forward_listener(port: i32, protocol: ProtocolEnum, tg_arn: &str) -> Listener {
+        Listener::builder()
+            .port(port)
+            .protocol(protocol)
+            .default_actions(
+                Action::builder()
+                    .r#type(ActionTypeEnum::Forward)
+                    .target_group_arn(tg_arn)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn target_group() -> TargetGroup {
+        TargetGroup::builder()
+            .target_group_name("tg-main")
+            .target_group_arn(TG_ARN)
+            .protocol(ProtocolEnum::Http)
+            .port(80)
+            .health_check_protocol(ProtocolEnum::Http)
+            .health_check_path("/health")
+            .healthy_threshold_count(2)
+            .unhealthy_threshold_count(3)
+            .build()
+    }
+
+    fn target_health(id: &str, state: TargetHealthStateEnum) -> TargetHealthDescription {
+        TargetHealthDescription::builder()
+            .target(TargetDescription::builder().id(id).port(80).build())
+            .target_health(TargetHealth::builder().state(state).build())
+            .build()
+    }
 
     #[test]
     fn load_balancer_markdown_contains_listener_and_target_group_sections() {
@@ -507,6 +456,7 @@ mod tests {
                     health_status: "healthy".to_string(),
                 }],
             }],
+            alarms: vec![],
         };
 
         let md = detail.to_markdown(Language::English);
@@ -517,121 +467,98 @@ mod tests {
     }
 
     #[test]
-    fn parse_load_balancers_output_maps_scheme_text() {
-        let payload = r#"
-            {
-              "LoadBalancers": [
-                {
-                  "LoadBalancerName": "alb-main",
-                  "LoadBalancerArn": "arn:aws:elasticloadbalancing:...:loadbalancer/app/alb-main/1234",
-                  "DNSName": "alb-main.example.com",
-                  "Type": "application",
-                  "Scheme": "internet-facing",
-                  "VpcId": "vpc-1111",
-                  "AvailabilityZones": [],
-                  "SecurityGroups": []
-                }
-              ]
-            }
-        "#;
-
+    fn map_load_balancer_resource_maps_scheme_text() {
         let i18n = I18n::new(Language::English);
-        let lbs = parse_load_balancers_output(payload, &i18n).expect("parse lbs");
-        assert_eq!(lbs.len(), 1);
-        assert!(lbs[0].name.contains("Public"));
-        assert_eq!(lbs[0].state, "application");
+        let public = map_load_balancer_resource(
+            &load_balancer("alb-main", LB_ARN, LoadBalancerSchemeEnum::InternetFacing),
+            &i18n,
+        );
+        assert_eq!(public.name, "alb-main (Public)");
+        assert_eq!(public.id, LB_ARN);
+        assert_eq!(public.state, "application");
+
+        let private = map_load_balancer_resource(
+            &load_balancer("alb-int", LB_ARN, LoadBalancerSchemeEnum::Internal),
+            &i18n,
+        );
+        assert!(private.name.contains("Private"));
     }
 
     #[test]
-    fn parse_target_group_info_outputs_extracts_targets() {
-        let tg_payload = r#"
-            {
-              "TargetGroups": [
-                {
-                  "TargetGroupName": "tg-main",
-                  "TargetGroupArn": "arn:aws:elasticloadbalancing:...:targetgroup/tg-main/1234",
-                  "Protocol": "HTTP",
-                  "Port": 80,
-                  "TargetType": "instance",
-                  "HealthCheckProtocol": "HTTP",
-                  "HealthCheckPath": "/health",
-                  "HealthyThresholdCount": 2,
-                  "UnhealthyThresholdCount": 3
-                }
-              ]
-            }
-        "#;
-
-        let health_payload = r#"
-            {
-              "TargetHealthDescriptions": [
-                {
-                  "Target": {"Id": "i-1234", "Port": 80},
-                  "HealthCheckPort": "traffic-port",
-                  "TargetHealth": {"State": "healthy"}
-                }
-              ]
-            }
-        "#;
-
-        let info = parse_target_group_info_outputs(tg_payload, health_payload).expect("tg info");
+    fn map_target_group_info_extracts_targets_sorted() {
+        let info = map_target_group_info(
+            &target_group(),
+            &[
+                target_health("i-2222", TargetHealthStateEnum::Unhealthy),
+                target_health("i-1111", TargetHealthStateEnum::Healthy),
+            ],
+        );
         assert_eq!(info.name, "tg-main");
-        assert_eq!(info.targets.len(), 1);
-        assert_eq!(info.targets[0].id, "i-1234");
+        assert_eq!(info.protocol, "HTTP");
+        assert_eq!(info.healthy_threshold, 2);
+        assert_eq!(info.targets.len(), 2);
+        assert_eq!(info.targets[0].id, "i-1111");
+        assert_eq!(info.targets[0].health_status, "healthy");
     }
 
     #[test]
-    fn parse_listener_infos_extracts_default_action_and_dedups_target_groups() {
-        let listeners_payload = r#"
-            {
-              "Listeners": [
-                {
-                  "Port": 80,
-                  "Protocol": "HTTP",
-                  "DefaultActions": [
-                    {"Type":"forward","TargetGroupArn":"arn:...:targetgroup/tg-a/1"}
-                  ]
-                },
-                {
-                  "Port": 443,
-                  "Protocol": "HTTPS",
-                  "DefaultActions": [
-                    {"Type":"forward","TargetGroupArn":"arn:...:targetgroup/tg-a/1"}
-                  ]
-                }
-              ]
-            }
-        "#;
-        let (listeners, tg_arns) = parse_listener_infos(listeners_payload).expect("listeners");
-        assert_eq!(listeners.len(), 2);
+    fn map_listener_infos_extracts_default_action_and_dedups_target_groups() {
+        let (listeners, tg_arns) = map_listener_infos(&[
+            forward_listener(443, ProtocolEnum::Https, TG_ARN),
+            forward_listener(80, ProtocolEnum::Http, TG_ARN),
+            Listener::builder().port(8080).build(),
+        ]);
+        assert_eq!(listeners.len(), 3);
+        assert_eq!(listeners[0].port, 80);
         assert_eq!(listeners[0].default_action, "forward");
-        assert_eq!(tg_arns.len(), 1);
+        assert_eq!(listeners[2].default_action, "-");
+        assert_eq!(tg_arns, vec![TG_ARN.to_string()]);
     }
 
     #[test]
-    fn parse_load_balancer_detail_output_applies_defaults() {
-        let lb_payload = r#"
-            {
-              "LoadBalancers": [
-                {
-                  "LoadBalancerName": "lb-a",
-                  "LoadBalancerArn": "arn:...:loadbalancer/app/lb-a/1",
-                  "DNSName": "lb-a.example.com",
-                  "Type": "application",
-                  "Scheme": "internal",
-                  "VpcId": "vpc-1",
-                  "AvailabilityZones": [],
-                  "SecurityGroups": []
-                }
-              ]
-            }
-        "#;
-        let detail = parse_load_balancer_detail_output(lb_payload, vec![], vec![]).expect("detail");
+    fn map_load_balancer_detail_applies_defaults() {
+        let detail = map_load_balancer_detail(
+            &load_balancer("lb-a", LB_ARN, LoadBalancerSchemeEnum::Internal),
+            vec![],
+            vec![],
+        );
         assert_eq!(detail.name, "lb-a");
+        assert_eq!(detail.scheme, "internal");
         assert_eq!(detail.ip_address_type, "ipv4");
         assert_eq!(detail.state, "unknown");
     }
 
+    #[test]
+    fn get_load_balancer_detail_follows_listeners_to_target_health() {
+        let provider = FakeProvider {
+            load_balancers: vec![load_balancer(
+                "alb-main",
+                LB_ARN,
+                LoadBalancerSchemeEnum::InternetFacing,
+            )],
+            listeners: [(
+                LB_ARN.to_string(),
+                vec![forward_listener(80, ProtocolEnum::Http, TG_ARN)],
+            )]
+            .into(),
+            target_groups: vec![target_group()],
+            target_health: [(
+                TG_ARN.to_string(),
+                vec![target_health("i-1234", TargetHealthStateEnum::Healthy)],
+            )]
+            .into(),
+            ..FakeProvider::default()
+        };
+
+        assert_eq!(list_load_balancers(&provider).len(), 1);
+
+        let detail = get_load_balancer_detail(&provider, LB_ARN).expect("detail");
+        assert_eq!(detail.listeners.len(), 1);
+        assert_eq!(detail.target_groups.len(), 1);
+        assert_eq!(detail.target_groups[0].targets[0].id, "i-1234");
+        assert!(get_load_balancer_detail(&provider, "arn:missing").is_none());
+    }
+
     #[test]
     fn load_balancer_markdown_omits_listener_and_target_sections_when_empty() {
         let detail = LoadBalancerDetail {
@@ -647,10 +574,52 @@ mod tests {
             security_groups: vec![],
             listeners: vec![],
             target_groups: vec![],
+            alarms: vec![],
         };
         let md = detail.to_markdown(Language::English);
         assert!(md.contains("NULL - lb-id"));
         assert!(!md.contains("### Listeners"));
         assert!(!md.contains("### Target Groups"));
+        assert!(!md.contains("### Alarms"));
+    }
+
+    #[test]
+    fn alarm_dimensions_cover_load_balancer_and_target_groups() {
+        let detail = LoadBalancerDetail {
+            name: "web".to_string(),
+            arn: "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/web/50dc6c495c0c9188".to_string(),
+            dns_name: "web.example.com".to_string(),
+            lb_type: "application".to_string(),
+            scheme: "internet-facing".to_string(),
+            vpc_id: "vpc-1".to_string(),
+            ip_address_type: "ipv4".to_string(),
+            state: "active".to_string(),
+            availability_zones: vec![],
+            security_groups: vec![],
+            listeners: vec![],
+            target_groups: vec![TargetGroupInfo {
+                name: "web-blue".to_string(),
+                arn: "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:targetgroup/web-blue/1111111111111111".to_string(),
+                protocol: "HTTP".to_string(),
+                port: 80,
+                target_type: "instance".to_string(),
+                health_check_protocol: "HTTP".to_string(),
+                health_check_path: "/".to_string(),
+                healthy_threshold: 5,
+                unhealthy_threshold: 2,
+                targets: vec![],
+            }],
+            alarms: vec![],
+        };
+        assert_eq!(
+            detail.alarm_dimensions(),
+            vec![
+                ("LoadBalancer", "app/web/50dc6c495c0c9188".to_string()),
+                (
+                    "TargetGroup",
+                    "targetgroup/web-blue/1111111111111111".to_string()
+                ),
+            ]
+        );
     }
 }
@@ -0,0 +1,295 @@
+//! `mfa_serial`이 있는 역할 프로필: MFA 코드를 받아 역할을 맡고 세션을 만료 전까지 재사용
+
+use crate::aws_cli::account::resolve_profile;
+use crate::aws_cli::client_cache::invalidate_client_cache;
+use crate::aws_cli::common::{
+    current_profile, effective_region, get_runtime, load_sdk_config, profile_section_name,
+    read_aws_config, read_config_section,
+};
+use crate::aws_cli::fixtures::{FixtureMode, fixture_mode};
+use aws_credential_types::Credentials;
+use aws_credential_types::provider::error::CredentialsError;
+use aws_credential_types::provider::{
+    ProvideCredentials, SharedCredentialsProvider, future as provider_future,
+};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{LazyLock, Mutex};
+use std::time::SystemTime;
+
+const SESSION_NAME: &str = "emd";
+/// 만료 직전 세션으로 요청이 실패하지 않도록 남겨 두는 시간
+const EXPIRY_MARGIN_SECS: i64 = 60;
+
+/// 프로필별 MFA 세션 (프로세스가 끝나면 사라짐)
+static SESSIONS: LazyLock<Mutex<HashMap<String, Credentials>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 역할을 맡을 때 쓰는 기본 자격 증명 (`source_profile` 또는 `credential_source`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoleSource {
+    Profile(String),
+    Environment,
+    Ec2InstanceMetadata,
+    EcsContainer,
+}
+
+impl RoleSource {
+    /// 둘 다 없거나 지원하지 않는 `credential_source`면 `None`
+    fn from_section(section: &BTreeMap<String, String>) -> Option<Self> {
+        if let Some(profile) = section.get("source_profile") {
+            return Some(RoleSource::Profile(profile.clone()));
+        }
+        match section.get("credential_source")?.as_str() {
+            "Environment" => Some(RoleSource::Environment),
+            "Ec2InstanceMetadata" => Some(RoleSource::Ec2InstanceMetadata),
+            "EcsContainer" => Some(RoleSource::EcsContainer),
+            _ => None,
+        }
+    }
+
+    /// 역할을 맡을 때 호출하는 STS 클라이언트 설정
+    async fn base_config(&self, profile: &str, region: &str) -> aws_config::SdkConfig {
+        let credentials = match self {
+            RoleSource::Profile(source_profile) => {
+                return load_sdk_config(source_profile, region).await;
+            }
+            RoleSource::Environment => SharedCredentialsProvider::new(
+                aws_config::environment::EnvironmentVariableCredentialsProvider::new(),
+            ),
+            RoleSource::Ec2InstanceMetadata => SharedCredentialsProvider::new(
+                aws_config::imds::credentials::ImdsCredentialsProvider::builder().build(),
+            ),
+            RoleSource::EcsContainer => SharedCredentialsProvider::new(
+                aws_config::ecs::EcsCredentialsProvider::builder().build(),
+            ),
+        };
+        load_sdk_config(profile, region)
+            .await
+            .to_builder()
+            .credentials_provider(credentials)
+            .build()
+    }
+}
+
+/// `~/.aws/config`의 MFA 역할 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MfaProfile {
+    pub role_arn: String,
+    pub mfa_serial: String,
+    pub source: Option<RoleSource>,
+    pub role_session_name: Option<String>,
+    pub duration_seconds: Option<i32>,
+    pub external_id: Option<String>,
+}
+
+impl MfaProfile {
+    /// `role_arn`과 `mfa_serial`이 모두 있는 프로필만 MFA 프로필로 봄
+    pub fn from_config(contents: &str, profile: &str) -> Option<Self> {
+        let section = read_config_section(contents, &profile_section_name(profile))?;
+        Some(Self {
+            role_arn: section.get("role_arn")?.clone(),
+            mfa_serial: section.get("mfa_serial")?.clone(),
+            source: RoleSource::from_section(&section),
+            role_session_name: section.get("role_session_name").cloned(),
+            duration_seconds: section
+                .get("duration_seconds")
+                .and_then(|value| value.parse().ok()),
+            external_id: section.get("external_id").cloned(),
+        })
+    }
+}
+
+/// 프로필의 MFA 설정
+pub fn mfa_profile(profile: &str) -> Option<MfaProfile> {
+    MfaProfile::from_config(&read_aws_config(), profile)
+}
+
+fn is_valid(credentials: &Credentials, now: DateTime<Utc>) -> bool {
+    credentials.expiry().is_none_or(|expiry| {
+        DateTime::<Utc>::from(expiry) > now + Duration::seconds(EXPIRY_MARGIN_SECS)
+    })
+}
+
+/// 만료되지 않은 MFA 세션 (만료된 세션은 지움)
+fn cached_session(profile: &str) -> Option<Credentials> {
+    let mut sessions = SESSIONS.lock().ok()?;
+    match sessions.get(profile) {
+        Some(credentials) if is_valid(credentials, Utc::now()) => Some(credentials.clone()),
+        Some(_) => {
+            sessions.remove(profile);
+            None
+        }
+        None => None,
+    }
+}
+
+/// 재생 모드는 녹화한 응답과 가짜 자격 증명만 쓰므로 MFA 세션이 필요 없음
+fn replaying() -> bool {
+    matches!(fixture_mode(), FixtureMode::Replay { .. })
+}
+
+/// 로그인한 프로필이 MFA 코드를 기다리면 MFA 장치 ARN (세션이 살아 있거나 재생 중이면 `None`)
+pub fn required_mfa_serial() -> Option<String> {
+    if replaying() {
+        return None;
+    }
+    let (profile, _) = resolve_profile(current_profile(), Vec::new());
+    let mfa = mfa_profile(&profile)?;
+    cached_session(&profile).is_none().then_some(mfa.mfa_serial)
+}
+
+/// MFA 프로필이면 세션 자격 증명 공급자. 세션이 없으면 코드가 필요하다는 오류를 냄
+/// (재생 중에는 재생용 자격 증명을 그대로 씀)
+pub(crate) fn credentials_provider(profile: &str) -> Option<SharedCredentialsProvider> {
+    if replaying() {
+        return None;
+    }
+    let mfa = mfa_profile(profile)?;
+    Some(match cached_session(profile) {
+        Some(credentials) => SharedCredentialsProvider::new(credentials),
+        None => SharedCredentialsProvider::new(MfaCodeRequired {
+            mfa_serial: mfa.mfa_serial,
+        }),
+    })
+}
+
+#[derive(Debug)]
+struct MfaCodeRequired {
+    mfa_serial: String,
+}
+
+impl ProvideCredentials for MfaCodeRequired {
+    fn provide_credentials<'a>(&'a self) -> provider_future::ProvideCredentials<'a>
+    where
+        Self: 'a,
+    {
+        provider_future::ProvideCredentials::ready(Err(CredentialsError::not_loaded(format!(
+            "MFA code required for {}",
+            self.mfa_serial
+        ))))
+    }
+}
+
+/// 로그인한 프로필의 역할을 MFA 코드로 맡고, 세션을 만료 시각까지 캐시
+pub fn submit_mfa_code(code: &str) -> Result<DateTime<Utc>, String> {
+    let (profile, _) = resolve_profile(current_profile(), Vec::new());
+    let mfa = mfa_profile(&profile).ok_or_else(|| format!("{} has no mfa_serial", profile))?;
+    let source = mfa.source.clone().ok_or_else(|| {
+        format!(
+            "{} has neither source_profile nor a supported credential_source",
+            profile
+        )
+    })?;
+    let region = effective_region();
+
+    let credentials = get_runtime().block_on(async {
+        let base = source.base_config(&profile, &region).await;
+        let output = aws_sdk_sts::Client::new(&base)
+            .assume_role()
+            .role_arn(&mfa.role_arn)
+            .role_session_name(mfa.role_session_name.as_deref().unwrap_or(SESSION_NAME))
+            .serial_number(&mfa.mfa_serial)
+            .token_code(code.trim())
+            .set_duration_seconds(mfa.duration_seconds)
+            .set_external_id(mfa.external_id.clone())
+            .send()
+            .await
+            .map_err(|error| aws_sdk_sts::Error::from(error).to_string())?;
+        let session = output
+            .credentials()
+            .ok_or_else(|| "AssumeRole returned no credentials".to_string())?;
+        Ok::<_, String>(Credentials::new(
+            session.access_key_id(),
+            session.secret_access_key(),
+            Some(session.session_token().to_string()),
+            SystemTime::try_from(*session.expiration()).ok(),
+            "emd-mfa",
+        ))
+    })?;
+
+    let expires_at = credentials
+        .expiry()
+        .map(DateTime::<Utc>::from)
+        .unwrap_or_else(Utc::now);
+    if let Ok(mut sessions) = SESSIONS.lock() {
+        sessions.insert(profile.clone(), credentials);
+    }
+    invalidate_client_cache();
+    tracing::info!(profile = %profile, expires_at = %expires_at, "MFA session started");
+    Ok(expires_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MfaProfile, RoleSource, is_valid};
+    use aws_credential_types::Credentials;
+    use chrono::{Duration, Utc};
+    use std::time::SystemTime;
+
+    const CONFIG: &str = "\
+[default]
+region = ap-northeast-2
+
+[profile admin]
+role_arn = arn:aws:iam::111122223333:role/Admin
+source_profile = default
+mfa_serial = arn:aws:iam::999988887777:mfa/alice
+duration_seconds = 3600
+
+[profile readonly]
+role_arn = arn:aws:iam::111122223333:role/ReadOnly
+source_profile = default
+
+[profile instance]
+role_arn = arn:aws:iam::111122223333:role/Admin
+credential_source = Ec2InstanceMetadata
+mfa_serial = arn:aws:iam::999988887777:mfa/alice
+
+[profile unsourced]
+role_arn = arn:aws:iam::111122223333:role/Admin
+mfa_serial = arn:aws:iam::999988887777:mfa/alice
+";
+
+    #[test]
+    fn mfa_profiles_need_role_and_serial() {
+        let admin = MfaProfile::from_config(CONFIG, "admin").expect("admin mfa");
+        assert_eq!(admin.role_arn, "arn:aws:iam::111122223333:role/Admin");
+        assert_eq!(admin.mfa_serial, "arn:aws:iam::999988887777:mfa/alice");
+        assert_eq!(
+            admin.source,
+            Some(RoleSource::Profile("default".to_string()))
+        );
+        assert_eq!(admin.duration_seconds, Some(3600));
+        assert_eq!(admin.role_session_name, None);
+
+        assert_eq!(MfaProfile::from_config(CONFIG, "readonly"), None);
+        assert_eq!(MfaProfile::from_config(CONFIG, "default"), None);
+    }
+
+    #[test]
+    fn mfa_profiles_read_credential_source_and_never_guess_default() {
+        let instance = MfaProfile::from_config(CONFIG, "instance").expect("instance mfa");
+        assert_eq!(instance.source, Some(RoleSource::Ec2InstanceMetadata));
+
+        let unsourced = MfaProfile::from_config(CONFIG, "unsourced").expect("unsourced mfa");
+        assert_eq!(unsourced.source, None);
+    }
+
+    #[test]
+    fn sessions_expire_before_their_expiry_margin() {
+        let now = Utc::now();
+        let session = |expires_in: Duration| {
+            Credentials::new(
+                "AKIA",
+                "secret",
+                Some("token".to_string()),
+                Some(SystemTime::from(now + expires_in)),
+                "test",
+            )
+        };
+        assert!(is_valid(&session(Duration::minutes(30)), now));
+        assert!(!is_valid(&session(Duration::seconds(30)), now));
+        assert!(!is_valid(&session(Duration::minutes(-5)), now));
+    }
+}
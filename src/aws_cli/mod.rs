@@ -1,32 +1,75 @@
 #![cfg_attr(test, allow(dead_code, unused_imports))]
 
+mod account;
+pub(crate) mod api_gateway;
+mod api_gateway_sdk;
 pub(crate) mod asg;
 mod asg_sdk;
+mod client_cache;
+pub(crate) mod cloudwatch;
+mod cloudwatch_sdk;
 mod common;
 mod ec2;
 pub(crate) mod ecr;
 mod ecr_sdk;
+pub(crate) mod efs;
+mod efs_sdk;
+pub(crate) mod eventbridge;
+mod eventbridge_sdk;
+#[cfg(test)]
+pub(crate) mod fake_provider;
+mod fixtures;
 pub(crate) mod iam;
+pub(crate) mod identity;
+pub(crate) mod kms;
+mod kms_sdk;
+pub(crate) mod lambda;
+mod lambda_sdk;
 mod load_balancer;
+pub(crate) mod mfa;
+pub(crate) mod organizations;
+mod partition;
+pub(crate) mod provider;
+mod sdk_provider;
+pub(crate) mod secrets_manager;
+mod secrets_manager_sdk;
 mod security_group;
+pub(crate) mod ssm;
+mod ssm_sdk;
+pub(crate) mod sso;
+pub(crate) mod step_functions;
+mod step_functions_sdk;
 mod vpc;
 
 // Re-export common types
+pub use account::{AccountTarget, VirtualProfile, set_virtual_profiles, with_account};
 pub use common::{
-    AwsAuthError, AwsAuthErrorCode, AwsResource, check_aws_login, list_aws_profiles,
-    set_aws_profile, set_region,
+    AwsAuthError, AwsAuthErrorCode, AwsLogin, AwsQueryError, AwsQueryErrorCode, AwsResource,
+    DEFAULT_MAX_CONCURRENCY, EndpointOverrides, RegionSource, RequestPolicy, cancelled,
+    capture_partial_failures, capture_query_errors, check_aws_login, list_aws_profiles,
+    map_concurrent, record_partial_failures, resolve_region, set_aws_profile,
+    set_endpoint_overrides, set_max_concurrency, set_region, set_request_policy, spawn_blocking,
+    with_cancellation, with_region,
 };
+pub(crate) use common::{cache_profile, current_profile, effective_region};
+pub use fixtures::{FixtureMode, set_fixture_mode};
+pub use partition::{Partition, console_url};
+
+// Re-export AWS API provider
+pub use provider::{AwsProvider, PartialFailure};
+pub use sdk_provider::SdkProvider;
 
 // Re-export EC2 types and functions
 #[allow(unused_imports)]
-pub use ec2::{Ec2Detail, VolumeDetail, get_instance_detail, list_instances};
+pub use ec2::{Ec2Detail, VolumeDetail, get_instance_detail, list_instances, list_regions};
 
 // Re-export VPC types and functions
 #[allow(unused_imports)]
 pub use vpc::{
-    EipDetail, NatDetail, NetworkDetail, RouteTableDetail, get_network_detail,
-    get_vpc_dns_hostnames, get_vpc_dns_support, get_vpc_info, list_eips, list_internet_gateways,
-    list_nat_gateways, list_route_tables, list_subnets, list_vpcs,
+    EipDetail, NatDetail, NetworkDetail, NetworkPart, NetworkPartData, RouteTableDetail,
+    fetch_network_part, get_network_detail, get_vpc_dns_hostnames, get_vpc_dns_support,
+    get_vpc_info, list_eips, list_internet_gateways, list_nat_gateways, list_route_tables,
+    list_subnets, list_vpcs,
 };
 
 // Re-export Security Group types and functions
@@ -48,3 +91,33 @@ pub use ecr::EcrDetail;
 // Re-export ASG types and functions
 #[allow(unused_imports)]
 pub use asg::{AsgDetail, ScalingPolicy};
+
+// Re-export CloudWatch alarm type
+pub use cloudwatch::CloudWatchAlarm;
+
+// Re-export API Gateway types
+#[allow(unused_imports)]
+pub use api_gateway::{
+    ApiAuthorizer, ApiDomainMapping, ApiGatewayDetail, ApiIntegration, ApiIntegrationKind,
+    ApiRoute, ApiStage,
+};
+
+// Re-export EFS types
+#[allow(unused_imports)]
+pub use efs::{EfsAccessPoint, EfsDetail, EfsLifecyclePolicy, EfsMountTarget, EfsTransition};
+
+// Re-export KMS / Secrets Manager / SSM Parameter types
+#[allow(unused_imports)]
+pub use kms::KmsKeyDetail;
+#[allow(unused_imports)]
+pub use secrets_manager::SecretDetail;
+#[allow(unused_imports)]
+pub use ssm::SsmParameterDetail;
+
+// Re-export EventBridge / Step Functions types
+#[allow(unused_imports)]
+pub use eventbridge::{EventRuleDetail, EventTarget};
+pub use step_functions::StateMachineDetail;
+
+// Re-export Lambda types
+pub use lambda::LambdaFunctionDetail;
@@ -0,0 +1,196 @@
+//! AWS Organizations 계정 목록과 멤버 계정 AssumeRole용 프로필
+
+use crate::aws_cli::partition::Partition;
+use crate::aws_cli::provider::AwsProvider;
+use std::collections::VecDeque;
+
+/// 멤버 계정 생성 시 Organizations가 만드는 기본 역할
+pub const DEFAULT_ORG_ROLE_NAME: &str = "OrganizationAccountAccessRole";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrgAccount {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    pub status: String,
+    /// 루트부터 계정이 속한 OU까지 (`Root/Workloads/Prod`)
+    pub ou_path: String,
+}
+
+impl OrgAccount {
+    pub fn role_arn(&self, partition: Partition, role_name: &str) -> String {
+        format!("arn:{}:iam::{}:role/{}", partition.id(), self.id, role_name)
+    }
+
+    /// `~/.aws/config`와 프로필 목록에 쓰는 이름 (계정 이름을 소문자/하이픈으로, 없으면 ID)
+    pub fn profile_name(&self) -> String {
+        let slug = self
+            .name
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if slug.is_empty() {
+            self.id.clone()
+        } else {
+            slug
+        }
+    }
+}
+
+/// 루트에서 OU를 너비 우선으로 내려가며 모든 계정을 모음 (권한이 없으면 빈 목록)
+pub fn list_organization_accounts(provider: &dyn AwsProvider) -> Vec<OrgAccount> {
+    let mut parents: VecDeque<(String, String)> = provider
+        .list_roots()
+        .iter()
+        .filter_map(|root| {
+            let id = root.id()?.to_string();
+            let name = root.name().unwrap_or("Root").to_string();
+            Some((id, name))
+        })
+        .collect();
+
+    let mut accounts = Vec::new();
+    while let Some((parent_id, path)) = parents.pop_front() {
+        for unit in provider.list_organizational_units_for_parent(&parent_id) {
+            if let Some(id) = unit.id() {
+                let name = unit.name().unwrap_or(id);
+                parents.push_back((id.to_string(), format!("{}/{}", path, name)));
+            }
+        }
+        accounts.extend(
+            provider
+                .list_accounts_for_parent(&parent_id)
+                .iter()
+                .filter_map(|account| {
+                    Some(OrgAccount {
+                        id: account.id()?.to_string(),
+                        name: account.name().unwrap_or_default().to_string(),
+                        email: account.email().unwrap_or_default().to_string(),
+                        status: account
+                            .state()
+                            .map(|state| state.as_str().to_string())
+                            .unwrap_or_default(),
+                        ou_path: path.clone(),
+                    })
+                }),
+        );
+    }
+    accounts.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+    accounts
+}
+
+/// 붙여 넣을 수 있는 `~/.aws/config` 프로필 섹션
+pub fn profile_sections(
+    accounts: &[OrgAccount],
+    partition: Partition,
+    role_name: &str,
+    source_profile: &str,
+    region: &str,
+) -> String {
+    accounts
+        .iter()
+        .map(|account| {
+            format!(
+                "# {} ({})\n[profile {}]\nrole_arn = {}\nsource_profile = {}\nregion = {}\n",
+                account.name,
+                account.id,
+                account.profile_name(),
+                account.role_arn(partition, role_name),
+                source_profile,
+                region
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrgAccount, list_organization_accounts, profile_sections};
+    use crate::aws_cli::fake_provider::FakeProvider;
+    use crate::aws_cli::partition::Partition;
+    use aws_sdk_organizations::types::{Account, AccountState, OrganizationalUnit, Root};
+    use std::collections::HashMap;
+
+    fn account(id: &str, name: &str) -> Account {
+        Account::builder()
+            .id(id)
+            .name(name)
+            .email(format!("{}@example.com", id))
+            .state(AccountState::Active)
+            .build()
+    }
+
+    #[test]
+    fn accounts_are_collected_through_nested_ous() {
+        let provider = FakeProvider {
+            org_roots: vec![Root::builder().id("r-1").name("Root").build()],
+            org_units: HashMap::from([
+                (
+                    "r-1".to_string(),
+                    vec![
+                        OrganizationalUnit::builder()
+                            .id("ou-1")
+                            .name("Workloads")
+                            .build(),
+                    ],
+                ),
+                (
+                    "ou-1".to_string(),
+                    vec![
+                        OrganizationalUnit::builder()
+                            .id("ou-2")
+                            .name("Prod")
+                            .build(),
+                    ],
+                ),
+            ]),
+            org_accounts: HashMap::from([
+                (
+                    "r-1".to_string(),
+                    vec![account("111111111111", "Management")],
+                ),
+                (
+                    "ou-2".to_string(),
+                    vec![account("222222222222", "App Prod")],
+                ),
+            ]),
+            ..FakeProvider::default()
+        };
+
+        let accounts = list_organization_accounts(&provider);
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].name, "App Prod");
+        assert_eq!(accounts[0].ou_path, "Root/Workloads/Prod");
+        assert_eq!(accounts[0].status, "ACTIVE");
+        assert_eq!(accounts[1].ou_path, "Root");
+
+        assert!(list_organization_accounts(&FakeProvider::default()).is_empty());
+    }
+
+    #[test]
+    fn profile_sections_use_partition_and_role_name() {
+        let accounts = vec![OrgAccount {
+            id: "222222222222".to_string(),
+            name: "App Prod".to_string(),
+            email: String::new(),
+            status: "ACTIVE".to_string(),
+            ou_path: "Root".to_string(),
+        }];
+        assert_eq!(accounts[0].profile_name(), "app-prod");
+        assert_eq!(
+            profile_sections(
+                &accounts,
+                Partition::AwsUsGov,
+                "Audit",
+                "management",
+                "us-gov-west-1"
+            ),
+            "# App Prod (222222222222)\n[profile app-prod]\n\
+             role_arn = arn:aws-us-gov:iam::222222222222:role/Audit\n\
+             source_profile = management\nregion = us-gov-west-1\n"
+        );
+    }
+}
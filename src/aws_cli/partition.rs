@@ -0,0 +1,189 @@
+//! AWS 파티션 (`aws`, `aws-cn`, `aws-us-gov`): 리전 소속, ARN 파싱, 콘솔 주소
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Partition {
+    #[default]
+    Aws,
+    AwsCn,
+    AwsUsGov,
+}
+
+impl Partition {
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "aws" => Some(Partition::Aws),
+            "aws-cn" => Some(Partition::AwsCn),
+            "aws-us-gov" => Some(Partition::AwsUsGov),
+            _ => None,
+        }
+    }
+
+    /// ARN에 쓰는 파티션 ID
+    pub fn id(&self) -> &'static str {
+        match self {
+            Partition::Aws => "aws",
+            Partition::AwsCn => "aws-cn",
+            Partition::AwsUsGov => "aws-us-gov",
+        }
+    }
+
+    /// 리전 코드가 속한 파티션 (`cn-*` 중국, `us-gov-*` GovCloud, 나머지는 상용)
+    pub fn from_region(region: &str) -> Self {
+        if region.starts_with("cn-") {
+            Partition::AwsCn
+        } else if region.starts_with("us-gov-") {
+            Partition::AwsUsGov
+        } else {
+            Partition::Aws
+        }
+    }
+
+    /// 호출자 ARN의 파티션 (ARN이 아니면 상용)
+    pub fn from_arn(arn: &str) -> Self {
+        Arn::parse(arn)
+            .and_then(|arn| Self::from_id(arn.partition))
+            .unwrap_or_default()
+    }
+
+    /// `DescribeRegions`처럼 파티션 안 아무 리전에서나 호출할 수 있는 API에 쓰는 리전
+    pub fn default_region(&self) -> &'static str {
+        match self {
+            Partition::Aws => "us-east-1",
+            Partition::AwsCn => "cn-north-1",
+            Partition::AwsUsGov => "us-gov-west-1",
+        }
+    }
+
+    pub fn console_host(&self) -> &'static str {
+        match self {
+            Partition::Aws => "console.aws.amazon.com",
+            Partition::AwsCn => "console.amazonaws.cn",
+            Partition::AwsUsGov => "console.amazonaws-us-gov.com",
+        }
+    }
+}
+
+/// `arn:partition:service:region:account-id:resource` 형식의 ARN
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arn<'a> {
+    pub partition: &'a str,
+    pub service: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: &'a str,
+}
+
+impl<'a> Arn<'a> {
+    pub fn parse(arn: &'a str) -> Option<Self> {
+        let mut parts = arn.splitn(6, ':');
+        if parts.next()? != "arn" {
+            return None;
+        }
+        let partition = parts.next()?;
+        let service = parts.next()?;
+        let region = parts.next()?;
+        let account_id = parts.next()?;
+        let resource = parts.next()?;
+        if partition.is_empty() || service.is_empty() || resource.is_empty() {
+            return None;
+        }
+        Some(Self {
+            partition,
+            service,
+            region,
+            account_id,
+            resource,
+        })
+    }
+
+    /// 리소스 경로의 마지막 구간 (`loadbalancer/app/web/1234` -> `1234`)
+    pub fn resource_id(&self) -> &'a str {
+        self.resource
+            .rsplit(['/', ':'])
+            .next()
+            .unwrap_or(self.resource)
+    }
+}
+
+/// ARN이면 리소스 경로의 마지막 구간, 아니면 마지막 `/` 뒤 (없으면 입력 그대로)
+pub fn resource_id_from_arn(value: &str) -> &str {
+    Arn::parse(value).map_or_else(
+        || value.rsplit('/').next().unwrap_or(value),
+        |arn| arn.resource_id(),
+    )
+}
+
+/// 리전이 속한 파티션의 콘솔 주소 (`path`는 서비스 경로, `fragment`는 `#` 뒤 화면 경로)
+pub fn console_url(region: &str, path: &str, fragment: &str) -> String {
+    let host = Partition::from_region(region).console_host();
+    let mut url = format!("https://{}/{}?region={}", host, path, region);
+    if !fragment.is_empty() {
+        url.push('#');
+        url.push_str(fragment);
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arn, Partition, console_url, resource_id_from_arn};
+
+    #[test]
+    fn regions_and_caller_arns_map_to_partitions() {
+        assert_eq!(Partition::from_region("ap-northeast-2"), Partition::Aws);
+        assert_eq!(Partition::from_region("cn-northwest-1"), Partition::AwsCn);
+        assert_eq!(Partition::from_region("us-gov-east-1"), Partition::AwsUsGov);
+        assert_eq!(
+            Partition::from_arn("arn:aws-us-gov:iam::123456789012:user/ops"),
+            Partition::AwsUsGov
+        );
+        assert_eq!(
+            Partition::from_arn("arn:aws-cn:sts::123456789012:assumed-role/r/s"),
+            Partition::AwsCn
+        );
+        assert_eq!(Partition::from_arn("unknown"), Partition::Aws);
+    }
+
+    #[test]
+    fn arn_parse_handles_every_partition() {
+        let arn = Arn::parse(
+            "arn:aws-us-gov:elasticloadbalancing:us-gov-west-1:123456789012:loadbalancer/app/web/50dc6c495c0c9188",
+        )
+        .expect("arn");
+        assert_eq!(arn.partition, "aws-us-gov");
+        assert_eq!(arn.service, "elasticloadbalancing");
+        assert_eq!(arn.region, "us-gov-west-1");
+        assert_eq!(arn.account_id, "123456789012");
+        assert_eq!(arn.resource, "loadbalancer/app/web/50dc6c495c0c9188");
+        assert_eq!(arn.resource_id(), "50dc6c495c0c9188");
+
+        assert_eq!(
+            resource_id_from_arn("arn:aws-cn:states:cn-north-1:123456789012:stateMachine:orders"),
+            "orders"
+        );
+        assert_eq!(resource_id_from_arn("vpc-1234"), "vpc-1234");
+        assert!(Arn::parse("arn:aws:s3").is_none());
+    }
+
+    #[test]
+    fn console_url_uses_partition_host() {
+        assert_eq!(
+            console_url(
+                "us-gov-west-1",
+                "ec2/home",
+                "InstanceDetails:instanceId=i-1"
+            ),
+            "https://console.amazonaws-us-gov.com/ec2/home?region=us-gov-west-1#InstanceDetails:instanceId=i-1"
+        );
+        assert_eq!(
+            console_url("cn-north-1", "vpc/home", ""),
+            "https://console.amazonaws.cn/vpc/home?region=cn-north-1"
+        );
+        assert!(
+            console_url("ap-northeast-2", "kms/home", "")
+                .starts_with("https://console.aws.amazon.com/")
+        );
+    }
+}
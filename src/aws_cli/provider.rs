@@ -0,0 +1,264 @@
+use crate::aws_cli::common::{AwsQueryError, AwsQueryErrorCode};
+use aws_sdk_apigateway::operation::get_rest_api::GetRestApiOutput;
+use aws_sdk_apigatewayv2::operation::get_api::GetApiOutput;
+use aws_sdk_eventbridge::operation::describe_rule::DescribeRuleOutput;
+use aws_sdk_kms::operation::get_key_rotation_status::GetKeyRotationStatusOutput;
+use aws_sdk_lambda::operation::get_function_configuration::GetFunctionConfigurationOutput;
+use aws_sdk_secretsmanager::operation::describe_secret::DescribeSecretOutput;
+use aws_sdk_sfn::operation::describe_state_machine::DescribeStateMachineOutput;
+use aws_sdk_sts::operation::get_caller_identity::GetCallerIdentityOutput;
+
+/// EC2 Describe* 호출 공통 필터 (ID 목록 + VPC)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ec2Filter {
+    pub ids: Vec<String>,
+    pub vpc_id: Option<String>,
+}
+
+impl Ec2Filter {
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn id(id: &str) -> Self {
+        Self {
+            ids: vec![id.to_string()],
+            vpc_id: None,
+        }
+    }
+
+    pub fn vpc(vpc_id: &str) -> Self {
+        Self {
+            ids: Vec::new(),
+            vpc_id: Some(vpc_id.to_string()),
+        }
+    }
+
+    /// ID/VPC 조건에 맞는지 (비어 있는 조건은 모두 통과, `FakeProvider`에서 사용)
+    #[cfg(test)]
+    pub fn matches(&self, id: Option<&str>, vpc_id: Option<&str>) -> bool {
+        let id_ok = self.ids.is_empty() || id.is_some_and(|id| self.ids.iter().any(|v| v == id));
+        let vpc_ok = self.vpc_id.is_none() || self.vpc_id.as_deref() == vpc_id;
+        id_ok && vpc_ok
+    }
+}
+
+/// 페이지 조회 도중 실패해 앞쪽 페이지 결과만 반환된 호출
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialFailure {
+    pub operation: &'static str,
+    /// 실패 전까지 받은 항목 수
+    pub items: usize,
+    /// 실패한 페이지 요청의 원인
+    pub code: AwsQueryErrorCode,
+    pub error: String,
+}
+
+impl PartialFailure {
+    /// 잘린 결과를 조회 실패로 (블루프린트 문서처럼 빠짐없는 결과가 필요한 곳에서 사용)
+    pub fn query_error(&self) -> AwsQueryError {
+        AwsQueryError::new(
+            self.code,
+            self.operation,
+            format!("stopped after {} items: {}", self.items, self.error),
+        )
+    }
+}
+
+/// 서비스 모듈이 사용하는 AWS API 호출 계층.
+///
+/// 메서드는 SDK 타입을 그대로 반환하며, 호출 실패는 구현체에서 로그로 남기고
+/// 빈 목록/`None`으로 돌려준다. 실패 원인은 `capture_query_errors` 범위에
+/// `AwsQueryError`로 기록된다. 목록 메서드는 모든 페이지를 읽으며, 중간 페이지가
+/// 실패하면 그때까지의 결과를 반환하고 `capture_partial_failures` 범위에 `PartialFailure`로 기록한다.
+/// 실제 구현은 `SdkProvider`, 테스트는 `FakeProvider`.
+pub trait AwsProvider: Send + Sync {
+    // EC2
+    /// 계정에서 사용할 수 있는 리전 (옵트인하지 않은 리전 제외)
+    fn describe_regions(&self) -> Vec<aws_sdk_ec2::types::Region>;
+    fn describe_instances(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Instance>;
+    fn describe_volumes(&self, volume_ids: &[String]) -> Vec<aws_sdk_ec2::types::Volume>;
+    /// base64 인코딩된 userData 속성
+    fn describe_instance_user_data(&self, instance_id: &str) -> Option<String>;
+    fn describe_images(&self, image_ids: &[String]) -> Vec<aws_sdk_ec2::types::Image>;
+    fn describe_vpcs(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Vpc>;
+    fn describe_vpc_attribute(
+        &self,
+        vpc_id: &str,
+        attribute: aws_sdk_ec2::types::VpcAttributeName,
+    ) -> Option<bool>;
+    fn describe_subnets(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Subnet>;
+    /// `vpc_id` 필터는 연결(attachment) 기준
+    fn describe_internet_gateways(
+        &self,
+        filter: &Ec2Filter,
+    ) -> Vec<aws_sdk_ec2::types::InternetGateway>;
+    fn describe_nat_gateways(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::NatGateway>;
+    fn describe_route_tables(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::RouteTable>;
+    fn describe_addresses(&self) -> Vec<aws_sdk_ec2::types::Address>;
+    fn describe_security_groups(
+        &self,
+        filter: &Ec2Filter,
+    ) -> Vec<aws_sdk_ec2::types::SecurityGroup>;
+
+    // Elastic Load Balancing v2
+    fn describe_load_balancers(
+        &self,
+        arns: &[String],
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::LoadBalancer>;
+    fn describe_listeners(
+        &self,
+        load_balancer_arn: &str,
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::Listener>;
+    fn describe_target_groups(
+        &self,
+        arns: &[String],
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::TargetGroup>;
+    fn describe_target_health(
+        &self,
+        target_group_arn: &str,
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::TargetHealthDescription>;
+
+    // IAM
+    fn get_role(&self, role_name: &str) -> Option<aws_sdk_iam::types::Role>;
+    fn list_attached_role_policies(
+        &self,
+        role_name: &str,
+    ) -> Vec<aws_sdk_iam::types::AttachedPolicy>;
+    fn list_role_policies(&self, role_name: &str) -> Vec<String>;
+    /// URL 인코딩된 인라인 정책 문서
+    fn get_role_policy(&self, role_name: &str, policy_name: &str) -> Option<String>;
+    fn list_account_aliases(&self) -> Vec<String>;
+
+    // STS
+    fn get_caller_identity(&self) -> Option<GetCallerIdentityOutput>;
+
+    // Organizations
+    fn list_roots(&self) -> Vec<aws_sdk_organizations::types::Root>;
+    fn list_organizational_units_for_parent(
+        &self,
+        parent_id: &str,
+    ) -> Vec<aws_sdk_organizations::types::OrganizationalUnit>;
+    fn list_accounts_for_parent(
+        &self,
+        parent_id: &str,
+    ) -> Vec<aws_sdk_organizations::types::Account>;
+
+    // ECR
+    fn describe_repositories(&self, names: &[String]) -> Vec<aws_sdk_ecr::types::Repository>;
+    fn describe_ecr_images(&self, repository_name: &str) -> Vec<aws_sdk_ecr::types::ImageDetail>;
+
+    // Auto Scaling
+    fn describe_auto_scaling_groups(
+        &self,
+        names: &[String],
+    ) -> Vec<aws_sdk_autoscaling::types::AutoScalingGroup>;
+    fn describe_policies(&self, asg_name: &str) -> Vec<aws_sdk_autoscaling::types::ScalingPolicy>;
+
+    // API Gateway (REST v1)
+    fn get_rest_apis(&self) -> Vec<aws_sdk_apigateway::types::RestApi>;
+    fn get_rest_api(&self, api_id: &str) -> Option<GetRestApiOutput>;
+    fn get_rest_stages(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Stage>;
+    /// 메서드/통합 정보를 포함한(embed=methods) 리소스 목록
+    fn get_rest_resources(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Resource>;
+    fn get_rest_authorizers(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Authorizer>;
+    fn get_rest_domain_names(&self) -> Vec<aws_sdk_apigateway::types::DomainName>;
+    fn get_base_path_mappings(
+        &self,
+        domain_name: &str,
+    ) -> Vec<aws_sdk_apigateway::types::BasePathMapping>;
+
+    // API Gateway (HTTP/WebSocket v2)
+    fn get_http_apis(&self) -> Vec<aws_sdk_apigatewayv2::types::Api>;
+    fn get_http_api(&self, api_id: &str) -> Option<GetApiOutput>;
+    fn get_http_stages(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Stage>;
+    fn get_http_routes(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Route>;
+    fn get_http_integrations(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Integration>;
+    fn get_http_authorizers(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Authorizer>;
+    fn get_http_domain_names(&self) -> Vec<aws_sdk_apigatewayv2::types::DomainName>;
+    fn get_api_mappings(&self, domain_name: &str) -> Vec<aws_sdk_apigatewayv2::types::ApiMapping>;
+
+    // KMS
+    fn list_keys(&self) -> Vec<aws_sdk_kms::types::KeyListEntry>;
+    fn describe_key(&self, key_id: &str) -> Option<aws_sdk_kms::types::KeyMetadata>;
+    fn list_aliases(&self, key_id: Option<&str>) -> Vec<aws_sdk_kms::types::AliasListEntry>;
+    /// 자동 교체를 지원하지 않는 키(비대칭/HMAC 등)는 `None`
+    fn get_key_rotation_status(&self, key_id: &str) -> Option<GetKeyRotationStatusOutput>;
+    fn get_key_policy(&self, key_id: &str) -> Option<String>;
+    fn list_grants(&self, key_id: &str) -> Vec<aws_sdk_kms::types::GrantListEntry>;
+    fn list_resource_tags(&self, key_id: &str) -> Vec<aws_sdk_kms::types::Tag>;
+
+    // Secrets Manager (메타데이터 API만 제공, 값 조회 메서드는 두지 않는다)
+    fn list_secrets(&self) -> Vec<aws_sdk_secretsmanager::types::SecretListEntry>;
+    fn describe_secret(&self, secret_id: &str) -> Option<DescribeSecretOutput>;
+
+    // SSM Parameter Store (DescribeParameters 메타데이터만, GetParameter(s)는 두지 않는다)
+    fn describe_parameters(&self, name: Option<&str>)
+    -> Vec<aws_sdk_ssm::types::ParameterMetadata>;
+    fn list_parameter_tags(&self, name: &str) -> Vec<aws_sdk_ssm::types::Tag>;
+
+    // EFS
+    fn describe_file_systems(
+        &self,
+        file_system_id: Option<&str>,
+    ) -> Vec<aws_sdk_efs::types::FileSystemDescription>;
+    fn describe_lifecycle_configuration(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::LifecyclePolicy>;
+    /// 백업 정책이 설정된 적 없는 파일 시스템은 `Disabled` 정책으로 반환
+    fn describe_backup_policy(
+        &self,
+        file_system_id: &str,
+    ) -> Option<aws_sdk_efs::types::BackupPolicy>;
+    fn describe_access_points(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::AccessPointDescription>;
+    fn describe_mount_targets(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::MountTargetDescription>;
+    fn describe_mount_target_security_groups(&self, mount_target_id: &str) -> Vec<String>;
+
+    // CloudWatch
+    /// 이름이 비어 있으면 전체 메트릭 경보
+    fn describe_metric_alarms(
+        &self,
+        names: &[String],
+    ) -> Vec<aws_sdk_cloudwatch::types::MetricAlarm>;
+
+    // EventBridge
+    fn list_event_buses(&self) -> Vec<aws_sdk_eventbridge::types::EventBus>;
+    fn list_rules(&self, event_bus: &str) -> Vec<aws_sdk_eventbridge::types::Rule>;
+    fn describe_rule(&self, event_bus: &str, name: &str) -> Option<DescribeRuleOutput>;
+    fn list_targets_by_rule(
+        &self,
+        event_bus: &str,
+        rule: &str,
+    ) -> Vec<aws_sdk_eventbridge::types::Target>;
+
+    // Step Functions
+    fn list_state_machines(&self) -> Vec<aws_sdk_sfn::types::StateMachineListItem>;
+    fn describe_state_machine(&self, arn: &str) -> Option<DescribeStateMachineOutput>;
+
+    // Lambda
+    fn list_functions(&self) -> Vec<aws_sdk_lambda::types::FunctionConfiguration>;
+    /// 함수 설정만 조회 (코드 다운로드 주소를 주는 GetFunction은 쓰지 않음)
+    fn get_function_configuration(&self, function: &str) -> Option<GetFunctionConfigurationOutput>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ec2Filter;
+
+    #[test]
+    fn ec2_filter_matches_ids_and_vpc() {
+        assert!(Ec2Filter::all().matches(Some("i-1"), None));
+        assert!(Ec2Filter::id("i-1").matches(Some("i-1"), Some("vpc-1")));
+        assert!(!Ec2Filter::id("i-1").matches(Some("i-2"), Some("vpc-1")));
+        assert!(!Ec2Filter::id("i-1").matches(None, None));
+        assert!(Ec2Filter::vpc("vpc-1").matches(Some("subnet-1"), Some("vpc-1")));
+        assert!(!Ec2Filter::vpc("vpc-1").matches(Some("subnet-1"), Some("vpc-2")));
+    }
+}
@@ -0,0 +1,1509 @@
+use crate::aws_cli::client_cache::{SdkClients, load_clients};
+use crate::aws_cli::common::{
+    AwsQueryError, AwsQueryErrorCode, cancelled, get_runtime, is_auth_failure_error,
+    is_network_error, record_partial_failures, record_query_errors, throttle,
+};
+use crate::aws_cli::provider::{AwsProvider, Ec2Filter, PartialFailure};
+use aws_sdk_apigateway::operation::get_rest_api::GetRestApiOutput;
+use aws_sdk_apigatewayv2::operation::get_api::GetApiOutput;
+use aws_sdk_ec2::error::{BuildError, DisplayErrorContext, ProvideErrorMetadata, SdkError};
+use aws_sdk_eventbridge::operation::describe_rule::DescribeRuleOutput;
+use aws_sdk_kms::operation::get_key_rotation_status::GetKeyRotationStatusOutput;
+use aws_sdk_lambda::operation::get_function_configuration::GetFunctionConfigurationOutput;
+use aws_sdk_secretsmanager::operation::describe_secret::DescribeSecretOutput;
+use aws_sdk_sfn::operation::describe_state_machine::DescribeStateMachineOutput;
+use aws_sdk_sts::operation::get_caller_identity::GetCallerIdentityOutput;
+use std::fmt::Debug;
+use std::time::Instant;
+
+/// paginator가 없는 목록 API를 토큰 기반 페이지 스트림으로 감쌈 (`drain_pages`에 전달).
+/// 요청마다 `$request`를 다시 만들어 이전 응답의 토큰을 설정
+macro_rules! token_pages {
+    ($request:expr, $set_token:ident, $token:ident) => {{
+        let mut token: Option<String> = None;
+        let mut done = false;
+        async move || {
+            if done {
+                return None;
+            }
+            let result = $request.$set_token(token.take()).send().await;
+            match &result {
+                Ok(output) => match output.$token() {
+                    Some(next) if !next.is_empty() => token = Some(next.to_string()),
+                    _ => done = true,
+                },
+                Err(_) => done = true,
+            }
+            Some(result)
+        }
+    }};
+}
+
+/// AWS SDK로 실제 API를 호출하는 `AwsProvider` 구현
+#[derive(Debug, Default)]
+pub struct SdkProvider;
+
+impl SdkProvider {
+    /// 모든 페이지의 항목을 모으고, 중간 페이지 실패는 현재 작업의 부분 결과로 기록
+    async fn collect_pages<P, T, E: QueryFailure>(
+        &self,
+        operation: &'static str,
+        next_page: impl AsyncFnMut() -> Option<Result<P, E>>,
+        items: impl Fn(P) -> Vec<T>,
+    ) -> Vec<T> {
+        let (collected, failure) = drain_pages(operation, next_page, items).await;
+        record_partial_failures(failure);
+        collected
+    }
+}
+
+/// 페이지를 끝까지 읽음. 첫 페이지 실패는 일반 실패(빈 결과)로, 이후 페이지 실패는
+/// 그때까지의 항목과 함께 `PartialFailure`로 반환
+async fn drain_pages<P, T, E: QueryFailure>(
+    operation: &'static str,
+    mut next_page: impl AsyncFnMut() -> Option<Result<P, E>>,
+    items: impl Fn(P) -> Vec<T>,
+) -> (Vec<T>, Option<PartialFailure>) {
+    let mut collected = Vec::new();
+    let mut pages = 0usize;
+    loop {
+        // 첫 페이지는 `call`에서 이미 속도 제한을 거침. 취소된 작업은 남은 페이지를 읽지 않음
+        if pages > 0 {
+            if cancelled() {
+                break;
+            }
+            throttle(operation).await;
+        }
+        let Some(page) = next_page().await else {
+            break;
+        };
+        match page {
+            Ok(page) => {
+                collected.extend(items(page));
+                pages += 1;
+            }
+            Err(e) if pages == 0 => {
+                tracing::error!(operation, "AWS SDK request failed: {:?}", e);
+                record_query_errors([e.query_error(operation)]);
+                break;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    operation,
+                    pages,
+                    items = collected.len(),
+                    "AWS SDK pagination stopped early; returning partial results: {:?}",
+                    e
+                );
+                let failure = PartialFailure {
+                    operation,
+                    items: collected.len(),
+                    code: e.query_error(operation).code,
+                    error: format!("{:?}", e),
+                };
+                return (collected, Some(failure));
+            }
+        }
+    }
+    (collected, None)
+}
+
+/// 프로필/리전별로 캐시된 SDK 클라이언트로 요청을 실행하고 소요 시간을 기록.
+/// 자격 증명을 얻을 수 없으면 SDK 재시도 대기 없이 기본값을 반환
+fn call<T: Default>(operation: &'static str, request: impl AsyncFnOnce(&SdkClients) -> T) -> T {
+    // 취소된 작업의 결과는 버려지므로 남은 요청을 보내지 않음
+    if cancelled() {
+        tracing::debug!(operation, "Loading task cancelled; skipping SDK request");
+        return T::default();
+    }
+    let started_at = Instant::now();
+    let (result, cached) = get_runtime().block_on(async {
+        throttle(operation).await;
+        let clients = match load_clients().await {
+            Ok(clients) => clients,
+            Err(error) => {
+                tracing::error!(
+                    operation,
+                    error = %error,
+                    "AWS credential provider unavailable; skipping SDK request"
+                );
+                record_query_errors([AwsQueryError::new(
+                    AwsQueryErrorCode::Credentials,
+                    operation,
+                    error,
+                )]);
+                return (T::default(), false);
+            }
+        };
+        (request(&clients).await, clients.cached)
+    });
+    tracing::debug!(
+        operation,
+        client_cached = cached,
+        elapsed_ms = started_at.elapsed().as_millis(),
+        "AWS SDK request finished"
+    );
+    result
+}
+
+fn ok_or_log<T, E: QueryFailure>(operation: &str, result: Result<T, E>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(e) => {
+            tracing::error!(operation, "AWS SDK request failed: {:?}", e);
+            record_query_errors([e.query_error(operation)]);
+            None
+        }
+    }
+}
+
+/// 실패한 SDK 호출을 `AwsQueryError`로 분류
+trait QueryFailure: Debug {
+    fn query_error(&self, operation: &str) -> AwsQueryError;
+}
+
+impl<E: ProvideErrorMetadata + std::error::Error + 'static, R: Debug> QueryFailure
+    for SdkError<E, R>
+{
+    fn query_error(&self, operation: &str) -> AwsQueryError {
+        let detail = DisplayErrorContext(self).to_string();
+        let code = match self {
+            SdkError::ServiceError(_) => {
+                let code = AwsQueryErrorCode::from_service_code(self.code().unwrap_or_default());
+                let detail = match (self.code(), self.message()) {
+                    (Some(code), Some(message)) => format!("{}: {}", code, message),
+                    (Some(code), None) => code.to_string(),
+                    _ => detail,
+                };
+                return AwsQueryError::new(code, operation, detail);
+            }
+            SdkError::TimeoutError(_) => AwsQueryErrorCode::Timeout,
+            SdkError::DispatchFailure(failure) if failure.is_timeout() => {
+                AwsQueryErrorCode::Timeout
+            }
+            SdkError::DispatchFailure(failure) if failure.is_io() => AwsQueryErrorCode::Network,
+            SdkError::ResponseError(_) => AwsQueryErrorCode::Parse,
+            // 자격 증명 해석 실패는 요청 생성/전송 단계의 오류로 나타남
+            _ if is_auth_failure_error(&detail) => AwsQueryErrorCode::Credentials,
+            _ if is_network_error(&detail) => AwsQueryErrorCode::Network,
+            _ => AwsQueryErrorCode::Unknown,
+        };
+        AwsQueryError::new(code, operation, detail)
+    }
+}
+
+/// 요청 입력값을 만들지 못한 경우 (AWS 호출 전 실패)
+impl QueryFailure for BuildError {
+    fn query_error(&self, operation: &str) -> AwsQueryError {
+        AwsQueryError::new(AwsQueryErrorCode::Unknown, operation, self.to_string())
+    }
+}
+
+/// 테스트용 페이지 스트림의 오류 (문자열을 서비스 오류 코드로 취급)
+#[cfg(test)]
+impl QueryFailure for &'static str {
+    fn query_error(&self, operation: &str) -> AwsQueryError {
+        AwsQueryError::new(AwsQueryErrorCode::from_service_code(self), operation, *self)
+    }
+}
+
+fn non_empty(values: &[String]) -> Option<Vec<String>> {
+    (!values.is_empty()).then(|| values.to_vec())
+}
+
+fn ec2_ids(filter: &Ec2Filter) -> Option<Vec<String>> {
+    non_empty(&filter.ids)
+}
+
+fn ec2_vpc_filter(filter: &Ec2Filter, name: &str) -> Option<Vec<aws_sdk_ec2::types::Filter>> {
+    filter.vpc_id.as_ref().map(|vpc_id| {
+        vec![
+            aws_sdk_ec2::types::Filter::builder()
+                .name(name)
+                .values(vpc_id)
+                .build(),
+        ]
+    })
+}
+
+impl AwsProvider for SdkProvider {
+    fn describe_regions(&self) -> Vec<aws_sdk_ec2::types::Region> {
+        call("ec2:DescribeRegions", async |clients| {
+            let output = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_regions()
+                .send()
+                .await;
+            ok_or_log("ec2:DescribeRegions", output)
+                .map(|output| output.regions().to_vec())
+                .unwrap_or_default()
+        })
+    }
+
+    fn describe_instances(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Instance> {
+        call("ec2:DescribeInstances", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_instances()
+                .set_instance_ids(ec2_ids(filter))
+                .set_filters(ec2_vpc_filter(filter, "vpc-id"))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeInstances",
+                async || pages.next().await,
+                |page| {
+                    page.reservations()
+                        .iter()
+                        .flat_map(|reservation| reservation.instances())
+                        .cloned()
+                        .collect()
+                },
+            )
+            .await
+        })
+    }
+
+    fn describe_volumes(&self, volume_ids: &[String]) -> Vec<aws_sdk_ec2::types::Volume> {
+        call("ec2:DescribeVolumes", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_volumes()
+                .set_volume_ids(non_empty(volume_ids))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeVolumes",
+                async || pages.next().await,
+                |page| page.volumes().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_instance_user_data(&self, instance_id: &str) -> Option<String> {
+        call("ec2:DescribeInstanceAttribute", async |clients| {
+            let output = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_instance_attribute()
+                .instance_id(instance_id)
+                .attribute(aws_sdk_ec2::types::InstanceAttributeName::UserData)
+                .send()
+                .await;
+            ok_or_log("ec2:DescribeInstanceAttribute", output)?
+                .user_data()
+                .and_then(|user_data| user_data.value())
+                .map(|value| value.to_string())
+        })
+    }
+
+    fn describe_images(&self, image_ids: &[String]) -> Vec<aws_sdk_ec2::types::Image> {
+        call("ec2:DescribeImages", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_images()
+                .set_image_ids(non_empty(image_ids))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeImages",
+                async || pages.next().await,
+                |page| page.images().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_vpcs(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Vpc> {
+        call("ec2:DescribeVpcs", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_vpcs()
+                .set_vpc_ids(ec2_ids(filter))
+                .set_filters(ec2_vpc_filter(filter, "vpc-id"))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeVpcs",
+                async || pages.next().await,
+                |page| page.vpcs().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_vpc_attribute(
+        &self,
+        vpc_id: &str,
+        attribute: aws_sdk_ec2::types::VpcAttributeName,
+    ) -> Option<bool> {
+        call("ec2:DescribeVpcAttribute", async |clients| {
+            let output = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_vpc_attribute()
+                .vpc_id(vpc_id)
+                .attribute(attribute.clone())
+                .send()
+                .await;
+            let output = ok_or_log("ec2:DescribeVpcAttribute", output)?;
+            let value = match attribute {
+                aws_sdk_ec2::types::VpcAttributeName::EnableDnsSupport => {
+                    output.enable_dns_support()
+                }
+                aws_sdk_ec2::types::VpcAttributeName::EnableDnsHostnames => {
+                    output.enable_dns_hostnames()
+                }
+                _ => None,
+            };
+            Some(value.and_then(|v| v.value()).unwrap_or(false))
+        })
+    }
+
+    fn describe_subnets(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::Subnet> {
+        call("ec2:DescribeSubnets", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_subnets()
+                .set_subnet_ids(ec2_ids(filter))
+                .set_filters(ec2_vpc_filter(filter, "vpc-id"))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeSubnets",
+                async || pages.next().await,
+                |page| page.subnets().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_internet_gateways(
+        &self,
+        filter: &Ec2Filter,
+    ) -> Vec<aws_sdk_ec2::types::InternetGateway> {
+        call("ec2:DescribeInternetGateways", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_internet_gateways()
+                .set_internet_gateway_ids(ec2_ids(filter))
+                .set_filters(ec2_vpc_filter(filter, "attachment.vpc-id"))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeInternetGateways",
+                async || pages.next().await,
+                |page| page.internet_gateways().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_nat_gateways(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::NatGateway> {
+        call("ec2:DescribeNatGateways", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_nat_gateways()
+                .set_nat_gateway_ids(ec2_ids(filter))
+                .set_filter(ec2_vpc_filter(filter, "vpc-id"))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeNatGateways",
+                async || pages.next().await,
+                |page| page.nat_gateways().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_route_tables(&self, filter: &Ec2Filter) -> Vec<aws_sdk_ec2::types::RouteTable> {
+        call("ec2:DescribeRouteTables", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_route_tables()
+                .set_route_table_ids(ec2_ids(filter))
+                .set_filters(ec2_vpc_filter(filter, "vpc-id"))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeRouteTables",
+                async || pages.next().await,
+                |page| page.route_tables().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_addresses(&self) -> Vec<aws_sdk_ec2::types::Address> {
+        call("ec2:DescribeAddresses", async |clients| {
+            let output = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_addresses()
+                .send()
+                .await;
+            ok_or_log("ec2:DescribeAddresses", output)
+                .map(|output| output.addresses().to_vec())
+                .unwrap_or_default()
+        })
+    }
+
+    fn describe_security_groups(
+        &self,
+        filter: &Ec2Filter,
+    ) -> Vec<aws_sdk_ec2::types::SecurityGroup> {
+        call("ec2:DescribeSecurityGroups", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ec2::Client::new)
+                .describe_security_groups()
+                .set_group_ids(ec2_ids(filter))
+                .set_filters(ec2_vpc_filter(filter, "vpc-id"))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ec2:DescribeSecurityGroups",
+                async || pages.next().await,
+                |page| page.security_groups().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_load_balancers(
+        &self,
+        arns: &[String],
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::LoadBalancer> {
+        call("elbv2:DescribeLoadBalancers", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_elasticloadbalancingv2::Client::new)
+                .describe_load_balancers()
+                .set_load_balancer_arns(non_empty(arns))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "elbv2:DescribeLoadBalancers",
+                async || pages.next().await,
+                |page| page.load_balancers().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_listeners(
+        &self,
+        load_balancer_arn: &str,
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::Listener> {
+        call("elbv2:DescribeListeners", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_elasticloadbalancingv2::Client::new)
+                .describe_listeners()
+                .load_balancer_arn(load_balancer_arn)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "elbv2:DescribeListeners",
+                async || pages.next().await,
+                |page| page.listeners().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_target_groups(
+        &self,
+        arns: &[String],
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::TargetGroup> {
+        call("elbv2:DescribeTargetGroups", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_elasticloadbalancingv2::Client::new)
+                .describe_target_groups()
+                .set_target_group_arns(non_empty(arns))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "elbv2:DescribeTargetGroups",
+                async || pages.next().await,
+                |page| page.target_groups().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_target_health(
+        &self,
+        target_group_arn: &str,
+    ) -> Vec<aws_sdk_elasticloadbalancingv2::types::TargetHealthDescription> {
+        call("elbv2:DescribeTargetHealth", async |clients| {
+            let output = clients
+                .get(aws_sdk_elasticloadbalancingv2::Client::new)
+                .describe_target_health()
+                .target_group_arn(target_group_arn)
+                .send()
+                .await;
+            ok_or_log("elbv2:DescribeTargetHealth", output)
+                .map(|output| output.target_health_descriptions().to_vec())
+                .unwrap_or_default()
+        })
+    }
+
+    fn get_role(&self, role_name: &str) -> Option<aws_sdk_iam::types::Role> {
+        call("iam:GetRole", async |clients| {
+            let output = clients
+                .get(aws_sdk_iam::Client::new)
+                .get_role()
+                .role_name(role_name)
+                .send()
+                .await;
+            ok_or_log("iam:GetRole", output)?.role().cloned()
+        })
+    }
+
+    fn list_attached_role_policies(
+        &self,
+        role_name: &str,
+    ) -> Vec<aws_sdk_iam::types::AttachedPolicy> {
+        call("iam:ListAttachedRolePolicies", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_iam::Client::new)
+                .list_attached_role_policies()
+                .role_name(role_name)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "iam:ListAttachedRolePolicies",
+                async || pages.next().await,
+                |page| page.attached_policies().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn list_role_policies(&self, role_name: &str) -> Vec<String> {
+        call("iam:ListRolePolicies", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_iam::Client::new)
+                .list_role_policies()
+                .role_name(role_name)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "iam:ListRolePolicies",
+                async || pages.next().await,
+                |page| page.policy_names().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_role_policy(&self, role_name: &str, policy_name: &str) -> Option<String> {
+        call("iam:GetRolePolicy", async |clients| {
+            let output = clients
+                .get(aws_sdk_iam::Client::new)
+                .get_role_policy()
+                .role_name(role_name)
+                .policy_name(policy_name)
+                .send()
+                .await;
+            ok_or_log("iam:GetRolePolicy", output)
+                .map(|output| output.policy_document().to_string())
+        })
+    }
+
+    fn list_account_aliases(&self) -> Vec<String> {
+        call("iam:ListAccountAliases", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_iam::Client::new)
+                .list_account_aliases()
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "iam:ListAccountAliases",
+                async || pages.next().await,
+                |page| page.account_aliases().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_caller_identity(&self) -> Option<GetCallerIdentityOutput> {
+        call("sts:GetCallerIdentity", async |clients| {
+            let output = clients
+                .get(aws_sdk_sts::Client::new)
+                .get_caller_identity()
+                .send()
+                .await;
+            ok_or_log("sts:GetCallerIdentity", output)
+        })
+    }
+
+    fn list_roots(&self) -> Vec<aws_sdk_organizations::types::Root> {
+        call("organizations:ListRoots", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_organizations::Client::new)
+                .list_roots()
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "organizations:ListRoots",
+                async || pages.next().await,
+                |page| page.roots().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn list_organizational_units_for_parent(
+        &self,
+        parent_id: &str,
+    ) -> Vec<aws_sdk_organizations::types::OrganizationalUnit> {
+        call(
+            "organizations:ListOrganizationalUnitsForParent",
+            async |clients| {
+                let mut pages = clients
+                    .get(aws_sdk_organizations::Client::new)
+                    .list_organizational_units_for_parent()
+                    .parent_id(parent_id)
+                    .into_paginator()
+                    .send();
+                self.collect_pages(
+                    "organizations:ListOrganizationalUnitsForParent",
+                    async || pages.next().await,
+                    |page| page.organizational_units().to_vec(),
+                )
+                .await
+            },
+        )
+    }
+
+    fn list_accounts_for_parent(
+        &self,
+        parent_id: &str,
+    ) -> Vec<aws_sdk_organizations::types::Account> {
+        call("organizations:ListAccountsForParent", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_organizations::Client::new)
+                .list_accounts_for_parent()
+                .parent_id(parent_id)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "organizations:ListAccountsForParent",
+                async || pages.next().await,
+                |page| page.accounts().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_repositories(&self, names: &[String]) -> Vec<aws_sdk_ecr::types::Repository> {
+        call("ecr:DescribeRepositories", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ecr::Client::new)
+                .describe_repositories()
+                .set_repository_names(non_empty(names))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ecr:DescribeRepositories",
+                async || pages.next().await,
+                |page| page.repositories().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_ecr_images(&self, repository_name: &str) -> Vec<aws_sdk_ecr::types::ImageDetail> {
+        call("ecr:DescribeImages", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_ecr::Client::new)
+                .describe_images()
+                .repository_name(repository_name)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "ecr:DescribeImages",
+                async || pages.next().await,
+                |page| page.image_details().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_auto_scaling_groups(
+        &self,
+        names: &[String],
+    ) -> Vec<aws_sdk_autoscaling::types::AutoScalingGroup> {
+        call("autoscaling:DescribeAutoScalingGroups", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_autoscaling::Client::new)
+                .describe_auto_scaling_groups()
+                .set_auto_scaling_group_names(non_empty(names))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "autoscaling:DescribeAutoScalingGroups",
+                async || pages.next().await,
+                |page| page.auto_scaling_groups().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_policies(&self, asg_name: &str) -> Vec<aws_sdk_autoscaling::types::ScalingPolicy> {
+        call("autoscaling:DescribePolicies", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_autoscaling::Client::new)
+                .describe_policies()
+                .auto_scaling_group_name(asg_name)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "autoscaling:DescribePolicies",
+                async || pages.next().await,
+                |page| page.scaling_policies().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_rest_apis(&self) -> Vec<aws_sdk_apigateway::types::RestApi> {
+        call("apigateway:GetRestApis", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_apigateway::Client::new)
+                .get_rest_apis()
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "apigateway:GetRestApis",
+                async || pages.next().await,
+                |page| page.items().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_rest_api(&self, api_id: &str) -> Option<GetRestApiOutput> {
+        call("apigateway:GetRestApi", async |clients| {
+            let output = clients
+                .get(aws_sdk_apigateway::Client::new)
+                .get_rest_api()
+                .rest_api_id(api_id)
+                .send()
+                .await;
+            ok_or_log("apigateway:GetRestApi", output)
+        })
+    }
+
+    fn get_rest_stages(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Stage> {
+        call("apigateway:GetStages", async |clients| {
+            let output = clients
+                .get(aws_sdk_apigateway::Client::new)
+                .get_stages()
+                .rest_api_id(api_id)
+                .send()
+                .await;
+            ok_or_log("apigateway:GetStages", output)
+                .map(|output| output.item().to_vec())
+                .unwrap_or_default()
+        })
+    }
+
+    fn get_rest_resources(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Resource> {
+        call("apigateway:GetResources", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_apigateway::Client::new)
+                .get_resources()
+                .rest_api_id(api_id)
+                .embed("methods")
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "apigateway:GetResources",
+                async || pages.next().await,
+                |page| page.items().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_rest_authorizers(&self, api_id: &str) -> Vec<aws_sdk_apigateway::types::Authorizer> {
+        call("apigateway:GetAuthorizers", async |clients| {
+            let client = clients.get(aws_sdk_apigateway::Client::new);
+            let pages = token_pages!(
+                client.get_authorizers().rest_api_id(api_id),
+                set_position,
+                position
+            );
+            self.collect_pages("apigateway:GetAuthorizers", pages, |page| {
+                page.items().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn get_rest_domain_names(&self) -> Vec<aws_sdk_apigateway::types::DomainName> {
+        call("apigateway:GetDomainNames", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_apigateway::Client::new)
+                .get_domain_names()
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "apigateway:GetDomainNames",
+                async || pages.next().await,
+                |page| page.items().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_base_path_mappings(
+        &self,
+        domain_name: &str,
+    ) -> Vec<aws_sdk_apigateway::types::BasePathMapping> {
+        call("apigateway:GetBasePathMappings", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_apigateway::Client::new)
+                .get_base_path_mappings()
+                .domain_name(domain_name)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "apigateway:GetBasePathMappings",
+                async || pages.next().await,
+                |page| page.items().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_http_apis(&self) -> Vec<aws_sdk_apigatewayv2::types::Api> {
+        call("apigatewayv2:GetApis", async |clients| {
+            let client = clients.get(aws_sdk_apigatewayv2::Client::new);
+            let pages = token_pages!(client.get_apis(), set_next_token, next_token);
+            self.collect_pages("apigatewayv2:GetApis", pages, |page| page.items().to_vec())
+                .await
+        })
+    }
+
+    fn get_http_api(&self, api_id: &str) -> Option<GetApiOutput> {
+        call("apigatewayv2:GetApi", async |clients| {
+            // REST API ID로도 먼저 조회하므로 실패는 디버그 로그만 남김
+            match clients
+                .get(aws_sdk_apigatewayv2::Client::new)
+                .get_api()
+                .api_id(api_id)
+                .send()
+                .await
+            {
+                Ok(output) => Some(output),
+                Err(e) => {
+                    tracing::debug!(api_id, "HTTP API not found: {:?}", e);
+                    None
+                }
+            }
+        })
+    }
+
+    fn get_http_stages(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Stage> {
+        call("apigatewayv2:GetStages", async |clients| {
+            let client = clients.get(aws_sdk_apigatewayv2::Client::new);
+            let pages = token_pages!(
+                client.get_stages().api_id(api_id),
+                set_next_token,
+                next_token
+            );
+            self.collect_pages("apigatewayv2:GetStages", pages, |page| {
+                page.items().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn get_http_routes(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Route> {
+        call("apigatewayv2:GetRoutes", async |clients| {
+            let client = clients.get(aws_sdk_apigatewayv2::Client::new);
+            let pages = token_pages!(
+                client.get_routes().api_id(api_id),
+                set_next_token,
+                next_token
+            );
+            self.collect_pages("apigatewayv2:GetRoutes", pages, |page| {
+                page.items().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn get_http_integrations(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Integration> {
+        call("apigatewayv2:GetIntegrations", async |clients| {
+            let client = clients.get(aws_sdk_apigatewayv2::Client::new);
+            let pages = token_pages!(
+                client.get_integrations().api_id(api_id),
+                set_next_token,
+                next_token
+            );
+            self.collect_pages("apigatewayv2:GetIntegrations", pages, |page| {
+                page.items().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn get_http_authorizers(&self, api_id: &str) -> Vec<aws_sdk_apigatewayv2::types::Authorizer> {
+        call("apigatewayv2:GetAuthorizers", async |clients| {
+            let client = clients.get(aws_sdk_apigatewayv2::Client::new);
+            let pages = token_pages!(
+                client.get_authorizers().api_id(api_id),
+                set_next_token,
+                next_token
+            );
+            self.collect_pages("apigatewayv2:GetAuthorizers", pages, |page| {
+                page.items().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn get_http_domain_names(&self) -> Vec<aws_sdk_apigatewayv2::types::DomainName> {
+        call("apigatewayv2:GetDomainNames", async |clients| {
+            let client = clients.get(aws_sdk_apigatewayv2::Client::new);
+            let pages = token_pages!(client.get_domain_names(), set_next_token, next_token);
+            self.collect_pages("apigatewayv2:GetDomainNames", pages, |page| {
+                page.items().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn get_api_mappings(&self, domain_name: &str) -> Vec<aws_sdk_apigatewayv2::types::ApiMapping> {
+        call("apigatewayv2:GetApiMappings", async |clients| {
+            let client = clients.get(aws_sdk_apigatewayv2::Client::new);
+            let pages = token_pages!(
+                client.get_api_mappings().domain_name(domain_name),
+                set_next_token,
+                next_token
+            );
+            self.collect_pages("apigatewayv2:GetApiMappings", pages, |page| {
+                page.items().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn list_keys(&self) -> Vec<aws_sdk_kms::types::KeyListEntry> {
+        call("kms:ListKeys", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_kms::Client::new)
+                .list_keys()
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "kms:ListKeys",
+                async || pages.next().await,
+                |page| page.keys().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_key(&self, key_id: &str) -> Option<aws_sdk_kms::types::KeyMetadata> {
+        call("kms:DescribeKey", async |clients| {
+            let output = clients
+                .get(aws_sdk_kms::Client::new)
+                .describe_key()
+                .key_id(key_id)
+                .send()
+                .await;
+            ok_or_log("kms:DescribeKey", output)?
+                .key_metadata()
+                .cloned()
+        })
+    }
+
+    fn list_aliases(&self, key_id: Option<&str>) -> Vec<aws_sdk_kms::types::AliasListEntry> {
+        call("kms:ListAliases", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_kms::Client::new)
+                .list_aliases()
+                .set_key_id(key_id.map(|s| s.to_string()))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "kms:ListAliases",
+                async || pages.next().await,
+                |page| page.aliases().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_key_rotation_status(&self, key_id: &str) -> Option<GetKeyRotationStatusOutput> {
+        call("kms:GetKeyRotationStatus", async |clients| {
+            // 비대칭/HMAC 키 등 자동 교체를 지원하지 않는 키는 에러를 반환
+            clients
+                .get(aws_sdk_kms::Client::new)
+                .get_key_rotation_status()
+                .key_id(key_id)
+                .send()
+                .await
+                .ok()
+        })
+    }
+
+    fn get_key_policy(&self, key_id: &str) -> Option<String> {
+        call("kms:GetKeyPolicy", async |clients| {
+            let output = clients
+                .get(aws_sdk_kms::Client::new)
+                .get_key_policy()
+                .key_id(key_id)
+                .policy_name("default")
+                .send()
+                .await;
+            ok_or_log("kms:GetKeyPolicy", output)?
+                .policy()
+                .map(|s| s.to_string())
+        })
+    }
+
+    fn list_grants(&self, key_id: &str) -> Vec<aws_sdk_kms::types::GrantListEntry> {
+        call("kms:ListGrants", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_kms::Client::new)
+                .list_grants()
+                .key_id(key_id)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "kms:ListGrants",
+                async || pages.next().await,
+                |page| page.grants().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn list_resource_tags(&self, key_id: &str) -> Vec<aws_sdk_kms::types::Tag> {
+        call("kms:ListResourceTags", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_kms::Client::new)
+                .list_resource_tags()
+                .key_id(key_id)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "kms:ListResourceTags",
+                async || pages.next().await,
+                |page| page.tags().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn list_secrets(&self) -> Vec<aws_sdk_secretsmanager::types::SecretListEntry> {
+        call("secretsmanager:ListSecrets", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_secretsmanager::Client::new)
+                .list_secrets()
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "secretsmanager:ListSecrets",
+                async || pages.next().await,
+                |page| page.secret_list().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_secret(&self, secret_id: &str) -> Option<DescribeSecretOutput> {
+        call("secretsmanager:DescribeSecret", async |clients| {
+            let output = clients
+                .get(aws_sdk_secretsmanager::Client::new)
+                .describe_secret()
+                .secret_id(secret_id)
+                .send()
+                .await;
+            ok_or_log("secretsmanager:DescribeSecret", output)
+        })
+    }
+
+    fn describe_parameters(
+        &self,
+        name: Option<&str>,
+    ) -> Vec<aws_sdk_ssm::types::ParameterMetadata> {
+        call("ssm:DescribeParameters", async |clients| {
+            let filter = match name {
+                Some(name) => {
+                    let filter = aws_sdk_ssm::types::ParameterStringFilter::builder()
+                        .key("Name")
+                        .option("Equals")
+                        .values(name)
+                        .build();
+                    Some(vec![ok_or_log("ssm:DescribeParameters", filter)?])
+                }
+                None => None,
+            };
+            let mut pages = clients
+                .get(aws_sdk_ssm::Client::new)
+                .describe_parameters()
+                .set_parameter_filters(filter)
+                .into_paginator()
+                .send();
+            let parameters = self
+                .collect_pages(
+                    "ssm:DescribeParameters",
+                    async || pages.next().await,
+                    |page| page.parameters().to_vec(),
+                )
+                .await;
+            Some(parameters)
+        })
+        .unwrap_or_default()
+    }
+
+    fn list_parameter_tags(&self, name: &str) -> Vec<aws_sdk_ssm::types::Tag> {
+        call("ssm:ListTagsForResource", async |clients| {
+            let output = clients
+                .get(aws_sdk_ssm::Client::new)
+                .list_tags_for_resource()
+                .resource_type(aws_sdk_ssm::types::ResourceTypeForTagging::Parameter)
+                .resource_id(name)
+                .send()
+                .await;
+            ok_or_log("ssm:ListTagsForResource", output)
+                .map(|output| output.tag_list().to_vec())
+                .unwrap_or_default()
+        })
+    }
+
+    fn describe_file_systems(
+        &self,
+        file_system_id: Option<&str>,
+    ) -> Vec<aws_sdk_efs::types::FileSystemDescription> {
+        call("efs:DescribeFileSystems", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_efs::Client::new)
+                .describe_file_systems()
+                .set_file_system_id(file_system_id.map(|s| s.to_string()))
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "efs:DescribeFileSystems",
+                async || pages.next().await,
+                |page| page.file_systems().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_lifecycle_configuration(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::LifecyclePolicy> {
+        call("efs:DescribeLifecycleConfiguration", async |clients| {
+            let output = clients
+                .get(aws_sdk_efs::Client::new)
+                .describe_lifecycle_configuration()
+                .file_system_id(file_system_id)
+                .send()
+                .await;
+            ok_or_log("efs:DescribeLifecycleConfiguration", output)
+                .map(|output| output.lifecycle_policies().to_vec())
+                .unwrap_or_default()
+        })
+    }
+
+    fn describe_backup_policy(
+        &self,
+        file_system_id: &str,
+    ) -> Option<aws_sdk_efs::types::BackupPolicy> {
+        call("efs:DescribeBackupPolicy", async |clients| {
+            match clients
+                .get(aws_sdk_efs::Client::new)
+                .describe_backup_policy()
+                .file_system_id(file_system_id)
+                .send()
+                .await
+            {
+                Ok(output) => output.backup_policy().cloned(),
+                // 백업 정책이 한 번도 설정되지 않은 파일 시스템은 PolicyNotFound를 반환
+                Err(e)
+                    if e.as_service_error()
+                        .is_some_and(|se| se.is_policy_not_found()) =>
+                {
+                    aws_sdk_efs::types::BackupPolicy::builder()
+                        .status(aws_sdk_efs::types::Status::Disabled)
+                        .build()
+                        .ok()
+                }
+                Err(e) => ok_or_log("efs:DescribeBackupPolicy", Err(e)),
+            }
+        })
+    }
+
+    fn describe_access_points(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::AccessPointDescription> {
+        call("efs:DescribeAccessPoints", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_efs::Client::new)
+                .describe_access_points()
+                .file_system_id(file_system_id)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "efs:DescribeAccessPoints",
+                async || pages.next().await,
+                |page| page.access_points().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_mount_targets(
+        &self,
+        file_system_id: &str,
+    ) -> Vec<aws_sdk_efs::types::MountTargetDescription> {
+        call("efs:DescribeMountTargets", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_efs::Client::new)
+                .describe_mount_targets()
+                .file_system_id(file_system_id)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "efs:DescribeMountTargets",
+                async || pages.next().await,
+                |page| page.mount_targets().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_mount_target_security_groups(&self, mount_target_id: &str) -> Vec<String> {
+        call("efs:DescribeMountTargetSecurityGroups", async |clients| {
+            let output = clients
+                .get(aws_sdk_efs::Client::new)
+                .describe_mount_target_security_groups()
+                .mount_target_id(mount_target_id)
+                .send()
+                .await;
+            ok_or_log("efs:DescribeMountTargetSecurityGroups", output)
+                .map(|output| output.security_groups().to_vec())
+                .unwrap_or_default()
+        })
+    }
+
+    fn describe_metric_alarms(
+        &self,
+        names: &[String],
+    ) -> Vec<aws_sdk_cloudwatch::types::MetricAlarm> {
+        call("cloudwatch:DescribeAlarms", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_cloudwatch::Client::new)
+                .describe_alarms()
+                .set_alarm_names(non_empty(names))
+                .alarm_types(aws_sdk_cloudwatch::types::AlarmType::MetricAlarm)
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "cloudwatch:DescribeAlarms",
+                async || pages.next().await,
+                |page| page.metric_alarms().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn list_event_buses(&self) -> Vec<aws_sdk_eventbridge::types::EventBus> {
+        call("events:ListEventBuses", async |clients| {
+            let client = clients.get(aws_sdk_eventbridge::Client::new);
+            let pages = token_pages!(client.list_event_buses(), set_next_token, next_token);
+            self.collect_pages("events:ListEventBuses", pages, |page| {
+                page.event_buses().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn list_rules(&self, event_bus: &str) -> Vec<aws_sdk_eventbridge::types::Rule> {
+        call("events:ListRules", async |clients| {
+            let client = clients.get(aws_sdk_eventbridge::Client::new);
+            let pages = token_pages!(
+                client.list_rules().event_bus_name(event_bus),
+                set_next_token,
+                next_token
+            );
+            self.collect_pages("events:ListRules", pages, |page| page.rules().to_vec())
+                .await
+        })
+    }
+
+    fn describe_rule(&self, event_bus: &str, name: &str) -> Option<DescribeRuleOutput> {
+        call("events:DescribeRule", async |clients| {
+            let output = clients
+                .get(aws_sdk_eventbridge::Client::new)
+                .describe_rule()
+                .name(name)
+                .event_bus_name(event_bus)
+                .send()
+                .await;
+            ok_or_log("events:DescribeRule", output)
+        })
+    }
+
+    fn list_targets_by_rule(
+        &self,
+        event_bus: &str,
+        rule: &str,
+    ) -> Vec<aws_sdk_eventbridge::types::Target> {
+        call("events:ListTargetsByRule", async |clients| {
+            let client = clients.get(aws_sdk_eventbridge::Client::new);
+            let pages = token_pages!(
+                client
+                    .list_targets_by_rule()
+                    .rule(rule)
+                    .event_bus_name(event_bus),
+                set_next_token,
+                next_token
+            );
+            self.collect_pages("events:ListTargetsByRule", pages, |page| {
+                page.targets().to_vec()
+            })
+            .await
+        })
+    }
+
+    fn list_state_machines(&self) -> Vec<aws_sdk_sfn::types::StateMachineListItem> {
+        call("states:ListStateMachines", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_sfn::Client::new)
+                .list_state_machines()
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "states:ListStateMachines",
+                async || pages.next().await,
+                |page| page.state_machines().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn describe_state_machine(&self, arn: &str) -> Option<DescribeStateMachineOutput> {
+        call("states:DescribeStateMachine", async |clients| {
+            let output = clients
+                .get(aws_sdk_sfn::Client::new)
+                .describe_state_machine()
+                .state_machine_arn(arn)
+                .send()
+                .await;
+            ok_or_log("states:DescribeStateMachine", output)
+        })
+    }
+
+    fn list_functions(&self) -> Vec<aws_sdk_lambda::types::FunctionConfiguration> {
+        call("lambda:ListFunctions", async |clients| {
+            let mut pages = clients
+                .get(aws_sdk_lambda::Client::new)
+                .list_functions()
+                .into_paginator()
+                .send();
+            self.collect_pages(
+                "lambda:ListFunctions",
+                async || pages.next().await,
+                |page| page.functions().to_vec(),
+            )
+            .await
+        })
+    }
+
+    fn get_function_configuration(&self, function: &str) -> Option<GetFunctionConfigurationOutput> {
+        call("lambda:GetFunctionConfiguration", async |clients| {
+            let output = clients
+                .get(aws_sdk_lambda::Client::new)
+                .get_function_configuration()
+                .function_name(function)
+                .send()
+                .await;
+            ok_or_log("lambda:GetFunctionConfiguration", output)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws_cli::common::capture_partial_failures;
+    use std::collections::VecDeque;
+
+    /// 미리 정한 페이지를 차례로 돌려주는 가짜 페이지 스트림
+    fn fake_pages(
+        pages: Vec<Result<Vec<u32>, &'static str>>,
+    ) -> impl AsyncFnMut() -> Option<Result<Vec<u32>, &'static str>> {
+        let mut pages = VecDeque::from(pages);
+        async move || pages.pop_front()
+    }
+
+    #[test]
+    fn drain_pages_collects_every_page() {
+        let pages = fake_pages(vec![Ok(vec![1, 2]), Ok(vec![3]), Ok(vec![4, 5])]);
+        let (items, failure) = get_runtime().block_on(drain_pages("test:List", pages, |page| page));
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(failure, None);
+    }
+
+    #[test]
+    fn drain_pages_returns_partial_results_when_a_later_page_fails() {
+        let pages = fake_pages(vec![
+            Ok(vec![1, 2]),
+            Ok(vec![3]),
+            Err("throttled"),
+            Ok(vec![4]),
+        ]);
+        let (items, failure) = get_runtime().block_on(drain_pages("test:List", pages, |page| page));
+        assert_eq!(items, vec![1, 2, 3]);
+        let failure = failure.expect("partial failure");
+        assert_eq!(failure.operation, "test:List");
+        assert_eq!(failure.items, 3);
+        assert!(failure.error.contains("throttled"));
+    }
+
+    #[test]
+    fn drain_pages_first_page_failure_is_not_partial() {
+        let pages = fake_pages(vec![Err("AccessDeniedException"), Ok(vec![1])]);
+        let ((items, failure), errors) = crate::aws_cli::capture_query_errors(|| {
+            get_runtime().block_on(drain_pages("test:List", pages, |page| page))
+        });
+        assert!(items.is_empty());
+        assert_eq!(failure, None);
+        // 첫 페이지 실패는 원인과 함께 조회 실패로 기록
+        assert_eq!(
+            errors,
+            vec![AwsQueryError::new(
+                AwsQueryErrorCode::AccessDenied,
+                "test:List",
+                "AccessDeniedException"
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_pages_records_partial_failures_in_the_current_scope() {
+        let provider = SdkProvider;
+        let pages = fake_pages(vec![Ok(vec![1]), Err("timeout")]);
+        let (items, failures) = capture_partial_failures(|| {
+            get_runtime().block_on(provider.collect_pages("test:List", pages, |page| page))
+        });
+        assert_eq!(items, vec![1]);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].items, 1);
+
+        // 범위 밖에서 잘린 결과는 남지 않음
+        let pages = fake_pages(vec![Ok(vec![1]), Err("timeout")]);
+        get_runtime().block_on(provider.collect_pages("test:List", pages, |page| page));
+        let ((), failures) = capture_partial_failures(|| ());
+        assert!(failures.is_empty());
+    }
+}
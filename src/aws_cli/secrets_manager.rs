@@ -0,0 +1,168 @@
+pub use crate::aws_cli::secrets_manager_sdk::{get_secret_detail, list_secrets};
+use crate::i18n::{I18n, Language};
+
+/// Secrets Manager 시크릿 메타데이터 (시크릿 값은 조회하지 않음)
+#[derive(Debug, Clone)]
+pub struct SecretDetail {
+    pub name: String,
+    pub arn: String,
+    pub description: String,
+    pub kms_key_id: Option<String>,
+    pub rotation_enabled: bool,
+    pub rotation_lambda_arn: Option<String>,
+    pub rotation_after_days: Option<i64>,
+    pub rotation_schedule: Option<String>,
+    pub last_rotated_date: String,
+    pub next_rotation_date: String,
+    pub last_changed_date: String,
+    pub last_accessed_date: String,
+    pub created_date: String,
+    pub primary_region: Option<String>,
+    pub owning_service: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl SecretDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.secret(), self.name),
+            format!("> {}\n", i18n.md_value_not_collected()),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+        ];
+
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        // KmsKeyId가 없으면 AWS 관리형 키(aws/secretsmanager) 사용
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_kms_key(),
+            self.kms_key_id.as_deref().unwrap_or("aws/secretsmanager")
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_rotation(),
+            if self.rotation_enabled {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        ));
+
+        if self.rotation_enabled {
+            let schedule = match (&self.rotation_schedule, self.rotation_after_days) {
+                (Some(expr), _) => expr.clone(),
+                (None, Some(days)) => i18n.md_days(days),
+                (None, None) => "-".to_string(),
+            };
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_rotation_schedule(),
+                schedule
+            ));
+            if let Some(ref lambda) = self.rotation_lambda_arn {
+                lines.push(format!("| {} | {} |", i18n.md_rotation_lambda(), lambda));
+            }
+        }
+
+        for (label, date) in [
+            (i18n.md_last_rotated(), &self.last_rotated_date),
+            (i18n.md_next_rotation(), &self.next_rotation_date),
+            (i18n.md_last_changed(), &self.last_changed_date),
+            (i18n.md_last_accessed(), &self.last_accessed_date),
+            (i18n.md_created_at(), &self.created_date),
+        ] {
+            if !date.is_empty() {
+                lines.push(format!("| {} | {} |", label, date));
+            }
+        }
+
+        if let Some(ref region) = self.primary_region {
+            lines.push(format!("| {} | {} |", i18n.md_primary_region(), region));
+        }
+        if let Some(ref service) = self.owning_service {
+            lines.push(format!("| {} | {} |", i18n.md_owning_service(), service));
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_tags()));
+            lines.push(format!("| {} | {} |", i18n.md_tag_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SecretDetail;
+    use crate::i18n::Language;
+
+    fn sample_secret() -> SecretDetail {
+        SecretDetail {
+            name: "prod/db/password".to_string(),
+            arn:
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/db/password-AbCdEf"
+                    .to_string(),
+            description: "RDS master credentials".to_string(),
+            kms_key_id: Some("alias/app-data".to_string()),
+            rotation_enabled: true,
+            rotation_lambda_arn: Some(
+                "arn:aws:lambda:ap-northeast-2:123456789012:function:rotate-db".to_string(),
+            ),
+            rotation_after_days: Some(30),
+            rotation_schedule: None,
+            last_rotated_date: "2026-09-01".to_string(),
+            next_rotation_date: "2026-10-01".to_string(),
+            last_changed_date: "2026-09-01".to_string(),
+            last_accessed_date: "2026-09-15".to_string(),
+            created_date: "2025-01-01".to_string(),
+            primary_region: None,
+            owning_service: None,
+            tags: vec![("Env".to_string(), "prod".to_string())],
+        }
+    }
+
+    #[test]
+    fn secret_markdown_renders_rotation_and_metadata_only() {
+        let md = sample_secret().to_markdown(Language::English);
+        assert!(md.contains("## Secrets Manager Secret (prod/db/password)"));
+        assert!(md.contains("> Values are never fetched (metadata only)"));
+        assert!(md.contains("| KMS Key | alias/app-data |"));
+        assert!(md.contains("| Rotation | Enabled |"));
+        assert!(md.contains("| Rotation Schedule | 30 days |"));
+        assert!(md.contains("function:rotate-db |"));
+        assert!(md.contains("| Last Changed | 2026-09-01 |"));
+        assert!(md.contains("| Env | prod |"));
+    }
+
+    #[test]
+    fn secret_markdown_defaults_kms_key_and_hides_rotation_details_when_disabled() {
+        let mut secret = sample_secret();
+        secret.kms_key_id = None;
+        secret.rotation_enabled = false;
+        secret.last_accessed_date.clear();
+
+        let md = secret.to_markdown(Language::English);
+        assert!(md.contains("| KMS Key | aws/secretsmanager |"));
+        assert!(md.contains("| Rotation | Disabled |"));
+        assert!(!md.contains("Rotation Schedule"));
+        assert!(!md.contains("Rotation Lambda"));
+        assert!(!md.contains("Last Accessed"));
+    }
+}
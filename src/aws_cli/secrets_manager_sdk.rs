@@ -0,0 +1,127 @@
+use crate::aws_cli::common::{AwsResource, format_date};
+use crate::aws_cli::provider::AwsProvider;
+use crate::aws_cli::secrets_manager::SecretDetail;
+use aws_sdk_secretsmanager::operation::describe_secret::DescribeSecretOutput;
+use aws_sdk_secretsmanager::types::SecretListEntry;
+
+// 메타데이터 API(ListSecrets/DescribeSecret)만 사용하며
+// GetSecretValue/BatchGetSecretValue는 호출하지 않는다.
+
+/// List Secrets Manager secrets (metadata only)
+pub fn list_secrets(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut resources: Vec<AwsResource> = provider
+        .list_secrets()
+        .iter()
+        .map(map_secret_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get secret metadata (DescribeSecret, never the secret value)
+pub fn get_secret_detail(provider: &dyn AwsProvider, secret_id: &str) -> Option<SecretDetail> {
+    provider
+        .describe_secret(secret_id)
+        .map(|output| map_secret_detail(&output))
+}
+
+fn map_secret_resource(secret: &SecretListEntry) -> AwsResource {
+    let rotation = if secret.rotation_enabled().unwrap_or(false) {
+        "Rotation: on"
+    } else {
+        "Rotation: off"
+    };
+    AwsResource {
+        name: secret.name().unwrap_or_default().to_string(),
+        // DescribeSecret은 이름과 ARN 모두 허용하므로 ARN을 식별자로 사용
+        id: secret.arn().unwrap_or_default().to_string(),
+        state: rotation.to_string(),
+        az: String::new(),
+        cidr: format_date(secret.last_changed_date()),
+        region: None,
+    }
+}
+
+fn map_secret_detail(output: &DescribeSecretOutput) -> SecretDetail {
+    let rules = output.rotation_rules();
+    SecretDetail {
+        name: output.name().unwrap_or_default().to_string(),
+        arn: output.arn().unwrap_or_default().to_string(),
+        description: output.description().unwrap_or_default().to_string(),
+        kms_key_id: output.kms_key_id().map(|s| s.to_string()),
+        rotation_enabled: output.rotation_enabled().unwrap_or(false),
+        rotation_lambda_arn: output.rotation_lambda_arn().map(|s| s.to_string()),
+        rotation_after_days: rules.and_then(|r| r.automatically_after_days()),
+        rotation_schedule: rules
+            .and_then(|r| r.schedule_expression())
+            .map(|s| s.to_string()),
+        last_rotated_date: format_date(output.last_rotated_date()),
+        next_rotation_date: format_date(output.next_rotation_date()),
+        last_changed_date: format_date(output.last_changed_date()),
+        last_accessed_date: format_date(output.last_accessed_date()),
+        created_date: format_date(output.created_date()),
+        primary_region: output.primary_region().map(|s| s.to_string()),
+        owning_service: output.owning_service().map(|s| s.to_string()),
+        tags: output
+            .tags()
+            .iter()
+            .filter_map(|tag| {
+                tag.key()
+                    .map(|k| (k.to_string(), tag.value().unwrap_or_default().to_string()))
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_secret_detail, map_secret_resource};
+    use aws_sdk_secretsmanager::operation::describe_secret::DescribeSecretOutput;
+    use aws_sdk_secretsmanager::primitives::DateTime;
+    use aws_sdk_secretsmanager::types::{RotationRulesType, SecretListEntry, Tag};
+
+    #[test]
+    fn map_secret_resource_uses_arn_as_id() {
+        let secret = SecretListEntry::builder()
+            .name("prod/db/password")
+            .arn(
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/db/password-AbCdEf",
+            )
+            .rotation_enabled(true)
+            .last_changed_date(DateTime::from_secs(1_767_225_600))
+            .build();
+        let resource = map_secret_resource(&secret);
+        assert_eq!(resource.name, "prod/db/password");
+        assert!(resource.id.ends_with("password-AbCdEf"));
+        assert_eq!(resource.state, "Rotation: on");
+        assert_eq!(resource.cidr, "2026-01-01");
+
+        let resource = map_secret_resource(&SecretListEntry::builder().name("plain").build());
+        assert_eq!(resource.state, "Rotation: off");
+        assert_eq!(resource.cidr, "");
+    }
+
+    #[test]
+    fn map_secret_detail_reads_rotation_rules_and_tags() {
+        let output = DescribeSecretOutput::builder()
+            .name("prod/db/password")
+            .kms_key_id("alias/app-data")
+            .rotation_enabled(true)
+            .rotation_rules(
+                RotationRulesType::builder()
+                    .schedule_expression("rate(10 days)")
+                    .build(),
+            )
+            .tags(Tag::builder().key("Env").value("prod").build())
+            .tags(Tag::builder().value("orphan").build())
+            .build();
+
+        let detail = map_secret_detail(&output);
+        assert_eq!(detail.kms_key_id.as_deref(), Some("alias/app-data"));
+        assert!(detail.rotation_enabled);
+        assert_eq!(detail.rotation_schedule.as_deref(), Some("rate(10 days)"));
+        assert_eq!(detail.rotation_after_days, None);
+        assert_eq!(detail.tags, vec![("Env".to_string(), "prod".to_string())]);
+        assert_eq!(detail.last_changed_date, "");
+    }
+}
@@ -1,67 +1,7 @@
-use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::common::{AwsResource, ec2_name_tag};
+use crate::aws_cli::provider::{AwsProvider, Ec2Filter};
 use crate::i18n::{I18n, Language};
-use serde::Deserialize;
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct SecurityGroupsResponse {
-    security_groups: Vec<SecurityGroupInfo>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct SecurityGroupInfo {
-    group_id: String,
-    group_name: String,
-    description: String,
-    vpc_id: String,
-    #[serde(default)]
-    ip_permissions: Vec<IpPermission>,
-    #[serde(default)]
-    ip_permissions_egress: Vec<IpPermission>,
-    #[serde(default)]
-    tags: Vec<Tag>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct IpPermission {
-    ip_protocol: String,
-    #[serde(default)]
-    from_port: Option<i32>,
-    #[serde(default)]
-    to_port: Option<i32>,
-    #[serde(default)]
-    ip_ranges: Vec<IpRange>,
-    #[serde(default)]
-    ipv6_ranges: Vec<Ipv6Range>,
-    #[serde(default)]
-    user_id_group_pairs: Vec<UserIdGroupPair>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct IpRange {
-    cidr_ip: String,
-    #[serde(default)]
-    description: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct Ipv6Range {
-    cidr_ipv6: String,
-    #[serde(default)]
-    description: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct UserIdGroupPair {
-    group_id: String,
-    #[serde(default)]
-    description: Option<String>,
-}
+use aws_sdk_ec2::types::{IpPermission, SecurityGroup};
 
 #[derive(Debug)]
 pub struct SecurityGroupDetail {
@@ -138,87 +78,63 @@ impl SecurityGroupDetail {
     }
 }
 
-pub fn list_security_groups() -> Vec<AwsResource> {
-    let output = match run_aws_cli(&["ec2", "describe-security-groups", "--output", "json"]) {
-        Some(o) => o,
-        None => return Vec::new(),
-    };
-
-    parse_security_groups_list_output(&output).unwrap_or_default()
+pub fn list_security_groups(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut groups = provider.describe_security_groups(&Ec2Filter::all());
+    groups.sort_by(|a, b| a.group_id().cmp(&b.group_id()));
+    groups.iter().map(map_security_group_resource).collect()
 }
 
-fn parse_security_groups_list_output(output: &str) -> Option<Vec<AwsResource>> {
-    let response: SecurityGroupsResponse = match serde_json::from_str(output) {
-        Ok(r) => r,
-        Err(_) => return None,
-    };
-
-    Some(
-        response
-            .security_groups
-            .into_iter()
-            .map(|sg| {
-                let name = sg
-                    .tags
-                    .iter()
-                    .find(|t| t.key == "Name")
-                    .map(|t| t.value.clone())
-                    .unwrap_or_else(|| sg.group_name.clone());
-
-                AwsResource {
-                    name: format!("{} ({})", name, sg.group_name),
-                    id: sg.group_id,
-                    state: sg.vpc_id,
-                    az: String::new(),
-                    cidr: String::new(),
-                }
-            })
-            .collect(),
-    )
+fn display_name(sg: &SecurityGroup) -> String {
+    let name = ec2_name_tag(sg.tags());
+    if name.is_empty() {
+        sg.group_name().unwrap_or_default().to_string()
+    } else {
+        name
+    }
 }
 
-pub fn get_security_group_detail(sg_id: &str) -> Option<SecurityGroupDetail> {
-    let output = run_aws_cli(&[
-        "ec2",
-        "describe-security-groups",
-        "--group-ids",
-        sg_id,
-        "--output",
-        "json",
-    ])?;
-
-    parse_security_group_detail_output(&output)
+fn map_security_group_resource(sg: &SecurityGroup) -> AwsResource {
+    AwsResource {
+        name: format!(
+            "{} ({})",
+            display_name(sg),
+            sg.group_name().unwrap_or_default()
+        ),
+        id: sg.group_id().unwrap_or_default().to_string(),
+        state: sg.vpc_id().unwrap_or_default().to_string(),
+        az: String::new(),
+        cidr: String::new(),
+        region: None,
+    }
 }
 
-fn parse_security_group_detail_output(output: &str) -> Option<SecurityGroupDetail> {
-    let response: SecurityGroupsResponse = serde_json::from_str(output).ok()?;
-    let sg = response.security_groups.first()?;
-
-    let name = sg
-        .tags
-        .iter()
-        .find(|t| t.key == "Name")
-        .map(|t| t.value.clone())
-        .unwrap_or_else(|| sg.group_name.clone());
-
-    let inbound_rules = parse_security_rules(&sg.ip_permissions);
-    let outbound_rules = parse_security_rules(&sg.ip_permissions_egress);
+pub fn get_security_group_detail(
+    provider: &dyn AwsProvider,
+    sg_id: &str,
+) -> Option<SecurityGroupDetail> {
+    provider
+        .describe_security_groups(&Ec2Filter::id(sg_id))
+        .first()
+        .map(map_security_group_detail)
+}
 
-    Some(SecurityGroupDetail {
-        name,
-        id: sg.group_id.clone(),
-        description: sg.description.clone(),
-        vpc_id: sg.vpc_id.clone(),
-        inbound_rules,
-        outbound_rules,
-    })
+fn map_security_group_detail(sg: &SecurityGroup) -> SecurityGroupDetail {
+    SecurityGroupDetail {
+        name: display_name(sg),
+        id: sg.group_id().unwrap_or_default().to_string(),
+        description: sg.description().unwrap_or_default().to_string(),
+        vpc_id: sg.vpc_id().unwrap_or_default().to_string(),
+        inbound_rules: parse_security_rules(sg.ip_permissions()),
+        outbound_rules: parse_security_rules(sg.ip_permissions_egress()),
+    }
 }
 
 fn parse_security_rules(permissions: &[IpPermission]) -> Vec<SecurityRule> {
     let mut rules = Vec::new();
 
     for perm in permissions {
-        let protocol = match perm.ip_protocol.as_str() {
+        let ip_protocol = perm.ip_protocol().unwrap_or_default();
+        let protocol = match ip_protocol {
             "-1" => "All".to_string(),
             "tcp" => "TCP".to_string(),
             "udp" => "UDP".to_string(),
@@ -226,9 +142,9 @@ fn parse_security_rules(permissions: &[IpPermission]) -> Vec<SecurityRule> {
             other => other.to_uppercase(),
         };
 
-        let port_range = if perm.ip_protocol == "-1" {
+        let port_range = if ip_protocol == "-1" {
             "All".to_string()
-        } else if let (Some(from), Some(to)) = (perm.from_port, perm.to_port) {
+        } else if let (Some(from), Some(to)) = (perm.from_port(), perm.to_port()) {
             if from == to {
                 from.to_string()
             } else {
@@ -238,40 +154,32 @@ fn parse_security_rules(permissions: &[IpPermission]) -> Vec<SecurityRule> {
             "All".to_string()
         };
 
-        for ip_range in &perm.ip_ranges {
-            rules.push(SecurityRule {
-                protocol: protocol.clone(),
-                port_range: port_range.clone(),
-                source_dest: ip_range.cidr_ip.clone(),
-                description: ip_range
-                    .description
-                    .clone()
-                    .unwrap_or_else(|| "-".to_string()),
-            });
+        let rule = |source_dest: String, description: Option<&str>| SecurityRule {
+            protocol: protocol.clone(),
+            port_range: port_range.clone(),
+            source_dest,
+            description: description.unwrap_or("-").to_string(),
+        };
+
+        for ip_range in perm.ip_ranges() {
+            rules.push(rule(
+                ip_range.cidr_ip().unwrap_or_default().to_string(),
+                ip_range.description(),
+            ));
         }
 
-        for ipv6_range in &perm.ipv6_ranges {
-            rules.push(SecurityRule {
-                protocol: protocol.clone(),
-                port_range: port_range.clone(),
-                source_dest: ipv6_range.cidr_ipv6.clone(),
-                description: ipv6_range
-                    .description
-                    .clone()
-                    .unwrap_or_else(|| "-".to_string()),
-            });
+        for ipv6_range in perm.ipv6_ranges() {
+            rules.push(rule(
+                ipv6_range.cidr_ipv6().unwrap_or_default().to_string(),
+                ipv6_range.description(),
+            ));
         }
 
-        for sg_pair in &perm.user_id_group_pairs {
-            rules.push(SecurityRule {
-                protocol: protocol.clone(),
-                port_range: port_range.clone(),
-                source_dest: format!("sg: {}", sg_pair.group_id),
-                description: sg_pair
-                    .description
-                    .clone()
-                    .unwrap_or_else(|| "-".to_string()),
-            });
+        for sg_pair in perm.user_id_group_pairs() {
+            rules.push(rule(
+                format!("sg: {}", sg_pair.group_id().unwrap_or_default()),
+                sg_pair.description(),
+            ));
         }
     }
 
@@ -281,33 +189,60 @@ fn parse_security_rules(permissions: &[IpPermission]) -> Vec<SecurityRule> {
 #[cfg(test)]
 mod tests {
     use super::{
-        IpPermission, SecurityGroupDetail, SecurityRule, parse_security_group_detail_output,
-        parse_security_groups_list_output, parse_security_rules,
+        SecurityGroupDetail, SecurityRule, get_security_group_detail, list_security_groups,
+        parse_security_rules,
     };
+    use crate::aws_cli::fake_provider::FakeProvider;
     use crate::i18n::Language;
+    use aws_sdk_ec2::types::{
+        IpPermission, IpRange, Ipv6Range, SecurityGroup, Tag, UserIdGroupPair,
+    };
+
+    fn security_group(id: &str, group_name: &str, name_tag: Option<&str>) -> SecurityGroup {
+        SecurityGroup::builder()
+            .group_id(id)
+            .group_name(group_name)
+            .description(format!("{} sg", group_name))
+            .vpc_id("vpc-1")
+            .set_tags(name_tag.map(|name| vec![Tag::builder().key("Name").value(name).build()]))
+            .build()
+    }
 
     #[test]
     fn parse_security_rules_supports_ipv4_ipv6_and_sg_pair() {
-        let json = r#"
-            [
-              {
-                "IpProtocol":"tcp",
-                "FromPort":80,
-                "ToPort":80,
-                "IpRanges":[{"CidrIp":"0.0.0.0/0","Description":"web"}],
-                "Ipv6Ranges":[{"CidrIpv6":"::/0"}],
-                "UserIdGroupPairs":[{"GroupId":"sg-1234","Description":"peer"}]
-              }
-            ]
-        "#;
-        let permissions: Vec<IpPermission> =
-            serde_json::from_str(json).expect("deserialize permissions");
-        let rules = parse_security_rules(&permissions);
+        let permission = IpPermission::builder()
+            .ip_protocol("tcp")
+            .from_port(80)
+            .to_port(80)
+            .ip_ranges(
+                IpRange::builder()
+                    .cidr_ip("0.0.0.0/0")
+                    .description("web")
+                    .build(),
+            )
+            .ipv6_ranges(Ipv6Range::builder().cidr_ipv6("::/0").build())
+            .user_id_group_pairs(
+                UserIdGroupPair::builder()
+                    .group_id("sg-1234")
+                    .description("peer")
+                    .build(),
+            )
+            .build();
+        let rules = parse_security_rules(&[permission]);
 
         assert_eq!(rules.len(), 3);
         assert!(rules.iter().any(|r| r.source_dest == "0.0.0.0/0"));
-        assert!(rules.iter().any(|r| r.source_dest == "::/0"));
+        assert!(
+            rules
+                .iter()
+                .any(|r| r.source_dest == "::/0" && r.description == "-")
+        );
         assert!(rules.iter().any(|r| r.source_dest == "sg: sg-1234"));
+        assert!(
+            rules
+                .iter()
+                .all(|r| r.port_range == "80" && r.protocol == "TCP")
+        );
     }
 
     #[test]
@@ -338,61 +273,52 @@ mod tests {
     }
 
     #[test]
-    fn parse_security_groups_list_output_prefers_name_tag_or_group_name() {
-        let payload = r#"
-        {
-          "SecurityGroups": [
-            {
-              "GroupId": "sg-1",
-              "GroupName": "web-default",
-              "Description": "web",
-              "VpcId": "vpc-1",
-              "Tags": [{"Key":"Name","Value":"web-sg"}]
-            },
-            {
-              "GroupId": "sg-2",
-              "GroupName": "db-default",
-              "Description": "db",
-              "VpcId": "vpc-1",
-              "Tags": []
-            }
-          ]
-        }
-        "#;
-        let groups = parse_security_groups_list_output(payload).expect("groups");
+    fn list_security_groups_prefers_name_tag_or_group_name() {
+        let provider = FakeProvider {
+            security_groups: vec![
+                security_group("sg-2", "db-default", None),
+                security_group("sg-1", "web-default", Some("web-sg")),
+            ],
+            ..FakeProvider::default()
+        };
+        let groups = list_security_groups(&provider);
         assert_eq!(groups.len(), 2);
         assert_eq!(groups[0].id, "sg-1");
-        assert!(groups[0].name.contains("web-sg"));
-        assert!(groups[1].name.contains("db-default"));
+        assert_eq!(groups[0].name, "web-sg (web-default)");
+        assert_eq!(groups[1].name, "db-default (db-default)");
+        assert_eq!(groups[1].state, "vpc-1");
     }
 
     #[test]
-    fn parse_security_group_detail_output_builds_detail_and_rules() {
-        let payload = r#"
-        {
-          "SecurityGroups": [
-            {
-              "GroupId": "sg-1",
-              "GroupName": "web",
-              "Description": "web sg",
-              "VpcId": "vpc-1",
-              "IpPermissions": [
-                {"IpProtocol":"tcp","FromPort":443,"ToPort":443,"IpRanges":[{"CidrIp":"0.0.0.0/0"}]}
-              ],
-              "IpPermissionsEgress": [
-                {"IpProtocol":"-1","IpRanges":[{"CidrIp":"0.0.0.0/0"}]}
-              ],
-              "Tags": [{"Key":"Name","Value":"web-main"}]
-            }
-          ]
-        }
-        "#;
-        let detail = parse_security_group_detail_output(payload).expect("detail");
+    fn get_security_group_detail_builds_detail_and_rules() {
+        let mut sg = security_group("sg-1", "web", Some("web-main"));
+        sg.ip_permissions = Some(vec![
+            IpPermission::builder()
+                .ip_protocol("tcp")
+                .from_port(443)
+                .to_port(443)
+                .ip_ranges(IpRange::builder().cidr_ip("0.0.0.0/0").build())
+                .build(),
+        ]);
+        sg.ip_permissions_egress = Some(vec![
+            IpPermission::builder()
+                .ip_protocol("-1")
+                .ip_ranges(IpRange::builder().cidr_ip("0.0.0.0/0").build())
+                .build(),
+        ]);
+        let provider = FakeProvider {
+            security_groups: vec![sg],
+            ..FakeProvider::default()
+        };
+
+        let detail = get_security_group_detail(&provider, "sg-1").expect("detail");
         assert_eq!(detail.name, "web-main");
         assert_eq!(detail.id, "sg-1");
         assert_eq!(detail.inbound_rules.len(), 1);
         assert_eq!(detail.outbound_rules.len(), 1);
         assert_eq!(detail.inbound_rules[0].port_range, "443");
+        assert_eq!(detail.outbound_rules[0].port_range, "All");
+        assert!(get_security_group_detail(&provider, "sg-missing").is_none());
     }
 
     #[test]
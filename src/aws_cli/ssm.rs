@@ -0,0 +1,129 @@
+pub use crate::aws_cli::ssm_sdk::{get_ssm_parameter_detail, list_ssm_parameters};
+use crate::i18n::{I18n, Language};
+
+/// SSM 파라미터 메타데이터 (파라미터 값은 조회하지 않음)
+#[derive(Debug, Clone)]
+pub struct SsmParameterDetail {
+    pub name: String,
+    pub arn: String,
+    pub parameter_type: String,
+    pub tier: String,
+    pub data_type: String,
+    pub version: i64,
+    pub key_id: Option<String>,
+    pub description: String,
+    pub allowed_pattern: Option<String>,
+    pub last_modified_date: String,
+    pub last_modified_user: String,
+    pub policies: Vec<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl SsmParameterDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.ssm_parameter(), self.name),
+            format!("> {}\n", i18n.md_value_not_collected()),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+        ];
+
+        if !self.arn.is_empty() {
+            lines.push(format!("| ARN | {} |", self.arn));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_type(), self.parameter_type));
+        lines.push(format!("| {} | {} |", i18n.md_tier(), self.tier));
+        if !self.data_type.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_data_type(), self.data_type));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_version(), self.version));
+        if let Some(ref key_id) = self.key_id {
+            lines.push(format!("| {} | {} |", i18n.md_kms_key(), key_id));
+        }
+        if let Some(ref pattern) = self.allowed_pattern {
+            lines.push(format!("| {} | `{}` |", i18n.md_allowed_pattern(), pattern));
+        }
+        if !self.last_modified_date.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_last_modified(),
+                self.last_modified_date
+            ));
+        }
+        if !self.last_modified_user.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_last_modified_user(),
+                self.last_modified_user
+            ));
+        }
+
+        // Parameter Policies
+        if !self.policies.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_policies()));
+            for policy in &self.policies {
+                lines.push(format!("- {}", policy));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_tags()));
+            lines.push(format!("| {} | {} |", i18n.md_tag_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SsmParameterDetail;
+    use crate::i18n::Language;
+
+    #[test]
+    fn ssm_parameter_markdown_renders_metadata_without_value() {
+        let detail = SsmParameterDetail {
+            name: "/app/prod/db-url".to_string(),
+            arn: "arn:aws:ssm:ap-northeast-2:123456789012:parameter/app/prod/db-url".to_string(),
+            parameter_type: "SecureString".to_string(),
+            tier: "Standard".to_string(),
+            data_type: "text".to_string(),
+            version: 4,
+            key_id: Some("alias/aws/ssm".to_string()),
+            description: String::new(),
+            allowed_pattern: None,
+            last_modified_date: "2026-09-30".to_string(),
+            last_modified_user: "arn:aws:iam::123456789012:user/deployer".to_string(),
+            policies: vec!["Expiration (Pending)".to_string()],
+            tags: vec![("Env".to_string(), "prod".to_string())],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## SSM Parameter (/app/prod/db-url)"));
+        assert!(md.contains("> Values are never fetched (metadata only)"));
+        assert!(md.contains("| Type | SecureString |"));
+        assert!(md.contains("| Version | 4 |"));
+        assert!(md.contains("| KMS Key | alias/aws/ssm |"));
+        assert!(md.contains("| Last Modified | 2026-09-30 |"));
+        assert!(md.contains("- Expiration (Pending)"));
+        assert!(md.contains("| Env | prod |"));
+        assert!(!md.contains("Description"));
+    }
+}
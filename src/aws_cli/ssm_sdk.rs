@@ -0,0 +1,141 @@
+use crate::aws_cli::common::{AwsResource, format_date};
+use crate::aws_cli::provider::AwsProvider;
+use crate::aws_cli::ssm::SsmParameterDetail;
+use aws_sdk_ssm::types::{ParameterMetadata, Tag};
+
+// DescribeParameters 메타데이터만 사용하며 GetParameter(s)는 호출하지 않는다.
+
+/// List SSM Parameter Store parameters (metadata only)
+pub fn list_ssm_parameters(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut resources: Vec<AwsResource> = provider
+        .describe_parameters(None)
+        .iter()
+        .map(map_parameter_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get SSM parameter metadata (DescribeParameters, never the value)
+pub fn get_ssm_parameter_detail(
+    provider: &dyn AwsProvider,
+    name: &str,
+) -> Option<SsmParameterDetail> {
+    let parameters = provider.describe_parameters(Some(name));
+    let parameter = parameters.first()?;
+    let tags = provider.list_parameter_tags(name);
+    Some(map_parameter_detail(parameter, &tags))
+}
+
+fn map_parameter_resource(parameter: &ParameterMetadata) -> AwsResource {
+    let name = parameter.name().unwrap_or_default().to_string();
+    AwsResource {
+        name: name.clone(),
+        id: name,
+        state: parameter
+            .r#type()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_default(),
+        az: parameter
+            .tier()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_default(),
+        cidr: parameter.arn().unwrap_or_default().to_string(),
+        region: None,
+    }
+}
+
+fn map_parameter_detail(parameter: &ParameterMetadata, tags: &[Tag]) -> SsmParameterDetail {
+    SsmParameterDetail {
+        name: parameter.name().unwrap_or_default().to_string(),
+        arn: parameter.arn().unwrap_or_default().to_string(),
+        parameter_type: parameter
+            .r#type()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_default(),
+        tier: parameter
+            .tier()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_else(|| "Standard".to_string()),
+        data_type: parameter.data_type().unwrap_or_default().to_string(),
+        version: parameter.version(),
+        key_id: parameter.key_id().map(|s| s.to_string()),
+        description: parameter.description().unwrap_or_default().to_string(),
+        allowed_pattern: parameter.allowed_pattern().map(|s| s.to_string()),
+        last_modified_date: format_date(parameter.last_modified_date()),
+        last_modified_user: parameter
+            .last_modified_user()
+            .unwrap_or_default()
+            .to_string(),
+        policies: parameter
+            .policies()
+            .iter()
+            .map(|policy| {
+                let policy_type = policy.policy_type().unwrap_or("Policy");
+                match policy.policy_status() {
+                    Some(status) => format!("{} ({})", policy_type, status),
+                    None => policy_type.to_string(),
+                }
+            })
+            .collect(),
+        tags: tags
+            .iter()
+            .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_parameter_detail, map_parameter_resource};
+    use aws_sdk_ssm::types::{
+        ParameterInlinePolicy, ParameterMetadata, ParameterTier, ParameterType, Tag,
+    };
+
+    fn parameter() -> ParameterMetadata {
+        ParameterMetadata::builder()
+            .name("/app/prod/db-url")
+            .arn("arn:aws:ssm:ap-northeast-2:123456789012:parameter/app/prod/db-url")
+            .r#type(ParameterType::SecureString)
+            .tier(ParameterTier::Advanced)
+            .key_id("alias/aws/ssm")
+            .version(4)
+            .policies(
+                ParameterInlinePolicy::builder()
+                    .policy_type("Expiration")
+                    .policy_status("Pending")
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn map_parameter_resource_shows_type_and_tier() {
+        let resource = map_parameter_resource(&parameter());
+        assert_eq!(resource.id, "/app/prod/db-url");
+        assert_eq!(resource.state, "SecureString");
+        assert_eq!(resource.az, "Advanced");
+    }
+
+    #[test]
+    fn map_parameter_detail_reads_policies_and_tags() {
+        let tags = vec![
+            Tag::builder()
+                .key("Env")
+                .value("prod")
+                .build()
+                .expect("tag"),
+        ];
+        let detail = map_parameter_detail(&parameter(), &tags);
+        assert_eq!(detail.parameter_type, "SecureString");
+        assert_eq!(detail.version, 4);
+        assert_eq!(detail.key_id.as_deref(), Some("alias/aws/ssm"));
+        assert_eq!(detail.policies, vec!["Expiration (Pending)"]);
+        assert_eq!(detail.tags, vec![("Env".to_string(), "prod".to_string())]);
+
+        let detail =
+            map_parameter_detail(&ParameterMetadata::builder().name("/plain").build(), &[]);
+        assert_eq!(detail.tier, "Standard");
+        assert!(detail.policies.is_empty());
+    }
+}
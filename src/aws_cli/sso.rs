@@ -0,0 +1,311 @@
+//! IAM Identity Center(SSO) 기기 인증: `aws sso login` 없이 emd 안에서 로그인
+
+use crate::aws_cli::account::resolve_profile;
+use crate::aws_cli::client_cache::invalidate_client_cache;
+use crate::aws_cli::common::{
+    get_runtime, profile_section_name, read_aws_config, read_config_section,
+};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::Serialize;
+use std::path::PathBuf;
+
+const CLIENT_NAME: &str = "emd";
+const DEVICE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+/// `sso-session` 설정에 범위가 없을 때 요청하는 기본 범위
+const DEFAULT_SCOPE: &str = "sso:account:access";
+
+/// 프로필의 SSO 설정 (`sso_session` 방식 또는 프로필에 직접 적은 예전 방식)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SsoProfile {
+    pub session_name: Option<String>,
+    pub start_url: String,
+    pub region: String,
+    pub scopes: Vec<String>,
+}
+
+impl SsoProfile {
+    /// `~/.aws/config` 내용에서 `profile`의 SSO 설정을 찾음
+    pub fn from_config(contents: &str, profile: &str) -> Option<Self> {
+        let section = read_config_section(contents, &profile_section_name(profile))?;
+        if let Some(session_name) = section.get("sso_session") {
+            let session = read_config_section(contents, &format!("sso-session {}", session_name))?;
+            let scopes = session
+                .get("sso_registration_scopes")
+                .map(|scopes| {
+                    scopes
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|scope| !scope.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_else(|| vec![DEFAULT_SCOPE.to_string()]);
+            return Some(Self {
+                session_name: Some(session_name.clone()),
+                start_url: session.get("sso_start_url")?.clone(),
+                region: session.get("sso_region")?.clone(),
+                scopes,
+            });
+        }
+        Some(Self {
+            session_name: None,
+            start_url: section.get("sso_start_url")?.clone(),
+            region: section.get("sso_region")?.clone(),
+            scopes: Vec::new(),
+        })
+    }
+
+    /// SDK가 토큰을 찾는 캐시 파일 이름 (세션 이름, 없으면 시작 URL의 SHA-1)
+    pub fn cache_file_name(&self) -> String {
+        let identifier = self.session_name.as_deref().unwrap_or(&self.start_url);
+        let digest = ring::digest::digest(
+            &ring::digest::SHA1_FOR_LEGACY_USE_ONLY,
+            identifier.as_bytes(),
+        );
+        let hex: String = digest
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        format!("{}.json", hex)
+    }
+}
+
+/// 선택한 프로필의 SSO 설정 (가상 프로필은 시작 프로필 기준)
+pub fn sso_profile(profile: &str) -> Option<SsoProfile> {
+    let (profile, _) = resolve_profile(profile.to_string(), Vec::new());
+    SsoProfile::from_config(&read_aws_config(), &profile)
+}
+
+/// 진행 중인 기기 인증. 사용자가 `verification_uri`에서 `user_code`를 승인할 때까지 폴링
+#[derive(Debug, Clone)]
+pub struct SsoDeviceAuthorization {
+    pub profile: SsoProfile,
+    pub verification_uri: String,
+    pub user_code: String,
+    pub expires_at: DateTime<Utc>,
+    /// 폴링 간격(초), `SlowDown` 응답마다 늘어남
+    pub interval_secs: u64,
+    device_code: String,
+    client_id: String,
+    client_secret: String,
+    client_expires_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+impl SsoDeviceAuthorization {
+    pub fn sample() -> Self {
+        Self {
+            profile: SsoProfile {
+                session_name: Some("corp".to_string()),
+                start_url: "https://corp.awsapps.com/start".to_string(),
+                region: "us-east-1".to_string(),
+                scopes: vec![DEFAULT_SCOPE.to_string()],
+            },
+            verification_uri: "https://device.sso.us-east-1.amazonaws.com/?user_code=ABCD-EFGH"
+                .to_string(),
+            user_code: "ABCD-EFGH".to_string(),
+            expires_at: Utc::now() + Duration::minutes(10),
+            interval_secs: 5,
+            device_code: "device".to_string(),
+            client_id: "client".to_string(),
+            client_secret: String::new(),
+            client_expires_at: None,
+        }
+    }
+}
+
+/// 토큰 요청 한 번의 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SsoPoll {
+    Pending,
+    SlowDown,
+    /// 토큰을 SSO 캐시에 저장함
+    Complete,
+}
+
+fn oidc_client(region: &str) -> aws_sdk_ssooidc::Client {
+    let config = get_runtime().block_on(
+        aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(aws_config::Region::new(region.to_string()))
+            .no_credentials()
+            .load(),
+    );
+    aws_sdk_ssooidc::Client::new(&config)
+}
+
+/// 클라이언트를 등록하고 기기 인증을 시작 (확인 주소와 코드 발급)
+pub fn start_device_authorization(profile: SsoProfile) -> Result<SsoDeviceAuthorization, String> {
+    let client = oidc_client(&profile.region);
+    get_runtime().block_on(async {
+        let registration = client
+            .register_client()
+            .client_name(CLIENT_NAME)
+            .client_type("public")
+            .set_scopes((!profile.scopes.is_empty()).then(|| profile.scopes.clone()))
+            .send()
+            .await
+            .map_err(|error| aws_sdk_ssooidc::Error::from(error).to_string())?;
+        let client_id = registration.client_id().unwrap_or_default().to_string();
+        let client_secret = registration.client_secret().unwrap_or_default().to_string();
+
+        let authorization = client
+            .start_device_authorization()
+            .client_id(&client_id)
+            .client_secret(&client_secret)
+            .start_url(&profile.start_url)
+            .send()
+            .await
+            .map_err(|error| aws_sdk_ssooidc::Error::from(error).to_string())?;
+        tracing::info!(start_url = %profile.start_url, "SSO device authorization started");
+
+        Ok(SsoDeviceAuthorization {
+            verification_uri: authorization
+                .verification_uri_complete()
+                .or(authorization.verification_uri())
+                .unwrap_or_default()
+                .to_string(),
+            user_code: authorization.user_code().unwrap_or_default().to_string(),
+            expires_at: Utc::now() + Duration::seconds(authorization.expires_in().into()),
+            interval_secs: authorization.interval().max(1) as u64,
+            device_code: authorization.device_code().unwrap_or_default().to_string(),
+            client_id,
+            client_secret,
+            client_expires_at: DateTime::from_timestamp(registration.client_secret_expires_at(), 0)
+                .filter(|_| registration.client_secret_expires_at() > 0),
+            profile,
+        })
+    })
+}
+
+/// 토큰을 한 번 요청. 승인되면 SDK가 읽는 `~/.aws/sso/cache`에 저장하고 클라이언트 캐시를 비움
+pub fn poll_device_token(authorization: &SsoDeviceAuthorization) -> Result<SsoPoll, String> {
+    use aws_sdk_ssooidc::operation::create_token::CreateTokenError;
+
+    let client = oidc_client(&authorization.profile.region);
+    let output = get_runtime().block_on(
+        client
+            .create_token()
+            .client_id(&authorization.client_id)
+            .client_secret(&authorization.client_secret)
+            .grant_type(DEVICE_GRANT_TYPE)
+            .device_code(&authorization.device_code)
+            .send(),
+    );
+    let token = match output {
+        Ok(token) => token,
+        Err(error) => {
+            return match error.as_service_error() {
+                Some(CreateTokenError::AuthorizationPendingException(_)) => Ok(SsoPoll::Pending),
+                Some(CreateTokenError::SlowDownException(_)) => Ok(SsoPoll::SlowDown),
+                _ => Err(aws_sdk_ssooidc::Error::from(error).to_string()),
+            };
+        }
+    };
+
+    let cached = CachedToken {
+        access_token: token.access_token().unwrap_or_default(),
+        expires_at: format_time(Utc::now() + Duration::seconds(token.expires_in().into())),
+        refresh_token: token.refresh_token(),
+        client_id: &authorization.client_id,
+        client_secret: &authorization.client_secret,
+        registration_expires_at: authorization.client_expires_at.map(format_time),
+        region: &authorization.profile.region,
+        start_url: &authorization.profile.start_url,
+    };
+    write_cached_token(&authorization.profile.cache_file_name(), &cached)?;
+    invalidate_client_cache();
+    tracing::info!(start_url = %authorization.profile.start_url, "SSO login completed");
+    Ok(SsoPoll::Complete)
+}
+
+/// SDK SSO 토큰 캐시 형식 (`aws sso login`과 같은 파일)
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedToken<'a> {
+    access_token: &'a str,
+    expires_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<&'a str>,
+    client_id: &'a str,
+    client_secret: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    registration_expires_at: Option<String>,
+    region: &'a str,
+    start_url: &'a str,
+}
+
+fn format_time(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn sso_cache_dir() -> Option<PathBuf> {
+    Some(dirs::home_dir()?.join(".aws").join("sso").join("cache"))
+}
+
+fn write_cached_token(file_name: &str, token: &CachedToken) -> Result<(), String> {
+    let dir = sso_cache_dir().ok_or_else(|| "Home directory not found".to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|error| error.to_string())?;
+    let path = dir.join(file_name);
+    let content = serde_json::to_string_pretty(token).map_err(|error| error.to_string())?;
+    std::fs::write(&path, content).map_err(|error| error.to_string())?;
+    // 토큰 파일은 소유자만 읽도록 (aws CLI와 같은 권한)
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SsoProfile;
+
+    const CONFIG: &str = "\
+[default]
+region = ap-northeast-2
+
+[profile dev]
+sso_session = corp
+sso_account_id = 111122223333
+sso_role_name = ReadOnly
+
+[sso-session corp]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = us-east-1
+sso_registration_scopes = sso:account:access
+
+[profile legacy]
+sso_start_url = https://legacy.awsapps.com/start
+sso_region = eu-west-1
+sso_account_id = 444455556666
+";
+
+    #[test]
+    fn sso_profiles_are_read_from_sessions_and_legacy_sections() {
+        let dev = SsoProfile::from_config(CONFIG, "dev").expect("dev sso");
+        assert_eq!(dev.session_name.as_deref(), Some("corp"));
+        assert_eq!(dev.start_url, "https://corp.awsapps.com/start");
+        assert_eq!(dev.region, "us-east-1");
+        assert_eq!(dev.scopes, vec!["sso:account:access"]);
+
+        let legacy = SsoProfile::from_config(CONFIG, "legacy").expect("legacy sso");
+        assert_eq!(legacy.session_name, None);
+        assert_eq!(legacy.region, "eu-west-1");
+        assert!(legacy.scopes.is_empty());
+
+        assert_eq!(SsoProfile::from_config(CONFIG, "default"), None);
+        assert_eq!(SsoProfile::from_config(CONFIG, "missing"), None);
+    }
+
+    #[test]
+    fn cache_file_name_matches_sdk_hash() {
+        // SHA-1("corp"), SDK의 SSO 토큰 캐시와 같은 규칙
+        let dev = SsoProfile::from_config(CONFIG, "dev").expect("dev sso");
+        assert_eq!(
+            dev.cache_file_name(),
+            "ee0bfd2552fbd840c02cc48b6e823320543c450f.json"
+        );
+    }
+}
@@ -0,0 +1,396 @@
+pub use crate::aws_cli::step_functions_sdk::{get_state_machine_detail, list_state_machines};
+use crate::i18n::{I18n, Language};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct StateMachineDetail {
+    pub name: String,
+    pub arn: String,
+    pub machine_type: String,
+    pub status: String,
+    pub description: String,
+    pub role_arn: String,
+    pub logging_level: String,
+    pub include_execution_data: bool,
+    pub log_destinations: Vec<String>,
+    pub tracing_enabled: bool,
+    pub creation_date: String,
+    /// ASL(Amazon States Language) JSON 원문
+    pub definition: String,
+}
+
+impl StateMachineDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.state_machine(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_type(), self.machine_type),
+        ];
+
+        if !self.status.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_state(), self.status));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_iam_role(), self.role_arn));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_logging(),
+            self.logging_level
+        ));
+        if self.logging_level != "OFF" {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_include_execution_data(),
+                if self.include_execution_data {
+                    i18n.md_enabled()
+                } else {
+                    i18n.md_disabled()
+                }
+            ));
+        }
+        if !self.log_destinations.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_log_destinations(),
+                self.log_destinations.join(", ")
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_tracing(),
+            if self.tracing_enabled {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        ));
+        if !self.creation_date.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                self.creation_date
+            ));
+        }
+
+        // Workflow Diagram
+        if let Some(diagram) = generate_mermaid(&self.definition) {
+            lines.push(String::new());
+            lines.push(format!("### {}", i18n.md_workflow_diagram()));
+            lines.push(diagram);
+        }
+
+        // Definition
+        if !self.definition.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_definition()));
+            lines.push("```json".to_string());
+            lines.push(pretty_json(&self.definition));
+            lines.push("```".to_string());
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+fn pretty_json(raw: &str) -> String {
+    serde_json::from_str::<Value>(raw)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| raw.to_string())
+}
+
+fn escape_label(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+/// Choice 규칙을 짧은 조건식 라벨로 요약
+fn choice_label(rule: &Value) -> String {
+    // JSONata 조건식
+    if let Some(condition) = rule.get("Condition").and_then(Value::as_str) {
+        return condition.to_string();
+    }
+    let Some(fields) = rule.as_object() else {
+        return String::new();
+    };
+    let operator = fields
+        .iter()
+        .find(|(key, _)| !matches!(key.as_str(), "Variable" | "Next" | "Comment"));
+    match (fields.get("Variable").and_then(Value::as_str), operator) {
+        (Some(variable), Some((op, value))) => format!("{} {} {}", variable, op, value),
+        (None, Some((op, _))) => op.clone(),
+        (_, None) => String::new(),
+    }
+}
+
+/// ASL 정의를 Mermaid 순서도로 변환하는 빌더
+/// (노드 선언을 먼저 쓰고 엣지는 마지막에 모아 써야 서브그래프 배치가 꼬이지 않음)
+struct AslDiagram {
+    nodes: Vec<String>,
+    edges: Vec<String>,
+    counter: usize,
+}
+
+impl AslDiagram {
+    fn render_scope(&mut self, scope: &Value, depth: usize, exit: &str) -> Option<String> {
+        let states = scope.get("States")?.as_object()?;
+        let start_at = scope.get("StartAt")?.as_str()?;
+
+        let ids: HashMap<&str, String> = states
+            .keys()
+            .map(|name| {
+                self.counter += 1;
+                (name.as_str(), format!("s{}", self.counter))
+            })
+            .collect();
+        let pad = "    ".repeat(depth);
+
+        for (name, state) in states {
+            let id = &ids[name.as_str()];
+            let state_type = state.get("Type").and_then(Value::as_str).unwrap_or("");
+            let label = escape_label(name);
+            let target_of = |key: &str| {
+                state
+                    .get(key)
+                    .and_then(Value::as_str)
+                    .and_then(|next| ids.get(next))
+                    .cloned()
+            };
+            let follow = target_of("Next").unwrap_or_else(|| exit.to_string());
+
+            match state_type {
+                "Choice" => {
+                    self.nodes.push(format!("{}{}{{\"{}\"}}", pad, id, label));
+                    self.nodes
+                        .push(format!("{}style {} fill:#fff9c4,stroke:#f57f17", pad, id));
+                    let choices = state.get("Choices").and_then(Value::as_array);
+                    for rule in choices.into_iter().flatten() {
+                        if let Some(next) = rule
+                            .get("Next")
+                            .and_then(Value::as_str)
+                            .and_then(|next| ids.get(next))
+                        {
+                            self.edges.push(format!(
+                                "    {} -->|\"{}\"| {}",
+                                id,
+                                escape_label(&choice_label(rule)),
+                                next
+                            ));
+                        }
+                    }
+                    if let Some(default) = target_of("Default") {
+                        self.edges
+                            .push(format!("    {} -->|Default| {}", id, default));
+                    }
+                }
+                "Parallel" | "Map" => {
+                    self.nodes.push(format!("{}{}[[\"{}\"]]", pad, id, label));
+                    let branches: Vec<&Value> = if state_type == "Parallel" {
+                        state
+                            .get("Branches")
+                            .and_then(Value::as_array)
+                            .map(|branches| branches.iter().collect())
+                            .unwrap_or_default()
+                    } else {
+                        state
+                            .get("ItemProcessor")
+                            .or_else(|| state.get("Iterator"))
+                            .into_iter()
+                            .collect()
+                    };
+                    if branches.is_empty() {
+                        self.edges.push(format!("    {} --> {}", id, follow));
+                    }
+                    for (index, branch) in branches.into_iter().enumerate() {
+                        let title = if state_type == "Map" {
+                            format!("{} (Map)", label)
+                        } else {
+                            format!("{} #{}", label, index + 1)
+                        };
+                        self.nodes
+                            .push(format!("{}subgraph {}_b{}[\"{}\"]", pad, id, index, title));
+                        if let Some(start) = self.render_scope(branch, depth + 1, &follow) {
+                            self.edges.push(format!("    {} --> {}", id, start));
+                        }
+                        self.nodes.push(format!("{}end", pad));
+                    }
+                }
+                "Succeed" | "Fail" => {
+                    self.nodes.push(format!("{}{}([\"{}\"])", pad, id, label));
+                    let color = if state_type == "Succeed" {
+                        "fill:#e8f5e9,stroke:#1b5e20"
+                    } else {
+                        "fill:#ffebee,stroke:#b71c1c"
+                    };
+                    self.nodes.push(format!("{}style {} {}", pad, id, color));
+                    self.edges.push(format!("    {} --> {}", id, exit));
+                }
+                _ => {
+                    let node = if state_type == "Wait" {
+                        format!("{}{}([\"⏱ {}\"])", pad, id, label)
+                    } else {
+                        format!("{}{}[\"{}\"]", pad, id, label)
+                    };
+                    self.nodes.push(node);
+                    self.edges.push(format!("    {} --> {}", id, follow));
+                }
+            }
+
+            // Catch
+            let catchers = state.get("Catch").and_then(Value::as_array);
+            for catcher in catchers.into_iter().flatten() {
+                if let Some(next) = catcher
+                    .get("Next")
+                    .and_then(Value::as_str)
+                    .and_then(|next| ids.get(next))
+                {
+                    let errors: Vec<&str> = catcher
+                        .get("ErrorEquals")
+                        .and_then(Value::as_array)
+                        .map(|errors| errors.iter().filter_map(Value::as_str).collect())
+                        .unwrap_or_default();
+                    self.edges.push(format!(
+                        "    {} -.->|\"{}\"| {}",
+                        id,
+                        escape_label(&errors.join(", ")),
+                        next
+                    ));
+                }
+            }
+        }
+
+        ids.get(start_at).cloned()
+    }
+}
+
+/// ASL JSON 정의 -> Mermaid 순서도 (파싱 실패 시 None)
+pub fn generate_mermaid(definition: &str) -> Option<String> {
+    let asl: Value = serde_json::from_str(definition).ok()?;
+    let mut builder = AslDiagram {
+        nodes: Vec::new(),
+        edges: Vec::new(),
+        counter: 0,
+    };
+    let start = builder.render_scope(&asl, 1, "End")?;
+
+    let mut diagram = String::from("\n```mermaid\ngraph TD\n");
+    diagram.push_str("    Start((\"▶ Start\"))\n");
+    diagram.push_str("    End((\"■ End\"))\n");
+    for node in &builder.nodes {
+        diagram.push_str(node);
+        diagram.push('\n');
+    }
+    diagram.push('\n');
+    diagram.push_str(&format!("    Start --> {}\n", start));
+    for edge in &builder.edges {
+        diagram.push_str(edge);
+        diagram.push('\n');
+    }
+    diagram.push_str("```");
+    Some(diagram)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StateMachineDetail, generate_mermaid};
+    use crate::i18n::Language;
+
+    const ORDER_WORKFLOW: &str = r#"{
+        "StartAt": "Validate",
+        "States": {
+            "Validate": {
+                "Type": "Task",
+                "Resource": "arn:aws:states:::lambda:invoke",
+                "Next": "IsValid",
+                "Catch": [{"ErrorEquals": ["States.ALL"], "Next": "Failed"}]
+            },
+            "IsValid": {
+                "Type": "Choice",
+                "Choices": [{"Variable": "$.valid", "BooleanEquals": true, "Next": "Fulfil"}],
+                "Default": "Failed"
+            },
+            "Fulfil": {
+                "Type": "Parallel",
+                "Branches": [
+                    {"StartAt": "Charge", "States": {"Charge": {"Type": "Task", "Resource": "arn:aws:states:::lambda:invoke", "End": true}}},
+                    {"StartAt": "Ship", "States": {"Ship": {"Type": "Pass", "End": true}}}
+                ],
+                "Next": "Done"
+            },
+            "Done": {"Type": "Succeed"},
+            "Failed": {"Type": "Fail", "Error": "OrderFailed"}
+        }
+    }"#;
+
+    #[test]
+    fn generate_mermaid_renders_states_choices_branches_and_catch() {
+        let diagram = generate_mermaid(ORDER_WORKFLOW).expect("diagram");
+        assert!(diagram.starts_with("\n```mermaid\ngraph TD\n"));
+        assert!(diagram.ends_with("```"));
+
+        // 상위 범위 ID는 상태 이름 순서대로 할당: Done=s1, Failed=s2, Fulfil=s3, IsValid=s4, Validate=s5
+        assert!(diagram.contains("    Start --> s5\n"));
+        assert!(diagram.contains("    s5[\"Validate\"]\n"));
+        assert!(diagram.contains("    s4{\"IsValid\"}\n"));
+        assert!(diagram.contains("    s3[[\"Fulfil\"]]\n"));
+        assert!(diagram.contains("    s1([\"Done\"])\n"));
+        assert!(diagram.contains("    s5 --> s4\n"));
+        assert!(diagram.contains("    s5 -.->|\"States.ALL\"| s2\n"));
+        assert!(diagram.contains("    s4 -->|\"$.valid BooleanEquals true\"| s3\n"));
+        assert!(diagram.contains("    s4 -->|Default| s2\n"));
+        assert!(diagram.contains("    subgraph s3_b0[\"Fulfil #1\"]\n"));
+        assert!(diagram.contains("    subgraph s3_b1[\"Fulfil #2\"]\n"));
+        // 브랜치 종료 상태는 Parallel의 Next로 합류
+        assert!(diagram.contains("    s6 --> s1\n"));
+        assert!(diagram.contains("    s7 --> s1\n"));
+        assert!(diagram.contains("    s1 --> End\n"));
+        assert!(diagram.contains("    s2 --> End\n"));
+    }
+
+    #[test]
+    fn generate_mermaid_returns_none_for_invalid_definition() {
+        assert!(generate_mermaid("not json").is_none());
+        assert!(generate_mermaid(r#"{"States": {}}"#).is_none());
+    }
+
+    #[test]
+    fn state_machine_markdown_includes_diagram_and_definition() {
+        let detail = StateMachineDetail {
+            name: "order-workflow".to_string(),
+            arn: "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow"
+                .to_string(),
+            machine_type: "STANDARD".to_string(),
+            status: "ACTIVE".to_string(),
+            description: String::new(),
+            role_arn: "arn:aws:iam::123456789012:role/sfn-orders".to_string(),
+            logging_level: "ERROR".to_string(),
+            include_execution_data: false,
+            log_destinations: vec![
+                "arn:aws:logs:ap-northeast-2:123456789012:log-group:/sfn/orders:*".to_string(),
+            ],
+            tracing_enabled: true,
+            creation_date: "2026-09-01".to_string(),
+            definition: ORDER_WORKFLOW.to_string(),
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Step Functions State Machine (order-workflow)"));
+        assert!(md.contains("| Type | STANDARD |"));
+        assert!(md.contains("| Logging | ERROR |"));
+        assert!(md.contains("| Include Execution Data | Disabled |"));
+        assert!(md.contains("| X-Ray Tracing | Enabled |"));
+        assert!(md.contains("### Workflow Diagram\n\n```mermaid"));
+        assert!(md.contains("### Definition\n\n```json\n{\n  \"StartAt\": \"Validate\""));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::aws_cli::common::{AwsResource, format_date};
+use crate::aws_cli::provider::AwsProvider;
+use crate::aws_cli::step_functions::StateMachineDetail;
+use aws_sdk_sfn::operation::describe_state_machine::DescribeStateMachineOutput;
+use aws_sdk_sfn::types::StateMachineListItem;
+
+/// List Step Functions state machines
+pub fn list_state_machines(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut resources: Vec<AwsResource> = provider
+        .list_state_machines()
+        .iter()
+        .map(map_state_machine_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get Step Functions state machine detail by ARN
+pub fn get_state_machine_detail(
+    provider: &dyn AwsProvider,
+    state_machine_arn: &str,
+) -> Option<StateMachineDetail> {
+    provider
+        .describe_state_machine(state_machine_arn)
+        .map(|output| map_state_machine_detail(&output))
+}
+
+fn map_state_machine_resource(machine: &StateMachineListItem) -> AwsResource {
+    AwsResource {
+        name: machine.name().to_string(),
+        id: machine.state_machine_arn().to_string(),
+        state: machine.r#type().as_str().to_string(),
+        az: String::new(),
+        cidr: format_date(Some(machine.creation_date())),
+        region: None,
+    }
+}
+
+fn map_state_machine_detail(output: &DescribeStateMachineOutput) -> StateMachineDetail {
+    let logging = output.logging_configuration();
+    StateMachineDetail {
+        name: output.name().to_string(),
+        arn: output.state_machine_arn().to_string(),
+        machine_type: output.r#type().as_str().to_string(),
+        status: output
+            .status()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        description: output.description().unwrap_or_default().to_string(),
+        role_arn: output.role_arn().to_string(),
+        logging_level: logging
+            .and_then(|l| l.level())
+            .map(|level| level.as_str().to_string())
+            .unwrap_or_else(|| "OFF".to_string()),
+        include_execution_data: logging.is_some_and(|l| l.include_execution_data()),
+        log_destinations: logging
+            .map(|l| {
+                l.destinations()
+                    .iter()
+                    .filter_map(|dest| dest.cloud_watch_logs_log_group())
+                    .filter_map(|group| group.log_group_arn())
+                    .map(|arn| arn.to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        tracing_enabled: output.tracing_configuration().is_some_and(|t| t.enabled()),
+        creation_date: format_date(Some(output.creation_date())),
+        definition: output.definition().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_state_machine_detail, map_state_machine_resource};
+    use aws_sdk_sfn::operation::describe_state_machine::DescribeStateMachineOutput;
+    use aws_sdk_sfn::primitives::DateTime;
+    use aws_sdk_sfn::types::{
+        CloudWatchLogsLogGroup, LogDestination, LogLevel, LoggingConfiguration,
+        StateMachineListItem, StateMachineStatus, StateMachineType, TracingConfiguration,
+    };
+
+    const ARN: &str = "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow";
+
+    fn describe_output()
+    -> aws_sdk_sfn::operation::describe_state_machine::builders::DescribeStateMachineOutputBuilder
+    {
+        DescribeStateMachineOutput::builder()
+            .state_machine_arn(ARN)
+            .name("order-workflow")
+            .definition(r#"{"StartAt":"A","States":{"A":{"Type":"Pass","End":true}}}"#)
+            .role_arn("arn:aws:iam::123456789012:role/sfn-orders")
+            .r#type(StateMachineType::Express)
+            .creation_date(DateTime::from_secs(1_767_225_600))
+    }
+
+    #[test]
+    fn map_state_machine_resource_shows_type_and_creation_date() {
+        let item = StateMachineListItem::builder()
+            .state_machine_arn(ARN)
+            .name("order-workflow")
+            .r#type(StateMachineType::Standard)
+            .creation_date(DateTime::from_secs(1_767_225_600))
+            .build()
+            .expect("state machine list item");
+        let resource = map_state_machine_resource(&item);
+        assert_eq!(resource.id, ARN);
+        assert_eq!(resource.state, "STANDARD");
+        assert_eq!(resource.cidr, "2026-01-01");
+    }
+
+    #[test]
+    fn map_state_machine_detail_reads_logging_and_tracing() {
+        let output = describe_output()
+            .status(StateMachineStatus::Active)
+            .logging_configuration(
+                LoggingConfiguration::builder()
+                    .level(LogLevel::All)
+                    .include_execution_data(true)
+                    .destinations(
+                        LogDestination::builder()
+                            .cloud_watch_logs_log_group(
+                                CloudWatchLogsLogGroup::builder()
+                                    .log_group_arn(
+                                        "arn:aws:logs:ap-northeast-2:123456789012:log-group:/sfn/orders:*",
+                                    )
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .build(),
+            )
+            .tracing_configuration(TracingConfiguration::builder().enabled(true).build())
+            .build()
+            .expect("describe output");
+
+        let detail = map_state_machine_detail(&output);
+        assert_eq!(detail.machine_type, "EXPRESS");
+        assert_eq!(detail.status, "ACTIVE");
+        assert_eq!(detail.logging_level, "ALL");
+        assert!(detail.include_execution_data);
+        assert_eq!(detail.log_destinations.len(), 1);
+        assert!(detail.tracing_enabled);
+        assert_eq!(detail.creation_date, "2026-01-01");
+    }
+
+    #[test]
+    fn map_state_machine_detail_defaults_logging_off() {
+        let output = describe_output().build().expect("describe output");
+        let detail = map_state_machine_detail(&output);
+        assert_eq!(detail.logging_level, "OFF");
+        assert!(!detail.include_execution_data);
+        assert!(detail.log_destinations.is_empty());
+        assert!(!detail.tracing_enabled);
+    }
+}
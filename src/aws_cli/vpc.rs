@@ -1,148 +1,13 @@
-use crate::aws_cli::common::{
-    AwsResource, Tag, extract_json_value, extract_tags, parse_name_tag, parse_resources_from_json,
-};
+use crate::aws_cli::common::{AwsResource, ec2_name_tag, ec2_tags, map_concurrent};
+use crate::aws_cli::ec2::get_subnet_name;
+use crate::aws_cli::provider::{AwsProvider, Ec2Filter};
 use crate::i18n::{I18n, Language};
-use serde::Deserialize;
+use aws_sdk_ec2::types::{
+    Address, AttachmentStatus, InternetGateway, NatGateway, NatGatewayState, RouteTable,
+    VpcAttributeName,
+};
 use std::collections::HashMap;
 
-#[cfg(not(test))]
-mod cli_adapter {
-    pub(super) fn run(args: &[&str]) -> Option<String> {
-        crate::aws_cli::common::run_aws_cli(args)
-    }
-}
-
-#[cfg(test)]
-mod cli_adapter {
-    use std::collections::HashMap;
-    use std::sync::{Mutex, OnceLock};
-
-    static RESPONSES: OnceLock<Mutex<HashMap<String, Option<String>>>> = OnceLock::new();
-
-    fn key(args: &[&str]) -> String {
-        args.join("\x1f")
-    }
-
-    pub(super) fn run(args: &[&str]) -> Option<String> {
-        RESPONSES
-            .get_or_init(|| Mutex::new(HashMap::new()))
-            .lock()
-            .ok()
-            .and_then(|map| map.get(&key(args)).cloned().flatten())
-    }
-
-    pub(super) fn set(args: &[&str], output: Option<&str>) {
-        if let Ok(mut map) = RESPONSES.get_or_init(|| Mutex::new(HashMap::new())).lock() {
-            map.insert(key(args), output.map(str::to_string));
-        }
-    }
-
-    pub(super) fn clear() {
-        if let Ok(mut map) = RESPONSES.get_or_init(|| Mutex::new(HashMap::new())).lock() {
-            map.clear();
-        }
-    }
-}
-
-#[cfg(not(test))]
-mod subnet_adapter {
-    pub(super) fn get(subnet_id: &str) -> String {
-        crate::aws_cli::ec2::get_subnet_name(subnet_id)
-    }
-}
-
-#[cfg(test)]
-mod subnet_adapter {
-    use std::collections::HashMap;
-    use std::sync::{Mutex, OnceLock};
-
-    static RESPONSES: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
-
-    pub(super) fn get(subnet_id: &str) -> String {
-        RESPONSES
-            .get_or_init(|| Mutex::new(HashMap::new()))
-            .lock()
-            .ok()
-            .and_then(|map| map.get(subnet_id).cloned())
-            .unwrap_or_else(|| subnet_id.to_string())
-    }
-
-    pub(super) fn set(subnet_id: &str, subnet_name: &str) {
-        if let Ok(mut map) = RESPONSES.get_or_init(|| Mutex::new(HashMap::new())).lock() {
-            map.insert(subnet_id.to_string(), subnet_name.to_string());
-        }
-    }
-
-    pub(super) fn clear() {
-        if let Ok(mut map) = RESPONSES.get_or_init(|| Mutex::new(HashMap::new())).lock() {
-            map.clear();
-        }
-    }
-}
-
-// Serde structures
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct SubnetResponse {
-    subnets: Vec<Subnet>,
-}
-
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct Subnet {
-    subnet_id: String,
-    vpc_id: String,
-    cidr_block: String,
-    availability_zone: String,
-    state: String,
-    #[serde(default)]
-    map_public_ip_on_launch: bool,
-    #[serde(default)]
-    available_ip_address_count: i32,
-    #[serde(default)]
-    tags: Vec<Tag>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct NatGatewayResponse {
-    nat_gateways: Vec<NatGateway>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct NatGateway {
-    nat_gateway_id: String,
-    #[serde(default)]
-    subnet_id: String,
-    state: String,
-    #[serde(default)]
-    connectivity_type: String,
-    #[serde(default)]
-    availability_mode: String,
-    #[serde(default)]
-    auto_scaling_ips: String,
-    #[serde(default)]
-    auto_provision_zones: String,
-    #[serde(default)]
-    nat_gateway_addresses: Vec<NatGatewayAddress>,
-    #[serde(default)]
-    tags: Vec<Tag>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct NatGatewayAddress {
-    #[serde(default)]
-    allocation_id: String,
-    #[serde(default)]
-    public_ip: String,
-    #[allow(dead_code)]
-    #[serde(default)]
-    private_ip: String,
-}
-
 // Detail structures
 #[derive(Debug, Clone, PartialEq)]
 #[allow(dead_code)]
@@ -194,7 +59,73 @@ pub struct NetworkDetail {
     pub tags: Vec<(String, String)>,
 }
 
+/// VPC 상세를 구성하는 하위 조회. 서로 독립적이라 동시에 조회할 수 있다
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkPart {
+    Subnets,
+    InternetGateways,
+    NatGateways,
+    RouteTables,
+    Eips,
+    DnsAttributes,
+}
+
+impl NetworkPart {
+    pub const ALL: [NetworkPart; 6] = [
+        NetworkPart::Subnets,
+        NetworkPart::InternetGateways,
+        NetworkPart::NatGateways,
+        NetworkPart::RouteTables,
+        NetworkPart::Eips,
+        NetworkPart::DnsAttributes,
+    ];
+}
+
+/// 하위 조회 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetworkPartData {
+    Subnets(Vec<AwsResource>),
+    InternetGateways(Vec<AwsResource>),
+    NatGateways(Vec<NatDetail>),
+    RouteTables(Vec<RouteTableDetail>),
+    Eips(Vec<EipDetail>),
+    DnsAttributes { support: bool, hostnames: bool },
+}
+
 impl NetworkDetail {
+    /// 기본 정보만 채운 상세 (하위 목록은 `apply_part`로 채움)
+    pub fn from_info(vpc_id: &str, info: VpcInfo) -> Self {
+        let (name, cidr, state, tags) = info;
+        Self {
+            name,
+            id: vpc_id.to_string(),
+            cidr,
+            state,
+            subnets: Vec::new(),
+            igws: Vec::new(),
+            nats: Vec::new(),
+            route_tables: Vec::new(),
+            eips: Vec::new(),
+            dns_support: false,
+            dns_hostnames: false,
+            tags,
+        }
+    }
+
+    pub fn apply_part(&mut self, data: NetworkPartData) {
+        match data {
+            NetworkPartData::Subnets(subnets) => self.subnets = subnets,
+            NetworkPartData::InternetGateways(igws) => self.igws = igws,
+            NetworkPartData::NatGateways(nats) => self.nats = nats,
+            NetworkPartData::RouteTables(route_tables) => self.route_tables = route_tables,
+            NetworkPartData::Eips(eips) => self.eips = eips,
+            NetworkPartData::DnsAttributes { support, hostnames } => {
+                self.dns_support = support;
+                self.dns_hostnames = hostnames;
+            }
+        }
+    }
+
     pub fn to_markdown(&self, lang: Language) -> String {
         let i18n = I18n::new(lang);
         let vpc_display = if self.name.is_empty() || self.name == self.id {
@@ -575,676 +506,306 @@ impl NetworkDetail {
 }
 
 // Public functions
-pub fn list_vpcs() -> Vec<AwsResource> {
-    let command = [
-        "ec2",
-        "describe-vpcs",
-        "--query",
-        "Vpcs[*].[VpcId,Tags]",
-        "--output",
-        "json",
-    ];
-    let output = match cli_adapter::run(&command) {
-        Some(o) => o,
-        None => {
-            tracing::warn!(command = %command.join(" "), "list_vpcs: no output from aws adapter");
-            return Vec::new();
-        }
-    };
-    tracing::debug!(
-        command = %command.join(" "),
-        bytes = output.len(),
-        "list_vpcs: aws adapter response"
-    );
-
-    parse_resources_from_json(&output, "vpc-")
-}
-
-pub fn list_subnets(vpc_id: &str) -> Vec<AwsResource> {
-    let command = ["ec2", "describe-subnets", "--output", "json"];
-    let output = match cli_adapter::run(&command) {
-        Some(o) => o,
-        None => {
-            tracing::warn!(
-                vpc_id,
-                command = %command.join(" "),
-                "list_subnets: no output from aws adapter"
-            );
-            return Vec::new();
-        }
-    };
-    tracing::debug!(
-        vpc_id,
-        command = %command.join(" "),
-        bytes = output.len(),
-        "list_subnets: aws adapter response"
-    );
-
-    parse_subnets_output(&output, vpc_id)
-}
-
-fn parse_subnets_output(output: &str, vpc_id: &str) -> Vec<AwsResource> {
-    let response: SubnetResponse = match serde_json::from_str(output) {
-        Ok(r) => r,
-        Err(_) => return Vec::new(),
-    };
-
-    response
-        .subnets
-        .into_iter()
-        .filter(|s| s.vpc_id == vpc_id)
-        .map(|s| {
-            let name = s
-                .tags
-                .iter()
-                .find(|t| t.key == "Name")
-                .map(|t| t.value.clone())
-                .unwrap_or_default();
-
-            AwsResource {
-                name,
-                id: s.subnet_id,
-                state: s.state,
-                az: s.availability_zone,
-                cidr: s.cidr_block,
-            }
+pub fn list_vpcs(provider: &dyn AwsProvider) -> Vec<AwsResource> {
+    let mut vpcs = provider.describe_vpcs(&Ec2Filter::all());
+    vpcs.sort_by(|a, b| a.vpc_id().cmp(&b.vpc_id()));
+    vpcs.iter()
+        .map(|vpc| AwsResource {
+            name: ec2_name_tag(vpc.tags()),
+            id: vpc.vpc_id().unwrap_or_default().to_string(),
+            state: String::new(),
+            az: String::new(),
+            cidr: String::new(),
+            region: None,
         })
         .collect()
 }
 
-pub fn list_internet_gateways(vpc_id: &str) -> Vec<AwsResource> {
-    let filter = format!("Name=attachment.vpc-id,Values={}", vpc_id);
-    let command = [
-        "ec2",
-        "describe-internet-gateways",
-        "--filters",
-        &filter,
-        "--query",
-        "InternetGateways[*].[InternetGatewayId,Tags,Attachments]",
-        "--output",
-        "json",
-    ];
-    let output = match cli_adapter::run(&command) {
-        Some(o) => o,
-        None => {
-            tracing::warn!(
-                vpc_id,
-                command = %command.join(" "),
-                filter = %filter,
-                "list_internet_gateways: no output from aws adapter"
-            );
-            return Vec::new();
-        }
-    };
-    tracing::debug!(
-        vpc_id,
-        command = %command.join(" "),
-        bytes = output.len(),
-        "list_internet_gateways: aws adapter response"
-    );
-
-    parse_internet_gateways(&output)
-}
-
-fn parse_internet_gateways(json: &str) -> Vec<AwsResource> {
-    let mut resources = Vec::new();
-    let mut search_start = 0;
-
-    while let Some(pos) = json[search_start..].find("igw-") {
-        let start = search_start + pos;
-        if let Some(end) = json[start..].find('"') {
-            let id = &json[start..start + end];
-            if !id.starts_with("igw-") {
-                search_start = start + end;
-                continue;
-            }
-
-            let tag_start_offset = json[start..].find('[').unwrap_or_default();
-            let tag_start = start + tag_start_offset;
-            let tag_end_offset = json[tag_start..].find(']').unwrap_or_default();
-            let tag_end = tag_start + tag_end_offset + 1;
-            let tags_json = &json[tag_start..tag_end];
-            let name = parse_name_tag(tags_json);
-
-            let att_start_offset = json[tag_end..].find('[').unwrap_or_default();
-            let att_start = tag_end + att_start_offset;
-            let att_end_offset = json[att_start..].find(']').unwrap_or_default();
-            let att_end = att_start + att_end_offset + 1;
-            let att_json = &json[att_start..att_end];
-
-            let state = if att_json.contains("\"State\": \"available\"")
-                || att_json.contains("\"State\": \"attached\"")
-                || att_json.contains("vpc-")
-            {
-                "attached".to_string()
-            } else {
-                "detached".to_string()
-            };
-
-            resources.push(AwsResource {
-                name,
-                id: id.to_string(),
-                state,
-                az: String::new(),
-                cidr: String::new(),
-            });
-
-            search_start = att_end;
-        } else {
-            break;
-        }
-    }
-    resources
-}
-
-pub fn list_nat_gateways(vpc_id: &str) -> Vec<NatDetail> {
-    let filter = format!("Name=vpc-id,Values={}", vpc_id);
-    let command = [
-        "ec2",
-        "describe-nat-gateways",
-        "--filter",
-        &filter,
-        "--output",
-        "json",
-    ];
-    let output = match cli_adapter::run(&command) {
-        Some(o) => o,
-        None => {
-            tracing::warn!(
-                vpc_id,
-                command = %command.join(" "),
-                filter = %filter,
-                "list_nat_gateways: no output from aws adapter"
-            );
-            return Vec::new();
-        }
-    };
-    tracing::debug!(
-        vpc_id,
-        command = %command.join(" "),
-        bytes = output.len(),
-        "list_nat_gateways: aws adapter response"
-    );
-
-    parse_nat_gateways_output(&output)
-}
-
-fn parse_nat_gateways_output(output: &str) -> Vec<NatDetail> {
-    let response: NatGatewayResponse = match serde_json::from_str(output) {
-        Ok(r) => r,
-        Err(_) => return Vec::new(),
-    };
-
-    response
-        .nat_gateways
-        .into_iter()
-        .filter(|nat| nat.state != "deleted")
-        .map(|nat| {
-            let name = nat
-                .tags
-                .iter()
-                .find(|t| t.key == "Name")
-                .map(|t| t.value.clone())
-                .unwrap_or_default();
-
-            let (public_ip, allocation_id) = nat
-                .nat_gateway_addresses
-                .first()
-                .map(|addr| (addr.public_ip.clone(), addr.allocation_id.clone()))
-                .unwrap_or_default();
-
-            let tags: Vec<(String, String)> = nat
-                .tags
-                .iter()
-                .filter(|t| t.key != "Name")
-                .map(|t| (t.key.clone(), t.value.clone()))
-                .collect();
-
-            NatDetail {
-                name,
-                id: nat.nat_gateway_id,
-                state: nat.state,
-                connectivity_type: nat.connectivity_type,
-                availability_mode: nat.availability_mode,
-                auto_scaling_ips: nat.auto_scaling_ips,
-                auto_provision_zones: nat.auto_provision_zones,
-                public_ip,
-                allocation_id,
-                subnet_id: nat.subnet_id,
-                tags,
-            }
+pub fn list_subnets(provider: &dyn AwsProvider, vpc_id: &str) -> Vec<AwsResource> {
+    let mut subnets = provider.describe_subnets(&Ec2Filter::vpc(vpc_id));
+    subnets.sort_by(|a, b| a.subnet_id().cmp(&b.subnet_id()));
+    subnets
+        .iter()
+        .map(|subnet| AwsResource {
+            name: ec2_name_tag(subnet.tags()),
+            id: subnet.subnet_id().unwrap_or_default().to_string(),
+            state: subnet
+                .state()
+                .map(|state| state.as_str().to_string())
+                .unwrap_or_default(),
+            az: subnet.availability_zone().unwrap_or_default().to_string(),
+            cidr: subnet.cidr_block().unwrap_or_default().to_string(),
+            region: None,
         })
         .collect()
 }
 
-pub fn list_route_tables(vpc_id: &str) -> Vec<RouteTableDetail> {
-    let filter = format!("Name=vpc-id,Values={}", vpc_id);
-    let command = [
-        "ec2",
-        "describe-route-tables",
-        "--filters",
-        &filter,
-        "--query",
-        "RouteTables[*].[RouteTableId,Tags,Routes,Associations]",
-        "--output",
-        "json",
-    ];
-    let output = match cli_adapter::run(&command) {
-        Some(o) => o,
-        None => {
-            tracing::warn!(
-                vpc_id,
-                command = %command.join(" "),
-                filter = %filter,
-                "list_route_tables: no output from aws adapter"
-            );
-            return Vec::new();
-        }
-    };
-    tracing::debug!(
-        vpc_id,
-        command = %command.join(" "),
-        bytes = output.len(),
-        "list_route_tables: aws adapter response"
-    );
-
-    parse_route_tables(&output)
+pub fn list_internet_gateways(provider: &dyn AwsProvider, vpc_id: &str) -> Vec<AwsResource> {
+    provider
+        .describe_internet_gateways(&Ec2Filter::vpc(vpc_id))
+        .iter()
+        .map(map_internet_gateway)
+        .collect()
 }
 
-pub fn parse_route_tables(json: &str) -> Vec<RouteTableDetail> {
-    let mut details = Vec::new();
-    let mut search_start = 0;
-
-    while let Some(pos) = json[search_start..].find("rtb-") {
-        let start = search_start + pos;
-        if let Some(end) = json[start..].find('"') {
-            let id = &json[start..start + end];
-
-            if !id.starts_with("rtb-") {
-                search_start = start + end;
-                continue;
-            }
-
-            let tag_start_offset = json[start..].find('[').unwrap_or_default();
-            let tag_start = start + tag_start_offset;
-            let tag_end_offset = json[tag_start..].find(']').unwrap_or_default();
-            let tag_end = tag_start + tag_end_offset + 1;
-            let tags_json = &json[tag_start..tag_end];
-            let name = parse_name_tag(tags_json);
-
-            let route_start_offset = json[tag_end..].find('[').unwrap_or_default();
-            let route_start = tag_end + route_start_offset;
-            let route_end_offset = find_balanced_bracket_end(&json[route_start..]).unwrap_or(0);
-            let route_end = route_start + route_end_offset;
-            let routes_json = &json[route_start..route_end];
-            let routes = extract_routes(routes_json);
-
-            let assoc_start_offset = json[route_end..].find('[').unwrap_or_default();
-            let assoc_start = route_end + assoc_start_offset;
-            let assoc_end_offset = find_balanced_bracket_end(&json[assoc_start..]).unwrap_or(0);
-            let assoc_end = assoc_start + assoc_end_offset;
-            let assocs_json = &json[assoc_start..assoc_end];
-            let associations = extract_associations(assocs_json);
-
-            details.push(RouteTableDetail {
-                name,
-                id: id.to_string(),
-                routes,
-                associations,
-            });
-
-            search_start = assoc_end;
-        } else {
-            break;
-        }
+fn map_internet_gateway(igw: &InternetGateway) -> AwsResource {
+    let attached = igw.attachments().iter().any(|attachment| {
+        attachment.vpc_id().is_some_and(|id| !id.is_empty())
+            || matches!(
+                attachment.state(),
+                Some(AttachmentStatus::Attached | AttachmentStatus::Attaching)
+            )
+    });
+
+    AwsResource {
+        name: ec2_name_tag(igw.tags()),
+        id: igw.internet_gateway_id().unwrap_or_default().to_string(),
+        state: if attached { "attached" } else { "detached" }.to_string(),
+        az: String::new(),
+        cidr: String::new(),
+        region: None,
     }
-    details
 }
 
-fn find_balanced_bracket_end(s: &str) -> Option<usize> {
-    let mut depth = 0;
-    for (i, c) in s.char_indices() {
-        if c == '[' {
-            depth += 1;
-        } else if c == ']' {
-            depth -= 1;
-            if depth == 0 {
-                return Some(i + 1);
-            }
-        }
-    }
-    None
+pub fn list_nat_gateways(provider: &dyn AwsProvider, vpc_id: &str) -> Vec<NatDetail> {
+    provider
+        .describe_nat_gateways(&Ec2Filter::vpc(vpc_id))
+        .iter()
+        .filter(|nat| nat.state() != Some(&NatGatewayState::Deleted))
+        .map(map_nat_gateway)
+        .collect()
 }
 
-fn extract_routes(json: &str) -> Vec<String> {
-    let mut routes = Vec::new();
-    let mut search_start = 0;
-
-    while let Some(pos) = json[search_start..].find("\"DestinationCidrBlock\": \"") {
-        let block_start = search_start + pos;
-        let obj_end = if let Some(end) = json[block_start..].find('}') {
-            block_start + end
-        } else {
-            json.len()
-        };
-
-        let section = &json[block_start..obj_end];
-
-        let dest = extract_json_value(section, "DestinationCidrBlock").unwrap_or_default();
-        let gateway = extract_json_value(section, "GatewayId").unwrap_or_default();
-        let nat = extract_json_value(section, "NatGatewayId").unwrap_or_default();
-        let target = if !gateway.is_empty() {
-            gateway
-        } else if !nat.is_empty() {
-            nat
-        } else {
-            "local".to_string()
-        };
-        let state = extract_json_value(section, "State").unwrap_or_else(|| "active".to_string());
-
-        // Format: "dest|target|state"
-        routes.push(format!("{}|{}|{}", dest, target, state));
+fn map_nat_gateway(nat: &NatGateway) -> NatDetail {
+    let (public_ip, allocation_id) = nat
+        .nat_gateway_addresses()
+        .first()
+        .map(|addr| {
+            (
+                addr.public_ip().unwrap_or_default().to_string(),
+                addr.allocation_id().unwrap_or_default().to_string(),
+            )
+        })
+        .unwrap_or_default();
 
-        search_start = obj_end;
+    let tags: Vec<(String, String)> = ec2_tags(nat.tags())
+        .into_iter()
+        .filter(|(k, _)| k != "Name")
+        .collect();
+
+    NatDetail {
+        name: ec2_name_tag(nat.tags()),
+        id: nat.nat_gateway_id().unwrap_or_default().to_string(),
+        state: nat
+            .state()
+            .map(|s| s.as_str())
+            .unwrap_or("unknown")
+            .to_string(),
+        connectivity_type: nat
+            .connectivity_type()
+            .map(|v| v.as_str().to_string())
+            .unwrap_or_default(),
+        availability_mode: nat
+            .availability_mode()
+            .map(|v| v.as_str().to_string())
+            .unwrap_or_default(),
+        auto_scaling_ips: nat
+            .auto_scaling_ips()
+            .map(|v| v.as_str().to_string())
+            .unwrap_or_default(),
+        auto_provision_zones: nat
+            .auto_provision_zones()
+            .map(|v| v.as_str().to_string())
+            .unwrap_or_default(),
+        public_ip,
+        allocation_id,
+        subnet_id: nat.subnet_id().unwrap_or_default().to_string(),
+        tags,
     }
-    routes
 }
 
-fn extract_associations(json: &str) -> Vec<String> {
-    let mut assocs = Vec::new();
-    let mut search_start = 0;
-
-    while let Some(pos) = json[search_start..].find("\"SubnetId\": \"") {
-        let block_start = search_start + pos;
-        let obj_end = if let Some(end) = json[block_start..].find('}') {
-            block_start + end
-        } else {
-            json.len()
-        };
+pub fn list_route_tables(provider: &dyn AwsProvider, vpc_id: &str) -> Vec<RouteTableDetail> {
+    let route_tables = provider.describe_route_tables(&Ec2Filter::vpc(vpc_id));
+    let mut subnet_names: HashMap<String, String> = HashMap::new();
 
-        let section = &json[block_start..obj_end];
-        let subnet_id = extract_json_value(section, "SubnetId").unwrap_or_default();
+    route_tables
+        .iter()
+        .map(|rt| {
+            map_route_table(rt, |subnet_id| {
+                subnet_names
+                    .entry(subnet_id.to_string())
+                    .or_insert_with(|| get_subnet_name(provider, subnet_id))
+                    .clone()
+            })
+        })
+        .collect()
+}
 
-        let name = subnet_adapter::get(&subnet_id);
-        assocs.push(format!("{} ({})", name, subnet_id));
+fn map_route_table(
+    rt: &RouteTable,
+    mut subnet_name: impl FnMut(&str) -> String,
+) -> RouteTableDetail {
+    // Format: "dest|target|state"
+    let routes = rt
+        .routes()
+        .iter()
+        .filter_map(|route| {
+            let dest = route.destination_cidr_block()?;
+            let target = route
+                .gateway_id()
+                .filter(|id| !id.is_empty())
+                .or_else(|| route.nat_gateway_id().filter(|id| !id.is_empty()))
+                .unwrap_or("local");
+            let state = route.state().map(|s| s.as_str()).unwrap_or("active");
+            Some(format!("{}|{}|{}", dest, target, state))
+        })
+        .collect();
 
-        search_start = obj_end;
+    let associations = rt
+        .associations()
+        .iter()
+        .filter_map(|association| association.subnet_id())
+        .map(|subnet_id| format!("{} ({})", subnet_name(subnet_id), subnet_id))
+        .collect();
+
+    RouteTableDetail {
+        name: ec2_name_tag(rt.tags()),
+        id: rt.route_table_id().unwrap_or_default().to_string(),
+        routes,
+        associations,
     }
-    assocs
 }
 
-pub fn list_eips() -> Vec<EipDetail> {
-    let command = [
-        "ec2",
-        "describe-addresses",
-        "--query",
-        "Addresses[*].{AllocationId:AllocationId, PublicIp:PublicIp, AssociationId:AssociationId, InstanceId:InstanceId, PrivateIpAddress:PrivateIpAddress, Tags:Tags}",
-        "--output",
-        "json",
-    ];
-    let output = match cli_adapter::run(&command) {
-        Some(o) => o,
-        None => {
-            tracing::warn!(
-                command = %command.join(" "),
-                "list_eips: no output from aws adapter"
-            );
-            return Vec::new();
-        }
-    };
-    tracing::debug!(command = %command.join(" "), bytes = output.len(), "list_eips: aws adapter response");
-
-    parse_eips_output(&output)
+pub fn list_eips(provider: &dyn AwsProvider) -> Vec<EipDetail> {
+    provider.describe_addresses().iter().map(map_eip).collect()
 }
 
-fn parse_eips_output(json: &str) -> Vec<EipDetail> {
-    let mut details = Vec::new();
-    let mut search_start = 0;
-
-    while let Some(pos) = json[search_start..].find("AllocationId") {
-        let block_start = search_start + pos;
-        let obj_start = json[..block_start].rfind('{').unwrap_or(0);
-
-        let mut depth = 0;
-        let mut obj_end = json.len();
-        for (i, c) in json[obj_start..].char_indices() {
-            if c == '{' {
-                depth += 1;
-            } else if c == '}' {
-                depth -= 1;
-                if depth == 0 {
-                    obj_end = obj_start + i + 1;
-                    break;
-                }
-            }
-        }
-
-        let section = &json[obj_start..obj_end];
+fn map_eip(address: &Address) -> EipDetail {
+    let public_ip = address.public_ip().unwrap_or_default().to_string();
+    let name = ec2_name_tag(address.tags());
 
-        let public_ip = extract_json_value(section, "PublicIp").unwrap_or_default();
-        let instance_id = extract_json_value(section, "InstanceId").unwrap_or_default();
-        let private_ip = extract_json_value(section, "PrivateIpAddress").unwrap_or_default();
-
-        let name = parse_name_tag(section);
-        let display_name = if name.is_empty() {
+    EipDetail {
+        name: if name.is_empty() {
             public_ip.clone()
         } else {
             name
-        };
-
-        details.push(EipDetail {
-            name: display_name,
-            public_ip,
-            instance_id,
-            private_ip,
-        });
-
-        search_start = obj_end;
+        },
+        public_ip,
+        instance_id: address.instance_id().unwrap_or_default().to_string(),
+        private_ip: address.private_ip_address().unwrap_or_default().to_string(),
     }
-
-    details
 }
 
-pub fn get_network_detail(vpc_id: &str) -> Option<NetworkDetail> {
-    let command = [
-        "ec2",
-        "describe-vpcs",
-        "--vpc-ids",
-        vpc_id,
-        "--output",
-        "json",
-    ];
-    let output = match cli_adapter::run(&command) {
-        Some(output) => output,
-        None => {
-            tracing::warn!(
-                vpc_id,
-                command = %command.join(" "),
-                "get_network_detail: no output from aws adapter"
-            );
-            return None;
-        }
-    };
-    tracing::debug!(
-        vpc_id,
-        command = %command.join(" "),
-        bytes = output.len(),
-        "get_network_detail: aws adapter response"
-    );
-
-    let json = &output;
-    let cidr = extract_json_value(json, "CidrBlock").unwrap_or_default();
-    let state = extract_state(json);
-    let tags = extract_tags(json);
-
-    let name = tags
-        .iter()
-        .find(|(k, _)| k == "Name")
-        .map(|(_, v)| v.clone())
-        .unwrap_or_else(|| vpc_id.to_string());
-
-    let subnets = list_subnets(vpc_id);
-    let igws = list_internet_gateways(vpc_id);
-    let nats = list_nat_gateways(vpc_id);
-    let route_tables = list_route_tables(vpc_id);
-    let eips = list_eips();
-
-    let dns_support = get_vpc_attribute(vpc_id, "enableDnsSupport");
-    let dns_hostnames = get_vpc_attribute(vpc_id, "enableDnsHostnames");
-
-    Some(NetworkDetail {
-        name,
-        id: vpc_id.to_string(),
-        cidr,
-        state,
-        subnets,
-        igws,
-        nats,
-        route_tables,
-        eips,
-        dns_support,
-        dns_hostnames,
-        tags,
-    })
-}
-
-fn extract_state(json: &str) -> String {
-    for state in ["available", "pending", "deleting", "deleted"] {
-        if json.contains(&format!("\"State\": \"{}\"", state)) {
-            return state.to_string();
-        }
+pub fn get_network_detail(provider: &dyn AwsProvider, vpc_id: &str) -> Option<NetworkDetail> {
+    let info = get_vpc_info(provider, vpc_id)?;
+    let mut detail = NetworkDetail::from_info(vpc_id, info);
+    for data in map_concurrent(&NetworkPart::ALL, |part| {
+        fetch_network_part(provider, vpc_id, *part)
+    }) {
+        detail.apply_part(data);
     }
-    "unknown".to_string()
+    Some(detail)
 }
 
-fn get_vpc_attribute(vpc_id: &str, attribute: &str) -> bool {
-    let command = [
-        "ec2",
-        "describe-vpc-attribute",
-        "--vpc-id",
-        vpc_id,
-        "--attribute",
-        attribute,
-        "--output",
-        "json",
-    ];
-    let output = match cli_adapter::run(&command) {
-        Some(json) => {
-            tracing::debug!(
-                vpc_id,
-                attribute,
-                command = %command.join(" "),
-                bytes = json.len(),
-                "get_vpc_attribute: aws adapter response"
-            );
-            json
+pub fn fetch_network_part(
+    provider: &dyn AwsProvider,
+    vpc_id: &str,
+    part: NetworkPart,
+) -> NetworkPartData {
+    match part {
+        NetworkPart::Subnets => NetworkPartData::Subnets(list_subnets(provider, vpc_id)),
+        NetworkPart::InternetGateways => {
+            NetworkPartData::InternetGateways(list_internet_gateways(provider, vpc_id))
         }
-        None => {
-            tracing::warn!(
-                vpc_id,
-                attribute,
-                command = %command.join(" "),
-                "get_vpc_attribute: no output from aws adapter"
-            );
-            return false;
+        NetworkPart::NatGateways => {
+            NetworkPartData::NatGateways(list_nat_gateways(provider, vpc_id))
         }
-    };
-
-    parse_vpc_attribute_response(&output, attribute)
-}
-
-fn parse_vpc_attribute_response(json: &str, attribute: &str) -> bool {
-    let key = if attribute == "enableDnsSupport" {
-        "EnableDnsSupport"
-    } else {
-        "EnableDnsHostnames"
-    };
-    if let Some(pos) = json.find(key) {
-        let section = &json[pos..];
-        if let Some(val_pos) = section.find("\"Value\": ") {
-            let val_start = val_pos + 9;
-            if section[val_start..].starts_with("true") {
-                return true;
-            }
+        NetworkPart::RouteTables => {
+            NetworkPartData::RouteTables(list_route_tables(provider, vpc_id))
         }
+        NetworkPart::Eips => NetworkPartData::Eips(list_eips(provider)),
+        NetworkPart::DnsAttributes => NetworkPartData::DnsAttributes {
+            support: get_vpc_dns_support(provider, vpc_id),
+            hostnames: get_vpc_dns_hostnames(provider, vpc_id),
+        },
     }
-    false
 }
 
 /// VPC 기본 정보만 조회 (name, cidr, state, tags)
-type VpcInfo = (String, String, String, Vec<(String, String)>);
-
-pub fn get_vpc_info(vpc_id: &str) -> Option<VpcInfo> {
-    let command = [
-        "ec2",
-        "describe-vpcs",
-        "--vpc-ids",
-        vpc_id,
-        "--output",
-        "json",
-    ];
-    let output = match cli_adapter::run(&command) {
-        Some(output) => output,
-        None => {
-            tracing::warn!(
-                vpc_id,
-                command = %command.join(" "),
-                "get_vpc_info: no output from aws adapter"
-            );
-            return None;
-        }
-    };
-    tracing::debug!(
-        vpc_id,
-        command = %command.join(" "),
-        bytes = output.len(),
-        "get_vpc_info: aws adapter response"
-    );
-
-    parse_vpc_info_output(&output, vpc_id)
-}
-
-fn parse_vpc_info_output(json: &str, vpc_id: &str) -> Option<VpcInfo> {
-    let cidr = extract_json_value(json, "CidrBlock").unwrap_or_default();
-    let state = extract_state(json);
-    let tags = extract_tags(json);
+pub type VpcInfo = (String, String, String, Vec<(String, String)>);
 
-    let name = tags
-        .iter()
-        .find(|(k, _)| k == "Name")
-        .map(|(_, v)| v.clone())
-        .unwrap_or_else(|| vpc_id.to_string());
+pub fn get_vpc_info(provider: &dyn AwsProvider, vpc_id: &str) -> Option<VpcInfo> {
+    let vpcs = provider.describe_vpcs(&Ec2Filter::id(vpc_id));
+    let vpc = vpcs.first()?;
 
-    Some((name, cidr, state, tags))
+    let name = ec2_name_tag(vpc.tags());
+    let name = if name.is_empty() {
+        vpc_id.to_string()
+    } else {
+        name
+    };
+    let cidr = vpc.cidr_block().unwrap_or_default().to_string();
+    let state = vpc
+        .state()
+        .map(|s| s.as_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    Some((name, cidr, state, ec2_tags(vpc.tags())))
 }
 
-pub fn get_vpc_dns_support(vpc_id: &str) -> bool {
-    get_vpc_attribute(vpc_id, "enableDnsSupport")
+pub fn get_vpc_dns_support(provider: &dyn AwsProvider, vpc_id: &str) -> bool {
+    provider
+        .describe_vpc_attribute(vpc_id, VpcAttributeName::EnableDnsSupport)
+        .unwrap_or(false)
 }
 
-pub fn get_vpc_dns_hostnames(vpc_id: &str) -> bool {
-    get_vpc_attribute(vpc_id, "enableDnsHostnames")
+pub fn get_vpc_dns_hostnames(provider: &dyn AwsProvider, vpc_id: &str) -> bool {
+    provider
+        .describe_vpc_attribute(vpc_id, VpcAttributeName::EnableDnsHostnames)
+        .unwrap_or(false)
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        EipDetail, NatDetail, NetworkDetail, RouteTableDetail, cli_adapter, extract_routes,
-        find_balanced_bracket_end, get_network_detail, list_eips, list_nat_gateways,
-        list_route_tables, list_subnets, list_vpcs, parse_eips_output, parse_internet_gateways,
-        parse_nat_gateways_output, parse_route_tables, parse_subnets_output,
-        parse_vpc_attribute_response, parse_vpc_info_output, subnet_adapter,
+        EipDetail, NatDetail, NetworkDetail, RouteTableDetail, get_network_detail, get_vpc_info,
+        list_eips, list_internet_gateways, list_nat_gateways, list_route_tables, list_subnets,
+        list_vpcs, map_eip, map_internet_gateway, map_nat_gateway, map_route_table,
     };
     use crate::aws_cli::common::AwsResource;
+    use crate::aws_cli::fake_provider::FakeProvider;
     use crate::i18n::Language;
-    use std::sync::{Mutex, OnceLock};
+    use aws_sdk_ec2::types::{
+        Address, AttachmentStatus, InternetGateway, InternetGatewayAttachment, NatGateway,
+        NatGatewayAddress, NatGatewayState, Route, RouteState, RouteTable, RouteTableAssociation,
+        Subnet, SubnetState, Tag, Vpc, VpcAttributeName, VpcState,
+    };
 
-    fn test_lock() -> std::sync::MutexGuard<'static, ()> {
-        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-        match LOCK.get_or_init(|| Mutex::new(())).lock() {
-            Ok(guard) => guard,
-            Err(poisoned) => poisoned.into_inner(),
-        }
+    fn tag(key: &str, value: &str) -> Tag {
+        Tag::builder().key(key).value(value).build()
+    }
+
+    fn route(dest: &str, gateway: Option<&str>, nat: Option<&str>) -> Route {
+        Route::builder()
+            .destination_cidr_block(dest)
+            .set_gateway_id(gateway.map(str::to_string))
+            .set_nat_gateway_id(nat.map(str::to_string))
+            .state(RouteState::Active)
+            .build()
+    }
+
+    fn attached_igw(id: &str, vpc_id: &str) -> InternetGateway {
+        InternetGateway::builder()
+            .internet_gateway_id(id)
+            .tags(tag("Name", "igw-main"))
+            .attachments(
+                InternetGatewayAttachment::builder()
+                    .vpc_id(vpc_id)
+                    .state(AttachmentStatus::Attached)
+                    .build(),
+            )
+            .build()
     }
 
     fn sample_network_detail() -> NetworkDetail {
@@ -1259,6 +820,7 @@ mod tests {
                 state: "available".to_string(),
                 az: "ap-northeast-2a".to_string(),
                 cidr: "10.0.1.0/24".to_string(),
+                region: None,
             }],
             igws: vec![AwsResource {
                 name: "igw-main".to_string(),
@@ -1266,6 +828,7 @@ mod tests {
                 state: "attached".to_string(),
                 az: String::new(),
                 cidr: String::new(),
+                region: None,
             }],
             nats: vec![NatDetail {
                 name: "nat-main".to_string(),
@@ -1299,43 +862,51 @@ mod tests {
     }
 
     #[test]
-    fn parse_internet_gateways_extracts_id_name_and_state() {
-        let payload = r#"
-            [
-              ["igw-1234", [{"Key":"Name","Value":"igw-main"}], [{"VpcId":"vpc-1111","State":"available"}]],
-              ["igw-5678", [], []]
-            ]
-        "#;
-        let igws = parse_internet_gateways(payload);
-        assert_eq!(igws.len(), 2);
-        assert_eq!(igws[0].id, "igw-1234");
-        assert_eq!(igws[0].state, "attached");
-    }
-
-    #[test]
-    fn route_parsing_helpers_handle_nested_arrays() {
-        assert_eq!(find_balanced_bracket_end("[[]]"), Some(4));
-        assert_eq!(find_balanced_bracket_end("[[]"), None);
-
-        let routes = extract_routes(
-            r#"[{"DestinationCidrBlock": "0.0.0.0/0", "GatewayId": "igw-1234", "State": "active"}]"#,
+    fn map_internet_gateway_reads_attachment_state() {
+        let attached = map_internet_gateway(&attached_igw("igw-1234", "vpc-1111"));
+        assert_eq!(attached.id, "igw-1234");
+        assert_eq!(attached.name, "igw-main");
+        assert_eq!(attached.state, "attached");
+
+        let detached = map_internet_gateway(
+            &InternetGateway::builder()
+                .internet_gateway_id("igw-5678")
+                .build(),
         );
-        assert_eq!(routes, vec!["0.0.0.0/0|igw-1234|active".to_string()]);
+        assert_eq!(detached.state, "detached");
     }
 
     #[test]
-    fn parse_route_tables_extracts_table_id() {
-        let payload = r#"
-            [
-              ["rtb-1234", [{"Key":"Name","Value":"rt-main"}],
-                [{"DestinationCidrBlock":"0.0.0.0/0","GatewayId":"igw-1234","State":"active"}],
-                [{"SubnetId":"subnet-1111"}]
-              ]
+    fn map_route_table_uses_gateway_nat_or_local_target() {
+        let rt = RouteTable::builder()
+            .route_table_id("rtb-1234")
+            .tags(tag("Name", "rt-main"))
+            .routes(route("0.0.0.0/0", Some("igw-1234"), None))
+            .routes(route("10.1.0.0/16", None, Some("nat-1111")))
+            .routes(route("10.0.0.0/16", None, None))
+            .associations(
+                RouteTableAssociation::builder()
+                    .subnet_id("subnet-1111")
+                    .build(),
+            )
+            .associations(RouteTableAssociation::builder().main(true).build())
+            .build();
+
+        let detail = map_route_table(&rt, |subnet_id| format!("name-of-{}", subnet_id));
+        assert_eq!(detail.id, "rtb-1234");
+        assert_eq!(detail.name, "rt-main");
+        assert_eq!(
+            detail.routes,
+            vec![
+                "0.0.0.0/0|igw-1234|active".to_string(),
+                "10.1.0.0/16|nat-1111|active".to_string(),
+                "10.0.0.0/16|local|active".to_string(),
             ]
-        "#;
-        let tables = parse_route_tables(payload);
-        assert_eq!(tables.len(), 1);
-        assert_eq!(tables[0].id, "rtb-1234");
+        );
+        assert_eq!(
+            detail.associations,
+            vec!["name-of-subnet-1111 (subnet-1111)".to_string()]
+        );
     }
 
     #[test]
@@ -1347,54 +918,6 @@ mod tests {
         assert!(md.contains("```mermaid"));
     }
 
-    #[test]
-    fn parse_internet_gateways_marks_detached_when_attachment_is_missing() {
-        let payload = r#"
-            [
-              ["igw-1111", [{"Key":"Name","Value":"igw-a"}], []],
-              ["invalid-id", [], []]
-            ]
-        "#;
-        let igws = parse_internet_gateways(payload);
-        assert_eq!(igws.len(), 1);
-        assert_eq!(igws[0].id, "igw-1111");
-        assert_eq!(igws[0].state, "detached");
-    }
-
-    #[test]
-    fn extract_routes_uses_nat_or_local_when_gateway_is_missing() {
-        let routes = extract_routes(
-            r#"
-            [
-              {"DestinationCidrBlock": "0.0.0.0/0", "NatGatewayId": "nat-1111", "State": "active"},
-              {"DestinationCidrBlock": "10.0.0.0/16", "State": "active"}
-            ]
-            "#,
-        );
-        assert_eq!(routes[0], "0.0.0.0/0|nat-1111|active");
-        assert_eq!(routes[1], "10.0.0.0/16|local|active");
-    }
-
-    #[test]
-    fn parse_route_tables_supports_multiple_tables() {
-        let payload = r#"
-            [
-              ["rtb-1111", [{"Key":"Name","Value":"rt-a"}],
-                [{"DestinationCidrBlock":"0.0.0.0/0","GatewayId":"igw-1","State":"active"}],
-                [{"SubnetId":"subnet-1111"}]
-              ],
-              ["rtb-2222", [{"Key":"Name","Value":"rt-b"}],
-                [{"DestinationCidrBlock":"10.0.0.0/16","State":"active"}],
-                []
-              ]
-            ]
-        "#;
-        let tables = parse_route_tables(payload);
-        assert_eq!(tables.len(), 2);
-        assert_eq!(tables[0].id, "rtb-1111");
-        assert_eq!(tables[1].id, "rtb-2222");
-    }
-
     #[test]
     fn network_markdown_zonal_nat_path_renders_subnet_and_private_assoc() {
         let detail = NetworkDetail {
@@ -1408,6 +931,7 @@ mod tests {
                 state: "available".to_string(),
                 az: "ap-northeast-2a".to_string(),
                 cidr: "10.1.1.0/24".to_string(),
+                region: None,
             }],
             igws: vec![],
             nats: vec![NatDetail {
@@ -1443,315 +967,168 @@ mod tests {
     }
 
     #[test]
-    fn subnet_nat_eip_and_vpc_attribute_parsers_handle_fixture_payloads() {
-        let subnets_payload = r#"
-        {
-          "Subnets": [
-            {
-              "SubnetId": "subnet-a",
-              "VpcId": "vpc-1",
-              "CidrBlock": "10.0.1.0/24",
-              "AvailabilityZone": "ap-northeast-2a",
-              "State": "available",
-              "MapPublicIpOnLaunch": true,
-              "AvailableIpAddressCount": 251,
-              "Tags": [{"Key":"Name","Value":"public-a"}]
-            },
-            {
-              "SubnetId": "subnet-b",
-              "VpcId": "vpc-2",
-              "CidrBlock": "10.1.1.0/24",
-              "AvailabilityZone": "ap-northeast-2b",
-              "State": "available",
-              "Tags": []
-            }
-          ]
-        }
-        "#;
-        let subnets = parse_subnets_output(subnets_payload, "vpc-1");
-        assert_eq!(subnets.len(), 1);
-        assert_eq!(subnets[0].id, "subnet-a");
-        assert_eq!(subnets[0].name, "public-a");
-
-        let nat_payload = r#"
-        {
-          "NatGateways": [
-            {
-              "NatGatewayId": "nat-1",
-              "SubnetId": "subnet-a",
-              "State": "available",
-              "ConnectivityType": "public",
-              "AvailabilityMode": "regional",
-              "AutoScalingIps": "enabled",
-              "AutoProvisionZones": "enabled",
-              "NatGatewayAddresses": [
-                {"AllocationId":"eipalloc-1","PublicIp":"3.3.3.3","PrivateIp":"10.0.1.10"}
-              ],
-              "Tags": [
-                {"Key":"Name","Value":"nat-main"},
-                {"Key":"Env","Value":"prod"}
-              ]
-            },
-            {
-              "NatGatewayId": "nat-del",
-              "State": "deleted",
-              "NatGatewayAddresses": [],
-              "Tags": []
-            }
-          ]
-        }
-        "#;
-        let nats = parse_nat_gateways_output(nat_payload);
-        assert_eq!(nats.len(), 1);
-        assert_eq!(nats[0].id, "nat-1");
-        assert_eq!(nats[0].allocation_id, "eipalloc-1");
-        assert_eq!(nats[0].tags[0], ("Env".to_string(), "prod".to_string()));
-
-        let eip_payload = r#"
-        [
-          {"AllocationId": "eipalloc-1", "PublicIp": "3.3.3.3", "InstanceId": "i-1", "PrivateIpAddress": "10.0.1.10", "Tags": [{"Key": "Name", "Value": "edge-eip"}]},
-          {"AllocationId": "eipalloc-2", "PublicIp": "3.3.3.4", "AssociationId": "eipassoc-2", "Tags": []}
-        ]
-        "#;
-        let eips = parse_eips_output(eip_payload);
-        assert_eq!(eips.len(), 2);
-        assert_eq!(eips[0].name, "edge-eip");
-        assert_eq!(eips[1].name, "3.3.3.4");
-
-        let attr_true = r#"{"EnableDnsSupport":{"Value": true}}"#;
-        let attr_false = r#"{"EnableDnsHostnames":{"Value": false}}"#;
-        assert!(parse_vpc_attribute_response(attr_true, "enableDnsSupport"));
-        assert!(!parse_vpc_attribute_response(
-            attr_false,
-            "enableDnsHostnames"
-        ));
+    fn nat_and_eip_mappers_handle_names_and_defaults() {
+        let nat = map_nat_gateway(
+            &NatGateway::builder()
+                .nat_gateway_id("nat-1")
+                .subnet_id("subnet-a")
+                .state(NatGatewayState::Available)
+                .nat_gateway_addresses(
+                    NatGatewayAddress::builder()
+                        .allocation_id("eipalloc-1")
+                        .public_ip("3.3.3.3")
+                        .build(),
+                )
+                .tags(tag("Name", "nat-main"))
+                .tags(tag("Env", "prod"))
+                .build(),
+        );
+        assert_eq!(nat.name, "nat-main");
+        assert_eq!(nat.state, "available");
+        assert_eq!(nat.allocation_id, "eipalloc-1");
+        assert_eq!(nat.tags, vec![("Env".to_string(), "prod".to_string())]);
+
+        let named = map_eip(
+            &Address::builder()
+                .public_ip("3.3.3.3")
+                .instance_id("i-1")
+                .tags(tag("Name", "edge-eip"))
+                .build(),
+        );
+        let unnamed = map_eip(&Address::builder().public_ip("3.3.3.4").build());
+        assert_eq!(named.name, "edge-eip");
+        assert_eq!(named.instance_id, "i-1");
+        assert_eq!(unnamed.name, "3.3.3.4");
     }
 
     #[test]
-    fn parse_vpc_info_output_prefers_name_tag_and_extracts_defaults() {
-        let payload = r#"
-        {
-          "Vpcs": [
-            {
-              "VpcId": "vpc-abc",
-              "CidrBlock": "10.2.0.0/16",
-              "State": "available",
-              "Tags":[
-                {"Key": "Name", "Value": "core-vpc"},
-                {"Key": "Env", "Value": "prod"}
-              ]
-            }
-          ]
-        }
-        "#;
-        let info = parse_vpc_info_output(payload, "vpc-abc").expect("vpc info");
+    fn get_vpc_info_prefers_name_tag_and_falls_back_to_id() {
+        let provider = FakeProvider {
+            vpcs: vec![
+                Vpc::builder()
+                    .vpc_id("vpc-abc")
+                    .cidr_block("10.2.0.0/16")
+                    .state(VpcState::Available)
+                    .tags(tag("Name", "core-vpc"))
+                    .tags(tag("Env", "prod"))
+                    .build(),
+                Vpc::builder().vpc_id("vpc-untagged").build(),
+            ],
+            ..FakeProvider::default()
+        };
+
+        let info = get_vpc_info(&provider, "vpc-abc").expect("vpc info");
         assert_eq!(info.0, "core-vpc");
         assert_eq!(info.1, "10.2.0.0/16");
         assert_eq!(info.2, "available");
         assert!(info.3.iter().any(|(k, v)| k == "Env" && v == "prod"));
+
+        let untagged = get_vpc_info(&provider, "vpc-untagged").expect("vpc info");
+        assert_eq!(untagged.0, "vpc-untagged");
+        assert_eq!(untagged.2, "unknown");
+        assert!(get_vpc_info(&provider, "vpc-missing").is_none());
     }
 
     #[test]
-    fn top_level_vpc_functions_use_mocked_cli_outputs() {
-        let _guard = test_lock();
-        cli_adapter::clear();
-        subnet_adapter::clear();
-
-        subnet_adapter::set("subnet-1111", "public-a");
-
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-vpcs",
-                "--query",
-                "Vpcs[*].[VpcId,Tags]",
-                "--output",
-                "json",
-            ],
-            Some(r#"[["vpc-1111",[{"Key": "Name", "Value": "main-vpc"}]]]"#),
-        );
-        cli_adapter::set(
-            &["ec2", "describe-subnets", "--output", "json"],
-            Some(
-                r#"
-                {
-                  "Subnets": [
-                    {
-                      "SubnetId": "subnet-1111",
-                      "VpcId": "vpc-1111",
-                      "CidrBlock": "10.0.1.0/24",
-                      "AvailabilityZone": "ap-northeast-2a",
-                      "State": "available",
-                      "Tags": [{"Key":"Name","Value":"public-a"}]
-                    }
-                  ]
-                }
-                "#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-internet-gateways",
-                "--filters",
-                "Name=attachment.vpc-id,Values=vpc-1111",
-                "--query",
-                "InternetGateways[*].[InternetGatewayId,Tags,Attachments]",
-                "--output",
-                "json",
-            ],
-            Some(
-                r#"
-                [
-                  ["igw-1234", [{"Key": "Name", "Value": "igw-main"}], [{"VpcId": "vpc-1111", "State": "available"}]]
-                ]
-                "#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-nat-gateways",
-                "--filter",
-                "Name=vpc-id,Values=vpc-1111",
-                "--output",
-                "json",
-            ],
-            Some(
-                r#"
-                {
-                  "NatGateways": [
-                    {
-                      "NatGatewayId": "nat-1234",
-                      "SubnetId": "subnet-1111",
-                      "State": "available",
-                      "ConnectivityType": "public",
-                      "NatGatewayAddresses": [{"AllocationId": "eipalloc-1234", "PublicIp": "52.0.0.1"}],
-                      "Tags": [{"Key": "Name", "Value": "nat-main"}]
-                    }
-                  ]
-                }
-                "#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-route-tables",
-                "--filters",
-                "Name=vpc-id,Values=vpc-1111",
-                "--query",
-                "RouteTables[*].[RouteTableId,Tags,Routes,Associations]",
-                "--output",
-                "json",
+    fn top_level_vpc_functions_filter_by_vpc_through_provider() {
+        let provider = FakeProvider {
+            vpcs: vec![
+                Vpc::builder()
+                    .vpc_id("vpc-1111")
+                    .cidr_block("10.0.0.0/16")
+                    .state(VpcState::Available)
+                    .tags(tag("Name", "main-vpc"))
+                    .build(),
             ],
-            Some(
-                r#"
-                [
-                  [
-                    "rtb-1234",
-                    [{"Key": "Name", "Value": "rt-main"}],
-                    [{"DestinationCidrBlock": "0.0.0.0/0", "GatewayId": "igw-1234", "State": "active"}],
-                    [{"SubnetId": "subnet-1111"}]
-                  ]
-                ]
-                "#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-addresses",
-                "--query",
-                "Addresses[*].{AllocationId:AllocationId, PublicIp:PublicIp, AssociationId:AssociationId, InstanceId:InstanceId, PrivateIpAddress:PrivateIpAddress, Tags:Tags}",
-                "--output",
-                "json",
+            subnets: vec![
+                Subnet::builder()
+                    .subnet_id("subnet-1111")
+                    .vpc_id("vpc-1111")
+                    .cidr_block("10.0.1.0/24")
+                    .availability_zone("ap-northeast-2a")
+                    .state(SubnetState::Available)
+                    .tags(tag("Name", "public-a"))
+                    .build(),
+                Subnet::builder()
+                    .subnet_id("subnet-2222")
+                    .vpc_id("vpc-2222")
+                    .build(),
             ],
-            Some(
-                r#"
-                [
-                  {"AllocationId": "eipalloc-1234", "PublicIp": "52.0.0.1", "InstanceId": "i-1234", "Tags": [{"Key": "Name", "Value": "edge-eip"}]}
-                ]
-                "#,
-            ),
-        );
-
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-vpcs",
-                "--vpc-ids",
-                "vpc-1111",
-                "--output",
-                "json",
+            internet_gateways: vec![attached_igw("igw-1234", "vpc-1111")],
+            nat_gateways: vec![
+                NatGateway::builder()
+                    .nat_gateway_id("nat-1234")
+                    .vpc_id("vpc-1111")
+                    .state(NatGatewayState::Available)
+                    .build(),
+                NatGateway::builder()
+                    .nat_gateway_id("nat-deleted")
+                    .vpc_id("vpc-1111")
+                    .state(NatGatewayState::Deleted)
+                    .build(),
             ],
-            Some(
-                r#"
-                {
-                  "Vpcs": [
-                    {
-                      "VpcId": "vpc-1111",
-                      "CidrBlock": "10.0.0.0/16",
-                      "State": "available",
-                      "Tags": [{"Key": "Name", "Value": "main-vpc"}]
-                    }
-                  ]
-                }
-                "#,
-            ),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-vpc-attribute",
-                "--vpc-id",
-                "vpc-1111",
-                "--attribute",
-                "enableDnsSupport",
-                "--output",
-                "json",
+            route_tables: vec![
+                RouteTable::builder()
+                    .route_table_id("rtb-1234")
+                    .vpc_id("vpc-1111")
+                    .routes(route("0.0.0.0/0", Some("igw-1234"), None))
+                    .associations(
+                        RouteTableAssociation::builder()
+                            .subnet_id("subnet-1111")
+                            .build(),
+                    )
+                    .build(),
             ],
-            Some(r#"{"EnableDnsSupport":{"Value": true}}"#),
-        );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-vpc-attribute",
-                "--vpc-id",
-                "vpc-1111",
-                "--attribute",
-                "enableDnsHostnames",
-                "--output",
-                "json",
+            addresses: vec![
+                Address::builder()
+                    .public_ip("52.0.0.1")
+                    .instance_id("i-1234")
+                    .tags(tag("Name", "edge-eip"))
+                    .build(),
             ],
-            Some(r#"{"EnableDnsHostnames":{"Value": true}}"#),
-        );
+            vpc_attributes: [
+                (
+                    ("vpc-1111".to_string(), VpcAttributeName::EnableDnsSupport),
+                    true,
+                ),
+                (
+                    ("vpc-1111".to_string(), VpcAttributeName::EnableDnsHostnames),
+                    true,
+                ),
+            ]
+            .into(),
+            ..FakeProvider::default()
+        };
 
-        let vpcs = list_vpcs();
+        let vpcs = list_vpcs(&provider);
         assert_eq!(vpcs.len(), 1);
-        assert_eq!(vpcs[0].id, "vpc-1111");
+        assert_eq!(vpcs[0].name, "main-vpc");
 
-        let subnets = list_subnets("vpc-1111");
+        let subnets = list_subnets(&provider, "vpc-1111");
         assert_eq!(subnets.len(), 1);
         assert_eq!(subnets[0].name, "public-a");
+        assert_eq!(subnets[0].state, "available");
+
+        assert_eq!(list_internet_gateways(&provider, "vpc-1111").len(), 1);
+        assert!(list_internet_gateways(&provider, "vpc-2222").is_empty());
 
-        let nats = list_nat_gateways("vpc-1111");
+        let nats = list_nat_gateways(&provider, "vpc-1111");
         assert_eq!(nats.len(), 1);
         assert_eq!(nats[0].id, "nat-1234");
 
-        let routes = list_route_tables("vpc-1111");
+        let routes = list_route_tables(&provider, "vpc-1111");
         assert_eq!(routes.len(), 1);
-        assert_eq!(routes[0].id, "rtb-1234");
         assert_eq!(routes[0].associations[0], "public-a (subnet-1111)");
 
-        let eips = list_eips();
+        let eips = list_eips(&provider);
         assert_eq!(eips.len(), 1);
         assert_eq!(eips[0].name, "edge-eip");
 
-        let detail = get_network_detail("vpc-1111").expect("network detail");
-        assert_eq!(detail.id, "vpc-1111");
+        let detail = get_network_detail(&provider, "vpc-1111").expect("network detail");
         assert_eq!(detail.name, "main-vpc");
+        assert_eq!(detail.subnets.len(), 1);
         assert!(detail.dns_support);
         assert!(detail.dns_hostnames);
+        assert!(get_network_detail(&provider, "vpc-missing").is_none());
     }
 }
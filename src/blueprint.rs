@@ -1,3 +1,5 @@
+use crate::aws_cli::eventbridge::parse_rule_arn;
+use crate::aws_cli::{AccountTarget, console_url};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -11,6 +13,15 @@ pub enum ResourceType {
     LoadBalancer,
     Ecr,
     Asg,
+    ApiGateway,
+    KmsKey,
+    Secret,
+    SsmParameter,
+    Efs,
+    CloudWatchAlarm,
+    EventRule,
+    StateMachine,
+    Lambda,
 }
 
 impl ResourceType {
@@ -22,6 +33,15 @@ impl ResourceType {
             ResourceType::LoadBalancer => "Load Balancer",
             ResourceType::Ecr => "ECR",
             ResourceType::Asg => "Auto Scaling Group",
+            ResourceType::ApiGateway => "API Gateway",
+            ResourceType::KmsKey => "KMS Key",
+            ResourceType::Secret => "Secrets Manager",
+            ResourceType::SsmParameter => "SSM Parameter",
+            ResourceType::Efs => "EFS",
+            ResourceType::CloudWatchAlarm => "CloudWatch Alarm",
+            ResourceType::EventRule => "EventBridge Rule",
+            ResourceType::StateMachine => "Step Functions",
+            ResourceType::Lambda => "Lambda",
         }
     }
 }
@@ -32,6 +52,9 @@ pub struct BlueprintResource {
     pub region: String,
     pub resource_id: String,
     pub resource_name: String,
+    /// 다른 계정의 리소스를 조회할 프로필/역할 (없으면 선택한 프로필)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountTarget>,
 }
 
 impl BlueprintResource {
@@ -44,6 +67,39 @@ impl BlueprintResource {
             self.region
         )
     }
+
+    /// 리전이 속한 파티션(상용, 중국, GovCloud)의 콘솔에서 리소스를 여는 주소
+    pub fn console_url(&self) -> String {
+        let id = self.resource_id.as_str();
+        let (path, fragment) = match self.resource_type {
+            ResourceType::Ec2 => ("ec2/home", format!("InstanceDetails:instanceId={}", id)),
+            ResourceType::Network => ("vpc/home", format!("VpcDetails:VpcId={}", id)),
+            ResourceType::SecurityGroup => ("ec2/home", format!("SecurityGroup:groupId={}", id)),
+            ResourceType::LoadBalancer => {
+                ("ec2/home", format!("LoadBalancer:loadBalancerArn={}", id))
+            }
+            ResourceType::Ecr => ("ecr/home", format!("/repositories/{}", id)),
+            ResourceType::Asg => ("ec2/home", format!("AutoScalingGroupDetails:id={}", id)),
+            ResourceType::ApiGateway => ("apigateway/home", format!("/apis/{}/resources", id)),
+            ResourceType::KmsKey => ("kms/home", format!("/kms/keys/{}", id)),
+            ResourceType::Secret => (
+                "secretsmanager/home",
+                format!("/secret?name={}", self.resource_name),
+            ),
+            ResourceType::SsmParameter => {
+                ("systems-manager/parameters", format!("{}/description", id))
+            }
+            ResourceType::Efs => ("efs/home", format!("/file-systems/{}", id)),
+            ResourceType::CloudWatchAlarm => ("cloudwatch/home", format!("alarmsV2:alarm/{}", id)),
+            ResourceType::EventRule => match parse_rule_arn(id) {
+                Some((bus, name)) => ("events/home", format!("/eventbus/{}/rules/{}", bus, name)),
+                None => ("events/home", "/rules".to_string()),
+            },
+            ResourceType::StateMachine => ("states/home", format!("/statemachines/view/{}", id)),
+            ResourceType::Lambda => ("lambda/home", format!("/functions/{}", self.resource_name)),
+        };
+        console_url(&self.region, path, &fragment)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,10 +125,11 @@ impl Blueprint {
 
     pub fn add_resource(&mut self, resource: BlueprintResource) {
         // Check for duplicates
-        let exists = self
-            .resources
-            .iter()
-            .any(|r| r.resource_id == resource.resource_id && r.region == resource.region);
+        let exists = self.resources.iter().any(|r| {
+            r.resource_id == resource.resource_id
+                && r.region == resource.region
+                && r.account == resource.account
+        });
         if !exists {
             self.resources.push(resource);
             self.updated_at = Utc::now();
@@ -166,6 +223,7 @@ mod tests {
             region: "ap-northeast-2".to_string(),
             resource_id: format!("id-{}", suffix),
             resource_name: format!("name-{}", suffix),
+            account: None,
         }
     }
 
@@ -177,6 +235,15 @@ mod tests {
         assert_eq!(ResourceType::LoadBalancer.display(), "Load Balancer");
         assert_eq!(ResourceType::Ecr.display(), "ECR");
         assert_eq!(ResourceType::Asg.display(), "Auto Scaling Group");
+        assert_eq!(ResourceType::ApiGateway.display(), "API Gateway");
+        assert_eq!(ResourceType::KmsKey.display(), "KMS Key");
+        assert_eq!(ResourceType::Secret.display(), "Secrets Manager");
+        assert_eq!(ResourceType::SsmParameter.display(), "SSM Parameter");
+        assert_eq!(ResourceType::Efs.display(), "EFS");
+        assert_eq!(ResourceType::CloudWatchAlarm.display(), "CloudWatch Alarm");
+        assert_eq!(ResourceType::EventRule.display(), "EventBridge Rule");
+        assert_eq!(ResourceType::StateMachine.display(), "Step Functions");
+        assert_eq!(ResourceType::Lambda.display(), "Lambda");
     }
 
     #[test]
@@ -189,6 +256,40 @@ mod tests {
         assert!(text.contains("ap-northeast-2"));
     }
 
+    #[test]
+    fn console_url_follows_resource_region_partition() {
+        let mut resource = sample_resource(ResourceType::Ec2, "abc");
+        assert_eq!(
+            resource.console_url(),
+            "https://console.aws.amazon.com/ec2/home?region=ap-northeast-2#InstanceDetails:instanceId=id-abc"
+        );
+
+        resource.region = "us-gov-west-1".to_string();
+        assert!(
+            resource
+                .console_url()
+                .starts_with("https://console.amazonaws-us-gov.com/ec2/home?region=us-gov-west-1#")
+        );
+
+        let rule = BlueprintResource {
+            resource_type: ResourceType::EventRule,
+            region: "cn-north-1".to_string(),
+            resource_id: "arn:aws-cn:events:cn-north-1:123456789012:rule/nightly".to_string(),
+            resource_name: "nightly".to_string(),
+            account: None,
+        };
+        assert_eq!(
+            rule.console_url(),
+            "https://console.amazonaws.cn/events/home?region=cn-north-1#/eventbus/default/rules/nightly"
+        );
+
+        let function = sample_resource(ResourceType::Lambda, "abc");
+        assert_eq!(
+            function.console_url(),
+            "https://console.aws.amazon.com/lambda/home?region=ap-northeast-2#/functions/name-abc"
+        );
+    }
+
     #[test]
     fn blueprint_add_resource_deduplicates_same_id_and_region() {
         let mut blueprint = Blueprint::new("bp".to_string());
@@ -0,0 +1,214 @@
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// 캐시 유효 기간 기본값 (1시간)
+pub const DEFAULT_CACHE_TTL_SECS: u64 = 60 * 60;
+
+/// 캐시 항목을 구분하는 키 (프로필/리전/작업/인자)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheKey {
+    pub profile: String,
+    pub region: String,
+    pub operation: String,
+    pub args: String,
+}
+
+impl CacheKey {
+    pub fn new(profile: &str, region: &str, operation: &str, args: &str) -> Self {
+        Self {
+            profile: profile.to_string(),
+            region: region.to_string(),
+            operation: operation.to_string(),
+            args: args.to_string(),
+        }
+    }
+}
+
+/// 캐시에서 읽은 값과 저장 시각
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cached<T> {
+    pub value: T,
+    pub cached_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<T> {
+    cached_at: DateTime<Utc>,
+    value: T,
+}
+
+/// `~/.emd/cache/` 아래에 조회 결과를 JSON으로 저장하는 디스크 캐시
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: Option<PathBuf>,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    pub fn new(ttl_secs: u64) -> Self {
+        Self {
+            dir: default_cache_dir(),
+            ttl: Duration::from_secs(ttl_secs),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_dir(dir: PathBuf, ttl_secs: u64) -> Self {
+        Self {
+            dir: Some(dir),
+            ttl: Duration::from_secs(ttl_secs),
+        }
+    }
+
+    /// 유효 기간 안의 항목만 반환 (TTL 0이면 항상 새로 조회)
+    pub fn get<T: DeserializeOwned>(&self, key: &CacheKey) -> Option<Cached<T>> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+        let cached = self.get_stale(key)?;
+        let age = Utc::now().signed_duration_since(cached.cached_at);
+        let ttl = chrono::Duration::from_std(self.ttl).ok()?;
+        (age <= ttl).then_some(cached)
+    }
+
+    /// 유효 기간과 관계없이 저장된 항목을 반환 (오프라인 모드)
+    pub fn get_stale<T: DeserializeOwned>(&self, key: &CacheKey) -> Option<Cached<T>> {
+        let path = self.entry_path(key)?;
+        let content = fs::read_to_string(&path).ok()?;
+        match serde_json::from_str::<CacheEntry<T>>(&content) {
+            Ok(entry) => Some(Cached {
+                value: entry.value,
+                cached_at: entry.cached_at,
+            }),
+            Err(error) => {
+                tracing::warn!(path = %path.display(), %error, "Ignoring unreadable cache entry");
+                None
+            }
+        }
+    }
+
+    pub fn put<T: Serialize>(&self, key: &CacheKey, value: &T) {
+        let Some(path) = self.entry_path(key) else {
+            return;
+        };
+        let entry = CacheEntry {
+            cached_at: Utc::now(),
+            value,
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| Ok(serde_json::to_string(&entry)?))
+            .and_then(|content| fs::write(&path, content));
+        if let Err(error) = result {
+            tracing::warn!(path = %path.display(), %error, "Failed to write cache entry");
+        }
+    }
+
+    /// `<cache>/<profile>/<region>/<operation>-<args hash>.json`
+    fn entry_path(&self, key: &CacheKey) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        Some(
+            dir.join(sanitize(&key.profile))
+                .join(sanitize(&key.region))
+                .join(format!(
+                    "{}-{:016x}.json",
+                    sanitize(&key.operation),
+                    fnv1a(&key.args)
+                )),
+        )
+    }
+}
+
+fn default_cache_dir() -> Option<PathBuf> {
+    // 테스트는 사용자 캐시를 읽거나 쓰지 않도록 `with_dir`로만 캐시를 사용
+    if cfg!(test) {
+        return None;
+    }
+    Some(dirs::home_dir()?.join(".emd").join("cache"))
+}
+
+pub(crate) fn sanitize(value: &str) -> String {
+    let cleaned: String = value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if cleaned.is_empty() || cleaned.starts_with('.') {
+        format!("_{}", cleaned)
+    } else {
+        cleaned
+    }
+}
+
+/// 실행/버전에 관계없이 같은 값을 내는 FNV-1a 해시 (파일 이름용)
+pub(crate) fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheKey, ResponseCache, fnv1a, sanitize};
+    use std::path::PathBuf;
+
+    fn temp_dir(prefix: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        std::env::temp_dir().join(format!("emd-{}-{}-{}", prefix, std::process::id(), nanos))
+    }
+
+    #[test]
+    fn put_then_get_round_trips_per_key() {
+        let dir = temp_dir("cache");
+        let cache = ResponseCache::with_dir(dir.clone(), 60);
+        let key = CacheKey::new("dev", "ap-northeast-2", "ec2:instances", "");
+        let other_region = CacheKey::new("dev", "us-east-1", "ec2:instances", "");
+
+        assert!(cache.get::<Vec<String>>(&key).is_none());
+        cache.put(&key, &vec!["i-1".to_string()]);
+
+        let cached = cache.get::<Vec<String>>(&key).expect("cached value");
+        assert_eq!(cached.value, vec!["i-1".to_string()]);
+        assert!(cache.get::<Vec<String>>(&other_region).is_none());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn zero_ttl_skips_reads_but_stale_reads_still_work() {
+        let dir = temp_dir("cache-ttl");
+        let cache = ResponseCache::with_dir(dir.clone(), 0);
+        let key = CacheKey::new("dev", "ap-northeast-2", "blueprint:Ec2", "i-1:Korean");
+        cache.put(&key, &"## EC2".to_string());
+
+        assert!(cache.get::<String>(&key).is_none());
+        assert_eq!(
+            cache.get_stale::<String>(&key).map(|c| c.value).as_deref(),
+            Some("## EC2")
+        );
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn key_parts_are_safe_file_names() {
+        assert_eq!(sanitize("prod/admin"), "prod_admin");
+        assert_eq!(sanitize(".."), "_..");
+        assert_eq!(sanitize(""), "_");
+        assert_eq!(fnv1a(""), 0xcbf29ce484222325);
+        assert_ne!(fnv1a("i-1"), fnv1a("i-2"));
+    }
+}
@@ -1,4 +1,8 @@
+use crate::app::{App, query_error_reason};
+use crate::aws_cli::{self, AwsQueryErrorCode, FixtureMode};
 use clap::{Parser, Subcommand};
+use std::io::Write;
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "emd")]
@@ -7,31 +11,154 @@ use clap::{Parser, Subcommand};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Command>,
+
+    /// Browse lists and render blueprints from the local cache without calling AWS
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Send AWS requests to this endpoint (e.g. LocalStack or moto).
+    /// Overrides AWS_ENDPOINT_URL and the endpoints in ~/.emd/settings.json
+    #[arg(long, global = true, value_name = "URL")]
+    pub endpoint_url: Option<String>,
+
+    /// Save every AWS response under DIR so it can be replayed later (e.g. attached to an issue)
+    #[arg(long, global = true, value_name = "DIR", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Serve AWS responses from a directory written by --record, without network or credentials
+    #[arg(long, global = true, value_name = "DIR")]
+    pub replay: Option<PathBuf>,
+
+    /// Replace 12-digit account IDs with 123456789012 in recorded responses
+    #[arg(long, global = true, requires = "record")]
+    pub scrub_account_ids: bool,
+}
+
+impl Cli {
+    fn fixture_mode(&self) -> FixtureMode {
+        match (&self.record, &self.replay) {
+            (Some(dir), _) => FixtureMode::Record {
+                dir: dir.clone(),
+                scrub_account_ids: self.scrub_account_ids,
+            },
+            (None, Some(dir)) => FixtureMode::Replay { dir: dir.clone() },
+            (None, None) => FixtureMode::Off,
+        }
+    }
 }
 
 #[derive(Subcommand)]
 pub enum Command {
     /// Update to the latest version
     Update,
+    /// Generate a saved blueprint's Markdown without opening the TUI.
+    /// Exits with 3 (access denied), 4 (not found), 5 (throttled), 6 (network),
+    /// 7 (timeout), 8 (credentials), 9 (unreadable response) or 1 when a resource fails
+    Generate {
+        /// Blueprint name
+        blueprint: String,
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+}
+
+/// TUI 실행 옵션
+pub struct LaunchOptions {
+    pub offline: bool,
+    pub endpoint_url: Option<String>,
 }
 
-pub fn run() -> Option<()> {
+/// 서브커맨드를 실행했으면 `None`, TUI를 띄워야 하면 실행 옵션을 반환
+pub fn run() -> Option<LaunchOptions> {
     let cli = Cli::parse();
+    // 녹화/재생은 TUI와 `emd generate` 모두에 적용
+    crate::aws_cli::set_fixture_mode(cli.fixture_mode());
 
-    match cli.command? {
-        Command::Update => {
+    match cli.command {
+        Some(Command::Update) => {
             if let Err(e) = crate::update::perform_update() {
                 eprintln!("Update failed: {}", e);
                 std::process::exit(1);
             }
-            Some(())
+            None
         }
+        Some(Command::Generate { blueprint, output }) => {
+            std::process::exit(generate(
+                &blueprint,
+                output.as_deref(),
+                cli.offline,
+                cli.endpoint_url,
+            ));
+        }
+        None => Some(LaunchOptions {
+            offline: cli.offline,
+            endpoint_url: cli.endpoint_url,
+        }),
     }
 }
 
+/// 블루프린트 문서를 생성해 파일(또는 표준 출력)에 쓰고 종료 코드를 반환.
+/// 리소스 조회가 실패했어도 문서는 쓰고, 첫 번째 실패 원인의 종료 코드를 반환
+fn generate(name: &str, output: Option<&str>, offline: bool, endpoint_url: Option<String>) -> i32 {
+    let mut app = App::new();
+    app.set_endpoint_url_flag(endpoint_url);
+    app.start_headless(offline);
+    if !offline && let Err(error) = prompt_mfa_code() {
+        eprintln!("MFA authentication failed: {}", error);
+        return AwsQueryErrorCode::Credentials.exit_code();
+    }
+    if !offline {
+        app.load_identity();
+    }
+    if !crate::handler::generate_blueprint(&mut app, name) {
+        eprintln!("Blueprint not found or empty: {}", name);
+        return 1;
+    }
+
+    let written = match output {
+        Some(path) => crate::output::save_markdown(path, &app.preview_content),
+        None => {
+            print!("{}", app.preview_content);
+            Ok(())
+        }
+    };
+    if let Err(e) = written {
+        eprintln!("Failed to write output: {}", e);
+        return 1;
+    }
+
+    for error in &app.query_errors {
+        eprintln!(
+            "{}: {} - {}",
+            error.operation,
+            query_error_reason(error.code, &app.i18n),
+            error.as_str().lines().next().unwrap_or_default()
+        );
+    }
+    app.query_errors
+        .first()
+        .map_or(0, |error| error.code.exit_code())
+}
+
+/// 프로필에 MFA 세션이 필요하면 표준 입력으로 코드를 받아 역할을 맡음 (프롬프트는 표준 에러)
+fn prompt_mfa_code() -> Result<(), String> {
+    let Some(mfa_serial) = aws_cli::mfa::required_mfa_serial() else {
+        return Ok(());
+    };
+    eprint!("MFA code for {}: ", mfa_serial);
+    std::io::stderr().flush().map_err(|e| e.to_string())?;
+    let mut code = String::new();
+    std::io::stdin()
+        .read_line(&mut code)
+        .map_err(|e| e.to_string())?;
+    aws_cli::mfa::submit_mfa_code(code.trim()).map(|_| ())
+}
+
 #[cfg(test)]
 mod tests {
     use super::{Cli, Command};
+    use crate::aws_cli::FixtureMode;
     use clap::Parser;
 
     #[test]
@@ -40,6 +167,56 @@ mod tests {
         assert!(cli.command.is_none());
     }
 
+    #[test]
+    fn parse_offline_flag() {
+        let cli = Cli::parse_from(["emd", "--offline"]);
+        assert!(cli.offline);
+        assert!(cli.command.is_none());
+        assert!(!Cli::parse_from(["emd"]).offline);
+    }
+
+    #[test]
+    fn parse_generate_subcommand() {
+        let cli = Cli::parse_from(["emd", "generate", "prod", "-o", "prod.md", "--offline"]);
+        assert!(cli.offline);
+        match cli.command {
+            Some(Command::Generate { blueprint, output }) => {
+                assert_eq!(blueprint, "prod");
+                assert_eq!(output.as_deref(), Some("prod.md"));
+            }
+            _ => panic!("expected generate subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_endpoint_url_flag() {
+        let cli = Cli::parse_from(["emd", "--endpoint-url", "http://localhost:4566"]);
+        assert_eq!(cli.endpoint_url.as_deref(), Some("http://localhost:4566"));
+        assert!(Cli::parse_from(["emd"]).endpoint_url.is_none());
+    }
+
+    #[test]
+    fn parse_record_and_replay_flags() {
+        let cli = Cli::parse_from(["emd", "--record", "fixtures", "--scrub-account-ids"]);
+        assert_eq!(
+            cli.fixture_mode(),
+            FixtureMode::Record {
+                dir: "fixtures".into(),
+                scrub_account_ids: true,
+            }
+        );
+        let cli = Cli::parse_from(["emd", "generate", "prod", "--replay", "fixtures"]);
+        assert_eq!(
+            cli.fixture_mode(),
+            FixtureMode::Replay {
+                dir: "fixtures".into()
+            }
+        );
+        assert_eq!(Cli::parse_from(["emd"]).fixture_mode(), FixtureMode::Off);
+        assert!(Cli::try_parse_from(["emd", "--record", "a", "--replay", "b"]).is_err());
+        assert!(Cli::try_parse_from(["emd", "--scrub-account-ids"]).is_err());
+    }
+
     #[test]
     fn parse_update_subcommand() {
         let cli = Cli::parse_from(["emd", "update"]);
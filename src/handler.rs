@@ -1,291 +1,65 @@
-use crate::app::{App, LoadingTask, REGIONS, SERVICE_KEYS, Screen};
-use crate::aws_cli::NetworkDetail;
-use crate::blueprint::{BlueprintResource, ResourceType};
+use crate::app::{
+    App, BlueprintPart, LoadApply, LoadMessage, LoadingTask, SERVICE_KEYS, Screen,
+    fill_account_alias, query_error_message, query_error_reason,
+};
+use crate::aws_cli::identity::AccountIdentity;
+use crate::aws_cli::sso::SsoPoll;
+use crate::aws_cli::{
+    self, AccountTarget, ApiGatewayDetail, AsgDetail, AwsProvider, AwsQueryError,
+    AwsQueryErrorCode, AwsResource, CloudWatchAlarm, Ec2Detail, EcrDetail, EfsDetail,
+    EventRuleDetail, KmsKeyDetail, LambdaFunctionDetail, LoadBalancerDetail, NetworkDetail,
+    PartialFailure, Partition, SecretDetail, SecurityGroupDetail, SsmParameterDetail,
+    StateMachineDetail,
+};
+use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
+use crate::cache::{CacheKey, ResponseCache};
+use crate::i18n::{I18n, Language};
+use crate::settings::DocumentIdentity;
+use chrono::{DateTime, Utc};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, TryRecvError};
+use std::time::Instant;
 
-type VpcInfoTuple = (String, String, String, Vec<(String, String)>);
-
-#[cfg(not(test))]
-mod aws_adapter {
-    use super::VpcInfoTuple;
-    use crate::aws_cli;
-
-    pub fn set_region(region: &str) {
-        aws_cli::set_region(region);
-    }
-
-    pub fn list_instances() -> Vec<aws_cli::AwsResource> {
-        aws_cli::list_instances()
-    }
-
-    pub fn get_instance_detail(id: &str) -> Option<aws_cli::Ec2Detail> {
-        aws_cli::get_instance_detail(id)
-    }
-
-    pub fn list_vpcs() -> Vec<aws_cli::AwsResource> {
-        aws_cli::list_vpcs()
-    }
-
-    pub fn get_network_detail(vpc_id: &str) -> Option<aws_cli::NetworkDetail> {
-        aws_cli::get_network_detail(vpc_id)
-    }
-
-    pub fn get_vpc_info(vpc_id: &str) -> Option<VpcInfoTuple> {
-        aws_cli::get_vpc_info(vpc_id)
-    }
-
-    pub fn list_subnets(vpc_id: &str) -> Vec<aws_cli::AwsResource> {
-        aws_cli::list_subnets(vpc_id)
-    }
-
-    pub fn list_internet_gateways(vpc_id: &str) -> Vec<aws_cli::AwsResource> {
-        aws_cli::list_internet_gateways(vpc_id)
-    }
-
-    pub fn list_nat_gateways(vpc_id: &str) -> Vec<aws_cli::NatDetail> {
-        aws_cli::list_nat_gateways(vpc_id)
-    }
-
-    pub fn list_route_tables(vpc_id: &str) -> Vec<aws_cli::RouteTableDetail> {
-        aws_cli::list_route_tables(vpc_id)
-    }
-
-    pub fn list_eips() -> Vec<aws_cli::EipDetail> {
-        aws_cli::list_eips()
-    }
-
-    pub fn get_vpc_dns_support(vpc_id: &str) -> bool {
-        aws_cli::get_vpc_dns_support(vpc_id)
-    }
-
-    pub fn get_vpc_dns_hostnames(vpc_id: &str) -> bool {
-        aws_cli::get_vpc_dns_hostnames(vpc_id)
-    }
-
-    pub fn list_security_groups() -> Vec<aws_cli::AwsResource> {
-        aws_cli::list_security_groups()
-    }
-
-    pub fn get_security_group_detail(id: &str) -> Option<aws_cli::SecurityGroupDetail> {
-        aws_cli::get_security_group_detail(id)
-    }
-
-    pub fn list_load_balancers() -> Vec<aws_cli::AwsResource> {
-        aws_cli::list_load_balancers()
-    }
-
-    pub fn get_load_balancer_detail(id: &str) -> Option<aws_cli::LoadBalancerDetail> {
-        aws_cli::get_load_balancer_detail(id)
-    }
-
-    pub fn list_ecr_repositories() -> Vec<aws_cli::AwsResource> {
-        aws_cli::ecr::list_ecr_repositories()
-    }
-
-    pub fn get_ecr_detail(id: &str) -> Option<aws_cli::EcrDetail> {
-        aws_cli::ecr::get_ecr_detail(id)
-    }
-
-    pub fn list_auto_scaling_groups() -> Vec<aws_cli::AwsResource> {
-        aws_cli::asg::list_auto_scaling_groups()
-    }
-
-    pub fn get_asg_detail(name: &str) -> Option<aws_cli::AsgDetail> {
-        aws_cli::asg::get_asg_detail(name)
-    }
-}
-
-#[cfg(test)]
-mod aws_adapter {
-    use super::VpcInfoTuple;
-    use crate::aws_cli;
-
-    fn resource(id: &str, name: &str) -> aws_cli::AwsResource {
-        aws_cli::AwsResource {
-            name: name.to_string(),
-            id: id.to_string(),
-            state: "available".to_string(),
-            az: "ap-northeast-2a".to_string(),
-            cidr: "10.0.0.0/24".to_string(),
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    // 로딩 중에는 Esc(취소)만 처리
+    if app.loading {
+        if !app.loading_task.is_quiet() {
+            if key.code == KeyCode::Esc {
+                cancel_loading(app);
+            }
+            return;
         }
+        drop_quiet_loading(app);
     }
 
-    pub fn set_region(_region: &str) {}
-
-    pub fn list_instances() -> Vec<aws_cli::AwsResource> {
-        vec![resource("i-test", "ec2-test")]
-    }
-
-    pub fn get_instance_detail(id: &str) -> Option<aws_cli::Ec2Detail> {
-        Some(aws_cli::Ec2Detail {
-            name: format!("ec2-{}", id),
-            instance_id: id.to_string(),
-            instance_type: "t3.micro".to_string(),
-            ami: "ami-test".to_string(),
-            platform: "Linux".to_string(),
-            architecture: "x86_64".to_string(),
-            key_pair: "kp".to_string(),
-            vpc: "vpc-test".to_string(),
-            subnet: "subnet-test".to_string(),
-            az: "ap-northeast-2a".to_string(),
-            public_ip: "-".to_string(),
-            private_ip: "10.0.0.10".to_string(),
-            security_groups: vec!["sg-test".to_string()],
-            state: "running".to_string(),
-            ebs_optimized: false,
-            monitoring: "Disabled".to_string(),
-            iam_role: None,
-            iam_role_detail: None,
-            launch_time: String::new(),
-            tags: vec![("Name".to_string(), "ec2-test".to_string())],
-            volumes: vec![],
-            user_data: None,
-        })
-    }
-
-    pub fn list_vpcs() -> Vec<aws_cli::AwsResource> {
-        vec![resource("vpc-test", "vpc-test")]
-    }
-
-    pub fn get_network_detail(vpc_id: &str) -> Option<aws_cli::NetworkDetail> {
-        Some(aws_cli::NetworkDetail {
-            name: format!("network-{}", vpc_id),
-            id: vpc_id.to_string(),
-            cidr: "10.0.0.0/16".to_string(),
-            state: "available".to_string(),
-            subnets: vec![],
-            igws: vec![],
-            nats: vec![],
-            route_tables: vec![],
-            eips: vec![],
-            dns_support: true,
-            dns_hostnames: true,
-            tags: vec![],
-        })
-    }
-
-    pub fn get_vpc_info(vpc_id: &str) -> Option<VpcInfoTuple> {
-        Some((
-            format!("network-{}", vpc_id),
-            "10.0.0.0/16".to_string(),
-            "available".to_string(),
-            vec![],
-        ))
-    }
-
-    pub fn list_subnets(_vpc_id: &str) -> Vec<aws_cli::AwsResource> {
-        vec![resource("subnet-test", "subnet-test")]
-    }
-
-    pub fn list_internet_gateways(_vpc_id: &str) -> Vec<aws_cli::AwsResource> {
-        vec![resource("igw-test", "igw-test")]
-    }
-
-    pub fn list_nat_gateways(_vpc_id: &str) -> Vec<aws_cli::NatDetail> {
-        vec![]
-    }
-
-    pub fn list_route_tables(_vpc_id: &str) -> Vec<aws_cli::RouteTableDetail> {
-        vec![]
-    }
-
-    pub fn list_eips() -> Vec<aws_cli::EipDetail> {
-        vec![]
-    }
-
-    pub fn get_vpc_dns_support(_vpc_id: &str) -> bool {
-        true
-    }
-
-    pub fn get_vpc_dns_hostnames(_vpc_id: &str) -> bool {
-        false
-    }
-
-    pub fn list_security_groups() -> Vec<aws_cli::AwsResource> {
-        vec![resource("sg-test", "sg-test")]
-    }
-
-    pub fn get_security_group_detail(id: &str) -> Option<aws_cli::SecurityGroupDetail> {
-        Some(aws_cli::SecurityGroupDetail {
-            name: format!("sg-{}", id),
-            id: id.to_string(),
-            description: "sg".to_string(),
-            vpc_id: "vpc-test".to_string(),
-            inbound_rules: vec![],
-            outbound_rules: vec![],
-        })
-    }
-
-    pub fn list_load_balancers() -> Vec<aws_cli::AwsResource> {
-        vec![resource("lb-test", "lb-test")]
-    }
-
-    pub fn get_load_balancer_detail(id: &str) -> Option<aws_cli::LoadBalancerDetail> {
-        Some(aws_cli::LoadBalancerDetail {
-            name: format!("lb-{}", id),
-            arn: id.to_string(),
-            dns_name: "lb.example.com".to_string(),
-            lb_type: "application".to_string(),
-            scheme: "internal".to_string(),
-            vpc_id: "vpc-test".to_string(),
-            ip_address_type: "ipv4".to_string(),
-            state: "active".to_string(),
-            availability_zones: vec![],
-            security_groups: vec![],
-            listeners: vec![],
-            target_groups: vec![],
-        })
-    }
-
-    pub fn list_ecr_repositories() -> Vec<aws_cli::AwsResource> {
-        vec![resource("repo-test", "repo-test")]
-    }
-
-    pub fn get_ecr_detail(id: &str) -> Option<aws_cli::EcrDetail> {
-        Some(aws_cli::EcrDetail {
-            name: id.to_string(),
-            uri: "123456789012.dkr.ecr.ap-northeast-2.amazonaws.com/repo-test".to_string(),
-            tag_mutability: "MUTABLE".to_string(),
-            encryption_type: "AES256".to_string(),
-            kms_key: None,
-            created_at: "2026-01-01".to_string(),
-            image_count: 0,
-        })
-    }
-
-    pub fn list_auto_scaling_groups() -> Vec<aws_cli::AwsResource> {
-        vec![resource("asg-test", "asg-test")]
+    // 프로필 전환 창은 어느 화면에서든 Ctrl+P로 열고, 열려 있으면 키를 먼저 받음
+    if app.profile_switcher.is_some() {
+        handle_profile_switcher(app, key);
+        return;
     }
-
-    pub fn get_asg_detail(name: &str) -> Option<aws_cli::AsgDetail> {
-        Some(aws_cli::AsgDetail {
-            name: name.to_string(),
-            arn: format!("arn:aws:autoscaling:ap-northeast-2:123456789012:autoScalingGroup:{name}"),
-            launch_template_name: None,
-            launch_template_id: None,
-            launch_config_name: None,
-            min_size: 1,
-            max_size: 1,
-            desired_capacity: 1,
-            default_cooldown: 0,
-            availability_zones: vec![],
-            target_group_arns: vec![],
-            health_check_type: "EC2".to_string(),
-            health_check_grace_period: 0,
-            instances: vec![],
-            created_time: "2026-01-01T00:00:00Z".to_string(),
-            scaling_policies: vec![],
-            tags: vec![],
-        })
+    if key.code == KeyCode::Char('p')
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+        && app.can_switch_profile()
+    {
+        open_profile_switcher(app);
+        return;
     }
-}
 
-pub fn handle_key(app: &mut App, key: KeyEvent) {
     match &app.screen {
         Screen::Login => handle_login(app, key),
+        Screen::MfaInput => handle_mfa_input(app, key),
         Screen::BlueprintSelect => handle_blueprint_select(app, key),
         Screen::BlueprintDetail => handle_blueprint_detail(app, key),
         Screen::BlueprintNameInput => handle_blueprint_name_input(app, key),
+        Screen::AccountInput => handle_account_input(app, key),
+        Screen::OrganizationSelect => handle_organization_select(app, key),
+        Screen::OrgRoleInput => handle_org_role_input(app, key),
         Screen::BlueprintPreview => handle_blueprint_preview(app, key),
         Screen::RegionSelect => handle_region_select(app, key),
         Screen::ServiceSelect => handle_service_select(app, key),
@@ -295,12 +69,28 @@ pub fn handle_key(app: &mut App, key: KeyEvent) {
         Screen::LoadBalancerSelect => handle_load_balancer_select(app, key),
         Screen::EcrSelect => handle_ecr_select(app, key),
         Screen::AsgSelect => handle_asg_select(app, key),
+        Screen::ApiGatewaySelect => handle_api_gateway_select(app, key),
+        Screen::KmsKeySelect => handle_kms_key_select(app, key),
+        Screen::SecretSelect => handle_secret_select(app, key),
+        Screen::SsmParameterSelect => handle_ssm_parameter_select(app, key),
+        Screen::EfsSelect => handle_efs_select(app, key),
+        Screen::AlarmSelect => handle_alarm_select(app, key),
+        Screen::EventRuleSelect => handle_event_rule_select(app, key),
+        Screen::StateMachineSelect => handle_state_machine_select(app, key),
+        Screen::LambdaSelect => handle_lambda_select(app, key),
         Screen::Preview => handle_preview(app, key),
         Screen::Settings => handle_settings(app, key),
     }
 }
 
 pub fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    if app.loading {
+        if !app.loading_task.is_quiet() {
+            return;
+        }
+        drop_quiet_loading(app);
+    }
+
     // 미리보기 화면에서만 마우스 스크롤/드래그 처리
     match &app.screen {
         Screen::Preview | Screen::BlueprintPreview => {
@@ -351,340 +141,1249 @@ fn handle_preview_mouse(app: &mut App, mouse: MouseEvent) {
     }
 }
 
+/// 백그라운드에서 AWS를 조회하고, UI 스레드에서 실행할 반영 함수를 돌려주는 작업
+type LoadJob = Box<dyn FnOnce(&dyn AwsProvider, &LoadProgressSender) -> LoadApply + Send>;
+
+/// 작업 도중 진행 상황을 UI 스레드로 보냄
+struct LoadProgressSender(mpsc::Sender<LoadMessage>);
+
+impl LoadProgressSender {
+    /// 로딩이 취소되어 받을 곳이 없으면 `false`
+    fn send(&self, f: impl FnOnce(&mut App) + Send + 'static) -> bool {
+        !aws_cli::cancelled() && self.0.send(LoadMessage::Progress(Box::new(f))).is_ok()
+    }
+}
+
+fn job(f: impl FnOnce(&dyn AwsProvider) -> LoadApply + Send + 'static) -> Option<LoadJob> {
+    Some(Box::new(move |provider, _| f(provider)))
+}
+
+fn job_with_progress(
+    f: impl FnOnce(&dyn AwsProvider, &LoadProgressSender) -> LoadApply + Send + 'static,
+) -> Option<LoadJob> {
+    Some(Box::new(f))
+}
+
+fn apply(f: impl FnOnce(&mut App) + Send + 'static) -> LoadApply {
+    Box::new(f)
+}
+
+/// 작업을 `account` 계정으로 실행하고, 그동안 기록된 조회 실패와 부분 결과를 결과보다 먼저
+/// App에 쌓음 (`finish_loading`에서 상태 메시지로 표시). 작업마다 따로 모으므로 취소된 작업의
+/// 실패는 결과와 함께 버려짐
+fn run_job(
+    job: LoadJob,
+    provider: &dyn AwsProvider,
+    progress: &LoadProgressSender,
+    account: Option<AccountTarget>,
+) -> LoadApply {
+    let ((done, errors), partial_failures) = aws_cli::with_account(account.as_ref(), || {
+        aws_cli::capture_partial_failures(|| {
+            aws_cli::capture_query_errors(|| job(provider, progress))
+        })
+    });
+    if errors.is_empty() && partial_failures.is_empty() {
+        return done;
+    }
+    apply(move |app| {
+        app.query_errors.extend(errors);
+        app.partial_failures.extend(partial_failures);
+        done(app);
+    })
+}
+
+/// 현재 로딩 단계를 호출 스레드에서 바로 실행 (테스트와 `emd generate`용 동기 실행)
 pub fn process_loading(app: &mut App) {
+    let account = app.account.clone();
+    if let Some(job) = aws_cli::with_account(account.as_ref(), || prepare_loading(app)) {
+        let (sender, receiver) = mpsc::channel();
+        let done = run_job(
+            job,
+            app.provider.as_ref(),
+            &LoadProgressSender(sender),
+            account,
+        );
+        for message in receiver.try_iter() {
+            if let LoadMessage::Progress(progress) = message {
+                progress(app);
+            }
+        }
+        done(app);
+    }
+}
+
+/// 화면 없이 블루프린트 문서를 `preview_content`에 생성 (`emd generate`).
+/// 이름이 일치하는 블루프린트가 없거나 리소스가 비어 있으면 `false`
+pub fn generate_blueprint(app: &mut App, name: &str) -> bool {
+    let Some(blueprint) = app
+        .blueprint_store
+        .blueprints
+        .iter()
+        .find(|bp| bp.name == name && !bp.resources.is_empty())
+        .cloned()
+    else {
+        return false;
+    };
+    app.current_blueprint = Some(blueprint);
+    app.blueprint_markdown_parts.clear();
+    start_loading(app, LoadingTask::LoadBlueprintResources(0));
+    while app.loading {
+        process_loading(app);
+    }
+    true
+}
+
+/// 메인 루프에서 호출: 진행 중인 백그라운드 작업의 결과를 반영하거나 다음 단계를 시작
+pub fn poll_loading(app: &mut App) {
+    if app.loading_receiver.is_none() {
+        let account = app.account.clone();
+        if let Some(job) = aws_cli::with_account(account.as_ref(), || prepare_loading(app)) {
+            let (sender, receiver) = mpsc::channel();
+            let provider = Arc::clone(&app.provider);
+            let cancelled = Arc::new(AtomicBool::new(false));
+            app.loading_cancelled = Some(Arc::clone(&cancelled));
+            aws_cli::spawn_blocking(move || {
+                let done = aws_cli::with_cancellation(cancelled, || {
+                    run_job(
+                        job,
+                        provider.as_ref(),
+                        &LoadProgressSender(sender.clone()),
+                        account,
+                    )
+                });
+                // 취소되어 수신 측이 없어졌으면 결과는 버림
+                let _ = sender.send(LoadMessage::Done(done));
+            });
+            app.loading_receiver = Some(receiver);
+        }
+        return;
+    }
+
+    // 도착한 진행 상황과 완료 결과를 모두 반영
+    while let Some(receiver) = &app.loading_receiver {
+        match receiver.try_recv() {
+            Ok(LoadMessage::Progress(progress)) => progress(app),
+            Ok(LoadMessage::Done(done)) => {
+                app.loading_receiver = None;
+                done(app);
+            }
+            Err(TryRecvError::Empty) => break,
+            Err(TryRecvError::Disconnected) => {
+                // 작업 스레드가 결과 없이 종료됨 (panic 등)
+                tracing::error!(task = ?app.loading_task, "Background loading task ended without a result");
+                app.loading_receiver = None;
+                app.message = app.i18n.query_failed().to_string();
+                finish_loading(app);
+            }
+        }
+    }
+}
+
+/// 진행 중인 로딩 취소. 이미 시작된 AWS 호출의 결과는 도착해도 반영하지 않음
+fn cancel_loading(app: &mut App) {
+    tracing::info!(task = ?app.loading_task, "Loading cancelled");
+    app.loading_receiver = None;
+    // 작업 스레드는 남은 AWS 호출과 캐시 저장을 건너뛰고 끝남
+    if let Some(cancelled) = app.loading_cancelled.take() {
+        cancelled.store(true, Ordering::Relaxed);
+    }
+    match app.loading_task {
+        LoadingTask::LoadVpcDetail(_, _) => app.network_detail = None,
+        LoadingTask::LoadBlueprintResources(_) => app.blueprint_markdown_parts.clear(),
+        LoadingTask::SsoLogin(_) => app.sso_login = None,
+        _ => {}
+    }
+    finish_loading(app);
+    app.message = app.i18n.loading_cancelled().to_string();
+}
+
+/// 뒤에서 돌던 작업을 알림 없이 버리고 입력을 그대로 처리하게 함
+fn drop_quiet_loading(app: &mut App) {
+    let message = std::mem::take(&mut app.message);
+    cancel_loading(app);
+    app.message = message;
+}
+
+/// `loading_task`에 필요한 입력을 App에서 꺼내 조회 작업을 만든다.
+/// AWS 호출이 필요 없는 단계(완료 처리 등)는 바로 반영하고 `None`을 돌려준다.
+fn prepare_loading(app: &mut App) -> Option<LoadJob> {
+    if app.offline && app.loading_task.requires_aws() {
+        app.message = app.i18n.offline_unavailable().to_string();
+        finish_loading(app);
+        return None;
+    }
+
     match app.loading_task.clone() {
+        LoadingTask::LoadRegions => load_regions(app),
+        LoadingTask::LoadOrganization => load_organization(app),
+        LoadingTask::SsoLogin(profile) => sso_login(app, profile),
+        LoadingTask::MfaLogin => mfa_login(app),
+        LoadingTask::ValidateLogin(next) => validate_login(app, next),
+        LoadingTask::CheckLogin => check_login(app),
+        LoadingTask::CheckSession => check_session(app),
+        LoadingTask::LoadProfileIdentities => load_profile_identities(app),
         LoadingTask::RefreshEc2 => {
-            app.instances = aws_adapter::list_instances();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
+            refresh_list(app, "list_instances", aws_cli::list_instances, |app| {
+                &mut app.instances
+            })
         }
         LoadingTask::RefreshVpc => {
-            app.vpcs = aws_adapter::list_vpcs();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
+            refresh_list(app, "list_vpcs", aws_cli::list_vpcs, |app| &mut app.vpcs)
         }
-        LoadingTask::RefreshPreview => {
-            if app.ec2_detail.is_some() {
-                if let Some(new_detail) = aws_adapter::get_instance_detail(
-                    app.instances
-                        .get(app.selected_index)
-                        .map(|i| i.id.as_str())
-                        .unwrap_or(""),
-                ) {
-                    app.preview_content = new_detail.to_markdown(app.settings.language);
-                    app.preview_filename = format!("{}.md", new_detail.name);
-                    app.ec2_detail = Some(new_detail);
-                }
-            } else if app.network_detail.is_some() {
-                if let Some(new_detail) = aws_adapter::get_network_detail(
-                    app.vpcs
-                        .get(app.selected_index)
-                        .map(|v| v.id.as_str())
-                        .unwrap_or(""),
-                ) {
-                    app.preview_content = new_detail.to_markdown(app.settings.language);
-                    app.preview_filename = format!("{}.md", new_detail.name);
-                    app.network_detail = Some(new_detail);
-                }
-            } else if app.sg_detail.is_some()
-                && let Some(new_detail) = aws_adapter::get_security_group_detail(
-                    app.security_groups
-                        .get(app.selected_index)
-                        .map(|sg| sg.id.as_str())
-                        .unwrap_or(""),
-                )
-            {
-                app.preview_content = new_detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", new_detail.name);
-                app.sg_detail = Some(new_detail);
-            } else if app.lb_detail.is_some() {
-                if let Some(new_detail) = aws_adapter::get_load_balancer_detail(
-                    app.load_balancers
-                        .get(app.selected_index)
-                        .map(|lb| lb.id.as_str())
-                        .unwrap_or(""),
-                ) {
-                    app.preview_content = new_detail.to_markdown(app.settings.language);
-                    app.preview_filename = format!("{}.md", new_detail.name);
-                    app.lb_detail = Some(new_detail);
-                }
-            } else if app.ecr_detail.is_some() {
-                if let Some(new_detail) = aws_adapter::get_ecr_detail(
-                    app.ecr_repositories
-                        .get(app.selected_index)
-                        .map(|repo| repo.id.as_str())
-                        .unwrap_or(""),
-                ) {
-                    app.preview_content = new_detail.to_markdown(app.settings.language);
-                    app.preview_filename = format!("{}.md", new_detail.name);
-                    app.ecr_detail = Some(new_detail);
-                }
-            } else if app.asg_detail.is_some()
-                && let Some(new_detail) = aws_adapter::get_asg_detail(
-                    app.auto_scaling_groups
-                        .get(app.selected_index)
-                        .map(|asg| asg.id.as_str())
-                        .unwrap_or(""),
-                )
-            {
-                app.preview_content = new_detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", new_detail.name);
-                app.asg_detail = Some(new_detail);
-            }
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
+        LoadingTask::RefreshPreview => prepare_refresh_preview(app),
+        LoadingTask::LoadEc2 => load_list(
+            app,
+            "list_instances",
+            aws_cli::list_instances,
+            |app| &mut app.instances,
+            Screen::Ec2Select,
+        ),
+        LoadingTask::LoadEc2Detail(id) => load_detail(
+            move |provider| aws_cli::get_instance_detail(provider, &id),
+            show_ec2_detail,
+        ),
+        LoadingTask::LoadVpc => load_list(
+            app,
+            "list_vpcs",
+            aws_cli::list_vpcs,
+            |app| &mut app.vpcs,
+            Screen::VpcSelect,
+        ),
+
+        LoadingTask::LoadVpcDetail(id, step) => prepare_vpc_detail_step(app, &id, step),
+        LoadingTask::RefreshSecurityGroup => refresh_list(
+            app,
+            "list_security_groups",
+            aws_cli::list_security_groups,
+            |app| &mut app.security_groups,
+        ),
+        LoadingTask::LoadSecurityGroup => load_list(
+            app,
+            "list_security_groups",
+            aws_cli::list_security_groups,
+            |app| &mut app.security_groups,
+            Screen::SecurityGroupSelect,
+        ),
+        LoadingTask::LoadSecurityGroupDetail(id) => load_detail(
+            move |provider| aws_cli::get_security_group_detail(provider, &id),
+            show_sg_detail,
+        ),
+
+        LoadingTask::RefreshLoadBalancer => refresh_list(
+            app,
+            "list_load_balancers",
+            aws_cli::list_load_balancers,
+            |app| &mut app.load_balancers,
+        ),
+        LoadingTask::LoadLoadBalancer => load_list(
+            app,
+            "list_load_balancers",
+            aws_cli::list_load_balancers,
+            |app| &mut app.load_balancers,
+            Screen::LoadBalancerSelect,
+        ),
+        LoadingTask::LoadLoadBalancerDetail(id) => load_detail(
+            move |provider| aws_cli::get_load_balancer_detail(provider, &id),
+            show_lb_detail,
+        ),
+
+        LoadingTask::RefreshEcr => refresh_list(
+            app,
+            "list_ecr_repositories",
+            aws_cli::ecr::list_ecr_repositories,
+            |app| &mut app.ecr_repositories,
+        ),
+        LoadingTask::LoadEcr => load_list(
+            app,
+            "list_ecr_repositories",
+            aws_cli::ecr::list_ecr_repositories,
+            |app| &mut app.ecr_repositories,
+            Screen::EcrSelect,
+        ),
+        LoadingTask::LoadEcrDetail(id) => load_detail(
+            move |provider| aws_cli::ecr::get_ecr_detail(provider, &id),
+            show_ecr_detail,
+        ),
+
+        LoadingTask::RefreshAsg => refresh_list(
+            app,
+            "list_auto_scaling_groups",
+            aws_cli::asg::list_auto_scaling_groups,
+            |app| &mut app.auto_scaling_groups,
+        ),
+        LoadingTask::LoadAsg => load_list(
+            app,
+            "list_auto_scaling_groups",
+            aws_cli::asg::list_auto_scaling_groups,
+            |app| &mut app.auto_scaling_groups,
+            Screen::AsgSelect,
+        ),
+        LoadingTask::LoadAsgDetail(name) => load_detail(
+            move |provider| aws_cli::asg::get_asg_detail(provider, &name),
+            show_asg_detail,
+        ),
+
+        LoadingTask::RefreshApiGateway => refresh_list(
+            app,
+            "list_api_gateways",
+            aws_cli::api_gateway::list_api_gateways,
+            |app| &mut app.api_gateways,
+        ),
+        LoadingTask::LoadApiGateway => load_list(
+            app,
+            "list_api_gateways",
+            aws_cli::api_gateway::list_api_gateways,
+            |app| &mut app.api_gateways,
+            Screen::ApiGatewaySelect,
+        ),
+        LoadingTask::LoadApiGatewayDetail(id) => load_detail(
+            move |provider| aws_cli::api_gateway::get_api_gateway_detail(provider, &id),
+            show_api_gateway_detail,
+        ),
+
+        LoadingTask::RefreshKmsKey => {
+            refresh_list(app, "list_kms_keys", aws_cli::kms::list_kms_keys, |app| {
+                &mut app.kms_keys
+            })
         }
-        LoadingTask::LoadEc2 => {
-            app.instances = aws_adapter::list_instances();
-            app.selected_index = 0;
-            app.screen = Screen::Ec2Select;
-            finish_loading(app);
+        LoadingTask::LoadKmsKey => load_list(
+            app,
+            "list_kms_keys",
+            aws_cli::kms::list_kms_keys,
+            |app| &mut app.kms_keys,
+            Screen::KmsKeySelect,
+        ),
+        LoadingTask::LoadKmsKeyDetail(key_id) => load_detail(
+            move |provider| aws_cli::kms::get_kms_key_detail(provider, &key_id),
+            show_kms_key_detail,
+        ),
+
+        LoadingTask::RefreshSecret => refresh_list(
+            app,
+            "list_secrets",
+            aws_cli::secrets_manager::list_secrets,
+            |app| &mut app.secrets,
+        ),
+        LoadingTask::LoadSecret => load_list(
+            app,
+            "list_secrets",
+            aws_cli::secrets_manager::list_secrets,
+            |app| &mut app.secrets,
+            Screen::SecretSelect,
+        ),
+        LoadingTask::LoadSecretDetail(secret_id) => load_detail(
+            move |provider| aws_cli::secrets_manager::get_secret_detail(provider, &secret_id),
+            show_secret_detail,
+        ),
+
+        LoadingTask::RefreshSsmParameter => refresh_list(
+            app,
+            "list_ssm_parameters",
+            aws_cli::ssm::list_ssm_parameters,
+            |app| &mut app.ssm_parameters,
+        ),
+        LoadingTask::LoadSsmParameter => load_list(
+            app,
+            "list_ssm_parameters",
+            aws_cli::ssm::list_ssm_parameters,
+            |app| &mut app.ssm_parameters,
+            Screen::SsmParameterSelect,
+        ),
+        LoadingTask::LoadSsmParameterDetail(name) => load_detail(
+            move |provider| aws_cli::ssm::get_ssm_parameter_detail(provider, &name),
+            show_ssm_parameter_detail,
+        ),
+
+        LoadingTask::RefreshEfs => refresh_list(
+            app,
+            "list_efs_file_systems",
+            aws_cli::efs::list_efs_file_systems,
+            |app| &mut app.efs_file_systems,
+        ),
+        LoadingTask::LoadEfs => load_list(
+            app,
+            "list_efs_file_systems",
+            aws_cli::efs::list_efs_file_systems,
+            |app| &mut app.efs_file_systems,
+            Screen::EfsSelect,
+        ),
+        LoadingTask::LoadEfsDetail(file_system_id) => load_detail(
+            move |provider| aws_cli::efs::get_efs_detail(provider, &file_system_id),
+            show_efs_detail,
+        ),
+
+        LoadingTask::RefreshAlarm => refresh_list(
+            app,
+            "list_alarms",
+            aws_cli::cloudwatch::list_alarms,
+            |app| &mut app.alarms,
+        ),
+        LoadingTask::LoadAlarm => load_list(
+            app,
+            "list_alarms",
+            aws_cli::cloudwatch::list_alarms,
+            |app| &mut app.alarms,
+            Screen::AlarmSelect,
+        ),
+        LoadingTask::LoadAlarmDetail(alarm_name) => load_detail(
+            move |provider| aws_cli::cloudwatch::get_alarm_detail(provider, &alarm_name),
+            show_alarm_detail,
+        ),
+
+        LoadingTask::RefreshEventRule => refresh_list(
+            app,
+            "list_event_rules",
+            aws_cli::eventbridge::list_event_rules,
+            |app| &mut app.event_rules,
+        ),
+        LoadingTask::LoadEventRule => load_list(
+            app,
+            "list_event_rules",
+            aws_cli::eventbridge::list_event_rules,
+            |app| &mut app.event_rules,
+            Screen::EventRuleSelect,
+        ),
+        LoadingTask::LoadEventRuleDetail(rule_arn) => load_detail(
+            move |provider| aws_cli::eventbridge::get_event_rule_detail(provider, &rule_arn),
+            show_event_rule_detail,
+        ),
+
+        LoadingTask::RefreshStateMachine => refresh_list(
+            app,
+            "list_state_machines",
+            aws_cli::step_functions::list_state_machines,
+            |app| &mut app.state_machines,
+        ),
+        LoadingTask::LoadStateMachine => load_list(
+            app,
+            "list_state_machines",
+            aws_cli::step_functions::list_state_machines,
+            |app| &mut app.state_machines,
+            Screen::StateMachineSelect,
+        ),
+        LoadingTask::LoadStateMachineDetail(state_machine_arn) => load_detail(
+            move |provider| {
+                aws_cli::step_functions::get_state_machine_detail(provider, &state_machine_arn)
+            },
+            show_state_machine_detail,
+        ),
+
+        LoadingTask::RefreshLambda => refresh_list(
+            app,
+            "list_lambda_functions",
+            aws_cli::lambda::list_lambda_functions,
+            |app| &mut app.lambda_functions,
+        ),
+        LoadingTask::LoadLambda => load_list(
+            app,
+            "list_lambda_functions",
+            aws_cli::lambda::list_lambda_functions,
+            |app| &mut app.lambda_functions,
+            Screen::LambdaSelect,
+        ),
+        LoadingTask::LoadLambdaDetail(function_arn) => load_detail(
+            move |provider| aws_cli::lambda::get_lambda_function_detail(provider, &function_arn),
+            show_lambda_detail,
+        ),
+
+        LoadingTask::LoadBlueprintResources(current_index) => {
+            prepare_blueprint_resource(app, current_index)
         }
-        LoadingTask::LoadEc2Detail(id) => {
-            if let Some(detail) = aws_adapter::get_instance_detail(&id) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.ec2_detail = Some(detail);
-                app.screen = Screen::Preview;
-            }
-            finish_loading(app);
+        LoadingTask::None => None,
+    }
+}
+
+type ListFn = fn(&dyn AwsProvider) -> Vec<AwsResource>;
+type ListField = fn(&mut App) -> &mut Vec<AwsResource>;
+
+/// 목록을 읽어올 위치: 디스크 캐시(유효 기간 안, 오프라인이면 기간 무시) 또는 AWS
+struct ListSource {
+    cache: ResponseCache,
+    profile: String,
+    region: String,
+    operation: String,
+    /// 모든 리전 모드에서 나눠 조회할 리전 (비어 있으면 현재 리전만)
+    regions: Vec<String>,
+    bypass_cache: bool,
+    offline: bool,
+}
+
+impl ListSource {
+    fn new(app: &App, operation: &str, bypass_cache: bool) -> Self {
+        Self {
+            cache: app.response_cache.clone(),
+            profile: aws_cli::cache_profile(),
+            region: aws_cli::effective_region(),
+            operation: operation.to_string(),
+            regions: Vec::new(),
+            bypass_cache,
+            offline: app.offline,
         }
-        LoadingTask::LoadVpc => {
-            app.vpcs = aws_adapter::list_vpcs();
-            app.selected_index = 0;
-            app.screen = Screen::VpcSelect;
-            finish_loading(app);
+    }
+
+    /// 모든 리전 모드이면 리전별로 나눠 조회하는 목록 소스
+    fn for_resources(app: &App, operation: &str, bypass_cache: bool) -> Self {
+        let mut source = Self::new(app, operation, bypass_cache);
+        if app.all_regions {
+            source.regions = app.fan_out_regions();
         }
+        source
+    }
 
-        LoadingTask::LoadVpcDetail(id, step) => {
-            process_vpc_detail_step(app, &id, step);
+    /// 목록과, 캐시에서 읽었다면 저장 시각
+    fn fetch<T: Serialize + DeserializeOwned>(
+        &self,
+        provider: &dyn AwsProvider,
+        list: fn(&dyn AwsProvider) -> Vec<T>,
+    ) -> (Vec<T>, Option<DateTime<Utc>>) {
+        self.fetch_region(provider, list, &self.region)
+    }
+
+    fn fetch_region<T: Serialize + DeserializeOwned>(
+        &self,
+        provider: &dyn AwsProvider,
+        list: fn(&dyn AwsProvider) -> Vec<T>,
+        region: &str,
+    ) -> (Vec<T>, Option<DateTime<Utc>>) {
+        let key = CacheKey::new(&self.profile, region, &self.operation, "");
+        let cached = if self.offline {
+            self.cache.get_stale(&key)
+        } else if self.bypass_cache {
+            None
+        } else {
+            self.cache.get(&key)
+        };
+        if let Some(cached) = cached {
+            return (cached.value, Some(cached.cached_at));
         }
-        LoadingTask::RefreshSecurityGroup => {
-            app.security_groups = aws_adapter::list_security_groups();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
+        if self.offline {
+            return (Vec::new(), None);
         }
-        LoadingTask::LoadSecurityGroup => {
-            app.security_groups = aws_adapter::list_security_groups();
-            app.selected_index = 0;
-            app.screen = Screen::SecurityGroupSelect;
-            finish_loading(app);
+
+        let (items, partial_failures) = aws_cli::capture_partial_failures(|| list(provider));
+        // 빈 목록은 조회 실패일 수 있고, 페이지 조회가 중간에 실패한 목록은 잘렸으므로 저장하지 않음.
+        // 취소된 작업의 결과도 저장하지 않음
+        if !items.is_empty() && partial_failures.is_empty() && !aws_cli::cancelled() {
+            self.cache.put(&key, &items);
         }
-        LoadingTask::LoadSecurityGroupDetail(id) => {
-            if let Some(detail) = aws_adapter::get_security_group_detail(&id) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.sg_detail = Some(detail);
-                app.screen = Screen::Preview;
-            }
-            finish_loading(app);
+        // 부분 결과는 작업 범위로 넘겨 `finish_loading`에서 경고로 표시
+        aws_cli::record_partial_failures(partial_failures);
+        (items, None)
+    }
+
+    /// 리소스 목록. 모든 리전 모드에서는 리전마다 동시에 조회해 합치고 각 리소스에 리전을 표시하며,
+    /// 캐시 시각은 가장 오래된 값
+    fn fetch_resources(
+        &self,
+        provider: &dyn AwsProvider,
+        list: ListFn,
+    ) -> (Vec<AwsResource>, Option<DateTime<Utc>>) {
+        if self.regions.is_empty() {
+            return self.fetch(provider, list);
+        }
+        let results = aws_cli::map_concurrent(&self.regions, |region| {
+            aws_cli::with_region(region, || self.fetch_region(provider, list, region))
+        });
+        let mut resources = Vec::new();
+        let mut oldest: Option<DateTime<Utc>> = None;
+        for (region, (items, cached_at)) in self.regions.iter().zip(results) {
+            resources.extend(items.into_iter().map(|mut resource| {
+                resource.region = Some(region.clone());
+                resource
+            }));
+            oldest = match (oldest, cached_at) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
         }
+        (resources, oldest)
+    }
+}
 
-        LoadingTask::RefreshLoadBalancer => {
-            app.load_balancers = aws_adapter::list_load_balancers();
+/// 캐시를 건너뛰고 목록을 다시 조회해 현재 화면에 반영
+fn refresh_list(app: &App, operation: &str, list: ListFn, field: ListField) -> Option<LoadJob> {
+    let source = ListSource::for_resources(app, operation, true);
+    job(move |provider| {
+        let (items, cached_at) = source.fetch_resources(provider, list);
+        apply(move |app| {
+            *field(app) = items;
+            app.list_cached_at = cached_at.map(|at| (app.screen.clone(), at));
             app.message = app.i18n.refresh_complete().to_string();
             finish_loading(app);
-        }
-        LoadingTask::LoadLoadBalancer => {
-            app.load_balancers = aws_adapter::list_load_balancers();
+        })
+    })
+}
+
+/// 목록을 조회한 뒤 선택 화면으로 이동
+fn load_list(
+    app: &App,
+    operation: &str,
+    list: ListFn,
+    field: ListField,
+    screen: Screen,
+) -> Option<LoadJob> {
+    let source = ListSource::for_resources(app, operation, false);
+    job(move |provider| {
+        let (items, cached_at) = source.fetch_resources(provider, list);
+        apply(move |app| {
+            *field(app) = items;
+            app.list_cached_at = cached_at.map(|at| (screen.clone(), at));
             app.selected_index = 0;
-            app.screen = Screen::LoadBalancerSelect;
+            app.screen = screen;
             finish_loading(app);
-        }
-        LoadingTask::LoadLoadBalancerDetail(id) => {
-            if let Some(detail) = aws_adapter::get_load_balancer_detail(&id) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.lb_detail = Some(detail);
+        })
+    })
+}
+
+/// 상세 정보를 조회해 미리보기 화면으로 이동 (조회 실패 시 현재 화면 유지)
+fn load_detail<D: Send + 'static>(
+    fetch: impl FnOnce(&dyn AwsProvider) -> Option<D> + Send + 'static,
+    show: fn(&mut App, D),
+) -> Option<LoadJob> {
+    job(move |provider| {
+        let detail = fetch(provider);
+        apply(move |app| {
+            if let Some(detail) = detail {
+                show(app, detail);
                 app.screen = Screen::Preview;
             }
             finish_loading(app);
-        }
+        })
+    })
+}
 
-        LoadingTask::RefreshEcr => {
-            app.ecr_repositories = aws_adapter::list_ecr_repositories();
+/// 미리보기 중인 상세 정보를 다시 조회
+fn refresh_detail<D: Send + 'static>(
+    fetch: impl FnOnce(&dyn AwsProvider) -> Option<D> + Send + 'static,
+    show: fn(&mut App, D),
+) -> Option<LoadJob> {
+    job(move |provider| {
+        let detail = fetch(provider);
+        apply(move |app| {
+            if let Some(detail) = detail {
+                show(app, detail);
+            }
             app.message = app.i18n.refresh_complete().to_string();
             finish_loading(app);
-        }
-        LoadingTask::LoadEcr => {
-            app.ecr_repositories = aws_adapter::list_ecr_repositories();
-            app.selected_index = 0;
-            app.screen = Screen::EcrSelect;
+        })
+    })
+}
+
+fn selected_id(items: &[AwsResource], index: usize) -> String {
+    items
+        .get(index)
+        .map(|item| item.id.clone())
+        .unwrap_or_default()
+}
+
+fn prepare_refresh_preview(app: &mut App) -> Option<LoadJob> {
+    let index = app.selected_index;
+    if app.ec2_detail.is_some() {
+        let id = selected_id(&app.instances, index);
+        refresh_detail(
+            move |provider| aws_cli::get_instance_detail(provider, &id),
+            show_ec2_detail,
+        )
+    } else if app.network_detail.is_some() {
+        let id = selected_id(&app.vpcs, index);
+        refresh_detail(
+            move |provider| aws_cli::get_network_detail(provider, &id),
+            show_network_detail,
+        )
+    } else if app.sg_detail.is_some() {
+        let id = selected_id(&app.security_groups, index);
+        refresh_detail(
+            move |provider| aws_cli::get_security_group_detail(provider, &id),
+            show_sg_detail,
+        )
+    } else if app.lb_detail.is_some() {
+        let id = selected_id(&app.load_balancers, index);
+        refresh_detail(
+            move |provider| aws_cli::get_load_balancer_detail(provider, &id),
+            show_lb_detail,
+        )
+    } else if app.ecr_detail.is_some() {
+        let id = selected_id(&app.ecr_repositories, index);
+        refresh_detail(
+            move |provider| aws_cli::ecr::get_ecr_detail(provider, &id),
+            show_ecr_detail,
+        )
+    } else if app.asg_detail.is_some() {
+        let id = selected_id(&app.auto_scaling_groups, index);
+        refresh_detail(
+            move |provider| aws_cli::asg::get_asg_detail(provider, &id),
+            show_asg_detail,
+        )
+    } else if app.api_gateway_detail.is_some() {
+        let id = selected_id(&app.api_gateways, index);
+        refresh_detail(
+            move |provider| aws_cli::api_gateway::get_api_gateway_detail(provider, &id),
+            show_api_gateway_detail,
+        )
+    } else if app.kms_key_detail.is_some() {
+        let id = selected_id(&app.kms_keys, index);
+        refresh_detail(
+            move |provider| aws_cli::kms::get_kms_key_detail(provider, &id),
+            show_kms_key_detail,
+        )
+    } else if app.secret_detail.is_some() {
+        let id = selected_id(&app.secrets, index);
+        refresh_detail(
+            move |provider| aws_cli::secrets_manager::get_secret_detail(provider, &id),
+            show_secret_detail,
+        )
+    } else if app.ssm_parameter_detail.is_some() {
+        let id = selected_id(&app.ssm_parameters, index);
+        refresh_detail(
+            move |provider| aws_cli::ssm::get_ssm_parameter_detail(provider, &id),
+            show_ssm_parameter_detail,
+        )
+    } else if app.efs_detail.is_some() {
+        let id = selected_id(&app.efs_file_systems, index);
+        refresh_detail(
+            move |provider| aws_cli::efs::get_efs_detail(provider, &id),
+            show_efs_detail,
+        )
+    } else if app.alarm_detail.is_some() {
+        let id = selected_id(&app.alarms, index);
+        refresh_detail(
+            move |provider| aws_cli::cloudwatch::get_alarm_detail(provider, &id),
+            show_alarm_detail,
+        )
+    } else if app.event_rule_detail.is_some() {
+        let id = selected_id(&app.event_rules, index);
+        refresh_detail(
+            move |provider| aws_cli::eventbridge::get_event_rule_detail(provider, &id),
+            show_event_rule_detail,
+        )
+    } else if app.state_machine_detail.is_some() {
+        let id = selected_id(&app.state_machines, index);
+        refresh_detail(
+            move |provider| aws_cli::step_functions::get_state_machine_detail(provider, &id),
+            show_state_machine_detail,
+        )
+    } else if app.lambda_detail.is_some() {
+        let id = selected_id(&app.lambda_functions, index);
+        refresh_detail(
+            move |provider| aws_cli::lambda::get_lambda_function_detail(provider, &id),
+            show_lambda_detail,
+        )
+    } else {
+        app.message = app.i18n.refresh_complete().to_string();
+        finish_loading(app);
+        None
+    }
+}
+
+fn show_ec2_detail(app: &mut App, detail: Ec2Detail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = format!("{}.md", detail.name);
+    app.ec2_detail = Some(detail);
+}
+
+fn show_network_detail(app: &mut App, detail: NetworkDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = format!("{}.md", detail.name);
+    app.network_detail = Some(detail);
+}
+
+fn show_sg_detail(app: &mut App, detail: SecurityGroupDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = format!("{}.md", detail.name);
+    app.sg_detail = Some(detail);
+}
+
+fn show_lb_detail(app: &mut App, detail: LoadBalancerDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = format!("{}.md", detail.name);
+    app.lb_detail = Some(detail);
+}
+
+fn show_ecr_detail(app: &mut App, detail: EcrDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = format!("{}.md", detail.name);
+    app.ecr_detail = Some(detail);
+}
+
+fn show_asg_detail(app: &mut App, detail: AsgDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = format!("{}.md", detail.name);
+    app.asg_detail = Some(detail);
+}
+
+fn show_api_gateway_detail(app: &mut App, detail: ApiGatewayDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = format!("{}.md", detail.name);
+    app.api_gateway_detail = Some(detail);
+}
+
+fn show_kms_key_detail(app: &mut App, detail: KmsKeyDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = preview_filename(detail.display_name());
+    app.kms_key_detail = Some(detail);
+}
+
+fn show_secret_detail(app: &mut App, detail: SecretDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = preview_filename(&detail.name);
+    app.secret_detail = Some(detail);
+}
+
+fn show_ssm_parameter_detail(app: &mut App, detail: SsmParameterDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = preview_filename(&detail.name);
+    app.ssm_parameter_detail = Some(detail);
+}
+
+fn show_efs_detail(app: &mut App, detail: EfsDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = format!("{}.md", detail.id);
+    app.efs_detail = Some(detail);
+}
+
+fn show_alarm_detail(app: &mut App, detail: CloudWatchAlarm) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = preview_filename(&detail.name);
+    app.alarm_detail = Some(detail);
+}
+
+fn show_event_rule_detail(app: &mut App, detail: EventRuleDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = preview_filename(&detail.name);
+    app.event_rule_detail = Some(detail);
+}
+
+fn show_state_machine_detail(app: &mut App, detail: StateMachineDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = preview_filename(&detail.name);
+    app.state_machine_detail = Some(detail);
+}
+
+fn show_lambda_detail(app: &mut App, detail: LambdaFunctionDetail) {
+    app.preview_content = detail.to_markdown(app.settings.language);
+    app.preview_filename = preview_filename(&detail.name);
+    app.lambda_detail = Some(detail);
+}
+
+/// 문서 머리의 계정 정보 표 (설정에 따라 계정 ID를 가리거나 생략)
+fn identity_markdown(app: &App) -> String {
+    let identity = match app.settings.document_identity {
+        DocumentIdentity::Full => app.login_info.clone(),
+        DocumentIdentity::Redacted => app.login_info.as_ref().map(AccountIdentity::redacted),
+        DocumentIdentity::Hidden => None,
+    };
+    let Some(identity) = identity else {
+        return String::new();
+    };
+    let i = &app.i18n;
+    format!(
+        "| {} | {} | {} | {} |\n|:---|:---|:---|:---|\n| {} | `{}` | {} | {} |\n\n",
+        i.account(),
+        i.caller(),
+        i.profile(),
+        i.region(),
+        identity.label(),
+        identity.arn,
+        aws_cli::current_profile(),
+        aws_cli::effective_region()
+    )
+}
+
+fn prepare_blueprint_resource(app: &mut App, current_index: usize) -> Option<LoadJob> {
+    let blueprint = match &app.current_blueprint {
+        Some(bp) => bp.clone(),
+        None => {
             finish_loading(app);
+            return None;
         }
-        LoadingTask::LoadEcrDetail(id) => {
-            if let Some(detail) = aws_adapter::get_ecr_detail(&id) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.ecr_detail = Some(detail);
-                app.screen = Screen::Preview;
+    };
+
+    if current_index >= blueprint.resources.len() {
+        // All resources loaded, generate table of contents and combine markdown
+        let mut toc = vec![format!("## {}\n", app.i18n.toc())];
+        let markdowns: Vec<&str> = app
+            .blueprint_markdown_parts
+            .iter()
+            .map(|part| match part {
+                BlueprintPart::Done(markdown) => markdown.as_str(),
+                _ => "",
+            })
+            .collect();
+        // 다른 계정의 리소스가 있으면 계정 머리글 아래에 그 계정의 리소스를 모음
+        let multi_account = blueprint.resources.iter().any(|res| res.account.is_some());
+        let mut sections = Vec::new();
+        let mut number = 0;
+        for (account, indices) in account_groups(&blueprint) {
+            let header = multi_account.then(|| {
+                let label = account.map_or_else(
+                    || app.i18n.current_account().to_string(),
+                    AccountTarget::label,
+                );
+                format!("{}: {}", app.i18n.account(), label)
+            });
+            let indent = if header.is_some() { "  " } else { "" };
+            if let Some(header) = &header {
+                toc.push(format!("- **{}**", header));
             }
-            finish_loading(app);
-        }
+            for (position, index) in indices.into_iter().enumerate() {
+                let res = &blueprint.resources[index];
+                let markdown = markdowns[index];
+                number += 1;
+                toc.push(format!(
+                    "{}- [{}. {} - {}](#{}) ([{}]({}))",
+                    indent,
+                    number,
+                    res.resource_type.display(),
+                    res.resource_name,
+                    resource_anchor(res),
+                    app.i18n.console(),
+                    res.console_url()
+                ));
+
+                // ### 헤더들을 서브 목차로 추가
+                for line in markdown.lines() {
+                    if line.starts_with("### ") {
+                        let header = line.trim_start_matches("### ").trim();
+                        let sub_anchor = header
+                            .to_lowercase()
+                            .replace(" ", "-")
+                            .replace("(", "")
+                            .replace(")", "");
+                        toc.push(format!("{}  - [{}](#{})", indent, header, sub_anchor));
+                    }
+                }
 
-        LoadingTask::RefreshAsg => {
-            app.auto_scaling_groups = aws_adapter::list_auto_scaling_groups();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
+                match &header {
+                    Some(header) if position == 0 => {
+                        sections.push(format!("## {}\n\n{}", header, markdown))
+                    }
+                    _ => sections.push(markdown.to_string()),
+                }
+            }
         }
-        LoadingTask::LoadAsg => {
-            app.auto_scaling_groups = aws_adapter::list_auto_scaling_groups();
-            app.selected_index = 0;
-            app.screen = Screen::AsgSelect;
-            finish_loading(app);
+        toc.push("\n".to_string());
+
+        let combined = sections.join("\n---\n\n");
+        let toc_str = toc.join("\n");
+        app.preview_content = format!(
+            "# Blueprint: {}\n\n{}{}{}",
+            blueprint.name,
+            identity_markdown(app),
+            toc_str,
+            combined
+        );
+        app.preview_filename = format!("{}.md", blueprint.name);
+        app.preview_scroll = 0;
+        app.screen = Screen::BlueprintPreview;
+        if app.blueprint_cache_hits > 0 {
+            app.message = app
+                .i18n
+                .blueprint_cache_hits(app.blueprint_cache_hits, blueprint.resources.len());
         }
-        LoadingTask::LoadAsgDetail(name) => {
-            if let Some(detail) = aws_adapter::get_asg_detail(&name) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.asg_detail = Some(detail);
-                app.screen = Screen::Preview;
+        finish_loading(app);
+        return None;
+    }
+
+    if app.blueprint_markdown_parts.len() != blueprint.resources.len() {
+        app.blueprint_markdown_parts = vec![BlueprintPart::Pending; blueprint.resources.len()];
+        app.blueprint_cache_hits = 0;
+    }
+    let pending: Vec<usize> = (0..blueprint.resources.len())
+        .filter(|&i| !matches!(app.blueprint_markdown_parts[i], BlueprintPart::Done(_)))
+        .collect();
+
+    let lang = app.settings.language;
+    let cache = app.response_cache.clone();
+    let bypass_cache = app.bypass_cache;
+    let offline = app.offline;
+    job_with_progress(move |provider, progress| {
+        // 리소스별로 자신의 리전에서 동시에 조회하고, 끝나는 대로 결과를 제자리에 채움
+        aws_cli::map_concurrent(&pending, |&index| {
+            let resource = blueprint.resources[index].clone();
+            let started = progress.send(move |app| {
+                if let Some(part) = app.blueprint_markdown_parts.get_mut(index) {
+                    *part = BlueprintPart::Loading;
+                }
+            });
+            if !started {
+                return;
             }
-            finish_loading(app);
-        }
 
-        LoadingTask::LoadBlueprintResources(current_index) => {
-            process_blueprint_resources(app, current_index);
+            // 리소스마다 자신의 계정으로 조회 (계정이 없으면 선택한 프로필)
+            let account = resource.account.clone();
+            let profile = aws_cli::with_account(account.as_ref(), aws_cli::cache_profile);
+            let links = blueprint_links(&resource.resource_type, &blueprint);
+            let key = CacheKey::new(
+                &profile,
+                &resource.region,
+                &format!("blueprint_{:?}", resource.resource_type),
+                &format!(
+                    "{}:{:?}{}",
+                    resource.resource_id,
+                    lang,
+                    links_fingerprint(&links)
+                ),
+            );
+            let cached = if offline {
+                cache.get_stale::<String>(&key)
+            } else if bypass_cache {
+                None
+            } else {
+                cache.get::<String>(&key)
+            };
+            let from_cache = cached.is_some();
+            let mut errors = Vec::new();
+            let markdown = match cached {
+                Some(cached) => Some(cached.value),
+                None if offline => None,
+                None => {
+                    let ((markdown, mut failures), truncated) =
+                        aws_cli::capture_partial_failures(|| {
+                            aws_cli::capture_query_errors(|| {
+                                aws_cli::with_account(account.as_ref(), || {
+                                    aws_cli::with_region(&resource.region, || {
+                                        blueprint_resource_markdown(
+                                            provider, &resource, lang, &links,
+                                        )
+                                    })
+                                })
+                            })
+                        });
+                    // 페이지 조회가 중간에 실패한 하위 목록(라우트, 탑재 대상 등)도 문서를 불완전하게 만듦
+                    failures.extend(truncated.iter().map(PartialFailure::query_error));
+                    match markdown {
+                        // 하위 조회가 실패한 문서는 불완전하므로, 취소된 작업의 문서는 버려지므로 캐시하지 않음
+                        Some(ref markdown) if failures.is_empty() && !aws_cli::cancelled() => {
+                            cache.put(&key, markdown)
+                        }
+                        // 실패 없이 비어 있으면 리소스가 삭제된 경우
+                        None if failures.is_empty() => errors.push(AwsQueryError::new(
+                            AwsQueryErrorCode::NotFound,
+                            &resource.resource_id,
+                            "no matching resource",
+                        )),
+                        _ => errors = failures,
+                    }
+                    markdown
+                }
+            };
+
+            progress.send(move |app| {
+                let markdown = match markdown {
+                    Some(markdown) if errors.is_empty() => markdown,
+                    Some(markdown) => incomplete_resource_markdown(&app.i18n, markdown, &errors),
+                    None => unavailable_resource_markdown(&app.i18n, &resource, offline, &errors),
+                };
+                app.query_errors.extend(errors);
+                if let Some(part) = app.blueprint_markdown_parts.get_mut(index) {
+                    *part = BlueprintPart::Done(markdown);
+                }
+                if from_cache {
+                    app.blueprint_cache_hits += 1;
+                }
+                if let LoadingTask::LoadBlueprintResources(ref mut loaded) = app.loading_task {
+                    *loaded += 1;
+                }
+            });
+        });
+        let total = blueprint.resources.len();
+        apply(move |app| {
+            app.loading_task = LoadingTask::LoadBlueprintResources(total);
+        })
+    })
+}
+
+/// 블루프린트 리소스 하나의 상세 마크다운 (조회 실패 시 `None`)
+fn blueprint_resource_markdown(
+    provider: &dyn AwsProvider,
+    resource: &BlueprintResource,
+    lang: Language,
+    links: &HashMap<String, String>,
+) -> Option<String> {
+    let id = resource.resource_id.as_str();
+    match resource.resource_type {
+        ResourceType::Ec2 => {
+            aws_cli::get_instance_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::Network => {
+            aws_cli::get_network_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::SecurityGroup => {
+            aws_cli::get_security_group_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::LoadBalancer => {
+            aws_cli::get_load_balancer_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::Ecr => {
+            aws_cli::ecr::get_ecr_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::Asg => {
+            aws_cli::asg::get_asg_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::ApiGateway => aws_cli::api_gateway::get_api_gateway_detail(provider, id)
+            .map(|d| d.to_markdown_with_links(lang, links)),
+        ResourceType::KmsKey => {
+            aws_cli::kms::get_kms_key_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::Secret => {
+            aws_cli::secrets_manager::get_secret_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::SsmParameter => {
+            aws_cli::ssm::get_ssm_parameter_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::Efs => aws_cli::efs::get_efs_detail(provider, id)
+            .map(|d| d.to_markdown_with_links(lang, links)),
+        ResourceType::CloudWatchAlarm => {
+            aws_cli::cloudwatch::get_alarm_detail(provider, id).map(|d| d.to_markdown(lang))
+        }
+        ResourceType::EventRule => aws_cli::eventbridge::get_event_rule_detail(provider, id)
+            .map(|d| d.to_markdown_with_links(lang, links)),
+        ResourceType::StateMachine => {
+            aws_cli::step_functions::get_state_machine_detail(provider, id)
+                .map(|d| d.to_markdown(lang))
+        }
+        ResourceType::Lambda => {
+            aws_cli::lambda::get_lambda_function_detail(provider, id).map(|d| d.to_markdown(lang))
         }
-        LoadingTask::None => {}
     }
 }
 
-fn process_blueprint_resources(app: &mut App, current_index: usize) {
-    let blueprint = match &app.current_blueprint {
-        Some(bp) => bp.clone(),
-        None => {
-            finish_loading(app);
-            return;
-        }
+/// 조회하지 못한 블루프린트 리소스 자리에 넣는 섹션 (실패 원인 포함)
+fn unavailable_resource_markdown(
+    i18n: &I18n,
+    resource: &BlueprintResource,
+    offline: bool,
+    errors: &[AwsQueryError],
+) -> String {
+    let status = if offline {
+        i18n.not_cached()
+    } else {
+        i18n.query_failed()
     };
+    let mut markdown = format!(
+        "## {}: {} ({})\n",
+        resource_type_label(i18n, &resource.resource_type),
+        resource.resource_name,
+        status
+    );
+    markdown.push_str(&query_error_notes(i18n, errors));
+    markdown
+}
 
-    if current_index >= blueprint.resources.len() {
-        // All resources loaded, generate table of contents and combine markdown
-        let mut toc = vec![format!("## {}\n", app.i18n.toc())];
-        for (i, (res, markdown)) in blueprint
-            .resources
-            .iter()
-            .zip(app.blueprint_markdown_parts.iter())
-            .enumerate()
-        {
-            let anchor = format!(
-                "{}-{}",
-                res.resource_type.display().to_lowercase().replace(" ", "-"),
-                res.resource_name.to_lowercase().replace(" ", "-")
-            );
-            toc.push(format!(
-                "- [{}. {} - {}](#{})",
-                i + 1,
-                res.resource_type.display(),
-                res.resource_name,
-                anchor
-            ));
-
-            // ### 헤더들을 서브 목차로 추가
-            for line in markdown.lines() {
-                if line.starts_with("### ") {
-                    let header = line.trim_start_matches("### ").trim();
-                    let sub_anchor = header
-                        .to_lowercase()
-                        .replace(" ", "-")
-                        .replace("(", "")
-                        .replace(")", "");
-                    toc.push(format!("  - [{}](#{})", header, sub_anchor));
-                }
-            }
-        }
-        toc.push("\n".to_string());
+/// 하위 조회 일부가 실패한 리소스 문서 끝에 붙이는 경고 (실패 원인 포함)
+fn incomplete_resource_markdown(i18n: &I18n, markdown: String, errors: &[AwsQueryError]) -> String {
+    format!(
+        "{}\n\n> **{}**\n{}",
+        markdown.trim_end(),
+        i18n.incomplete_resource(),
+        query_error_notes(i18n, errors)
+    )
+}
 
-        let combined = app.blueprint_markdown_parts.join("\n---\n\n");
-        let toc_str = toc.join("\n");
-        app.preview_content = format!("# Blueprint: {}\n\n{}{}", blueprint.name, toc_str, combined);
-        app.preview_filename = format!("{}.md", blueprint.name);
-        app.preview_scroll = 0;
-        app.screen = Screen::BlueprintPreview;
-        finish_loading(app);
-        return;
+/// 조회 실패 원인 인용 줄
+fn query_error_notes(i18n: &I18n, errors: &[AwsQueryError]) -> String {
+    errors
+        .iter()
+        .map(|error| {
+            format!(
+                "\n> {}: `{}` - {}\n",
+                query_error_reason(error.code, i18n),
+                error.operation,
+                error.as_str().lines().next().unwrap_or_default()
+            )
+        })
+        .collect()
+}
+
+/// 조회 실패 시 블루프린트 본문에 표시할 리소스 종류 이름
+fn resource_type_label(i18n: &I18n, resource_type: &ResourceType) -> &'static str {
+    match resource_type {
+        ResourceType::Ec2 => i18n.ec2(),
+        ResourceType::Network => i18n.network(),
+        ResourceType::SecurityGroup => i18n.security_group(),
+        ResourceType::LoadBalancer => i18n.load_balancer(),
+        ResourceType::Ecr => i18n.md_ecr_repository(),
+        ResourceType::Asg => i18n.auto_scaling_group(),
+        ResourceType::ApiGateway => i18n.api_gateway(),
+        ResourceType::KmsKey => i18n.kms_key(),
+        ResourceType::Secret => i18n.secret(),
+        ResourceType::SsmParameter => i18n.ssm_parameter(),
+        ResourceType::Efs => i18n.efs(),
+        ResourceType::CloudWatchAlarm => i18n.cloudwatch_alarm(),
+        ResourceType::EventRule => i18n.event_rule(),
+        ResourceType::StateMachine => i18n.state_machine(),
+        ResourceType::Lambda => i18n.lambda_function(),
     }
+}
 
-    let resource = &blueprint.resources[current_index];
+/// 시크릿/파라미터 이름의 '/' 계층을 파일명에 쓸 수 있도록 치환
+fn preview_filename(name: &str) -> String {
+    format!("{}.md", name.trim_start_matches('/').replace('/', "_"))
+}
 
-    // Set region for this resource
-    aws_adapter::set_region(&resource.region);
+/// 블루프린트 목차와 동일한 규칙의 리소스 앵커
+fn resource_anchor(res: &BlueprintResource) -> String {
+    format!(
+        "{}-{}",
+        res.resource_type.display().to_lowercase().replace(" ", "-"),
+        res.resource_name.to_lowercase().replace(" ", "-")
+    )
+}
 
-    // Fetch resource detail and generate markdown
-    let failed = app.i18n.query_failed();
-    let markdown = match resource.resource_type {
-        ResourceType::Ec2 => aws_adapter::get_instance_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.ec2(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-        ResourceType::Network => aws_adapter::get_network_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.network(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-        ResourceType::SecurityGroup => {
-            aws_adapter::get_security_group_detail(&resource.resource_id)
-                .map(|d| d.to_markdown(app.settings.language))
-                .unwrap_or_else(|| {
-                    format!(
-                        "## {}: {} ({})\n",
-                        app.i18n.security_group(),
-                        resource.resource_name,
-                        failed
-                    )
-                })
-        }
-        ResourceType::LoadBalancer => aws_adapter::get_load_balancer_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.load_balancer(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-        ResourceType::Ecr => aws_adapter::get_ecr_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.md_ecr_repository(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-        ResourceType::Asg => aws_adapter::get_asg_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.auto_scaling_group(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-    };
+/// 리소스 문서가 가리킬 수 있는 같은 블루프린트 내 리소스
+/// (EFS는 VPC, EventBridge 규칙은 ARN 대상, API Gateway는 Lambda 함수 ARN)
+fn blueprint_links(resource_type: &ResourceType, blueprint: &Blueprint) -> HashMap<String, String> {
+    match resource_type {
+        ResourceType::Efs => network_links(blueprint),
+        ResourceType::EventRule | ResourceType::ApiGateway => arn_links(blueprint),
+        _ => HashMap::new(),
+    }
+}
+
+/// 캐시 키에 붙이는 링크 지문. 블루프린트 구성이 바뀌어 링크가 달라지면 캐시된 문서를 쓰지 않음
+fn links_fingerprint(links: &HashMap<String, String>) -> String {
+    if links.is_empty() {
+        return String::new();
+    }
+    let mut hasher = DefaultHasher::new();
+    links.iter().collect::<BTreeMap<_, _>>().hash(&mut hasher);
+    format!(":{:016x}", hasher.finish())
+}
+
+/// 같은 블루프린트에 포함된 VPC(Network 리소스) ID -> 앵커
+fn network_links(blueprint: &Blueprint) -> HashMap<String, String> {
+    blueprint
+        .resources
+        .iter()
+        .filter(|res| res.resource_type == ResourceType::Network)
+        .map(|res| (res.resource_id.clone(), resource_anchor(res)))
+        .collect()
+}
 
-    app.blueprint_markdown_parts.push(markdown);
+/// 리소스 인덱스를 계정별로 묶음 (처음 나온 계정 순, `None`은 선택한 프로필)
+fn account_groups(blueprint: &Blueprint) -> Vec<(Option<&AccountTarget>, Vec<usize>)> {
+    let mut groups: Vec<(Option<&AccountTarget>, Vec<usize>)> = Vec::new();
+    for (index, res) in blueprint.resources.iter().enumerate() {
+        let account = res.account.as_ref();
+        match groups.iter_mut().find(|(group, _)| *group == account) {
+            Some((_, indices)) => indices.push(index),
+            None => groups.push((account, vec![index])),
+        }
+    }
+    groups
+}
 
-    // Move to next resource
-    app.loading_task = LoadingTask::LoadBlueprintResources(current_index + 1);
+/// 같은 블루프린트에 ARN으로 등록된 리소스(Step Functions, 로드 밸런서 등) ARN -> 앵커
+fn arn_links(blueprint: &Blueprint) -> HashMap<String, String> {
+    blueprint
+        .resources
+        .iter()
+        .filter(|res| res.resource_id.starts_with("arn:"))
+        .map(|res| (res.resource_id.clone(), resource_anchor(res)))
+        .collect()
 }
 
-fn process_vpc_detail_step(app: &mut App, vpc_id: &str, step: u8) {
+fn prepare_vpc_detail_step(app: &mut App, vpc_id: &str, step: u8) -> Option<LoadJob> {
     if step > 0 && app.network_detail.is_none() {
         tracing::warn!(
             vpc_id,
@@ -693,13 +1392,22 @@ fn process_vpc_detail_step(app: &mut App, vpc_id: &str, step: u8) {
         );
         app.message = app.i18n.network_detail_unavailable(vpc_id);
         finish_loading(app);
-        return;
+        return None;
     }
 
+    let vpc_id = vpc_id.to_string();
     match step {
-        0 => {
-            // Step 0: VPC 기본 정보
-            if let Some(info) = aws_adapter::get_vpc_info(vpc_id) {
+        0 => job(move |provider| {
+            // Step 0: VPC 기본 정보 (없으면 나머지 조회를 하지 않음)
+            let info = aws_cli::get_vpc_info(provider, &vpc_id);
+            apply(move |app| {
+                let Some(info) = info else {
+                    tracing::warn!(vpc_id, "Network detail step 0 failed to load VPC info");
+                    app.message = app.i18n.network_detail_unavailable(&vpc_id);
+                    app.network_detail = None;
+                    finish_loading(app);
+                    return;
+                };
                 tracing::info!(
                     vpc_id,
                     cidr = %info.1,
@@ -707,140 +1415,27 @@ fn process_vpc_detail_step(app: &mut App, vpc_id: &str, step: u8) {
                     tag_count = info.3.len(),
                     "Network detail step 0 loaded VPC info"
                 );
-                app.network_detail = Some(NetworkDetail {
-                    name: info.0,
-                    id: vpc_id.to_string(),
-                    cidr: info.1,
-                    state: info.2,
-                    tags: info.3,
-                    subnets: Vec::new(),
-                    igws: Vec::new(),
-                    nats: Vec::new(),
-                    route_tables: Vec::new(),
-                    eips: Vec::new(),
-                    dns_support: false,
-                    dns_hostnames: false,
-                });
+                app.network_detail = Some(NetworkDetail::from_info(&vpc_id, info));
                 app.loading_progress.vpc_info = true;
-            } else {
-                tracing::warn!(vpc_id, "Network detail step 0 failed to load VPC info");
-                app.message = app.i18n.network_detail_unavailable(vpc_id);
-                app.network_detail = None;
-                finish_loading(app);
-                return;
-            }
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 1);
-        }
-        1 => {
-            // Step 1: Subnets
-            let subnets = aws_adapter::list_subnets(vpc_id);
-            tracing::info!(
-                vpc_id,
-                subnet_count = subnets.len(),
-                "Network detail step 1 loaded subnets"
-            );
-            if subnets.is_empty() {
-                tracing::warn!(vpc_id, "Network detail step 1 returned empty subnet list");
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.subnets = subnets;
-            }
-            app.loading_progress.subnets = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 2);
-        }
-        2 => {
-            // Step 2: Internet Gateways
-            let igws = aws_adapter::list_internet_gateways(vpc_id);
-            tracing::info!(
-                vpc_id,
-                igw_count = igws.len(),
-                "Network detail step 2 loaded internet gateways"
-            );
-            if igws.is_empty() {
-                tracing::warn!(
-                    vpc_id,
-                    "Network detail step 2 returned empty internet gateway list"
-                );
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.igws = igws;
-            }
-            app.loading_progress.igws = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 3);
-        }
-        3 => {
-            // Step 3: NAT Gateways
-            let nats = aws_adapter::list_nat_gateways(vpc_id);
-            tracing::info!(
-                vpc_id,
-                nat_count = nats.len(),
-                "Network detail step 3 loaded NAT gateways"
-            );
-            if nats.is_empty() {
-                tracing::warn!(
-                    vpc_id,
-                    "Network detail step 3 returned empty NAT gateway list"
-                );
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.nats = nats;
-            }
-            app.loading_progress.nats = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 4);
-        }
-        4 => {
-            // Step 4: Route Tables
-            let route_tables = aws_adapter::list_route_tables(vpc_id);
-            tracing::info!(
-                vpc_id,
-                route_table_count = route_tables.len(),
-                "Network detail step 4 loaded route tables"
-            );
-            if route_tables.is_empty() {
-                tracing::warn!(
-                    vpc_id,
-                    "Network detail step 4 returned empty route table list"
-                );
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.route_tables = route_tables;
-            }
-            app.loading_progress.route_tables = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 5);
-        }
-        5 => {
-            // Step 5: Elastic IPs
-            let eips = aws_adapter::list_eips();
-            tracing::info!(
-                vpc_id,
-                eip_count = eips.len(),
-                "Network detail step 5 loaded EIPs"
-            );
-            if eips.is_empty() {
-                tracing::warn!(vpc_id, "Network detail step 5 returned empty EIP list");
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.eips = eips;
-            }
-            app.loading_progress.eips = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 6);
-        }
-        6 => {
-            // Step 6: DNS Attributes
-            tracing::debug!(vpc_id, "Network detail step 6 loading DNS attributes");
-            if let Some(ref mut detail) = app.network_detail {
-                detail.dns_support = aws_adapter::get_vpc_dns_support(vpc_id);
-                detail.dns_hostnames = aws_adapter::get_vpc_dns_hostnames(vpc_id);
-                tracing::info!(
-                    vpc_id,
-                    dns_support = detail.dns_support,
-                    dns_hostnames = detail.dns_hostnames,
-                    "Network detail step 6 loaded DNS attributes"
-                );
-            }
-            app.loading_progress.dns_attrs = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 7);
-        }
+                app.loading_task = LoadingTask::LoadVpcDetail(vpc_id, 1);
+            })
+        }),
+        1..=6 => job_with_progress(move |provider, progress| {
+            // Step 1-6: 서브넷/IGW/NAT/라우팅 테이블/EIP/DNS를 동시에 조회하고 끝나는 대로 체크
+            aws_cli::map_concurrent(&aws_cli::NetworkPart::ALL, |&part| {
+                let data = aws_cli::fetch_network_part(provider, &vpc_id, part);
+                tracing::info!(vpc_id, part = ?part, "Network detail part loaded");
+                progress.send(move |app| {
+                    if let Some(ref mut detail) = app.network_detail {
+                        detail.apply_part(data);
+                    }
+                    app.loading_progress.mark(part);
+                });
+            });
+            apply(move |app| {
+                app.loading_task = LoadingTask::LoadVpcDetail(vpc_id, 7);
+            })
+        }),
         _ => {
             // 완료: Preview 화면으로 전환
             if let Some(ref detail) = app.network_detail {
@@ -849,20 +1444,38 @@ fn process_vpc_detail_step(app: &mut App, vpc_id: &str, step: u8) {
             }
             app.screen = Screen::Preview;
             finish_loading(app);
+            None
         }
     }
 }
 
 fn finish_loading(app: &mut App) {
     app.loading = false;
+    app.bypass_cache = false;
     app.loading_task = LoadingTask::None;
     app.loading_progress.reset();
+    app.loading_started = None;
+    app.loading_cancelled = None;
+
+    // 페이지 조회가 중간에 실패했으면 결과가 잘렸음을 알림
+    let failures = std::mem::take(&mut app.partial_failures);
+    if !failures.is_empty() {
+        let operations: Vec<&str> = failures.iter().map(|f| f.operation).collect();
+        app.message = app.i18n.partial_results(&operations.join(", "));
+    }
+    // 조회 실패가 있으면 원인을 우선 표시
+    if let Some(message) = query_error_message(&app.query_errors, &app.i18n) {
+        app.message = message;
+    }
 }
 
 fn start_loading(app: &mut App, task: LoadingTask) {
     app.loading = true;
     app.loading_progress.reset();
+    app.loading_started = Some(Instant::now());
     app.loading_task = task;
+    app.query_errors.clear();
+    app.partial_failures.clear();
     app.message = app.i18n.loading_msg().to_string();
 }
 
@@ -878,17 +1491,280 @@ fn handle_login(app: &mut App, key: KeyEvent) {
                 app.selected_profile_index += 1;
             }
         }
-        KeyCode::Enter => {
-            app.select_current_profile_and_login();
-        }
+        KeyCode::Enter => login_with_selected_profile(app),
         KeyCode::Char('r') => {
             app.refresh_profiles();
         }
+        KeyCode::Char('s') => start_sso_login(app),
         KeyCode::Char('q') => app.running = false,
         _ => {}
     }
 }
 
+/// 로그인 화면에서 고른 프로필로 전환하고 로그인 확인을 백그라운드로 시작
+fn login_with_selected_profile(app: &mut App) {
+    if app.select_current_profile() {
+        start_loading(app, LoadingTask::CheckLogin);
+    }
+}
+
+/// 선택한 프로필의 SSO 기기 인증 시작 (확인 주소와 코드는 로딩 화면에 표시)
+fn start_sso_login(app: &mut App) {
+    let Some(profile) = app
+        .available_profiles
+        .get(app.selected_profile_index)
+        .cloned()
+    else {
+        return;
+    };
+    if aws_cli::sso::sso_profile(&profile).is_none() {
+        app.message = app.i18n.sso_not_configured(&profile);
+        return;
+    }
+    app.sso_login = None;
+    start_loading(app, LoadingTask::SsoLogin(profile));
+}
+
+/// SSO 로그인 단계: 인증을 시작한 뒤 승인될 때까지 `interval_secs`마다 토큰 요청을 반복
+fn sso_login(app: &mut App, profile: String) -> Option<LoadJob> {
+    let Some(authorization) = app.sso_login.clone() else {
+        let Some(sso) = aws_cli::sso::sso_profile(&profile) else {
+            app.message = app.i18n.sso_not_configured(&profile);
+            finish_loading(app);
+            return None;
+        };
+        return job(move |_| {
+            let result = aws_cli::sso::start_device_authorization(sso);
+            apply(move |app| match result {
+                // 로딩을 유지해 다음 단계에서 폴링
+                Ok(authorization) => app.sso_login = Some(authorization),
+                Err(error) => {
+                    app.message = app.i18n.sso_login_failed(&error);
+                    finish_loading(app);
+                }
+            })
+        });
+    };
+    if Utc::now() >= authorization.expires_at {
+        app.sso_login = None;
+        app.message = app.i18n.sso_login_expired().to_string();
+        finish_loading(app);
+        return None;
+    }
+    job(move |_| {
+        std::thread::sleep(std::time::Duration::from_secs(authorization.interval_secs));
+        let result = aws_cli::sso::poll_device_token(&authorization);
+        apply(move |app| match result {
+            Ok(SsoPoll::Pending) => {}
+            Ok(SsoPoll::SlowDown) => {
+                if let Some(authorization) = app.sso_login.as_mut() {
+                    authorization.interval_secs += 5;
+                }
+            }
+            Ok(SsoPoll::Complete) => {
+                app.sso_login = None;
+                finish_loading(app);
+                login_with_selected_profile(app);
+            }
+            Err(error) => {
+                app.sso_login = None;
+                app.message = app.i18n.sso_login_failed(&error);
+                finish_loading(app);
+            }
+        })
+    })
+}
+
+/// 프로필 전환 창을 열고, 처음이면 프로필별 계정을 조회
+fn open_profile_switcher(app: &mut App) {
+    app.open_profile_switcher();
+    if app.profile_identities.is_empty() && !app.offline {
+        start_loading(app, LoadingTask::LoadProfileIdentities);
+    }
+}
+
+fn load_profile_identities(app: &App) -> Option<LoadJob> {
+    let profiles = app.available_profiles.clone();
+    job(move |provider| {
+        let identities = aws_cli::identity::profile_identities(provider, &profiles);
+        apply(move |app| {
+            app.profile_identities = identities.into_iter().collect();
+            finish_loading(app);
+            app.message.clear();
+        })
+    })
+}
+
+fn handle_profile_switcher(app: &mut App, key: KeyEvent) {
+    let Some(index) = app.profile_switcher else {
+        return;
+    };
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.profile_switcher = Some(index.saturating_sub(1));
+        }
+        KeyCode::Down | KeyCode::Char('j') if index + 1 < app.available_profiles.len() => {
+            app.profile_switcher = Some(index + 1);
+        }
+        KeyCode::Enter => {
+            let Some(profile) = app.available_profiles.get(index).cloned() else {
+                return;
+            };
+            app.switch_profile(&profile);
+            let next = list_refresh_task(&app.screen);
+            start_login_validation(app, next);
+        }
+        KeyCode::Char('r') => {
+            app.profile_identities.clear();
+            open_profile_switcher(app);
+        }
+        KeyCode::Esc => app.profile_switcher = None,
+        _ => {}
+    }
+}
+
+/// 프로필을 바꾼 뒤 로그인 확인을 백그라운드로 시작 (오프라인이면 확인 없이 `next`만 시작)
+fn start_login_validation(app: &mut App, next: Option<LoadingTask>) {
+    if app.offline {
+        if let Some(next) = next {
+            start_loading(app, next);
+        }
+        return;
+    }
+    start_loading(app, LoadingTask::ValidateLogin(next.map(Box::new)));
+}
+
+/// 주기 로그인 확인을 로딩 화면 없이 백그라운드로 시작 (키 입력이 오면 버리고 다음 주기에 다시 확인)
+pub fn check_session_if_due(app: &mut App) {
+    if app.loading || !app.session_check_due() {
+        return;
+    }
+    app.last_login_check = Some(Instant::now());
+    let message = std::mem::take(&mut app.message);
+    start_loading(app, LoadingTask::CheckSession);
+    app.message = message;
+}
+
+/// 현재 프로필의 로그인을 확인하고 계정 별칭을 채움 (작업 스레드에서 실행)
+fn current_login(
+    previous: Option<&AccountIdentity>,
+    provider: &dyn AwsProvider,
+) -> Result<aws_cli::AwsLogin, aws_cli::AwsAuthError> {
+    aws_cli::check_aws_login().map(|mut login| {
+        fill_account_alias(&mut login, previous, provider);
+        login
+    })
+}
+
+/// 바뀐 프로필의 로그인과 계정 별칭을 조회하고, 로그인 화면으로 가지 않았으면 `next`를 이어서 시작
+fn validate_login(app: &mut App, next: Option<Box<LoadingTask>>) -> Option<LoadJob> {
+    let previous = app.login_info.clone();
+    job(move |provider| {
+        let result = current_login(previous.as_ref(), provider);
+        apply(move |app| apply_validated_login(app, result, next))
+    })
+}
+
+/// 로그인 화면에서 고른 프로필(SSO/MFA 로그인 직후 포함)을 확인하고, 되면 블루프린트 선택으로
+fn check_login(app: &mut App) -> Option<LoadJob> {
+    let previous = app.login_info.clone();
+    job(move |provider| {
+        let result = current_login(previous.as_ref(), provider);
+        apply(move |app| {
+            finish_loading(app);
+            app.apply_login_result(result);
+        })
+    })
+}
+
+/// 세션 중 주기 확인. 인증이 필요할 때만 로그인 화면으로 보내고 상태 메시지는 유지
+fn check_session(app: &mut App) -> Option<LoadJob> {
+    let previous = app.login_info.clone();
+    job(move |provider| {
+        let result = current_login(previous.as_ref(), provider);
+        apply(move |app| {
+            finish_loading(app);
+            app.apply_session_login(result);
+        })
+    })
+}
+
+fn apply_validated_login(
+    app: &mut App,
+    result: Result<aws_cli::AwsLogin, aws_cli::AwsAuthError>,
+    next: Option<Box<LoadingTask>>,
+) {
+    let valid = result.is_ok();
+    finish_loading(app);
+    app.apply_session_login(result);
+    if !app.can_switch_profile() {
+        return;
+    }
+    match next {
+        Some(next) => start_loading(app, *next),
+        None if valid => {
+            app.message = app.i18n.profile_switched(&aws_cli::current_profile());
+        }
+        None => {}
+    }
+}
+
+/// 목록 화면에서 `r`로 다시 조회하는 작업 (프로필을 바꾼 뒤 목록 갱신에 사용)
+fn list_refresh_task(screen: &Screen) -> Option<LoadingTask> {
+    Some(match screen {
+        Screen::Ec2Select => LoadingTask::RefreshEc2,
+        Screen::VpcSelect => LoadingTask::RefreshVpc,
+        Screen::SecurityGroupSelect => LoadingTask::RefreshSecurityGroup,
+        Screen::LoadBalancerSelect => LoadingTask::RefreshLoadBalancer,
+        Screen::EcrSelect => LoadingTask::RefreshEcr,
+        Screen::AsgSelect => LoadingTask::RefreshAsg,
+        Screen::ApiGatewaySelect => LoadingTask::RefreshApiGateway,
+        Screen::KmsKeySelect => LoadingTask::RefreshKmsKey,
+        Screen::SecretSelect => LoadingTask::RefreshSecret,
+        Screen::SsmParameterSelect => LoadingTask::RefreshSsmParameter,
+        Screen::EfsSelect => LoadingTask::RefreshEfs,
+        Screen::AlarmSelect => LoadingTask::RefreshAlarm,
+        Screen::EventRuleSelect => LoadingTask::RefreshEventRule,
+        Screen::StateMachineSelect => LoadingTask::RefreshStateMachine,
+        Screen::LambdaSelect => LoadingTask::RefreshLambda,
+        _ => return None,
+    })
+}
+
+fn handle_mfa_input(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter if !app.input_buffer.is_empty() => {
+            start_loading(app, LoadingTask::MfaLogin);
+        }
+        KeyCode::Esc => {
+            app.input_buffer.clear();
+            app.screen = Screen::Login;
+        }
+        KeyCode::Backspace => {
+            app.input_buffer.pop();
+        }
+        KeyCode::Char(c) if c.is_ascii_digit() => {
+            app.input_buffer.push(c);
+        }
+        _ => {}
+    }
+}
+
+/// 입력한 MFA 코드로 역할을 맡고, 성공하면 로그인 확인을 다시 실행
+fn mfa_login(app: &mut App) -> Option<LoadJob> {
+    let code = std::mem::take(&mut app.input_buffer);
+    job(move |_| {
+        let result = aws_cli::mfa::submit_mfa_code(&code);
+        apply(move |app| {
+            finish_loading(app);
+            match result {
+                Ok(_) => start_loading(app, LoadingTask::CheckLogin),
+                Err(error) => app.message = app.i18n.mfa_login_failed(&error),
+            }
+        })
+    })
+}
+
 fn handle_blueprint_select(app: &mut App, key: KeyEvent) {
     let list_len = app.blueprint_store.blueprints.len() + 1; // +1 for "새 블루프린터"
 
@@ -916,6 +1792,10 @@ fn handle_blueprint_select(app: &mut App, key: KeyEvent) {
                     app.selected_blueprint_index = bp_index;
                     app.blueprint_resource_index = 0;
                     app.screen = Screen::BlueprintDetail;
+                    // 전환한 프로필에 로그인이 필요하면 확인 뒤 로그인 화면으로 이동
+                    if app.use_blueprint_profile() {
+                        start_login_validation(app, None);
+                    }
                 }
             }
         }
@@ -928,7 +1808,13 @@ fn handle_blueprint_select(app: &mut App, key: KeyEvent) {
                         app.current_blueprint = Some(bp.clone());
                         app.selected_blueprint_index = bp_index;
                         app.blueprint_markdown_parts.clear();
-                        start_loading(app, LoadingTask::LoadBlueprintResources(0));
+                        let task = LoadingTask::LoadBlueprintResources(0);
+                        // 전환한 프로필에 로그인이 필요하면 확인 뒤 로그인 화면에서 멈춤
+                        if app.use_blueprint_profile() {
+                            start_login_validation(app, Some(task));
+                        } else {
+                            start_loading(app, task);
+                        }
                     } else {
                         app.message = app.i18n.no_resources().to_string();
                     }
@@ -948,8 +1834,9 @@ fn handle_blueprint_select(app: &mut App, key: KeyEvent) {
         KeyCode::Char('s') => {
             // 단일 리소스 모드로 전환 (리전 선택)
             app.blueprint_mode = false;
-            app.screen = Screen::RegionSelect;
+            open_region_select(app);
         }
+        KeyCode::Char('o') => start_loading(app, LoadingTask::LoadOrganization),
         KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
             // Switch to Settings tab
             app.selected_tab = 1;
@@ -994,7 +1881,16 @@ fn handle_blueprint_detail(app: &mut App, key: KeyEvent) {
         KeyCode::Char('a') => {
             // 리소스 추가 (리전 선택으로 이동)
             app.blueprint_mode = true;
-            app.screen = Screen::RegionSelect;
+            open_region_select(app);
+        }
+        KeyCode::Char('c') => {
+            // 이후 추가할 리소스의 계정 (프로필/역할 ARN)
+            app.input_buffer = app
+                .account
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            app.screen = Screen::AccountInput;
         }
         KeyCode::Char('d') => {
             // 리소스 삭제
@@ -1058,6 +1954,110 @@ fn handle_blueprint_name_input(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_account_input(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => match AccountTarget::parse(&app.input_buffer) {
+            Ok(account) => {
+                app.message = app.i18n.account_selected(&account.as_ref().map_or_else(
+                    || app.i18n.current_account().to_string(),
+                    AccountTarget::label,
+                ));
+                app.account = account;
+                app.regions_loaded = false;
+                app.input_buffer.clear();
+                app.screen = Screen::BlueprintDetail;
+            }
+            Err(input) => app.message = app.i18n.invalid_account(&input),
+        },
+        KeyCode::Esc => {
+            app.input_buffer.clear();
+            app.screen = Screen::BlueprintDetail;
+        }
+        KeyCode::Backspace => {
+            app.input_buffer.pop();
+        }
+        KeyCode::Char(c) => {
+            app.input_buffer.push(c);
+        }
+        _ => {}
+    }
+}
+
+/// 선택한 프로필로 Organizations 계정 목록을 읽음 (블루프린트 계정이 아닌 관리 계정 기준)
+fn load_organization(app: &App) -> Option<LoadJob> {
+    let region = app.partition.default_region().to_string();
+    job(move |provider| {
+        let accounts = aws_cli::with_account(None, || {
+            aws_cli::with_region(&region, || {
+                aws_cli::organizations::list_organization_accounts(provider)
+            })
+        });
+        apply(move |app| {
+            if accounts.is_empty() {
+                app.message = app.i18n.organization_unavailable().to_string();
+            } else {
+                app.org_accounts = accounts;
+                app.selected_org_account = 0;
+                app.screen = Screen::OrganizationSelect;
+            }
+            finish_loading(app);
+        })
+    })
+}
+
+fn handle_organization_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.selected_org_account = app.selected_org_account.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j')
+            if app.selected_org_account + 1 < app.org_accounts.len() =>
+        {
+            app.selected_org_account += 1;
+        }
+        KeyCode::Enter => {
+            // 멤버 계정의 역할을 맡아 단일 리소스 모드로 조회
+            app.browse_org_account();
+            app.blueprint_mode = false;
+            open_region_select(app);
+        }
+        KeyCode::Char('r') => {
+            app.input_buffer = app.settings.org_role_name.clone();
+            app.screen = Screen::OrgRoleInput;
+        }
+        KeyCode::Char('p') => app.save_org_profiles(),
+        KeyCode::Char('e') => {
+            if let Err(error) = app.export_org_profiles() {
+                app.message = error.to_string();
+            }
+        }
+        KeyCode::Esc => app.screen = Screen::BlueprintSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_org_role_input(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => {
+            let role_name = std::mem::take(&mut app.input_buffer);
+            app.set_org_role_name(&role_name);
+            app.screen = Screen::OrganizationSelect;
+        }
+        KeyCode::Esc => {
+            app.input_buffer.clear();
+            app.screen = Screen::OrganizationSelect;
+        }
+        KeyCode::Backspace => {
+            app.input_buffer.pop();
+        }
+        KeyCode::Char(c) => {
+            app.input_buffer.push(c);
+        }
+        _ => {}
+    }
+}
+
 fn handle_blueprint_preview(app: &mut App, key: KeyEvent) {
     let content_lines = app.preview_content.lines().count() as u16;
 
@@ -1089,6 +2089,12 @@ fn handle_blueprint_preview(app: &mut App, key: KeyEvent) {
         KeyCode::Enter | KeyCode::Char('s') => {
             let _ = app.save_file();
         }
+        // 캐시를 건너뛰고 모든 리소스를 다시 조회
+        KeyCode::Char('r') if app.current_blueprint.is_some() => {
+            app.blueprint_markdown_parts.clear();
+            app.bypass_cache = true;
+            start_loading(app, LoadingTask::LoadBlueprintResources(0));
+        }
         KeyCode::Esc => {
             app.preview_scroll = 0;
             if app.current_blueprint.is_some() {
@@ -1103,6 +2109,38 @@ fn handle_blueprint_preview(app: &mut App, key: KeyEvent) {
     }
 }
 
+/// 리전 선택 화면으로 이동. 계정의 리전 목록은 세션에서 처음 한 번만 조회
+fn open_region_select(app: &mut App) {
+    if app.regions_loaded {
+        app.preselect_region();
+        app.screen = Screen::RegionSelect;
+    } else {
+        start_loading(app, LoadingTask::LoadRegions);
+    }
+}
+
+/// 계정의 리전 목록을 읽고 리전 선택 화면으로 이동 (조회 실패 시 내장 표 유지)
+fn load_regions(app: &App) -> Option<LoadJob> {
+    // DescribeRegions는 자격 증명의 파티션 안 리전으로 보내야 함 (GovCloud/중국 자격 증명은 상용 리전에서 실패)
+    let region = aws_cli::effective_region();
+    let region = if Partition::from_region(&region) == app.partition {
+        region
+    } else {
+        app.partition.default_region().to_string()
+    };
+    let source = aws_cli::with_region(&region, || ListSource::new(app, "describe_regions", false));
+    job(move |provider| {
+        let (regions, _) =
+            aws_cli::with_region(&region, || source.fetch(provider, aws_cli::list_regions));
+        apply(move |app| {
+            app.set_available_regions(regions);
+            app.preselect_region();
+            app.screen = Screen::RegionSelect;
+            finish_loading(app);
+        })
+    })
+}
+
 fn handle_region_select(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Up | KeyCode::Char('k') => {
@@ -1110,11 +2148,11 @@ fn handle_region_select(app: &mut App, key: KeyEvent) {
                 app.selected_region -= 1;
             }
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_region < REGIONS.len() - 1 {
-                app.selected_region += 1;
-            }
+        KeyCode::Down | KeyCode::Char('j') if app.selected_region + 1 < app.regions.len() => {
+            app.selected_region += 1;
         }
+        KeyCode::Char('f') => app.toggle_favorite_region(),
+        KeyCode::Char('a') => app.select_all_regions(),
         KeyCode::Enter => app.select_region(),
         KeyCode::Esc => {
             if app.blueprint_mode {
@@ -1148,7 +2186,16 @@ fn handle_service_select(app: &mut App, key: KeyEvent) {
             3 => start_loading(app, LoadingTask::LoadLoadBalancer),
             4 => start_loading(app, LoadingTask::LoadEcr),
             5 => start_loading(app, LoadingTask::LoadAsg),
-            6 => {
+            6 => start_loading(app, LoadingTask::LoadApiGateway),
+            7 => start_loading(app, LoadingTask::LoadKmsKey),
+            8 => start_loading(app, LoadingTask::LoadSecret),
+            9 => start_loading(app, LoadingTask::LoadSsmParameter),
+            10 => start_loading(app, LoadingTask::LoadEfs),
+            11 => start_loading(app, LoadingTask::LoadAlarm),
+            12 => start_loading(app, LoadingTask::LoadEventRule),
+            13 => start_loading(app, LoadingTask::LoadStateMachine),
+            14 => start_loading(app, LoadingTask::LoadLambda),
+            n if n == SERVICE_KEYS.len() => {
                 // Exit
                 if app.blueprint_mode {
                     app.screen = Screen::BlueprintDetail;
@@ -1184,7 +2231,8 @@ fn handle_ec2_select(app: &mut App, key: KeyEvent) {
         }
         KeyCode::Enter => {
             if app.selected_index < app.instances.len() {
-                let inst = &app.instances[app.selected_index];
+                let inst = app.instances[app.selected_index].clone();
+                app.focus_resource(&inst);
                 if app.blueprint_mode {
                     add_resource_to_blueprint(
                         app,
@@ -1220,7 +2268,8 @@ fn handle_vpc_select(app: &mut App, key: KeyEvent) {
         }
         KeyCode::Enter => {
             if app.selected_index < app.vpcs.len() {
-                let vpc = &app.vpcs[app.selected_index];
+                let vpc = app.vpcs[app.selected_index].clone();
+                app.focus_resource(&vpc);
                 if app.blueprint_mode {
                     add_resource_to_blueprint(
                         app,
@@ -1256,7 +2305,8 @@ fn handle_security_group_select(app: &mut App, key: KeyEvent) {
         }
         KeyCode::Enter => {
             if app.selected_index < app.security_groups.len() {
-                let sg = &app.security_groups[app.selected_index];
+                let sg = app.security_groups[app.selected_index].clone();
+                app.focus_resource(&sg);
                 if app.blueprint_mode {
                     add_resource_to_blueprint(
                         app,
@@ -1292,7 +2342,8 @@ fn handle_load_balancer_select(app: &mut App, key: KeyEvent) {
         }
         KeyCode::Enter => {
             if app.selected_index < app.load_balancers.len() {
-                let lb = &app.load_balancers[app.selected_index];
+                let lb = app.load_balancers[app.selected_index].clone();
+                app.focus_resource(&lb);
                 if app.blueprint_mode {
                     add_resource_to_blueprint(
                         app,
@@ -1328,7 +2379,8 @@ fn handle_ecr_select(app: &mut App, key: KeyEvent) {
         }
         KeyCode::Enter => {
             if app.selected_index < app.ecr_repositories.len() {
-                let repo = &app.ecr_repositories[app.selected_index];
+                let repo = app.ecr_repositories[app.selected_index].clone();
+                app.focus_resource(&repo);
                 if app.blueprint_mode {
                     add_resource_to_blueprint(
                         app,
@@ -1392,12 +2444,13 @@ fn handle_preview(app: &mut App, key: KeyEvent) {
                     app.get_current_resource_info(),
                 )
             {
-                let region = app.get_current_region();
+                let region = app.resource_region();
                 let resource = BlueprintResource {
                     resource_type,
                     region,
                     resource_id,
                     resource_name,
+                    account: app.account.clone(),
                 };
                 app.add_resource_to_current_blueprint(resource);
 
@@ -1408,6 +2461,15 @@ fn handle_preview(app: &mut App, key: KeyEvent) {
                 app.lb_detail = None;
                 app.ecr_detail = None;
                 app.asg_detail = None;
+                app.api_gateway_detail = None;
+                app.kms_key_detail = None;
+                app.secret_detail = None;
+                app.ssm_parameter_detail = None;
+                app.efs_detail = None;
+                app.alarm_detail = None;
+                app.event_rule_detail = None;
+                app.state_machine_detail = None;
+                app.lambda_detail = None;
                 app.preview_scroll = 0;
                 app.screen = Screen::BlueprintDetail;
             }
@@ -1422,6 +2484,15 @@ fn handle_preview(app: &mut App, key: KeyEvent) {
                 app.lb_detail = None;
                 app.ecr_detail = None;
                 app.asg_detail = None;
+                app.api_gateway_detail = None;
+                app.kms_key_detail = None;
+                app.secret_detail = None;
+                app.ssm_parameter_detail = None;
+                app.efs_detail = None;
+                app.alarm_detail = None;
+                app.event_rule_detail = None;
+                app.state_machine_detail = None;
+                app.lambda_detail = None;
                 app.screen = Screen::BlueprintDetail;
             } else if app.ec2_detail.is_some() {
                 app.ec2_detail = None;
@@ -1441,6 +2512,33 @@ fn handle_preview(app: &mut App, key: KeyEvent) {
             } else if app.asg_detail.is_some() {
                 app.asg_detail = None;
                 app.screen = Screen::AsgSelect;
+            } else if app.api_gateway_detail.is_some() {
+                app.api_gateway_detail = None;
+                app.screen = Screen::ApiGatewaySelect;
+            } else if app.kms_key_detail.is_some() {
+                app.kms_key_detail = None;
+                app.screen = Screen::KmsKeySelect;
+            } else if app.secret_detail.is_some() {
+                app.secret_detail = None;
+                app.screen = Screen::SecretSelect;
+            } else if app.ssm_parameter_detail.is_some() {
+                app.ssm_parameter_detail = None;
+                app.screen = Screen::SsmParameterSelect;
+            } else if app.efs_detail.is_some() {
+                app.efs_detail = None;
+                app.screen = Screen::EfsSelect;
+            } else if app.alarm_detail.is_some() {
+                app.alarm_detail = None;
+                app.screen = Screen::AlarmSelect;
+            } else if app.event_rule_detail.is_some() {
+                app.event_rule_detail = None;
+                app.screen = Screen::EventRuleSelect;
+            } else if app.state_machine_detail.is_some() {
+                app.state_machine_detail = None;
+                app.screen = Screen::StateMachineSelect;
+            } else if app.lambda_detail.is_some() {
+                app.lambda_detail = None;
+                app.screen = Screen::LambdaSelect;
             } else {
                 app.screen = Screen::ServiceSelect;
             }
@@ -1457,14 +2555,23 @@ fn handle_settings(app: &mut App, key: KeyEvent) {
                 app.selected_setting -= 1;
             }
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            // Currently only 1 setting (language), so max index is 0
-            // Will be expanded when more settings are added
+        // 0: 언어, 1: 동시 요청 수, 2: 캐시 유효 기간,
+        // 3: 최대 시도 횟수, 4: 요청 제한 시간, 5: 초당 요청 수,
+        // 6: 문서의 계정 정보
+        KeyCode::Down | KeyCode::Char('j') if app.selected_setting < 6 => {
+            app.selected_setting += 1;
         }
         KeyCode::Enter | KeyCode::Char(' ') => {
             // Toggle current setting
-            if app.selected_setting == 0 {
-                app.toggle_language();
+            match app.selected_setting {
+                0 => app.toggle_language(),
+                1 => app.cycle_max_concurrency(),
+                2 => app.cycle_cache_ttl(),
+                3 => app.cycle_max_attempts(),
+                4 => app.cycle_request_timeout(),
+                5 => app.cycle_rate_limit(),
+                6 => app.cycle_document_identity(),
+                _ => {}
             }
         }
         KeyCode::Left | KeyCode::Char('h') | KeyCode::Tab | KeyCode::Esc => {
@@ -1491,7 +2598,8 @@ fn handle_asg_select(app: &mut App, key: KeyEvent) {
         }
         KeyCode::Enter => {
             if app.selected_index < app.auto_scaling_groups.len() {
-                let asg = &app.auto_scaling_groups[app.selected_index];
+                let asg = app.auto_scaling_groups[app.selected_index].clone();
+                app.focus_resource(&asg);
                 if app.blueprint_mode {
                     add_resource_to_blueprint(
                         app,
@@ -1513,6 +2621,146 @@ fn handle_asg_select(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_api_gateway_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.api_gateways,
+        ResourceType::ApiGateway,
+        LoadingTask::LoadApiGatewayDetail,
+        LoadingTask::RefreshApiGateway,
+    );
+}
+
+fn handle_kms_key_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.kms_keys,
+        ResourceType::KmsKey,
+        LoadingTask::LoadKmsKeyDetail,
+        LoadingTask::RefreshKmsKey,
+    );
+}
+
+fn handle_secret_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.secrets,
+        ResourceType::Secret,
+        LoadingTask::LoadSecretDetail,
+        LoadingTask::RefreshSecret,
+    );
+}
+
+fn handle_ssm_parameter_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.ssm_parameters,
+        ResourceType::SsmParameter,
+        LoadingTask::LoadSsmParameterDetail,
+        LoadingTask::RefreshSsmParameter,
+    );
+}
+
+fn handle_efs_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.efs_file_systems,
+        ResourceType::Efs,
+        LoadingTask::LoadEfsDetail,
+        LoadingTask::RefreshEfs,
+    );
+}
+
+fn handle_alarm_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.alarms,
+        ResourceType::CloudWatchAlarm,
+        LoadingTask::LoadAlarmDetail,
+        LoadingTask::RefreshAlarm,
+    );
+}
+
+fn handle_event_rule_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.event_rules,
+        ResourceType::EventRule,
+        LoadingTask::LoadEventRuleDetail,
+        LoadingTask::RefreshEventRule,
+    );
+}
+
+fn handle_state_machine_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.state_machines,
+        ResourceType::StateMachine,
+        LoadingTask::LoadStateMachineDetail,
+        LoadingTask::RefreshStateMachine,
+    );
+}
+
+fn handle_lambda_select(app: &mut App, key: KeyEvent) {
+    handle_resource_select(
+        app,
+        key,
+        |app| &app.lambda_functions,
+        ResourceType::Lambda,
+        LoadingTask::LoadLambdaDetail,
+        LoadingTask::RefreshLambda,
+    );
+}
+
+/// 리소스 목록 화면 공통 키 처리. Enter는 블루프린트 모드면 리소스를 추가하고, 아니면 상세 조회
+/// (이름이 없는 리소스는 ID를 이름으로 사용)
+fn handle_resource_select(
+    app: &mut App,
+    key: KeyEvent,
+    resources: fn(&App) -> &Vec<AwsResource>,
+    resource_type: ResourceType,
+    detail_task: fn(String) -> LoadingTask,
+    refresh_task: LoadingTask,
+) {
+    let len = resources(app).len();
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.selected_index = app.selected_index.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') if app.selected_index + 1 < len => {
+            app.selected_index += 1;
+        }
+        KeyCode::Enter => {
+            let Some(resource) = resources(app).get(app.selected_index).cloned() else {
+                return;
+            };
+            app.focus_resource(&resource);
+            if app.blueprint_mode {
+                let name = if resource.name.is_empty() {
+                    resource.id.clone()
+                } else {
+                    resource.name
+                };
+                add_resource_to_blueprint(app, resource_type, resource.id, name);
+            } else {
+                start_loading(app, detail_task(resource.id));
+            }
+        }
+        KeyCode::Char('r') => start_loading(app, refresh_task),
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
 fn add_resource_to_blueprint(
     app: &mut App,
     resource_type: ResourceType,
@@ -1520,12 +2768,13 @@ fn add_resource_to_blueprint(
     resource_name: String,
 ) {
     if app.current_blueprint.is_some() {
-        let region = app.get_current_region();
+        let region = app.resource_region();
         let resource = BlueprintResource {
             resource_type,
             region,
             resource_id,
             resource_name,
+            account: app.account.clone(),
         };
         app.add_resource_to_current_blueprint(resource);
     }
@@ -1533,15 +2782,30 @@ fn add_resource_to_blueprint(
 
 #[cfg(test)]
 mod tests {
-    use super::{handle_key, handle_mouse, process_loading};
+    use super::{
+        apply_validated_login, check_session_if_due, finish_loading, handle_key, handle_mouse,
+        identity_markdown, list_refresh_task, poll_loading, process_loading, start_loading,
+        start_login_validation,
+    };
     use crate::app::{App, LoadingTask, Screen};
+    use crate::aws_cli::fake_provider::{
+        FakeProvider, SAMPLE_LAMBDA_ARN, SAMPLE_LOAD_BALANCER_ARN,
+    };
+    use crate::aws_cli::provider::PartialFailure;
     use crate::aws_cli::{
-        AsgDetail, AwsResource, Ec2Detail, EcrDetail, LoadBalancerDetail, NetworkDetail,
-        SecurityGroupDetail,
+        AsgDetail, AwsQueryErrorCode, AwsResource, Ec2Detail, EcrDetail, LoadBalancerDetail,
+        NetworkDetail, SecurityGroupDetail,
     };
     use crossterm::event::{
         KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
     };
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    fn test_app() -> App {
+        App::with_provider(Arc::new(FakeProvider::sample()))
+    }
 
     fn key(code: KeyCode) -> KeyEvent {
         KeyEvent::new(code, KeyModifiers::NONE)
@@ -1558,12 +2822,13 @@ mod tests {
             state: "running".to_string(),
             az: "ap-northeast-2a".to_string(),
             cidr: "10.0.0.0/24".to_string(),
+            region: None,
         }
     }
 
     #[test]
     fn service_select_enter_sets_loading_tasks() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::ServiceSelect;
 
         app.selected_service = 0;
@@ -1579,11 +2844,368 @@ mod tests {
         app.loading = false;
         handle_key(&mut app, key(KeyCode::Enter));
         assert_eq!(app.loading_task, LoadingTask::LoadAsg);
+
+        app.selected_service = 6;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadApiGateway);
+
+        app.selected_service = 7;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadKmsKey);
+
+        app.selected_service = 8;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadSecret);
+
+        app.selected_service = 9;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadSsmParameter);
+
+        app.selected_service = 10;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEfs);
+
+        app.selected_service = 11;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadAlarm);
+
+        app.selected_service = 12;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEventRule);
+
+        app.selected_service = 13;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadStateMachine);
+
+        app.selected_service = 14;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadLambda);
+    }
+
+    #[test]
+    fn metadata_select_screens_list_their_own_resources() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadApiGateway;
+        process_loading(&mut app);
+        assert_eq!(app.api_gateways.len(), 1);
+
+        let screen_text = |app: &App| {
+            let backend = ratatui::backend::TestBackend::new(160, 50);
+            let mut terminal = ratatui::Terminal::new(backend).expect("create test terminal");
+            terminal
+                .draw(|frame| crate::ui::draw(frame, app))
+                .expect("draw");
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        assert!(screen_text(&app).contains("orders-api"));
+
+        for (task, screen, expected) in [
+            (
+                LoadingTask::LoadKmsKey,
+                Screen::KmsKeySelect,
+                "alias/app-data",
+            ),
+            (
+                LoadingTask::LoadSecret,
+                Screen::SecretSelect,
+                "prod/db/password",
+            ),
+            (
+                LoadingTask::LoadSsmParameter,
+                Screen::SsmParameterSelect,
+                "/app/prod/db-url",
+            ),
+        ] {
+            app.loading_task = task;
+            process_loading(&mut app);
+            assert_eq!(app.screen, screen);
+            let text = screen_text(&app);
+            assert!(text.contains(expected), "{:?} lists {}", screen, expected);
+            assert!(
+                !text.contains("orders-api"),
+                "{:?} lists API Gateway",
+                screen
+            );
+        }
+    }
+
+    #[test]
+    fn api_gateway_flow_loads_list_detail_and_returns_on_escape() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadApiGateway;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::ApiGatewaySelect);
+        assert_eq!(app.api_gateways.len(), 1);
+
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshApiGateway);
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadApiGatewayDetail("a1b2c3d4e5".to_string())
+        );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.preview_content.contains("[list-orders](https://"));
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::ApiGatewaySelect);
+        assert!(app.api_gateway_detail.is_none());
+    }
+
+    #[test]
+    fn kms_key_flow_loads_list_detail_and_returns_on_escape() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadKmsKey;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::KmsKeySelect);
+        assert_eq!(app.kms_keys.len(), 1);
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadKmsKeyDetail("1234abcd-12ab-34cd-56ef-1234567890ab".to_string())
+        );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "app-data.md");
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::KmsKeySelect);
+        assert!(app.kms_key_detail.is_none());
+    }
+
+    #[test]
+    fn secret_and_ssm_parameter_flows_render_metadata_with_safe_filenames() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadSecret;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::SecretSelect);
+
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshSecret);
+        process_loading(&mut app);
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "prod_db_password.md");
+        assert!(app.preview_content.contains("prod/db/password"));
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::SecretSelect);
+        assert!(app.secret_detail.is_none());
+
+        app.loading_task = LoadingTask::LoadSsmParameter;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::SsmParameterSelect);
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadSsmParameterDetail("/app/prod/db-url".to_string())
+        );
+        process_loading(&mut app);
+        assert_eq!(app.preview_filename, "app_prod_db-url.md");
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::SsmParameterSelect);
+        assert!(app.ssm_parameter_detail.is_none());
+    }
+
+    #[test]
+    fn efs_flow_loads_list_detail_and_returns_on_escape() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadEfs;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::EfsSelect);
+        assert_eq!(app.efs_file_systems.len(), 1);
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEfsDetail("fs-0123456789abcdef0".to_string())
+        );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.preview_content.contains("subnet-a (`subnet-1234`)"));
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::EfsSelect);
+        assert!(app.efs_detail.is_none());
+    }
+
+    #[test]
+    fn alarm_flow_loads_list_detail_and_returns_on_escape() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadAlarm;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::AlarmSelect);
+        assert_eq!(app.alarms.len(), 1);
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadAlarmDetail("web-cpu-high".to_string())
+        );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "web-cpu-high.md");
+        assert!(app.preview_content.contains("| `CPUUtilization` |"));
+        assert!(app.preview_content.contains("| 80 |"));
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::AlarmSelect);
+        assert!(app.alarm_detail.is_none());
+    }
+
+    #[test]
+    fn event_rule_flow_loads_list_detail_and_returns_on_escape() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadEventRule;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::EventRuleSelect);
+        assert_eq!(app.event_rules.len(), 1);
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEventRuleDetail(
+                "arn:aws:events:ap-northeast-2:123456789012:rule/nightly-report".to_string()
+            )
+        );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "nightly-report.md");
+        assert!(app.preview_content.contains("`cron(0 18 * * ? *)`"));
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::EventRuleSelect);
+        assert!(app.event_rule_detail.is_none());
+    }
+
+    #[test]
+    fn state_machine_flow_renders_workflow_diagram() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadStateMachine;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::StateMachineSelect);
+        assert_eq!(app.state_machines.len(), 1);
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "order-workflow.md");
+        assert!(app.preview_content.contains("```mermaid"));
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::StateMachineSelect);
+        assert!(app.state_machine_detail.is_none());
+    }
+
+    #[test]
+    fn lambda_flow_loads_list_detail_and_returns_on_escape() {
+        let mut app = test_app();
+        app.loading_task = LoadingTask::LoadLambda;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::LambdaSelect);
+        assert_eq!(app.lambda_functions.len(), 1);
+        assert_eq!(app.lambda_functions[0].state, "python3.12");
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadLambdaDetail(SAMPLE_LAMBDA_ARN.to_string())
+        );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "list-orders.md");
+        assert!(
+            app.preview_content
+                .contains(&format!("| {} | `app.handler` |", app.i18n.md_handler()))
+        );
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::LambdaSelect);
+        assert!(app.lambda_detail.is_none());
+    }
+
+    #[test]
+    fn arn_links_map_resource_arns_to_toc_anchors() {
+        let mut blueprint = crate::blueprint::Blueprint::new("bp".to_string());
+        blueprint.add_resource(crate::blueprint::BlueprintResource {
+            resource_type: crate::blueprint::ResourceType::StateMachine,
+            region: "ap-northeast-2".to_string(),
+            resource_id: "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow"
+                .to_string(),
+            resource_name: "order-workflow".to_string(),
+            account: None,
+        });
+        blueprint.add_resource(crate::blueprint::BlueprintResource {
+            resource_type: crate::blueprint::ResourceType::Network,
+            region: "ap-northeast-2".to_string(),
+            resource_id: "vpc-1234".to_string(),
+            resource_name: "main".to_string(),
+            account: None,
+        });
+
+        let links = super::arn_links(&blueprint);
+        assert_eq!(links.len(), 1);
+        assert_eq!(
+            links
+                .get("arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow")
+                .map(String::as_str),
+            Some("step-functions-order-workflow")
+        );
+    }
+
+    #[test]
+    fn network_links_map_vpc_ids_to_toc_anchors() {
+        let mut blueprint = crate::blueprint::Blueprint::new("bp".to_string());
+        blueprint.add_resource(crate::blueprint::BlueprintResource {
+            resource_type: crate::blueprint::ResourceType::Network,
+            region: "ap-northeast-2".to_string(),
+            resource_id: "vpc-1234".to_string(),
+            resource_name: "main vpc".to_string(),
+            account: None,
+        });
+        blueprint.add_resource(crate::blueprint::BlueprintResource {
+            resource_type: crate::blueprint::ResourceType::Efs,
+            region: "ap-northeast-2".to_string(),
+            resource_id: "fs-0123456789abcdef0".to_string(),
+            resource_name: "shared-data".to_string(),
+            account: None,
+        });
+
+        let links = super::network_links(&blueprint);
+        assert_eq!(links.len(), 1);
+        assert_eq!(
+            links.get("vpc-1234").map(String::as_str),
+            Some("network-main-vpc")
+        );
     }
 
     #[test]
     fn region_select_navigation_and_escape_work() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::RegionSelect;
         app.selected_region = 0;
 
@@ -1593,14 +3215,97 @@ mod tests {
         handle_key(&mut app, key(KeyCode::Up));
         assert_eq!(app.selected_region, 0);
 
-        app.blueprint_mode = false;
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::BlueprintSelect);
+        app.blueprint_mode = false;
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+    }
+
+    #[test]
+    fn region_select_lists_account_regions_once_and_falls_back_to_builtin_table() {
+        let region = |name: &str| {
+            aws_sdk_ec2::types::Region::builder()
+                .region_name(name)
+                .build()
+        };
+        let mut app = App::with_provider(Arc::new(FakeProvider {
+            regions: vec![region("me-central-1"), region("ap-northeast-2")],
+            ..FakeProvider::default()
+        }));
+        app.screen = Screen::BlueprintSelect;
+        handle_key(&mut app, key(KeyCode::Char('s')));
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::RegionSelect);
+        assert_eq!(app.regions, vec!["ap-northeast-2", "me-central-1"]);
+
+        handle_key(&mut app, key(KeyCode::Down));
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.selected_region, 1);
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.get_current_region(), "me-central-1");
+
+        // 이미 조회했으면 다시 묻지 않음
+        app.screen = Screen::BlueprintSelect;
+        handle_key(&mut app, key(KeyCode::Char('s')));
+        assert!(!app.loading);
+        assert_eq!(app.screen, Screen::RegionSelect);
+
+        let mut provider = FakeProvider::default();
+        provider.failures.insert(
+            "ec2:DescribeRegions",
+            crate::aws_cli::AwsQueryErrorCode::AccessDenied,
+        );
+        let mut denied = App::with_provider(Arc::new(provider));
+        denied.screen = Screen::BlueprintSelect;
+        handle_key(&mut denied, key(KeyCode::Char('s')));
+        process_loading(&mut denied);
+        assert_eq!(denied.screen, Screen::RegionSelect);
+        assert_eq!(
+            denied.regions,
+            crate::app::builtin_regions(crate::aws_cli::Partition::Aws)
+        );
+        assert_eq!(denied.query_errors[0].operation, "ec2:DescribeRegions");
+    }
+
+    #[test]
+    fn all_regions_list_tags_resources_and_selection_switches_region() {
+        let mut app = test_app();
+        app.set_available_regions(vec!["ap-northeast-2".to_string(), "us-east-1".to_string()]);
+        app.screen = Screen::RegionSelect;
+        handle_key(&mut app, key(KeyCode::Char('a')));
+        assert!(app.all_regions);
+        assert_eq!(app.screen, Screen::ServiceSelect);
+        assert_eq!(app.region_label().code, app.i18n.all_regions());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Ec2Select);
+        let per_region = app.instances.len() / 2;
+        assert!(per_region > 0);
+        assert_eq!(app.instances.len(), per_region * 2);
+        assert!(
+            app.instances[..per_region]
+                .iter()
+                .all(|i| i.region.as_deref() == Some("ap-northeast-2"))
+        );
+        assert!(
+            app.instances[per_region..]
+                .iter()
+                .all(|i| i.region.as_deref() == Some("us-east-1"))
+        );
+
+        app.selected_index = per_region;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEc2Detail(app.instances[per_region].id.clone())
+        );
+        assert_eq!(app.resource_region(), "us-east-1");
     }
 
     #[test]
     fn resource_select_enter_starts_detail_loading() {
-        let mut app = App::new();
+        let mut app = test_app();
 
         app.screen = Screen::Ec2Select;
         app.instances = vec![sample_resource("i-1111", "web-1")];
@@ -1631,7 +3336,7 @@ mod tests {
 
     #[test]
     fn preview_keys_update_scroll_and_escape_route() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Preview;
         app.preview_content = (0..100)
             .map(|n| format!("line {}", n))
@@ -1652,7 +3357,7 @@ mod tests {
 
     #[test]
     fn preview_mouse_scroll_and_drag_work() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Preview;
         app.preview_content = (0..30)
             .map(|n| format!("line {}", n))
@@ -1702,7 +3407,7 @@ mod tests {
 
     #[test]
     fn blueprint_name_input_and_settings_paths_work() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintNameInput;
         handle_key(&mut app, key(KeyCode::Char('a')));
         handle_key(&mut app, key(KeyCode::Char('b')));
@@ -1726,7 +3431,7 @@ mod tests {
 
     #[test]
     fn blueprint_detail_shift_reorder_shortcuts_are_handled() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintDetail;
         app.current_blueprint = Some(crate::blueprint::Blueprint {
             id: "bp".to_string(),
@@ -1737,12 +3442,14 @@ mod tests {
                     region: "ap-northeast-2".to_string(),
                     resource_id: "i-1".to_string(),
                     resource_name: "one".to_string(),
+                    account: None,
                 },
                 crate::blueprint::BlueprintResource {
                     resource_type: crate::blueprint::ResourceType::Ec2,
                     region: "ap-northeast-2".to_string(),
                     resource_id: "i-2".to_string(),
                     resource_name: "two".to_string(),
+                    account: None,
                 },
             ],
             created_at: chrono::Utc::now(),
@@ -1756,7 +3463,7 @@ mod tests {
 
     #[test]
     fn process_loading_none_is_noop() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.loading = true;
         app.loading_task = LoadingTask::None;
         process_loading(&mut app);
@@ -1766,7 +3473,7 @@ mod tests {
 
     #[test]
     fn preview_add_path_in_blueprint_mode_returns_to_blueprint_detail() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Preview;
         app.blueprint_mode = true;
         app.current_blueprint = Some(crate::blueprint::Blueprint {
@@ -1801,6 +3508,7 @@ mod tests {
             tags: vec![("Name".to_string(), "web".to_string())],
             volumes: Vec::new(),
             user_data: None,
+            alarms: vec![],
         });
 
         handle_key(&mut app, key(KeyCode::Char('a')));
@@ -1809,7 +3517,7 @@ mod tests {
 
     #[test]
     fn preview_escape_selects_resource_screen_for_each_detail_type() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Preview;
 
         app.ec2_detail = Some(Ec2Detail {
@@ -1835,6 +3543,7 @@ mod tests {
             tags: vec![("Name".to_string(), "web".to_string())],
             volumes: Vec::new(),
             user_data: None,
+            alarms: vec![],
         });
         handle_key(&mut app, key(KeyCode::Esc));
         assert_eq!(app.screen, Screen::Ec2Select);
@@ -1871,6 +3580,7 @@ mod tests {
             created_time: "2026-01-01".to_string(),
             scaling_policies: Vec::new(),
             tags: Vec::new(),
+            alarms: Vec::new(),
         });
         handle_key(&mut app, key(KeyCode::Esc));
         assert_eq!(app.screen, Screen::AsgSelect);
@@ -1878,7 +3588,7 @@ mod tests {
 
     #[test]
     fn blueprint_select_shortcuts_change_mode_and_tab() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintSelect;
         app.blueprint_store.blueprints = vec![crate::blueprint::Blueprint {
             id: "bp-1".to_string(),
@@ -1888,6 +3598,7 @@ mod tests {
                 region: "ap-northeast-2".to_string(),
                 resource_id: "i-1234".to_string(),
                 resource_name: "web".to_string(),
+                account: None,
             }],
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
@@ -1903,6 +3614,8 @@ mod tests {
         assert!(app.selected_blueprint_index <= 1);
 
         handle_key(&mut app, key(KeyCode::Char('s')));
+        assert_eq!(app.loading_task, LoadingTask::LoadRegions);
+        process_loading(&mut app);
         assert_eq!(app.screen, Screen::RegionSelect);
 
         app.screen = Screen::BlueprintSelect;
@@ -1912,7 +3625,7 @@ mod tests {
 
     #[test]
     fn blueprint_detail_actions_cover_add_delete_generate_and_escape() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintDetail;
         app.current_blueprint = Some(crate::blueprint::Blueprint {
             id: "bp".to_string(),
@@ -1922,11 +3635,13 @@ mod tests {
                 region: "ap-northeast-2".to_string(),
                 resource_id: "i-1234".to_string(),
                 resource_name: "web".to_string(),
+                account: None,
             }],
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         });
 
+        app.regions_loaded = true;
         handle_key(&mut app, key(KeyCode::Char('a')));
         assert_eq!(app.screen, Screen::RegionSelect);
         assert!(app.blueprint_mode);
@@ -1951,6 +3666,7 @@ mod tests {
                 region: "ap-northeast-2".to_string(),
                 resource_id: "i-1234".to_string(),
                 resource_name: "web".to_string(),
+                account: None,
             }],
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
@@ -1965,6 +3681,7 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         });
+        app.loading = false;
         app.loading_task = LoadingTask::None;
         handle_key(&mut app, key(KeyCode::Char('g')));
         assert_eq!(app.message, app.i18n.no_resources());
@@ -1976,7 +3693,7 @@ mod tests {
 
     #[test]
     fn service_select_exit_paths_cover_blueprint_and_running_flags() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::ServiceSelect;
         app.selected_service = crate::app::SERVICE_KEYS.len();
         app.blueprint_mode = true;
@@ -2004,7 +3721,7 @@ mod tests {
 
     #[test]
     fn blueprint_name_input_enter_creates_blueprint_and_opens_detail() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintNameInput;
         app.input_buffer = "new-bp".to_string();
 
@@ -2023,7 +3740,7 @@ mod tests {
 
     #[test]
     fn blueprint_preview_keys_cover_navigation_save_and_escape_paths() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintPreview;
         app.current_blueprint = Some(sample_blueprint("bp-a"));
         app.preview_content = (0..50)
@@ -2052,7 +3769,7 @@ mod tests {
 
     #[test]
     fn service_select_navigation_shortcuts_and_escape_work() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::ServiceSelect;
         app.selected_service = 1;
 
@@ -2066,28 +3783,34 @@ mod tests {
 
     #[test]
     fn resource_select_refresh_shortcuts_set_loading_tasks() {
-        let mut app = App::new();
+        let mut app = test_app();
 
+        app.loading = false;
         app.screen = Screen::Ec2Select;
         handle_key(&mut app, key(KeyCode::Char('r')));
         assert_eq!(app.loading_task, LoadingTask::RefreshEc2);
 
+        app.loading = false;
         app.screen = Screen::VpcSelect;
         handle_key(&mut app, key(KeyCode::Char('r')));
         assert_eq!(app.loading_task, LoadingTask::RefreshVpc);
 
+        app.loading = false;
         app.screen = Screen::SecurityGroupSelect;
         handle_key(&mut app, key(KeyCode::Char('r')));
         assert_eq!(app.loading_task, LoadingTask::RefreshSecurityGroup);
 
+        app.loading = false;
         app.screen = Screen::LoadBalancerSelect;
         handle_key(&mut app, key(KeyCode::Char('r')));
         assert_eq!(app.loading_task, LoadingTask::RefreshLoadBalancer);
 
+        app.loading = false;
         app.screen = Screen::EcrSelect;
         handle_key(&mut app, key(KeyCode::Char('r')));
         assert_eq!(app.loading_task, LoadingTask::RefreshEcr);
 
+        app.loading = false;
         app.screen = Screen::AsgSelect;
         handle_key(&mut app, key(KeyCode::Char('r')));
         assert_eq!(app.loading_task, LoadingTask::RefreshAsg);
@@ -2095,7 +3818,7 @@ mod tests {
 
     #[test]
     fn blueprint_mode_enter_adds_resource_from_each_select_screen() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.blueprint_mode = true;
         app.current_blueprint = Some(sample_blueprint("bp-r"));
         app.selected_index = 0;
@@ -2158,7 +3881,7 @@ mod tests {
 
     #[test]
     fn settings_screen_left_returns_to_blueprint_select() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Settings;
 
         handle_key(&mut app, key(KeyCode::Left));
@@ -2168,7 +3891,7 @@ mod tests {
 
     #[test]
     fn preview_blueprint_mode_escape_clears_details_and_returns_to_detail_screen() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Preview;
         app.blueprint_mode = true;
         app.ec2_detail = Some(Ec2Detail {
@@ -2194,6 +3917,7 @@ mod tests {
             tags: vec![],
             volumes: vec![],
             user_data: None,
+            alarms: vec![],
         });
 
         handle_key(&mut app, key(KeyCode::Esc));
@@ -2203,7 +3927,7 @@ mod tests {
 
     #[test]
     fn handle_mouse_outside_preview_is_noop() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::ServiceSelect;
         app.preview_scroll = 7;
 
@@ -2222,7 +3946,7 @@ mod tests {
 
     #[test]
     fn blueprint_select_enter_covers_new_and_existing_paths() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintSelect;
         app.input_buffer = "temp".to_string();
 
@@ -2247,7 +3971,7 @@ mod tests {
 
     #[test]
     fn process_loading_blueprint_resources_handles_none_and_empty_blueprint_paths() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.loading = true;
         app.loading_task = LoadingTask::LoadBlueprintResources(0);
         app.current_blueprint = None;
@@ -2276,7 +4000,7 @@ mod tests {
 
     #[test]
     fn process_loading_vpc_detail_steps_require_step_zero_init() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.loading = true;
         app.loading_task = LoadingTask::LoadVpcDetail("vpc-1".to_string(), 1);
 
@@ -2293,7 +4017,7 @@ mod tests {
 
     #[test]
     fn process_loading_refresh_preview_without_detail_finishes_cleanly() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.loading = true;
         app.loading_task = LoadingTask::RefreshPreview;
 
@@ -2305,7 +4029,7 @@ mod tests {
 
     #[test]
     fn process_loading_refresh_preview_updates_all_detail_types() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.selected_index = 0;
 
         let reset_refresh = |app: &mut App| {
@@ -2323,7 +4047,7 @@ mod tests {
         };
 
         clear_details(&mut app);
-        app.instances = vec![sample_resource("i-test", "ec2-test")];
+        app.instances = vec![sample_resource("i-1234", "web-1")];
         app.ec2_detail = Some(Ec2Detail {
             name: "seed-ec2".to_string(),
             instance_id: "i-seed".to_string(),
@@ -2347,14 +4071,15 @@ mod tests {
             tags: vec![],
             volumes: vec![],
             user_data: None,
+            alarms: vec![],
         });
         reset_refresh(&mut app);
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "ec2-i-test.md");
+        assert_eq!(app.preview_filename, "web-1.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
 
         clear_details(&mut app);
-        app.vpcs = vec![sample_resource("vpc-test", "vpc-test")];
+        app.vpcs = vec![sample_resource("vpc-1234", "main-vpc")];
         app.network_detail = Some(NetworkDetail {
             name: "seed-network".to_string(),
             id: "vpc-seed".to_string(),
@@ -2371,11 +4096,11 @@ mod tests {
         });
         reset_refresh(&mut app);
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "network-vpc-test.md");
+        assert_eq!(app.preview_filename, "main-vpc.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
 
         clear_details(&mut app);
-        app.security_groups = vec![sample_resource("sg-test", "sg-test")];
+        app.security_groups = vec![sample_resource("sg-1234", "web-sg")];
         app.sg_detail = Some(SecurityGroupDetail {
             name: "seed-sg".to_string(),
             id: "sg-seed".to_string(),
@@ -2386,11 +4111,11 @@ mod tests {
         });
         reset_refresh(&mut app);
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "sg-sg-test.md");
+        assert_eq!(app.preview_filename, "web-sg.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
 
         clear_details(&mut app);
-        app.load_balancers = vec![sample_resource("lb-test", "lb-test")];
+        app.load_balancers = vec![sample_resource(SAMPLE_LOAD_BALANCER_ARN, "web-alb")];
         app.lb_detail = Some(LoadBalancerDetail {
             name: "seed-lb".to_string(),
             arn: "arn:seed".to_string(),
@@ -2404,14 +4129,15 @@ mod tests {
             security_groups: vec![],
             listeners: vec![],
             target_groups: vec![],
+            alarms: vec![],
         });
         reset_refresh(&mut app);
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "lb-lb-test.md");
+        assert_eq!(app.preview_filename, "web-alb.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
 
         clear_details(&mut app);
-        app.ecr_repositories = vec![sample_resource("repo-test", "repo-test")];
+        app.ecr_repositories = vec![sample_resource("repo-a", "repo-a")];
         app.ecr_detail = Some(EcrDetail {
             name: "seed-repo".to_string(),
             uri: "seed-uri".to_string(),
@@ -2423,11 +4149,11 @@ mod tests {
         });
         reset_refresh(&mut app);
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "repo-test.md");
+        assert_eq!(app.preview_filename, "repo-a.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
 
         clear_details(&mut app);
-        app.auto_scaling_groups = vec![sample_resource("asg-test", "asg-test")];
+        app.auto_scaling_groups = vec![sample_resource("asg-a", "asg-a")];
         app.asg_detail = Some(AsgDetail {
             name: "seed-asg".to_string(),
             arn: "arn:seed-asg".to_string(),
@@ -2446,10 +4172,11 @@ mod tests {
             created_time: "2026-01-01".to_string(),
             scaling_policies: vec![],
             tags: vec![],
+            alarms: vec![],
         });
         reset_refresh(&mut app);
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "asg-test.md");
+        assert_eq!(app.preview_filename, "asg-a.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
         assert_eq!(app.loading_task, LoadingTask::None);
@@ -2457,16 +4184,171 @@ mod tests {
 
     #[test]
     fn login_screen_quit_shortcut_stops_app() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Login;
         app.running = true;
         handle_key(&mut app, key(KeyCode::Char('q')));
         assert!(!app.running);
     }
 
+    #[test]
+    fn process_loading_warns_when_pagination_returned_partial_results() {
+        let provider = FakeProvider::sample();
+        provider
+            .partial_failures
+            .lock()
+            .unwrap()
+            .push(PartialFailure {
+                operation: "ec2:DescribeInstances",
+                items: 1,
+                code: AwsQueryErrorCode::Throttled,
+                error: "ThrottlingException".to_string(),
+            });
+        let mut app = App::with_provider(Arc::new(provider));
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Ec2Select);
+        assert_eq!(app.instances.len(), 1);
+        assert!(app.message.contains("ec2:DescribeInstances"));
+
+        // 한 번 알린 실패는 다음 로딩에 다시 표시하지 않음
+        app.message.clear();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpc;
+        process_loading(&mut app);
+        assert!(!app.message.contains("ec2:DescribeInstances"));
+    }
+
+    /// 백그라운드 작업이 끝날 때까지 메인 루프처럼 `poll_loading`을 반복
+    fn poll_until_loaded(app: &mut App) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while app.loading {
+            assert!(Instant::now() < deadline, "background loading timed out");
+            poll_loading(app);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn poll_loading_runs_list_task_in_background_and_applies_result() {
+        let mut app = test_app();
+        app.screen = Screen::ServiceSelect;
+        app.selected_service = 0;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEc2);
+
+        poll_loading(&mut app);
+        assert!(app.loading_receiver.is_some());
+        poll_until_loaded(&mut app);
+
+        assert_eq!(app.screen, Screen::Ec2Select);
+        assert_eq!(app.instances.len(), 1);
+        assert!(app.loading_receiver.is_none());
+        assert_eq!(app.loading_task, LoadingTask::None);
+    }
+
+    #[test]
+    fn cancelled_job_does_not_leak_partial_results_into_the_next_load() {
+        let (mut app, dir) = cached_app("cancel");
+        let provider = Arc::new(FakeProvider::sample());
+        provider
+            .partial_failures
+            .lock()
+            .unwrap()
+            .push(PartialFailure {
+                operation: "ec2:DescribeInstances",
+                items: 1,
+                code: AwsQueryErrorCode::Throttled,
+                error: "ThrottlingException".to_string(),
+            });
+        app.provider = provider.clone();
+
+        // 목록 조회를 시작하고 결과가 반영되기 전에 취소
+        start_loading(&mut app, LoadingTask::LoadEc2);
+        poll_loading(&mut app);
+        let cancelled = app.loading_cancelled.clone().expect("cancel flag");
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(!app.loading);
+        assert!(cancelled.load(std::sync::atomic::Ordering::Relaxed));
+        assert_eq!(app.message, app.i18n.loading_cancelled());
+
+        // 취소된 작업이 잘린 페이지를 만난 뒤에 새 조회를 시작
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !provider.partial_failures.lock().unwrap().is_empty() {
+            assert!(Instant::now() < deadline, "cancelled job never ran");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        start_loading(&mut app, LoadingTask::LoadEc2);
+        poll_until_loaded(&mut app);
+        assert_eq!(app.instances.len(), 1);
+        assert!(!app.message.contains("ec2:DescribeInstances"));
+
+        // 새 조회의 완전한 목록은 캐시됨
+        start_loading(&mut app, LoadingTask::LoadEc2);
+        poll_until_loaded(&mut app);
+        assert!(app.list_cached_at.is_some());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn poll_loading_walks_vpc_detail_steps_to_preview() {
+        let mut app = test_app();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpcDetail("vpc-1234".to_string(), 0);
+        poll_until_loaded(&mut app);
+
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "main-vpc.md");
+        let detail = app.network_detail.as_ref().expect("network detail");
+        assert_eq!(detail.subnets.len(), 1);
+    }
+
+    #[test]
+    fn esc_cancels_in_flight_loading_and_discards_result() {
+        let mut app = test_app();
+        app.screen = Screen::ServiceSelect;
+        app.selected_service = 0;
+        handle_key(&mut app, key(KeyCode::Enter));
+        poll_loading(&mut app);
+        assert!(app.loading_receiver.is_some());
+
+        // 로딩 중 다른 키는 무시
+        handle_key(&mut app, key(KeyCode::Char('q')));
+        assert!(app.running);
+        assert!(app.loading);
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(!app.loading);
+        assert!(app.loading_receiver.is_none());
+        assert_eq!(app.loading_task, LoadingTask::None);
+        assert_eq!(app.message, app.i18n.loading_cancelled());
+
+        std::thread::sleep(Duration::from_millis(50));
+        poll_loading(&mut app);
+        assert_eq!(app.screen, Screen::ServiceSelect);
+        assert!(app.instances.is_empty());
+    }
+
+    #[test]
+    fn esc_during_vpc_detail_clears_partial_network_detail() {
+        let mut app = test_app();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpcDetail("vpc-1234".to_string(), 0);
+        process_loading(&mut app);
+        assert!(app.network_detail.is_some());
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(!app.loading);
+        assert!(app.network_detail.is_none());
+        assert!(!app.loading_progress.vpc_info);
+    }
+
     #[test]
     fn process_loading_load_list_tasks_open_each_select_screen() {
-        let mut app = App::new();
+        let mut app = test_app();
 
         app.loading = true;
         app.loading_task = LoadingTask::LoadEc2;
@@ -2507,7 +4389,7 @@ mod tests {
 
     #[test]
     fn process_loading_detail_tasks_open_preview_screen() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.settings.language = crate::i18n::Language::English;
 
         app.loading = true;
@@ -2523,7 +4405,8 @@ mod tests {
         assert!(app.sg_detail.is_some());
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadLoadBalancerDetail("lb-1234".to_string());
+        app.loading_task =
+            LoadingTask::LoadLoadBalancerDetail(SAMPLE_LOAD_BALANCER_ARN.to_string());
         process_loading(&mut app);
         assert_eq!(app.screen, Screen::Preview);
         assert!(app.lb_detail.is_some());
@@ -2542,114 +4425,645 @@ mod tests {
     }
 
     #[test]
-    fn process_loading_refresh_tasks_update_message_and_finish() {
-        let mut app = App::new();
+    fn process_loading_refresh_tasks_update_message_and_finish() {
+        let mut app = test_app();
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshEc2;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshVpc;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshSecurityGroup;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshLoadBalancer;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshEcr;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshAsg;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+    }
+
+    #[test]
+    fn process_loading_vpc_detail_step_zero_initializes_network_detail() {
+        let mut app = test_app();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpcDetail("vpc-1234".to_string(), 0);
+
+        process_loading(&mut app);
+
+        assert!(app.loading_progress.vpc_info);
+        assert_eq!(
+            app.network_detail.as_ref().map(|d| d.name.as_str()),
+            Some("main-vpc")
+        );
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadVpcDetail("vpc-1234".to_string(), 1)
+        );
+    }
+
+    #[test]
+    fn blueprint_groups_resources_by_account_with_account_headers() {
+        let mut app = test_app();
+        app.current_blueprint = Some(sample_blueprint("multi"));
+        app.screen = Screen::BlueprintDetail;
+
+        // 계정 입력: 잘못된 ARN은 거부, 역할 ARN은 이후 추가하는 리소스에 붙음
+        handle_key(&mut app, key(KeyCode::Char('c')));
+        assert_eq!(app.screen, Screen::AccountInput);
+        app.input_buffer = "arn:aws:s3:::bucket".to_string();
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.screen, Screen::AccountInput);
+        assert!(app.account.is_none());
+        app.input_buffer = "arn:aws:iam::444455556666:role/ReadOnly".to_string();
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+        let account = app.account.clone().expect("account");
+        assert_eq!(account.label(), "444455556666 (ReadOnly)");
+
+        super::add_resource_to_blueprint(
+            &mut app,
+            crate::blueprint::ResourceType::Ec2,
+            "i-1".to_string(),
+            "ec2-prod".to_string(),
+        );
+        app.account = None;
+        super::add_resource_to_blueprint(
+            &mut app,
+            crate::blueprint::ResourceType::SecurityGroup,
+            "sg-1".to_string(),
+            "sg-hub".to_string(),
+        );
+        let mut blueprint = app.current_blueprint.clone().expect("blueprint");
+        assert_eq!(blueprint.resources[0].account.as_ref(), Some(&account));
+        assert!(blueprint.resources[1].account.is_none());
+        // 같은 계정의 리소스는 블루프린트 순서와 관계없이 한 머리글 아래에 모임
+        let mut later = blueprint.resources[0].clone();
+        later.resource_id = "i-2".to_string();
+        later.resource_name = "ec2-prod-2".to_string();
+        blueprint.resources.push(later);
+        app.current_blueprint = Some(blueprint);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        while app.loading {
+            process_loading(&mut app);
+        }
+        let content = &app.preview_content;
+        let prod = format!("## {}: 444455556666 (ReadOnly)", app.i18n.account());
+        let hub = format!("## {}: {}", app.i18n.account(), app.i18n.current_account());
+        let prod_at = content.find(&prod).expect("prod account header");
+        let hub_at = content.find(&hub).expect("current account header");
+        assert!(prod_at < hub_at);
+        assert!(content.contains("  - [2. EC2 - ec2-prod-2]"));
+        assert!(content.contains("  - [3. Security Group - sg-hub]"));
+        assert!(content.contains(&format!(
+            "- **{}: 444455556666 (ReadOnly)**",
+            app.i18n.account()
+        )));
+    }
+
+    #[test]
+    fn process_loading_blueprint_resources_generates_preview_for_non_empty_blueprint() {
+        let mut app = test_app();
+        app.settings.language = crate::i18n::Language::English;
+        app.loading = true;
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp-full".to_string(),
+            name: "bp-full".to_string(),
+            resources: vec![
+                crate::blueprint::BlueprintResource {
+                    resource_type: crate::blueprint::ResourceType::Ec2,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "i-1".to_string(),
+                    resource_name: "ec2-a".to_string(),
+                    account: None,
+                },
+                crate::blueprint::BlueprintResource {
+                    resource_type: crate::blueprint::ResourceType::SecurityGroup,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "sg-1".to_string(),
+                    resource_name: "sg-a".to_string(),
+                    account: None,
+                },
+                crate::blueprint::BlueprintResource {
+                    resource_type: crate::blueprint::ResourceType::LoadBalancer,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "lb-1".to_string(),
+                    resource_name: "lb-a".to_string(),
+                    account: None,
+                },
+            ],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+
+        for _ in 0..10 {
+            if !app.loading {
+                break;
+            }
+            process_loading(&mut app);
+        }
+
+        assert_eq!(app.screen, Screen::BlueprintPreview);
+        assert!(app.preview_content.contains("# Blueprint: bp-full"));
+        assert!(app.preview_content.contains("## EC2"));
+        assert!(app.preview_content.contains("## Security Group"));
+        assert!(app.preview_content.contains("## Load Balancer"));
+        assert!(!app.loading);
+        assert_eq!(app.loading_task, LoadingTask::None);
+    }
+
+    #[test]
+    fn poll_loading_fetches_blueprint_resources_concurrently_in_blueprint_order() {
+        let mut app = test_app();
+        app.settings.language = crate::i18n::Language::English;
+        app.loading = true;
+        let resource = |resource_type, region: &str, id: &str, name: &str| {
+            crate::blueprint::BlueprintResource {
+                resource_type,
+                region: region.to_string(),
+                resource_id: id.to_string(),
+                resource_name: name.to_string(),
+                account: None,
+            }
+        };
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp-multi".to_string(),
+            name: "bp-multi".to_string(),
+            resources: vec![
+                resource(
+                    crate::blueprint::ResourceType::LoadBalancer,
+                    "us-east-1",
+                    "lb-1",
+                    "lb-a",
+                ),
+                resource(
+                    crate::blueprint::ResourceType::Ec2,
+                    "ap-northeast-2",
+                    "i-1",
+                    "ec2-a",
+                ),
+                resource(
+                    crate::blueprint::ResourceType::SecurityGroup,
+                    "eu-west-1",
+                    "sg-1",
+                    "sg-a",
+                ),
+            ],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+
+        poll_until_loaded(&mut app);
+
+        assert_eq!(app.screen, Screen::BlueprintPreview);
+        assert!(
+            app.blueprint_markdown_parts
+                .iter()
+                .all(|part| matches!(part, crate::app::BlueprintPart::Done(_)))
+        );
+        // 조회 완료 순서와 관계없이 블루프린트에 담긴 순서대로 합쳐짐
+        let position = |heading: &str| {
+            app.preview_content
+                .find(heading)
+                .unwrap_or_else(|| panic!("missing {heading}"))
+        };
+        assert!(position("## Load Balancer") < position("## EC2"));
+        assert!(position("## EC2") < position("## Security Group"));
+    }
+
+    fn cached_app(prefix: &str) -> (App, std::path::PathBuf) {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let dir =
+            std::env::temp_dir().join(format!("emd-{}-{}-{}", prefix, std::process::id(), nanos));
+        let mut app = test_app();
+        app.response_cache = crate::cache::ResponseCache::with_dir(dir.clone(), 3600);
+        (app, dir)
+    }
+
+    #[test]
+    fn list_load_is_served_from_cache_until_refresh_bypasses_it() {
+        let (mut app, dir) = cached_app("list-cache");
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2;
+        process_loading(&mut app);
+        assert_eq!(app.instances.len(), 1);
+        assert!(app.list_cached_at.is_none());
+
+        // 두 번째 조회는 캐시에서 읽고 화면과 저장 시각을 기록
+        app.instances.clear();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2;
+        process_loading(&mut app);
+        assert_eq!(app.instances.len(), 1);
+        assert_eq!(
+            app.list_cached_at.as_ref().map(|(screen, _)| screen),
+            Some(&Screen::Ec2Select)
+        );
+
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshEc2);
+        process_loading(&mut app);
+        assert_eq!(app.instances.len(), 1);
+        assert!(app.list_cached_at.is_none());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn truncated_list_is_not_cached() {
+        let (mut app, dir) = cached_app("partial-cache");
+        let provider = FakeProvider::sample();
+        provider
+            .partial_failures
+            .lock()
+            .unwrap()
+            .push(PartialFailure {
+                operation: "ec2:DescribeInstances",
+                items: 1,
+                code: AwsQueryErrorCode::Throttled,
+                error: "ThrottlingException".to_string(),
+            });
+        app.provider = Arc::new(provider);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2;
+        process_loading(&mut app);
+        assert_eq!(app.instances.len(), 1);
+        assert!(app.message.contains("ec2:DescribeInstances"));
+
+        // 잘린 목록은 캐시에 없어 다시 조회하고, 완전한 목록은 다음부터 캐시에서 읽음
+        for cached in [false, true] {
+            app.loading = true;
+            app.loading_task = LoadingTask::LoadEc2;
+            process_loading(&mut app);
+            assert_eq!(app.list_cached_at.is_some(), cached);
+        }
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn offline_mode_reads_only_cache_and_blocks_detail_queries() {
+        let (mut app, dir) = cached_app("offline");
+        app.offline = true;
 
+        // 캐시가 없으면 AWS를 호출하지 않고 빈 목록
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshEc2;
+        app.loading_task = LoadingTask::LoadEc2;
         process_loading(&mut app);
-        assert_eq!(app.message, app.i18n.refresh_complete());
-        assert!(!app.loading);
+        assert!(app.instances.is_empty());
 
+        app.offline = false;
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshVpc;
+        app.loading_task = LoadingTask::LoadEc2;
         process_loading(&mut app);
-        assert_eq!(app.message, app.i18n.refresh_complete());
-        assert!(!app.loading);
-
+        app.offline = true;
+        app.instances.clear();
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshSecurityGroup;
+        app.loading_task = LoadingTask::LoadEc2;
         process_loading(&mut app);
-        assert_eq!(app.message, app.i18n.refresh_complete());
-        assert!(!app.loading);
+        assert_eq!(app.instances.len(), 1);
+        assert!(app.list_cached_at.is_some());
 
+        app.screen = Screen::Ec2Select;
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshLoadBalancer;
+        app.loading_task = LoadingTask::LoadEc2Detail("i-1234".to_string());
         process_loading(&mut app);
-        assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
+        assert_eq!(app.screen, Screen::Ec2Select);
+        assert!(app.ec2_detail.is_none());
+        assert_eq!(app.message, app.i18n.offline_unavailable());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
 
+    #[test]
+    fn offline_blueprint_renders_cached_resources_and_marks_missing_ones() {
+        let (mut app, dir) = cached_app("offline-blueprint");
+        app.settings.language = crate::i18n::Language::English;
+        let resource = |resource_type, id: &str, name: &str| crate::blueprint::BlueprintResource {
+            resource_type,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: name.to_string(),
+            account: None,
+        };
+        let mut blueprint = sample_blueprint("bp-cache");
+        blueprint.resources = vec![resource(
+            crate::blueprint::ResourceType::Ec2,
+            "i-1234",
+            "ec2-a",
+        )];
+        app.current_blueprint = Some(blueprint.clone());
+
+        // 온라인에서 한 번 생성해 캐시를 채움
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshEcr;
-        process_loading(&mut app);
-        assert_eq!(app.message, app.i18n.refresh_complete());
-        assert!(!app.loading);
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        poll_until_loaded(&mut app);
+        assert_eq!(app.blueprint_cache_hits, 0);
+
+        blueprint.resources.push(resource(
+            crate::blueprint::ResourceType::SecurityGroup,
+            "sg-1",
+            "sg-a",
+        ));
+        app.current_blueprint = Some(blueprint);
+        app.offline = true;
+        app.blueprint_markdown_parts.clear();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        poll_until_loaded(&mut app);
+
+        assert_eq!(app.screen, Screen::BlueprintPreview);
+        assert_eq!(app.blueprint_cache_hits, 1);
+        assert!(!app.preview_content.contains("ec2-a (Query failed)"));
+        assert!(app.preview_content.contains("sg-a (not cached)"));
+        assert_eq!(app.message, app.i18n.blueprint_cache_hits(1, 2));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
 
+    #[test]
+    fn cached_blueprint_markdown_follows_blueprint_links() {
+        let (mut app, dir) = cached_app("blueprint-links");
+        let resource = |resource_type, id: &str, name: &str| crate::blueprint::BlueprintResource {
+            resource_type,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: name.to_string(),
+            account: None,
+        };
+        let mut blueprint = sample_blueprint("bp-links");
+        blueprint.resources = vec![resource(
+            crate::blueprint::ResourceType::Efs,
+            "fs-0123456789abcdef0",
+            "shared-data",
+        )];
+        app.current_blueprint = Some(blueprint.clone());
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshAsg;
-        process_loading(&mut app);
-        assert_eq!(app.message, app.i18n.refresh_complete());
-        assert!(!app.loading);
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        poll_until_loaded(&mut app);
+        assert!(!app.preview_content.contains("[vpc-1234](#network-main)"));
+
+        // VPC를 추가하면 EFS 문서는 캐시 대신 새 링크로 다시 만듦
+        blueprint.resources.push(resource(
+            crate::blueprint::ResourceType::Network,
+            "vpc-1234",
+            "main",
+        ));
+        app.current_blueprint = Some(blueprint);
+        app.blueprint_markdown_parts.clear();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        poll_until_loaded(&mut app);
+        assert_eq!(app.blueprint_cache_hits, 0);
+        assert!(app.preview_content.contains("[vpc-1234](#network-main)"));
+
+        let _ = std::fs::remove_dir_all(dir);
     }
 
     #[test]
-    fn process_loading_vpc_detail_step_zero_initializes_network_detail() {
-        let mut app = App::new();
+    fn api_gateway_blueprint_links_lambda_targets_inside_the_blueprint() {
+        let (mut app, dir) = cached_app("lambda-links");
+        let resource = |resource_type, id: &str, name: &str| crate::blueprint::BlueprintResource {
+            resource_type,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: name.to_string(),
+            account: None,
+        };
+        let mut blueprint = sample_blueprint("bp-lambda");
+        blueprint.resources = vec![resource(
+            crate::blueprint::ResourceType::ApiGateway,
+            "a1b2c3d4e5",
+            "orders-api",
+        )];
+        app.current_blueprint = Some(blueprint.clone());
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        poll_until_loaded(&mut app);
+        // 함수가 블루프린트에 없으면 콘솔 링크
+        assert!(app.preview_content.contains("[list-orders](https://"));
+
+        blueprint.resources.push(resource(
+            crate::blueprint::ResourceType::Lambda,
+            SAMPLE_LAMBDA_ARN,
+            "list-orders",
+        ));
+        app.current_blueprint = Some(blueprint);
+        app.blueprint_markdown_parts.clear();
         app.loading = true;
-        app.loading_task = LoadingTask::LoadVpcDetail("vpc-1".to_string(), 0);
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        poll_until_loaded(&mut app);
+        assert!(
+            app.preview_content
+                .contains("[list-orders](#lambda-list-orders)")
+        );
+        assert!(
+            app.preview_content
+                .contains(&format!("## {} (list-orders)", app.i18n.lambda_function()))
+        );
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
 
+    #[test]
+    fn query_failure_reasons_reach_message_and_blueprint_output() {
+        let mut provider = FakeProvider::sample();
+        provider
+            .failures
+            .insert("ec2:DescribeInstances", AwsQueryErrorCode::AccessDenied);
+        let mut app = App::with_provider(Arc::new(provider));
+        let access_denied = app.i18n.query_error_access_denied();
+        let not_found = app.i18n.query_error_not_found();
+
+        // 목록 조회 실패는 빈 목록 대신 원인과 API를 상태 메시지로 알림
+        super::start_loading(&mut app, LoadingTask::LoadEc2);
         process_loading(&mut app);
+        assert!(app.instances.is_empty());
+        assert_eq!(
+            app.message,
+            app.i18n
+                .query_error(access_denied, "ec2:DescribeInstances", 0)
+        );
 
-        assert!(app.loading_progress.vpc_info);
-        assert!(app.network_detail.is_some());
+        // 블루프린트는 실패한 리소스 자리에 원인을 남기고, 사라진 리소스는 NotFound로 기록
+        let resource = |resource_type, id: &str, name: &str| crate::blueprint::BlueprintResource {
+            resource_type,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: name.to_string(),
+            account: None,
+        };
+        let mut blueprint = sample_blueprint("bp-errors");
+        blueprint.resources = vec![
+            resource(crate::blueprint::ResourceType::Ec2, "i-1234", "ec2-a"),
+            resource(
+                crate::blueprint::ResourceType::SecurityGroup,
+                "sg-missing",
+                "sg-b",
+            ),
+        ];
+        app.blueprint_store.blueprints = vec![sample_blueprint("empty"), blueprint];
+
+        assert!(!super::generate_blueprint(&mut app, "empty"));
+        assert!(!super::generate_blueprint(&mut app, "unknown"));
+        assert!(super::generate_blueprint(&mut app, "bp-errors"));
+        assert!(!app.loading);
+        assert!(
+            app.preview_content
+                .contains(&format!("ec2-a ({})", app.i18n.query_failed()))
+        );
+        assert!(
+            app.preview_content
+                .contains(&format!("> {}: `ec2:DescribeInstances`", access_denied))
+        );
+        assert!(
+            app.preview_content
+                .contains(&format!("> {}: `sg-missing`", not_found))
+        );
+
+        let mut codes: Vec<_> = app.query_errors.iter().map(|e| e.code).collect();
+        codes.sort_by_key(|code| code.exit_code());
         assert_eq!(
-            app.loading_task,
-            LoadingTask::LoadVpcDetail("vpc-1".to_string(), 1)
+            codes,
+            vec![AwsQueryErrorCode::AccessDenied, AwsQueryErrorCode::NotFound]
+        );
+        let first = &app.query_errors[0];
+        assert_eq!(
+            app.message,
+            app.i18n.query_error(
+                crate::app::query_error_reason(first.code, &app.i18n),
+                &first.operation,
+                1
+            )
         );
     }
 
     #[test]
-    fn process_loading_blueprint_resources_generates_preview_for_non_empty_blueprint() {
-        let mut app = App::new();
-        app.settings.language = crate::i18n::Language::English;
-        app.loading = true;
-        app.current_blueprint = Some(crate::blueprint::Blueprint {
-            id: "bp-full".to_string(),
-            name: "bp-full".to_string(),
-            resources: vec![
-                crate::blueprint::BlueprintResource {
-                    resource_type: crate::blueprint::ResourceType::Ec2,
-                    region: "ap-northeast-2".to_string(),
-                    resource_id: "i-1".to_string(),
-                    resource_name: "ec2-a".to_string(),
-                },
-                crate::blueprint::BlueprintResource {
-                    resource_type: crate::blueprint::ResourceType::SecurityGroup,
-                    region: "ap-northeast-2".to_string(),
-                    resource_id: "sg-1".to_string(),
-                    resource_name: "sg-a".to_string(),
-                },
-                crate::blueprint::BlueprintResource {
-                    resource_type: crate::blueprint::ResourceType::LoadBalancer,
-                    region: "ap-northeast-2".to_string(),
-                    resource_id: "lb-1".to_string(),
-                    resource_name: "lb-a".to_string(),
-                },
-            ],
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        });
-        app.loading_task = LoadingTask::LoadBlueprintResources(0);
-
-        for _ in 0..10 {
-            if !app.loading {
-                break;
-            }
-            process_loading(&mut app);
+    fn partly_failed_blueprint_resource_is_flagged_and_not_cached() {
+        let (mut app, dir) = cached_app("partial-resource");
+        let mut provider = FakeProvider::sample();
+        provider
+            .failures
+            .insert("ec2:DescribeRouteTables", AwsQueryErrorCode::AccessDenied);
+        app.provider = Arc::new(provider);
+        let mut blueprint = sample_blueprint("bp-partial");
+        blueprint.resources = vec![crate::blueprint::BlueprintResource {
+            resource_type: crate::blueprint::ResourceType::Network,
+            region: "ap-northeast-2".to_string(),
+            resource_id: "vpc-1234".to_string(),
+            resource_name: "main".to_string(),
+            account: None,
+        }];
+        app.blueprint_store.blueprints = vec![blueprint];
+
+        for _ in 0..2 {
+            assert!(super::generate_blueprint(&mut app, "bp-partial"));
+            // 문서는 만들되 실패한 하위 조회를 경고로 남기고 캐시하지 않음
+            assert_eq!(app.blueprint_cache_hits, 0);
+            assert!(
+                !app.preview_content
+                    .contains(&format!("main ({})", app.i18n.query_failed()))
+            );
+            assert!(
+                app.preview_content
+                    .contains(&format!("> **{}**", app.i18n.incomplete_resource()))
+            );
+            assert!(app.preview_content.contains(&format!(
+                "> {}: `ec2:DescribeRouteTables`",
+                app.i18n.query_error_access_denied()
+            )));
+            // CLI 종료 코드는 첫 번째 실패 원인을 따름
+            assert_eq!(
+                app.query_errors.first().map(|error| error.code),
+                Some(AwsQueryErrorCode::AccessDenied)
+            );
         }
 
-        assert_eq!(app.screen, Screen::BlueprintPreview);
-        assert!(app.preview_content.contains("# Blueprint: bp-full"));
-        assert!(app.preview_content.contains("## EC2"));
-        assert!(app.preview_content.contains("## Security Group"));
-        assert!(app.preview_content.contains("## Load Balancer"));
-        assert!(!app.loading);
-        assert_eq!(app.loading_task, LoadingTask::None);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn truncated_sub_list_flags_blueprint_resource_and_skips_cache() {
+        let (mut app, dir) = cached_app("truncated-resource");
+        let provider = FakeProvider::sample();
+        provider
+            .partial_failures
+            .lock()
+            .unwrap()
+            .push(PartialFailure {
+                operation: "elasticfilesystem:DescribeMountTargets",
+                items: 1,
+                code: AwsQueryErrorCode::Throttled,
+                error: "ThrottlingException".to_string(),
+            });
+        app.provider = Arc::new(provider);
+        let mut blueprint = sample_blueprint("bp-truncated");
+        blueprint.resources = vec![crate::blueprint::BlueprintResource {
+            resource_type: crate::blueprint::ResourceType::Efs,
+            region: "ap-northeast-2".to_string(),
+            resource_id: "fs-0123456789abcdef0".to_string(),
+            resource_name: "shared-data".to_string(),
+            account: None,
+        }];
+        app.blueprint_store.blueprints = vec![blueprint];
+        let warning = format!("> **{}**", app.i18n.incomplete_resource());
+
+        // 탑재 대상 목록이 잘린 문서는 경고를 달고 캐시하지 않음
+        assert!(super::generate_blueprint(&mut app, "bp-truncated"));
+        assert!(app.preview_content.contains(&warning));
+        assert!(app.preview_content.contains(&format!(
+            "> {}: `elasticfilesystem:DescribeMountTargets`",
+            app.i18n.query_error_throttled()
+        )));
+        assert_eq!(
+            app.query_errors.first().map(|error| error.code),
+            Some(AwsQueryErrorCode::Throttled)
+        );
+
+        // 다시 조회한 완전한 문서는 경고 없이 캐시되고, 그다음부터 캐시에서 읽음
+        assert!(super::generate_blueprint(&mut app, "bp-truncated"));
+        assert!(!app.preview_content.contains(&warning));
+        assert_eq!(app.blueprint_cache_hits, 0);
+        assert!(super::generate_blueprint(&mut app, "bp-truncated"));
+        assert_eq!(app.blueprint_cache_hits, 1);
+
+        let _ = std::fs::remove_dir_all(dir);
     }
 
     #[test]
     fn blueprint_select_empty_generate_sets_message_and_quit_works() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintSelect;
         app.blueprint_store.blueprints = vec![sample_blueprint("empty")];
         app.selected_blueprint_index = 1;
@@ -2668,7 +5082,7 @@ mod tests {
 
     #[test]
     fn blueprint_name_input_enter_with_blank_keeps_screen_and_clears_buffer() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintNameInput;
         app.input_buffer = "   ".to_string();
 
@@ -2680,7 +5094,7 @@ mod tests {
 
     #[test]
     fn blueprint_detail_navigation_and_quit_shortcuts_work() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::BlueprintDetail;
         app.current_blueprint = Some(crate::blueprint::Blueprint {
             id: "bp-nav".to_string(),
@@ -2691,12 +5105,14 @@ mod tests {
                     region: "ap-northeast-2".to_string(),
                     resource_id: "i-1".to_string(),
                     resource_name: "one".to_string(),
+                    account: None,
                 },
                 crate::blueprint::BlueprintResource {
                     resource_type: crate::blueprint::ResourceType::Ec2,
                     region: "ap-northeast-2".to_string(),
                     resource_id: "i-2".to_string(),
                     resource_name: "two".to_string(),
+                    account: None,
                 },
             ],
             created_at: chrono::Utc::now(),
@@ -2716,7 +5132,7 @@ mod tests {
 
     #[test]
     fn region_select_enter_blueprint_escape_and_quit_paths_work() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::RegionSelect;
         app.selected_region = 1;
         app.blueprint_mode = false;
@@ -2737,7 +5153,7 @@ mod tests {
 
     #[test]
     fn service_select_remaining_enter_branches_and_quit_paths_work() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::ServiceSelect;
 
         app.selected_service = 2;
@@ -2754,6 +5170,7 @@ mod tests {
         handle_key(&mut app, key(KeyCode::Enter));
         assert_eq!(app.loading_task, LoadingTask::LoadEcr);
 
+        app.loading = false;
         app.screen = Screen::ServiceSelect;
         handle_key(&mut app, key(KeyCode::Char('l')));
         assert_eq!(app.screen, Screen::Settings);
@@ -2766,7 +5183,7 @@ mod tests {
 
     #[test]
     fn select_screens_bounds_escape_and_quit_paths_are_handled() {
-        let mut app = App::new();
+        let mut app = test_app();
 
         app.screen = Screen::Ec2Select;
         app.instances = vec![sample_resource("i-1", "ec2-a")];
@@ -2836,7 +5253,7 @@ mod tests {
 
     #[test]
     fn preview_shortcuts_cover_pageup_refresh_quit_and_non_blueprint_add() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Preview;
         app.preview_content = (0..120)
             .map(|n| format!("line {}", n))
@@ -2854,6 +5271,7 @@ mod tests {
         handle_key(&mut app, key(KeyCode::Char('r')));
         assert_eq!(app.loading_task, LoadingTask::RefreshPreview);
 
+        app.loading = false;
         app.running = true;
         handle_key(&mut app, key(KeyCode::Char('q')));
         assert!(!app.running);
@@ -2861,7 +5279,7 @@ mod tests {
 
     #[test]
     fn preview_escape_routes_for_network_security_group_and_load_balancer() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Preview;
         app.blueprint_mode = false;
 
@@ -2908,6 +5326,7 @@ mod tests {
             security_groups: vec![],
             listeners: vec![],
             target_groups: vec![],
+            alarms: vec![],
         });
         handle_key(&mut app, key(KeyCode::Esc));
         assert_eq!(app.screen, Screen::LoadBalancerSelect);
@@ -2915,13 +5334,17 @@ mod tests {
 
     #[test]
     fn settings_down_h_tab_and_quit_paths_work() {
-        let mut app = App::new();
+        let mut app = test_app();
         app.screen = Screen::Settings;
         app.selected_setting = 0;
         app.selected_tab = 1;
 
         handle_key(&mut app, key(KeyCode::Down));
-        assert_eq!(app.selected_setting, 0);
+        assert_eq!(app.selected_setting, 1);
+        for _ in 0..6 {
+            handle_key(&mut app, key(KeyCode::Down));
+        }
+        assert_eq!(app.selected_setting, 6);
 
         app.screen = Screen::Settings;
         app.selected_tab = 1;
@@ -2940,4 +5363,295 @@ mod tests {
         handle_key(&mut app, key(KeyCode::Char('q')));
         assert!(!app.running);
     }
+
+    #[test]
+    fn organization_view_browses_member_account_through_role() {
+        use aws_sdk_organizations::types::{Account, Root};
+
+        // 권한이 없거나 조직이 없으면 메시지만 표시
+        let mut app = test_app();
+        app.screen = Screen::BlueprintSelect;
+        handle_key(&mut app, key(KeyCode::Char('o')));
+        while app.loading {
+            process_loading(&mut app);
+        }
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+        assert_eq!(app.message, app.i18n.organization_unavailable());
+
+        let provider = FakeProvider {
+            org_roots: vec![Root::builder().id("r-1").name("Root").build()],
+            org_accounts: HashMap::from([(
+                "r-1".to_string(),
+                vec![Account::builder().id("444455556666").name("Prod").build()],
+            )]),
+            ..FakeProvider::sample()
+        };
+        let mut app = App::with_provider(Arc::new(provider));
+        app.screen = Screen::BlueprintSelect;
+        handle_key(&mut app, key(KeyCode::Char('o')));
+        while app.loading {
+            process_loading(&mut app);
+        }
+        assert_eq!(app.screen, Screen::OrganizationSelect);
+        assert_eq!(app.org_accounts.len(), 1);
+
+        // 역할 입력은 현재 역할 이름으로 시작
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.screen, Screen::OrgRoleInput);
+        assert_eq!(app.input_buffer, app.settings.org_role_name);
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::OrganizationSelect);
+
+        app.settings.org_role_name = "ReadOnly".to_string();
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert!(!app.blueprint_mode);
+        let account = app.account.clone().expect("account");
+        assert_eq!(
+            account.role_chain,
+            vec!["arn:aws:iam::444455556666:role/ReadOnly"]
+        );
+        assert!(account.profile.is_none());
+        while app.loading {
+            process_loading(&mut app);
+        }
+        assert_eq!(app.screen, Screen::RegionSelect);
+    }
+
+    #[test]
+    fn sso_login_requires_sso_configuration_for_profile() {
+        let mut app = test_app();
+        app.screen = Screen::Login;
+        app.available_profiles = vec!["emd-test-no-sso-profile".to_string()];
+        handle_key(&mut app, key(KeyCode::Char('s')));
+        assert!(!app.loading);
+        assert_eq!(
+            app.message,
+            app.i18n.sso_not_configured("emd-test-no-sso-profile")
+        );
+
+        // 진행 중인 인증은 취소하면 버림
+        app.loading = true;
+        app.loading_task = LoadingTask::SsoLogin("dev".to_string());
+        app.sso_login = Some(crate::aws_cli::sso::SsoDeviceAuthorization::sample());
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(!app.loading);
+        assert!(app.sso_login.is_none());
+    }
+
+    #[test]
+    fn mfa_input_takes_digits_and_starts_verification() {
+        let mut app = test_app();
+        app.screen = Screen::MfaInput;
+        for c in ['1', '2', 'x', '3'] {
+            handle_key(&mut app, key(KeyCode::Char(c)));
+        }
+        handle_key(&mut app, key(KeyCode::Backspace));
+        assert_eq!(app.input_buffer, "12");
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert!(app.loading);
+        assert_eq!(app.loading_task, LoadingTask::MfaLogin);
+
+        app.loading = false;
+        app.loading_task = LoadingTask::None;
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::Login);
+        assert!(app.input_buffer.is_empty());
+    }
+
+    #[test]
+    fn profile_switcher_opens_from_any_screen_with_account_labels() {
+        use aws_sdk_sts::operation::get_caller_identity::GetCallerIdentityOutput;
+
+        let provider = FakeProvider {
+            caller_identity: Some(
+                GetCallerIdentityOutput::builder()
+                    .account("111122223333")
+                    .arn("arn:aws:iam::111122223333:user/alice")
+                    .build(),
+            ),
+            account_aliases: vec!["acme-prod".to_string()],
+            ..FakeProvider::sample()
+        };
+        let mut app = App::with_provider(Arc::new(provider));
+        app.available_profiles = vec!["default".to_string(), "prod".to_string()];
+
+        // 로그인 화면에서는 열리지 않음
+        app.screen = Screen::Login;
+        handle_key(
+            &mut app,
+            key_with_mod(KeyCode::Char('p'), KeyModifiers::CONTROL),
+        );
+        assert!(app.profile_switcher.is_none());
+
+        app.screen = Screen::Ec2Select;
+        handle_key(
+            &mut app,
+            key_with_mod(KeyCode::Char('p'), KeyModifiers::CONTROL),
+        );
+        assert!(app.profile_switcher.is_some());
+        assert_eq!(app.loading_task, LoadingTask::LoadProfileIdentities);
+        while app.loading {
+            process_loading(&mut app);
+        }
+        assert_eq!(
+            app.profile_identities
+                .get("prod")
+                .cloned()
+                .flatten()
+                .map(|identity| identity.label()),
+            Some("acme-prod (111122223333)".to_string())
+        );
+
+        // 창이 열려 있으면 화면 키 대신 창이 키를 받음
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.profile_switcher, Some(1));
+        handle_key(&mut app, key(KeyCode::Char('q')));
+        assert!(app.running);
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(app.profile_switcher.is_none());
+        assert_eq!(app.screen, Screen::Ec2Select);
+
+        assert_eq!(
+            list_refresh_task(&Screen::Ec2Select),
+            Some(LoadingTask::RefreshEc2)
+        );
+        assert_eq!(list_refresh_task(&Screen::BlueprintDetail), None);
+    }
+
+    #[test]
+    fn profile_switch_validates_login_in_the_background_before_refreshing() {
+        use crate::aws_cli::identity::AccountIdentity;
+        use crate::aws_cli::{AwsAuthError, AwsAuthErrorCode, AwsLogin};
+
+        let mut app = test_app();
+        app.screen = Screen::Ec2Select;
+        start_login_validation(&mut app, list_refresh_task(&Screen::Ec2Select));
+        assert!(app.loading);
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::ValidateLogin(Some(Box::new(LoadingTask::RefreshEc2)))
+        );
+
+        // 로그인이 유효하면 이어서 목록을 다시 조회
+        let login = AwsLogin {
+            identity: AccountIdentity {
+                account_id: "111122223333".to_string(),
+                alias: Some("acme-prod".to_string()),
+                arn: "arn:aws:iam::111122223333:user/alice".to_string(),
+            },
+            expires_at: None,
+        };
+        apply_validated_login(&mut app, Ok(login), Some(Box::new(LoadingTask::RefreshEc2)));
+        assert_eq!(
+            app.login_info.as_ref().map(|identity| identity.label()),
+            Some("acme-prod (111122223333)".to_string())
+        );
+        assert_eq!(app.loading_task, LoadingTask::RefreshEc2);
+
+        // 인증이 필요하면 로그인 화면으로 가고 이어지는 작업은 시작하지 않음
+        finish_loading(&mut app);
+        app.loading = true;
+        app.loading_task = LoadingTask::ValidateLogin(Some(Box::new(LoadingTask::RefreshEc2)));
+        apply_validated_login(
+            &mut app,
+            Err(AwsAuthError {
+                code: AwsAuthErrorCode::CredentialsLoadFailed,
+                detail: "expired".to_string(),
+            }),
+            Some(Box::new(LoadingTask::RefreshEc2)),
+        );
+        assert_eq!(app.screen, Screen::Login);
+        assert!(!app.loading);
+        assert_eq!(app.loading_task, LoadingTask::None);
+        assert!(app.login_info.is_none());
+
+        // 오프라인이면 확인 없이 다음 작업만
+        app.offline = true;
+        app.screen = Screen::VpcSelect;
+        start_login_validation(&mut app, list_refresh_task(&Screen::VpcSelect));
+        assert_eq!(app.loading_task, LoadingTask::RefreshVpc);
+    }
+
+    #[test]
+    fn login_checks_run_in_the_background_and_session_checks_stay_quiet() {
+        use crate::aws_cli::identity::AccountIdentity;
+        use crate::aws_cli::{AwsAuthError, AwsAuthErrorCode, AwsLogin};
+
+        // 주기 확인은 로딩 화면을 띄우지 않고 상태 메시지를 유지
+        let mut app = test_app();
+        app.screen = Screen::Ec2Select;
+        app.message = "kept".to_string();
+        check_session_if_due(&mut app);
+        assert_eq!(app.loading_task, LoadingTask::CheckSession);
+        assert!(app.loading && !app.shows_loading());
+        assert_eq!(app.message, "kept");
+
+        // 키를 누르면 확인을 버리고 키를 그대로 처리
+        app.instances = vec![sample_resource("i-1", "a"), sample_resource("i-2", "b")];
+        handle_key(&mut app, key(KeyCode::Down));
+        assert!(!app.loading);
+        assert_eq!(app.selected_index, 1);
+        assert_eq!(app.message, "kept");
+
+        // 방금 확인했으면 다시 시작하지 않음
+        check_session_if_due(&mut app);
+        assert!(!app.loading);
+
+        // 로그인 화면의 확인 결과: 성공하면 블루프린트 선택, 인증 외 실패도 화면은 열어 둠
+        app.screen = Screen::Login;
+        app.loading = true;
+        app.loading_task = LoadingTask::CheckLogin;
+        assert!(app.shows_loading());
+        finish_loading(&mut app);
+        app.apply_login_result(Ok(AwsLogin {
+            identity: AccountIdentity {
+                account_id: "111122223333".to_string(),
+                alias: None,
+                arn: "arn:aws:iam::111122223333:user/alice".to_string(),
+            },
+            expires_at: None,
+        }));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+        assert!(app.login_info.is_some());
+
+        app.screen = Screen::Login;
+        app.apply_login_result(Err(AwsAuthError {
+            code: AwsAuthErrorCode::Network,
+            detail: "timeout".to_string(),
+        }));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+        assert!(app.login_info.is_none());
+        assert_eq!(app.message, app.i18n.auth_network_error());
+    }
+
+    #[test]
+    fn blueprint_documents_start_with_the_signed_in_identity() {
+        use crate::aws_cli::identity::AccountIdentity;
+        use crate::settings::DocumentIdentity;
+
+        let mut app = test_app();
+        assert_eq!(identity_markdown(&app), "");
+
+        app.login_info = Some(AccountIdentity {
+            account_id: "111122223333".to_string(),
+            alias: Some("acme-prod".to_string()),
+            arn: "arn:aws:iam::111122223333:user/alice".to_string(),
+        });
+        app.settings.document_identity = DocumentIdentity::Full;
+        let full = identity_markdown(&app);
+        assert!(
+            full.contains("| acme-prod (111122223333) | `arn:aws:iam::111122223333:user/alice` |")
+        );
+
+        app.settings.document_identity = DocumentIdentity::Redacted;
+        let redacted = identity_markdown(&app);
+        assert!(!redacted.contains("111122223333"));
+        assert!(!redacted.contains("acme-prod"));
+        assert!(redacted.contains("`arn:aws:iam::********3333:user/alice`"));
+
+        app.settings.document_identity = DocumentIdentity::Hidden;
+        assert_eq!(identity_markdown(&app), "");
+    }
 }
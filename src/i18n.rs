@@ -1,3 +1,4 @@
+use crate::aws_cli::RegionSource;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
@@ -939,6 +940,132 @@ impl I18n {
         }
     }
 
+    pub fn cached_at(&self, time: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("캐시: {}", time),
+            Language::English => format!("Cached at {}", time),
+        }
+    }
+
+    pub fn blueprint_cache_hits(&self, hits: usize, total: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("{}/{}개 리소스를 캐시에서 불러왔습니다", hits, total),
+            Language::English => format!("{}/{} resources loaded from cache", hits, total),
+        }
+    }
+
+    pub fn region_count(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("{}개 리전", count),
+            Language::English => format!("{} regions", count),
+        }
+    }
+
+    pub fn account_selected(&self, account: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("조회 계정: {}", account),
+            Language::English => format!("Browsing account: {}", account),
+        }
+    }
+
+    pub fn invalid_account(&self, input: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("프로필 이름이나 IAM 역할 ARN이 아닙니다: {}", input),
+            Language::English => format!("Not a profile name or IAM role ARN: {}", input),
+        }
+    }
+
+    pub fn virtual_profiles_saved(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("프로필 {}개를 저장했습니다 (프로필 목록에 표시)", count),
+            Language::English => format!("Saved {} profiles (shown in the profile list)", count),
+        }
+    }
+
+    pub fn sso_not_configured(&self, profile: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("{} 프로필에 SSO 설정이 없습니다", profile),
+            Language::English => format!("Profile {} has no SSO configuration", profile),
+        }
+    }
+
+    pub fn sso_login_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("SSO 로그인 실패: {}", error),
+            Language::English => format!("SSO login failed: {}", error),
+        }
+    }
+
+    pub fn mfa_login_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("MFA 인증 실패: {}", error),
+            Language::English => format!("MFA authentication failed: {}", error),
+        }
+    }
+
+    pub fn profile_switched(&self, profile: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("프로필을 {}(으)로 전환했습니다", profile),
+            Language::English => format!("Switched to profile {}", profile),
+        }
+    }
+
+    /// 리전을 정한 곳 (선택 / 환경 변수 / 프로필 설정 / 기본값)
+    pub fn region_source(&self, source: &RegionSource) -> String {
+        match (self.lang, source) {
+            (Language::Korean, RegionSource::Selected) => "선택함".to_string(),
+            (Language::English, RegionSource::Selected) => "selected".to_string(),
+            (_, RegionSource::Environment(name)) => name.to_string(),
+            (Language::Korean, RegionSource::Profile(profile)) => {
+                format!("프로필 {} 설정", profile)
+            }
+            (Language::English, RegionSource::Profile(profile)) => {
+                format!("profile {} config", profile)
+            }
+            (Language::Korean, RegionSource::Default) => "기본값".to_string(),
+            (Language::English, RegionSource::Default) => "default".to_string(),
+        }
+    }
+
+    pub fn sso_waiting(&self, remaining: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("승인을 기다리는 중... (남은 시간 {})", remaining),
+            Language::English => format!("Waiting for approval... ({} left)", remaining),
+        }
+    }
+
+    pub fn credentials_expire(&self, time: &str, remaining: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("자격 증명 만료: {} ({} 남음)", time, remaining),
+            Language::English => format!("Credentials expire at {} ({} left)", time, remaining),
+        }
+    }
+
+    pub fn partial_results(&self, operations: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("일부 결과만 표시합니다. 페이지 조회 실패: {}", operations),
+            Language::English => {
+                format!(
+                    "Showing partial results. Page request failed: {}",
+                    operations
+                )
+            }
+        }
+    }
+
+    pub fn query_error(&self, reason: &str, operation: &str, more: usize) -> String {
+        match (self.lang, more) {
+            (Language::Korean, 0) => format!("조회 실패 ({}): {}", reason, operation),
+            (Language::Korean, _) => {
+                format!("조회 실패 ({}): {} 외 {}건", reason, operation, more)
+            }
+            (Language::English, 0) => format!("Query failed ({}): {}", reason, operation),
+            (Language::English, _) => {
+                format!("Query failed ({}): {} and {} more", reason, operation, more)
+            }
+        }
+    }
+
     // VPC/Network markdown labels
     pub fn md_dns_support(&self) -> &'static str {
         match self.lang {
@@ -1476,6 +1603,1387 @@ impl I18n {
             Language::English => "Targets:",
         }
     }
+
+    // API Gateway
+    pub fn api_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "API Gateway",
+            Language::English => "API Gateway",
+        }
+    }
+
+    pub fn refreshing_api_gateway_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "API Gateway 목록 새로고침 중",
+            Language::English => "Refreshing API Gateway list",
+        }
+    }
+
+    pub fn loading_api_gateway_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "API Gateway 목록 조회 중",
+            Language::English => "Loading API Gateways",
+        }
+    }
+
+    pub fn loading_api_gateway_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "API Gateway 상세 정보 조회 중",
+            Language::English => "Loading API Gateway details",
+        }
+    }
+
+    pub fn no_api_gateways(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "API Gateway가 없습니다.",
+            Language::English => "No API Gateways found.",
+        }
+    }
+
+    pub fn md_endpoint_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "엔드포인트 유형",
+            Language::English => "Endpoint Type",
+        }
+    }
+
+    pub fn md_api_endpoint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "API 엔드포인트",
+            Language::English => "API Endpoint",
+        }
+    }
+
+    pub fn md_stages(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스테이지",
+            Language::English => "Stages",
+        }
+    }
+
+    pub fn md_deployment(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "배포",
+            Language::English => "Deployment",
+        }
+    }
+
+    pub fn md_auto_deploy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자동 배포",
+            Language::English => "Auto Deploy",
+        }
+    }
+
+    pub fn md_throttling(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스로틀링",
+            Language::English => "Throttling",
+        }
+    }
+
+    pub fn md_routes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "라우트",
+            Language::English => "Routes",
+        }
+    }
+
+    pub fn md_method(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "메서드",
+            Language::English => "Method",
+        }
+    }
+
+    pub fn md_path(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경로",
+            Language::English => "Path",
+        }
+    }
+
+    pub fn md_integration(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "통합",
+            Language::English => "Integration",
+        }
+    }
+
+    pub fn md_authorization(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "권한 부여",
+            Language::English => "Authorization",
+        }
+    }
+
+    pub fn md_authorizers(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "권한 부여자",
+            Language::English => "Authorizers",
+        }
+    }
+
+    pub fn md_identity_source(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자격 증명 소스",
+            Language::English => "Identity Source",
+        }
+    }
+
+    pub fn md_custom_domains(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용자 지정 도메인",
+            Language::English => "Custom Domains",
+        }
+    }
+
+    pub fn md_domain(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "도메인",
+            Language::English => "Domain",
+        }
+    }
+
+    pub fn md_base_path(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 경로",
+            Language::English => "Base Path",
+        }
+    }
+
+    pub fn md_stage(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스테이지",
+            Language::English => "Stage",
+        }
+    }
+
+    // KMS
+    pub fn kms_key(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "KMS 키",
+            Language::English => "KMS Key",
+        }
+    }
+
+    pub fn refreshing_kms_key_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "KMS 키 목록 새로고침 중",
+            Language::English => "Refreshing KMS key list",
+        }
+    }
+
+    pub fn loading_kms_key_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "KMS 키 목록 조회 중",
+            Language::English => "Loading KMS keys",
+        }
+    }
+
+    pub fn loading_kms_key_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "KMS 키 상세 정보 조회 중",
+            Language::English => "Loading KMS key details",
+        }
+    }
+
+    pub fn no_kms_keys(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "고객 관리형 KMS 키가 없습니다.",
+            Language::English => "No customer managed KMS keys found.",
+        }
+    }
+
+    pub fn md_aliases(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "별칭",
+            Language::English => "Aliases",
+        }
+    }
+
+    pub fn md_key_spec(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 사양",
+            Language::English => "Key Spec",
+        }
+    }
+
+    pub fn md_key_usage(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 용도",
+            Language::English => "Key Usage",
+        }
+    }
+
+    pub fn md_origin(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 구성 요소 출처",
+            Language::English => "Origin",
+        }
+    }
+
+    pub fn md_multi_region(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "다중 리전",
+            Language::English => "Multi-Region",
+        }
+    }
+
+    pub fn md_key_rotation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자동 키 교체",
+            Language::English => "Automatic Key Rotation",
+        }
+    }
+
+    pub fn md_rotation_period(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "교체 주기",
+            Language::English => "Rotation Period",
+        }
+    }
+
+    pub fn md_next_rotation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "다음 교체 일자",
+            Language::English => "Next Rotation",
+        }
+    }
+
+    pub fn md_grants(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "권한 부여(Grant) 수",
+            Language::English => "Grants",
+        }
+    }
+
+    pub fn md_key_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 정책",
+            Language::English => "Key Policy",
+        }
+    }
+
+    pub fn md_tags(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그",
+            Language::English => "Tags",
+        }
+    }
+
+    pub fn md_tag_key(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키",
+            Language::English => "Key",
+        }
+    }
+
+    pub fn md_days(&self, value: i64) -> String {
+        match self.lang {
+            Language::Korean => format!("{value}일"),
+            Language::English => format!("{value} days"),
+        }
+    }
+
+    // Secrets Manager
+    pub fn secret(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Secrets Manager 시크릿",
+            Language::English => "Secrets Manager Secret",
+        }
+    }
+
+    pub fn refreshing_secret_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시크릿 목록 새로고침 중",
+            Language::English => "Refreshing secret list",
+        }
+    }
+
+    pub fn loading_secret_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시크릿 목록 조회 중",
+            Language::English => "Loading secrets",
+        }
+    }
+
+    pub fn loading_secret_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시크릿 메타데이터 조회 중",
+            Language::English => "Loading secret metadata",
+        }
+    }
+
+    pub fn no_secrets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시크릿이 없습니다.",
+            Language::English => "No secrets found.",
+        }
+    }
+
+    pub fn md_kms_key(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "KMS 키",
+            Language::English => "KMS Key",
+        }
+    }
+
+    pub fn md_rotation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "교체",
+            Language::English => "Rotation",
+        }
+    }
+
+    pub fn md_rotation_schedule(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "교체 일정",
+            Language::English => "Rotation Schedule",
+        }
+    }
+
+    pub fn md_rotation_lambda(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "교체 Lambda",
+            Language::English => "Rotation Lambda",
+        }
+    }
+
+    pub fn md_last_rotated(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 교체",
+            Language::English => "Last Rotated",
+        }
+    }
+
+    pub fn md_last_changed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 변경",
+            Language::English => "Last Changed",
+        }
+    }
+
+    pub fn md_last_accessed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 접근",
+            Language::English => "Last Accessed",
+        }
+    }
+
+    pub fn md_primary_region(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 리전",
+            Language::English => "Primary Region",
+        }
+    }
+
+    pub fn md_owning_service(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "관리 서비스",
+            Language::English => "Owning Service",
+        }
+    }
+
+    pub fn md_value_not_collected(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "값은 수집하지 않습니다 (메타데이터만 기록)",
+            Language::English => "Values are never fetched (metadata only)",
+        }
+    }
+
+    // SSM Parameter Store
+    pub fn ssm_parameter(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SSM 파라미터",
+            Language::English => "SSM Parameter",
+        }
+    }
+
+    pub fn refreshing_ssm_parameter_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SSM 파라미터 목록 새로고침 중",
+            Language::English => "Refreshing SSM parameter list",
+        }
+    }
+
+    pub fn loading_ssm_parameter_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SSM 파라미터 목록 조회 중",
+            Language::English => "Loading SSM parameters",
+        }
+    }
+
+    pub fn loading_ssm_parameter_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SSM 파라미터 메타데이터 조회 중",
+            Language::English => "Loading SSM parameter metadata",
+        }
+    }
+
+    pub fn no_ssm_parameters(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SSM 파라미터가 없습니다.",
+            Language::English => "No SSM parameters found.",
+        }
+    }
+
+    pub fn md_tier(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "티어",
+            Language::English => "Tier",
+        }
+    }
+
+    pub fn md_data_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "데이터 유형",
+            Language::English => "Data Type",
+        }
+    }
+
+    pub fn md_version(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "버전",
+            Language::English => "Version",
+        }
+    }
+
+    pub fn md_last_modified(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 수정",
+            Language::English => "Last Modified",
+        }
+    }
+
+    pub fn md_last_modified_user(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "수정한 사용자",
+            Language::English => "Last Modified By",
+        }
+    }
+
+    pub fn md_allowed_pattern(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "허용 패턴",
+            Language::English => "Allowed Pattern",
+        }
+    }
+
+    pub fn md_policies(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정책",
+            Language::English => "Policies",
+        }
+    }
+
+    // EFS
+    pub fn efs(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EFS 파일 시스템",
+            Language::English => "EFS File System",
+        }
+    }
+
+    pub fn refreshing_efs_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EFS 목록 새로고침 중",
+            Language::English => "Refreshing EFS list",
+        }
+    }
+
+    pub fn loading_efs_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EFS 파일 시스템 목록 조회 중",
+            Language::English => "Loading EFS file systems",
+        }
+    }
+
+    pub fn loading_efs_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EFS 상세 정보 조회 중",
+            Language::English => "Loading EFS details",
+        }
+    }
+
+    pub fn no_efs_file_systems(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EFS 파일 시스템이 없습니다.",
+            Language::English => "No EFS file systems found.",
+        }
+    }
+
+    pub fn md_performance_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "성능 모드",
+            Language::English => "Performance Mode",
+        }
+    }
+
+    pub fn md_throughput_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "처리량 모드",
+            Language::English => "Throughput Mode",
+        }
+    }
+
+    pub fn md_backup_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자동 백업",
+            Language::English => "Automatic Backups",
+        }
+    }
+
+    pub fn md_lifecycle_policies(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "수명 주기 정책",
+            Language::English => "Lifecycle Policies",
+        }
+    }
+
+    pub fn md_transition_to_ia(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IA로 전환",
+            Language::English => "Transition into IA",
+        }
+    }
+
+    pub fn md_transition_to_archive(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Archive로 전환",
+            Language::English => "Transition into Archive",
+        }
+    }
+
+    pub fn md_transition_to_primary(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Standard로 복귀",
+            Language::English => "Transition into Standard",
+        }
+    }
+
+    pub fn md_access_points(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "액세스 포인트",
+            Language::English => "Access Points",
+        }
+    }
+
+    pub fn md_root_directory(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "루트 디렉터리",
+            Language::English => "Root Directory",
+        }
+    }
+
+    pub fn md_posix_user(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "POSIX 사용자",
+            Language::English => "POSIX User",
+        }
+    }
+
+    pub fn md_mount_targets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "탑재 대상",
+            Language::English => "Mount Targets",
+        }
+    }
+
+    pub fn md_ip_address(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IP 주소",
+            Language::English => "IP Address",
+        }
+    }
+
+    // CloudWatch Alarms
+    pub fn cloudwatch_alarm(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudWatch 경보",
+            Language::English => "CloudWatch Alarm",
+        }
+    }
+
+    pub fn refreshing_alarm_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경보 목록 새로고침 중",
+            Language::English => "Refreshing alarm list",
+        }
+    }
+
+    pub fn loading_alarm_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudWatch 경보 목록 조회 중",
+            Language::English => "Loading CloudWatch alarms",
+        }
+    }
+
+    pub fn loading_alarm_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경보 상세 정보 조회 중",
+            Language::English => "Loading alarm details",
+        }
+    }
+
+    pub fn no_alarms(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudWatch 경보가 없습니다.",
+            Language::English => "No CloudWatch alarms found.",
+        }
+    }
+
+    pub fn md_alarms(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경보",
+            Language::English => "Alarms",
+        }
+    }
+
+    pub fn md_metric(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "지표",
+            Language::English => "Metric",
+        }
+    }
+
+    pub fn md_namespace(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네임스페이스",
+            Language::English => "Namespace",
+        }
+    }
+
+    pub fn md_statistic(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "통계",
+            Language::English => "Statistic",
+        }
+    }
+
+    pub fn md_period(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기간",
+            Language::English => "Period",
+        }
+    }
+
+    pub fn md_evaluation_periods(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "평가 기간",
+            Language::English => "Evaluation Periods",
+        }
+    }
+
+    pub fn md_comparison(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "비교 연산자",
+            Language::English => "Comparison",
+        }
+    }
+
+    pub fn md_dimensions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "차원",
+            Language::English => "Dimensions",
+        }
+    }
+
+    pub fn md_actions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "작업",
+            Language::English => "Actions",
+        }
+    }
+
+    pub fn md_state_reason(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상태 사유",
+            Language::English => "State Reason",
+        }
+    }
+
+    // EventBridge
+    pub fn event_rule(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EventBridge 규칙",
+            Language::English => "EventBridge Rule",
+        }
+    }
+
+    pub fn refreshing_event_rule_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EventBridge 규칙 목록 새로고침 중",
+            Language::English => "Refreshing EventBridge rule list",
+        }
+    }
+
+    pub fn loading_event_rule_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EventBridge 규칙 목록 조회 중",
+            Language::English => "Loading EventBridge rules",
+        }
+    }
+
+    pub fn loading_event_rule_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EventBridge 규칙 상세 정보 조회 중",
+            Language::English => "Loading EventBridge rule details",
+        }
+    }
+
+    pub fn no_event_rules(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EventBridge 규칙이 없습니다.",
+            Language::English => "No EventBridge rules found.",
+        }
+    }
+
+    pub fn md_event_bus(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이벤트 버스",
+            Language::English => "Event Bus",
+        }
+    }
+
+    pub fn md_event_pattern(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이벤트 패턴",
+            Language::English => "Event Pattern",
+        }
+    }
+
+    pub fn md_schedule(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "일정",
+            Language::English => "Schedule",
+        }
+    }
+
+    pub fn md_managed_by(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "관리 주체",
+            Language::English => "Managed By",
+        }
+    }
+
+    pub fn md_input(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "입력",
+            Language::English => "Input",
+        }
+    }
+
+    pub fn md_dead_letter_queue(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "배달 못한 편지 대기열",
+            Language::English => "Dead-letter Queue",
+        }
+    }
+
+    pub fn md_rule_targets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "규칙 대상",
+            Language::English => "Rule Targets",
+        }
+    }
+
+    // Step Functions
+    pub fn state_machine(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Step Functions 상태 머신",
+            Language::English => "Step Functions State Machine",
+        }
+    }
+
+    pub fn refreshing_state_machine_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상태 머신 목록 새로고침 중",
+            Language::English => "Refreshing state machine list",
+        }
+    }
+
+    pub fn loading_state_machine_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Step Functions 상태 머신 목록 조회 중",
+            Language::English => "Loading Step Functions state machines",
+        }
+    }
+
+    pub fn loading_state_machine_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상태 머신 상세 정보 조회 중",
+            Language::English => "Loading state machine details",
+        }
+    }
+
+    pub fn no_state_machines(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Step Functions 상태 머신이 없습니다.",
+            Language::English => "No Step Functions state machines found.",
+        }
+    }
+
+    pub fn md_logging(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로깅",
+            Language::English => "Logging",
+        }
+    }
+
+    pub fn md_log_destinations(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로그 대상",
+            Language::English => "Log Destinations",
+        }
+    }
+
+    pub fn md_include_execution_data(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "실행 데이터 포함",
+            Language::English => "Include Execution Data",
+        }
+    }
+
+    pub fn md_tracing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "X-Ray 추적",
+            Language::English => "X-Ray Tracing",
+        }
+    }
+
+    pub fn md_definition(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정의",
+            Language::English => "Definition",
+        }
+    }
+
+    pub fn md_workflow_diagram(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "워크플로 다이어그램",
+            Language::English => "Workflow Diagram",
+        }
+    }
+
+    // Lambda
+    pub fn lambda_function(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Lambda 함수",
+            Language::English => "Lambda Function",
+        }
+    }
+
+    pub fn refreshing_lambda_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Lambda 함수 목록 새로고침 중",
+            Language::English => "Refreshing Lambda function list",
+        }
+    }
+
+    pub fn loading_lambda_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Lambda 함수 목록 조회 중",
+            Language::English => "Loading Lambda functions",
+        }
+    }
+
+    pub fn loading_lambda_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Lambda 함수 상세 정보 조회 중",
+            Language::English => "Loading Lambda function details",
+        }
+    }
+
+    pub fn no_lambda_functions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Lambda 함수가 없습니다.",
+            Language::English => "No Lambda functions found.",
+        }
+    }
+
+    pub fn md_runtime(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "런타임",
+            Language::English => "Runtime",
+        }
+    }
+
+    pub fn md_handler(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "핸들러",
+            Language::English => "Handler",
+        }
+    }
+
+    pub fn md_memory(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "메모리",
+            Language::English => "Memory",
+        }
+    }
+
+    pub fn md_timeout(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "제한 시간",
+            Language::English => "Timeout",
+        }
+    }
+
+    pub fn md_environment_variables(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "환경 변수",
+            Language::English => "Environment Variables",
+        }
+    }
+
+    pub fn md_env_values_omitted(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "값은 문서에 기록하지 않습니다 (이름만 표시)",
+            Language::English => "Values are not written to the document (names only)",
+        }
+    }
+
+    pub fn md_layers(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "레이어",
+            Language::English => "Layers",
+        }
+    }
+
+    // Loading
+    pub fn loading_cancelled(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로딩을 취소했습니다",
+            Language::English => "Loading cancelled",
+        }
+    }
+
+    // Settings
+    pub fn max_concurrency(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "동시 요청 수",
+            Language::English => "Concurrent requests",
+        }
+    }
+
+    // Cache
+    pub fn offline_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "오프라인 모드: 캐시된 결과만 표시합니다",
+            Language::English => "Offline mode: showing cached results only",
+        }
+    }
+
+    pub fn offline_unavailable(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "오프라인 모드에서는 캐시된 목록과 블루프린트만 볼 수 있습니다",
+            Language::English => "Only cached lists and blueprints are available offline",
+        }
+    }
+
+    pub fn not_cached(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "캐시 없음",
+            Language::English => "not cached",
+        }
+    }
+
+    pub fn cache_ttl(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "캐시 유효 기간",
+            Language::English => "Cache TTL",
+        }
+    }
+
+    pub fn cache_off(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "끔",
+            Language::English => "off",
+        }
+    }
+
+    // Request policy
+    pub fn max_attempts(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최대 시도 횟수",
+            Language::English => "Max attempts",
+        }
+    }
+
+    pub fn request_timeout(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "요청 제한 시간",
+            Language::English => "Request timeout",
+        }
+    }
+
+    pub fn rate_limit(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "초당 요청 수",
+            Language::English => "Requests per second",
+        }
+    }
+
+    pub fn unlimited(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "제한 없음",
+            Language::English => "unlimited",
+        }
+    }
+
+    // Query error reasons
+    pub fn query_error_access_denied(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "권한 없음",
+            Language::English => "Access denied",
+        }
+    }
+
+    pub fn query_error_not_found(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스 없음",
+            Language::English => "Not found",
+        }
+    }
+
+    pub fn query_error_throttled(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "요청 제한 초과",
+            Language::English => "Throttled",
+        }
+    }
+
+    pub fn query_error_network(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네트워크 오류",
+            Language::English => "Network error",
+        }
+    }
+
+    pub fn query_error_timeout(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시간 초과",
+            Language::English => "Timed out",
+        }
+    }
+
+    pub fn query_error_credentials(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자격 증명 오류",
+            Language::English => "Credential error",
+        }
+    }
+
+    pub fn query_error_parse(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "응답 해석 실패",
+            Language::English => "Unreadable response",
+        }
+    }
+
+    pub fn query_error_unknown(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "알 수 없는 오류",
+            Language::English => "Unknown error",
+        }
+    }
+
+    // Region select
+    pub fn favorite(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "즐겨찾기",
+            Language::English => "Favorite",
+        }
+    }
+
+    // Region select
+    pub fn loading_regions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리전 목록 조회 중",
+            Language::English => "Loading regions",
+        }
+    }
+
+    // Region select
+    pub fn all_regions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "모든 리전",
+            Language::English => "All regions",
+        }
+    }
+
+    // Console link
+    pub fn console(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "콘솔",
+            Language::English => "Console",
+        }
+    }
+
+    // Multi-account
+    pub fn account(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "계정",
+            Language::English => "Account",
+        }
+    }
+
+    pub fn account_input_title(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조회 계정",
+            Language::English => "Account to browse",
+        }
+    }
+
+    pub fn enter_account(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "프로필 이름 또는 역할 ARN (체인은 > 로 연결, 비우면 선택한 프로필)"
+            }
+            Language::English => {
+                "Profile name or role ARN (chain with >, empty for the selected profile)"
+            }
+        }
+    }
+
+    pub fn current_account(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "현재 계정",
+            Language::English => "Current account",
+        }
+    }
+
+    // Organizations
+    pub fn organization(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조직",
+            Language::English => "Organization",
+        }
+    }
+
+    pub fn organization_unavailable(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Organizations 계정 목록을 볼 수 없습니다 (권한 또는 조직 없음)",
+            Language::English => {
+                "Cannot list Organizations accounts (no access or no organization)"
+            }
+        }
+    }
+
+    pub fn org_role_input_title(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "멤버 계정 역할",
+            Language::English => "Member Account Role",
+        }
+    }
+
+    pub fn enter_org_role_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "멤버 계정에서 맡을 역할 이름을 입력하세요:",
+            Language::English => "Enter the role name to assume in member accounts:",
+        }
+    }
+
+    pub fn role(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "역할",
+            Language::English => "Role",
+        }
+    }
+
+    pub fn save_profiles(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로필 저장",
+            Language::English => "Save profiles",
+        }
+    }
+
+    pub fn export_profiles(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로필 내보내기",
+            Language::English => "Export profiles",
+        }
+    }
+
+    // Organizations loading
+    pub fn loading_organization(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조직 계정 목록 조회 중...",
+            Language::English => "Loading organization accounts...",
+        }
+    }
+
+    // SSO login
+    pub fn sso_login(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SSO 로그인",
+            Language::English => "SSO login",
+        }
+    }
+
+    pub fn sso_starting(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SSO 기기 인증을 시작하는 중...",
+            Language::English => "Starting SSO device authorization...",
+        }
+    }
+
+    pub fn sso_open_url(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "브라우저에서 아래 주소를 열고, 코드가 같은지 확인한 뒤 승인하세요:"
+            }
+            Language::English => {
+                "Open this URL in a browser, check that the code matches, then approve:"
+            }
+        }
+    }
+
+    pub fn sso_user_code(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "코드",
+            Language::English => "Code",
+        }
+    }
+
+    pub fn sso_login_expired(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SSO 인증 시간이 지났습니다. 다시 시도하세요",
+            Language::English => "SSO authorization expired. Please try again",
+        }
+    }
+
+    pub fn credentials_expiring_warning(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자격 증명이 곧 만료됩니다. 긴 작업 전에 다시 로그인하세요",
+            Language::English => "Credentials expire soon. Log in again before long runs",
+        }
+    }
+
+    // SSO login expiry
+    pub fn credentials_expired(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자격 증명이 만료되었습니다",
+            Language::English => "Credentials have expired",
+        }
+    }
+
+    // MFA
+    pub fn mfa_input_title(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MFA 코드",
+            Language::English => "MFA code",
+        }
+    }
+
+    pub fn enter_mfa_code(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MFA 장치에 표시된 코드를 입력하세요",
+            Language::English => "Enter the code shown on your MFA device",
+        }
+    }
+
+    pub fn mfa_device(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MFA 장치",
+            Language::English => "MFA device",
+        }
+    }
+
+    pub fn auth_mfa_required(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MFA 코드가 필요합니다",
+            Language::English => "An MFA code is required",
+        }
+    }
+
+    pub fn verifying_mfa_code(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MFA 코드 확인 중...",
+            Language::English => "Verifying MFA code...",
+        }
+    }
+
+    // Profile switcher
+    pub fn profile_switcher_title(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로필 전환",
+            Language::English => "Switch profile",
+        }
+    }
+
+    pub fn switch_profile(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로필 전환",
+            Language::English => "switch profile",
+        }
+    }
+
+    pub fn loading_profile_identities(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로필 계정 확인 중...",
+            Language::English => "Checking profile accounts...",
+        }
+    }
+
+    pub fn not_signed_in(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로그인 필요",
+            Language::English => "not signed in",
+        }
+    }
+
+    // Identity
+    pub fn profile(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로필",
+            Language::English => "Profile",
+        }
+    }
+
+    pub fn caller(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "호출자",
+            Language::English => "Caller",
+        }
+    }
+
+    pub fn document_identity(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "문서의 계정 정보",
+            Language::English => "Account info in documents",
+        }
+    }
+
+    pub fn identity_full(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "표시",
+            Language::English => "Shown",
+        }
+    }
+
+    pub fn identity_redacted(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "일부 가림",
+            Language::English => "Redacted",
+        }
+    }
+
+    pub fn identity_hidden(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "숨김",
+            Language::English => "Hidden",
+        }
+    }
+
+    // 블루프린트에서 일부 하위 조회가 실패한 리소스
+    pub fn incomplete_resource(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "일부 정보를 조회하지 못했습니다",
+            Language::English => "Some details could not be retrieved",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1502,7 +3010,8 @@ mod tests {
     }
 
     fn assert_all_labels_non_empty(i: &I18n) {
-        assert_non_empty!(i,
+        assert_non_empty!(
+            i,
             exit,
             settings,
             main_tab,
@@ -1701,7 +3210,198 @@ mod tests {
             md_threshold,
             md_healthy,
             md_unhealthy,
-            md_targets
+            md_targets,
+            api_gateway,
+            refreshing_api_gateway_list,
+            loading_api_gateway_list,
+            loading_api_gateway_detail,
+            no_api_gateways,
+            md_endpoint_type,
+            md_api_endpoint,
+            md_stages,
+            md_deployment,
+            md_auto_deploy,
+            md_throttling,
+            md_routes,
+            md_method,
+            md_path,
+            md_integration,
+            md_authorization,
+            md_authorizers,
+            md_identity_source,
+            md_custom_domains,
+            md_domain,
+            md_base_path,
+            md_stage,
+            kms_key,
+            refreshing_kms_key_list,
+            loading_kms_key_list,
+            loading_kms_key_detail,
+            no_kms_keys,
+            md_aliases,
+            md_key_spec,
+            md_key_usage,
+            md_origin,
+            md_multi_region,
+            md_key_rotation,
+            md_rotation_period,
+            md_next_rotation,
+            md_grants,
+            md_key_policy,
+            md_tags,
+            md_tag_key,
+            secret,
+            refreshing_secret_list,
+            loading_secret_list,
+            loading_secret_detail,
+            no_secrets,
+            md_kms_key,
+            md_rotation,
+            md_rotation_schedule,
+            md_rotation_lambda,
+            md_last_rotated,
+            md_last_changed,
+            md_last_accessed,
+            md_primary_region,
+            md_owning_service,
+            md_value_not_collected,
+            ssm_parameter,
+            refreshing_ssm_parameter_list,
+            loading_ssm_parameter_list,
+            loading_ssm_parameter_detail,
+            no_ssm_parameters,
+            md_tier,
+            md_data_type,
+            md_version,
+            md_last_modified,
+            md_last_modified_user,
+            md_allowed_pattern,
+            md_policies,
+            efs,
+            refreshing_efs_list,
+            loading_efs_list,
+            loading_efs_detail,
+            no_efs_file_systems,
+            md_performance_mode,
+            md_throughput_mode,
+            md_backup_policy,
+            md_lifecycle_policies,
+            md_transition_to_ia,
+            md_transition_to_archive,
+            md_transition_to_primary,
+            md_access_points,
+            md_root_directory,
+            md_posix_user,
+            md_mount_targets,
+            md_ip_address,
+            cloudwatch_alarm,
+            refreshing_alarm_list,
+            loading_alarm_list,
+            loading_alarm_detail,
+            no_alarms,
+            md_alarms,
+            md_metric,
+            md_namespace,
+            md_statistic,
+            md_period,
+            md_evaluation_periods,
+            md_comparison,
+            md_dimensions,
+            md_actions,
+            md_state_reason,
+            event_rule,
+            refreshing_event_rule_list,
+            loading_event_rule_list,
+            loading_event_rule_detail,
+            no_event_rules,
+            md_event_bus,
+            md_event_pattern,
+            md_schedule,
+            md_managed_by,
+            md_input,
+            md_dead_letter_queue,
+            md_rule_targets,
+            state_machine,
+            refreshing_state_machine_list,
+            loading_state_machine_list,
+            loading_state_machine_detail,
+            no_state_machines,
+            md_logging,
+            md_log_destinations,
+            md_include_execution_data,
+            md_tracing,
+            md_definition,
+            md_workflow_diagram,
+            lambda_function,
+            refreshing_lambda_list,
+            loading_lambda_list,
+            loading_lambda_detail,
+            no_lambda_functions,
+            md_runtime,
+            md_handler,
+            md_memory,
+            md_timeout,
+            md_environment_variables,
+            md_env_values_omitted,
+            md_layers,
+            loading_cancelled,
+            max_concurrency,
+            offline_mode,
+            offline_unavailable,
+            not_cached,
+            cache_ttl,
+            cache_off,
+            max_attempts,
+            request_timeout,
+            rate_limit,
+            unlimited,
+            query_error_access_denied,
+            query_error_not_found,
+            query_error_throttled,
+            query_error_network,
+            query_error_timeout,
+            query_error_credentials,
+            query_error_parse,
+            query_error_unknown,
+            favorite,
+            loading_regions,
+            all_regions,
+            console,
+            account,
+            account_input_title,
+            enter_account,
+            current_account,
+            organization,
+            organization_unavailable,
+            org_role_input_title,
+            enter_org_role_name,
+            role,
+            save_profiles,
+            export_profiles,
+            loading_organization,
+            sso_login,
+            sso_starting,
+            sso_open_url,
+            sso_user_code,
+            sso_login_expired,
+            credentials_expiring_warning,
+            credentials_expired,
+            mfa_input_title,
+            enter_mfa_code,
+            mfa_device,
+            auth_mfa_required,
+            verifying_mfa_code,
+            profile_switcher_title,
+            switch_profile,
+            loading_profile_identities,
+            not_signed_in,
+            profile,
+            caller,
+            document_identity,
+            identity_full,
+            identity_redacted,
+            identity_hidden,
+            incomplete_resource
         );
     }
 
@@ -1726,9 +3426,11 @@ mod tests {
 
         assert_non_empty!(ko.asg_seconds(10));
         assert_non_empty!(ko.asg_instances_with_count(3));
+        assert_non_empty!(ko.md_days(7));
         assert_non_empty!(ko.network_detail_unavailable("vpc-1234"));
         assert_non_empty!(en.asg_seconds(10));
         assert_non_empty!(en.asg_instances_with_count(3));
+        assert_non_empty!(en.md_days(7));
         assert_non_empty!(en.network_detail_unavailable("vpc-1234"));
     }
 }
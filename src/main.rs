@@ -1,9 +1,12 @@
 mod app;
 mod aws_cli;
 mod blueprint;
+mod cache;
 mod cli;
 mod handler;
 mod i18n;
+#[cfg(test)]
+mod moto_integration;
 mod output;
 mod settings;
 mod ui;
@@ -21,9 +24,9 @@ use std::time::Duration;
 use app::App;
 
 fn main() -> io::Result<()> {
-    if cli::run().is_some() {
+    let Some(options) = cli::run() else {
         return Ok(());
-    }
+    };
 
     // Setup logging
     let file_appender = tracing_appender::rolling::daily(".", "emd.log");
@@ -42,7 +45,12 @@ fn main() -> io::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new();
-    app.init_auth_flow();
+    app.set_endpoint_url_flag(options.endpoint_url);
+    if options.offline {
+        app.start_offline();
+    } else {
+        app.init_auth_flow();
+    }
 
     let res = run_app(&mut terminal, &mut app);
 
@@ -66,14 +74,14 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
     while app.running {
         terminal.draw(|f| ui::draw(f, app))?;
 
-        // 로딩 중이면 실제 작업 수행
+        // 로딩 중이면 백그라운드 작업 결과를 반영하거나 다음 단계를 시작
+        // (이벤트 폴링은 계속해 스피너 갱신과 Esc 취소를 처리)
         if app.loading {
-            handler::process_loading(app);
-            continue;
+            handler::poll_loading(app);
+        } else {
+            handler::check_session_if_due(app);
         }
 
-        app.check_login_if_needed_for_current_screen();
-
         // 100ms 타임아웃으로 이벤트 폴링
         if event::poll(Duration::from_millis(100))? {
             match event::read()? {
@@ -0,0 +1,271 @@
+//! 로컬 moto 서버를 대상으로 목록 → 상세 → 블루프린트 흐름을 실제 SDK로 실행하는 통합 테스트.
+//! 블루프린트 응답은 녹화한 뒤 재생해서 같은 문서가 나오는지도 확인한다.
+//!
+//! 서버가 필요하므로 기본 테스트에서는 제외(`#[ignore]`)되며, `EMD_MOTO_ENDPOINT`를
+//! 설정하고 `--ignored`로 실행한다. 자격 증명은 moto가 검증하지 않으므로 임의 값을 넘긴다.
+//!
+//! ```bash
+//! moto_server -p 5000 &
+//! EMD_MOTO_ENDPOINT=http://127.0.0.1:5000 AWS_ACCESS_KEY_ID=testing \
+//!     AWS_SECRET_ACCESS_KEY=testing cargo test moto_ -- --ignored
+//! ```
+
+use crate::app::{App, LoadingTask, Screen};
+use crate::aws_cli::{self, FixtureMode, SdkProvider};
+use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
+use crate::handler::{generate_blueprint, process_loading};
+use aws_sdk_ec2::types::{InstanceType, ResourceType as Ec2ResourceType, Tag, TagSpecification};
+use std::sync::Arc;
+
+const REGION: &str = "us-east-1";
+
+/// moto가 기본으로 제공하는 AMI (이미지 목록이 비어 있을 때 사용)
+const FALLBACK_AMI_ID: &str = "ami-12c6146b";
+
+fn moto_endpoint() -> String {
+    std::env::var("EMD_MOTO_ENDPOINT")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .expect("EMD_MOTO_ENDPOINT must point at a running moto server")
+}
+
+/// moto가 응답하지 못하는 API (CloudWatch의 CBOR 프로토콜 미지원으로 404)
+const UNSUPPORTED_BY_MOTO: &[&str] = &["cloudwatch:DescribeAlarms"];
+
+fn assert_no_query_errors(app: &App) {
+    let errors: Vec<_> = app
+        .query_errors
+        .iter()
+        .filter(|error| !UNSUPPORTED_BY_MOTO.contains(&error.operation.as_str()))
+        .collect();
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+fn without_unsupported_notes(markdown: &str) -> String {
+    markdown
+        .lines()
+        .filter(|line| {
+            !UNSUPPORTED_BY_MOTO
+                .iter()
+                .any(|operation| line.contains(&format!("`{}`", operation)))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn name_tag(resource_type: Ec2ResourceType, name: &str) -> TagSpecification {
+    TagSpecification::builder()
+        .resource_type(resource_type)
+        .tags(Tag::builder().key("Name").value(name).build())
+        .build()
+}
+
+struct Seeded {
+    suffix: String,
+    vpc_id: String,
+    group_id: String,
+    instance_id: String,
+}
+
+/// 테스트 데이터 생성 (moto 서버를 재사용해도 겹치지 않도록 이름에 접미사를 붙임)
+fn seed(endpoint: &str) -> Seeded {
+    let suffix = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default()
+        .to_string();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("build runtime");
+    runtime.block_on(async {
+        let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(aws_config::Region::new(REGION))
+            .endpoint_url(endpoint)
+            .load()
+            .await;
+        let ec2 = aws_sdk_ec2::Client::new(&config);
+
+        let vpc_id = ec2
+            .create_vpc()
+            .cidr_block("10.42.0.0/16")
+            .tag_specifications(name_tag(Ec2ResourceType::Vpc, &format!("vpc-{}", suffix)))
+            .send()
+            .await
+            .expect("create vpc")
+            .vpc()
+            .and_then(|vpc| vpc.vpc_id())
+            .expect("vpc id")
+            .to_string();
+        let subnet_id = ec2
+            .create_subnet()
+            .vpc_id(&vpc_id)
+            .cidr_block("10.42.1.0/24")
+            .send()
+            .await
+            .expect("create subnet")
+            .subnet()
+            .and_then(|subnet| subnet.subnet_id())
+            .expect("subnet id")
+            .to_string();
+        let group_id = ec2
+            .create_security_group()
+            .group_name(format!("sg-{}", suffix))
+            .description("emd moto integration")
+            .vpc_id(&vpc_id)
+            .send()
+            .await
+            .expect("create security group")
+            .group_id()
+            .expect("group id")
+            .to_string();
+
+        let image_id = ec2
+            .describe_images()
+            .owners("amazon")
+            .send()
+            .await
+            .ok()
+            .and_then(|output| {
+                output
+                    .images()
+                    .first()
+                    .and_then(|image| image.image_id())
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| FALLBACK_AMI_ID.to_string());
+        let instance_id = ec2
+            .run_instances()
+            .image_id(image_id)
+            .instance_type(InstanceType::T2Micro)
+            .min_count(1)
+            .max_count(1)
+            .subnet_id(&subnet_id)
+            .security_group_ids(&group_id)
+            .tag_specifications(name_tag(
+                Ec2ResourceType::Instance,
+                &format!("ec2-{}", suffix),
+            ))
+            .send()
+            .await
+            .expect("run instance")
+            .instances()
+            .first()
+            .and_then(|instance| instance.instance_id())
+            .expect("instance id")
+            .to_string();
+
+        Seeded {
+            suffix,
+            vpc_id,
+            group_id,
+            instance_id,
+        }
+    })
+}
+
+fn resource(resource_type: ResourceType, id: &str, name: &str) -> BlueprintResource {
+    BlueprintResource {
+        resource_type,
+        region: REGION.to_string(),
+        resource_id: id.to_string(),
+        resource_name: name.to_string(),
+        account: None,
+    }
+}
+
+#[test]
+#[ignore = "needs a moto server at EMD_MOTO_ENDPOINT"]
+fn moto_list_detail_and_blueprint_flow_uses_endpoint_override() {
+    let endpoint = moto_endpoint();
+    let seeded = seed(&endpoint);
+
+    let mut app = App::with_provider(Arc::new(SdkProvider));
+    app.set_endpoint_url_flag(Some(endpoint));
+
+    aws_cli::with_region(REGION, || {
+        // 목록
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Ec2Select);
+        assert_no_query_errors(&app);
+        assert!(
+            app.instances.iter().any(|i| i.id == seeded.instance_id),
+            "seeded instance missing from list: {:?}",
+            app.instances
+        );
+
+        // 다른 계정: 역할을 맡은 자격 증명으로도 같은 목록을 조회 (moto는 같은 계정으로 응답)
+        app.account = aws_cli::AccountTarget::parse("arn:aws:iam::123456789012:role/emd-readonly")
+            .expect("role arn");
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2;
+        process_loading(&mut app);
+        app.account = None;
+        assert_no_query_errors(&app);
+        assert!(app.instances.iter().any(|i| i.id == seeded.instance_id));
+
+        // 상세
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2Detail(seeded.instance_id.clone());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.preview_content.contains(&seeded.instance_id));
+
+        // 블루프린트
+        let name = format!("moto-{}", seeded.suffix);
+        app.blueprint_store.blueprints = vec![Blueprint {
+            id: name.clone(),
+            name: name.clone(),
+            resources: vec![
+                resource(ResourceType::Ec2, &seeded.instance_id, "ec2"),
+                resource(ResourceType::Network, &seeded.vpc_id, "network"),
+                resource(ResourceType::SecurityGroup, &seeded.group_id, "sg"),
+            ],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }];
+        // 문서 머리의 계정 정보 (moto 기본 계정)
+        app.load_identity();
+        app.settings.document_identity = crate::settings::DocumentIdentity::Full;
+        assert_eq!(
+            app.login_info
+                .as_ref()
+                .map(|identity| identity.account_id.as_str()),
+            Some("123456789012")
+        );
+        let fixtures = std::env::temp_dir().join(format!("emd-moto-fixtures-{}", seeded.suffix));
+        aws_cli::set_fixture_mode(FixtureMode::Record {
+            dir: fixtures.clone(),
+            scrub_account_ids: false,
+        });
+        assert!(generate_blueprint(&mut app, &name));
+        assert_no_query_errors(&app);
+        for id in [&seeded.instance_id, &seeded.vpc_id, &seeded.group_id] {
+            assert!(
+                app.preview_content.contains(id.as_str()),
+                "{} missing from blueprint",
+                id
+            );
+        }
+        assert!(app.preview_content.contains("| 123456789012 |"));
+        let recorded = app.preview_content.clone();
+
+        // 재생: 엔드포인트 없이 녹화한 응답만으로 같은 문서를 만듦
+        aws_cli::set_endpoint_overrides(aws_cli::EndpointOverrides::NONE);
+        aws_cli::set_fixture_mode(FixtureMode::Replay {
+            dir: fixtures.clone(),
+        });
+        app.blueprint_markdown_parts.clear();
+        assert!(generate_blueprint(&mut app, &name));
+        aws_cli::set_fixture_mode(FixtureMode::Off);
+        let _ = std::fs::remove_dir_all(&fixtures);
+        assert_no_query_errors(&app);
+        // moto가 응답하지 못한 API의 오류 본문에는 전송 계층 정보가 들어 있어 비교에서 제외
+        assert_eq!(
+            without_unsupported_notes(&app.preview_content),
+            without_unsupported_notes(&recorded)
+        );
+    });
+}
@@ -1,13 +1,136 @@
+use crate::aws_cli::organizations::DEFAULT_ORG_ROLE_NAME;
+use crate::aws_cli::{DEFAULT_MAX_CONCURRENCY, EndpointOverrides, RequestPolicy, VirtualProfile};
+use crate::cache::DEFAULT_CACHE_TTL_SECS;
 use crate::i18n::Language;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// 블루프린트 문서 머리에 넣는 계정 정보
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentIdentity {
+    #[default]
+    Full,
+    /// 계정 ID를 끝 4자리만 남기고 별칭은 뺌 (외부 공유용)
+    Redacted,
+    Hidden,
+}
+
+impl DocumentIdentity {
+    pub fn next(self) -> Self {
+        match self {
+            DocumentIdentity::Full => DocumentIdentity::Redacted,
+            DocumentIdentity::Redacted => DocumentIdentity::Hidden,
+            DocumentIdentity::Hidden => DocumentIdentity::Full,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
     pub language: Language,
     #[serde(default)]
     pub aws_profile: Option<String>,
+    /// 블루프린트/상세 조회 시 동시에 보낼 AWS 요청 수
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: usize,
+    /// 디스크 캐시 유효 기간(초), 0이면 항상 새로 조회
+    #[serde(default = "default_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+    /// AWS 요청 최대 시도 횟수 (재시도 포함)
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// AWS 요청 한 번의 제한 시간(초)
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// 초당 최대 AWS 요청 수 (0이면 제한 없음)
+    #[serde(default = "default_requests_per_second")]
+    pub requests_per_second: u32,
+    /// 모든 AWS 서비스에 사용할 엔드포인트 (LocalStack/moto 등)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<String>,
+    /// 서비스별 엔드포인트 (예: `{"ec2": "http://localhost:4566"}`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub service_endpoints: BTreeMap<String, String>,
+    /// 리전 선택 화면 맨 위에 보일 리전 (추가한 순서)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub favorite_regions: Vec<String>,
+    /// Organizations 멤버 계정을 조회할 때 맡을 역할 이름
+    #[serde(default = "default_org_role_name")]
+    pub org_role_name: String,
+    /// Organizations 화면에서 저장한 프로필 (프로필 목록에 함께 표시)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub virtual_profiles: Vec<VirtualProfile>,
+    /// 블루프린트 ID → 그 블루프린트에서 마지막으로 쓴 프로필
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub blueprint_profiles: BTreeMap<String, String>,
+    #[serde(default)]
+    pub document_identity: DocumentIdentity,
+}
+
+impl AppSettings {
+    pub fn request_policy(&self) -> RequestPolicy {
+        RequestPolicy {
+            max_attempts: self.max_attempts,
+            timeout_secs: self.timeout_secs,
+            requests_per_second: self.requests_per_second,
+        }
+    }
+
+    /// 설정 파일의 엔드포인트 (`--endpoint-url`은 실행 시 따로 적용)
+    pub fn endpoint_overrides(&self) -> EndpointOverrides {
+        EndpointOverrides {
+            flag: None,
+            url: self.endpoint_url.clone(),
+            services: self.service_endpoints.clone(),
+        }
+    }
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            aws_profile: None,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            cache_ttl_secs: DEFAULT_CACHE_TTL_SECS,
+            max_attempts: RequestPolicy::DEFAULT.max_attempts,
+            timeout_secs: RequestPolicy::DEFAULT.timeout_secs,
+            requests_per_second: RequestPolicy::DEFAULT.requests_per_second,
+            endpoint_url: None,
+            service_endpoints: BTreeMap::new(),
+            favorite_regions: Vec::new(),
+            org_role_name: default_org_role_name(),
+            virtual_profiles: Vec::new(),
+            blueprint_profiles: BTreeMap::new(),
+            document_identity: DocumentIdentity::default(),
+        }
+    }
+}
+
+fn default_max_concurrency() -> usize {
+    DEFAULT_MAX_CONCURRENCY
+}
+
+fn default_cache_ttl_secs() -> u64 {
+    DEFAULT_CACHE_TTL_SECS
+}
+
+fn default_max_attempts() -> u32 {
+    RequestPolicy::DEFAULT.max_attempts
+}
+
+fn default_timeout_secs() -> u64 {
+    RequestPolicy::DEFAULT.timeout_secs
+}
+
+fn default_requests_per_second() -> u32 {
+    RequestPolicy::DEFAULT.requests_per_second
+}
+
+fn default_org_role_name() -> String {
+    DEFAULT_ORG_ROLE_NAME.to_string()
 }
 
 fn get_settings_path() -> Option<PathBuf> {
@@ -49,7 +172,12 @@ pub fn save_settings(settings: &AppSettings) -> Result<(), std::io::Error> {
 
 #[cfg(test)]
 mod tests {
-    use super::{AppSettings, load_settings, save_settings};
+    use super::{AppSettings, DocumentIdentity, load_settings, save_settings};
+    use crate::aws_cli::organizations::DEFAULT_ORG_ROLE_NAME;
+    use crate::aws_cli::{
+        DEFAULT_MAX_CONCURRENCY, EndpointOverrides, RequestPolicy, VirtualProfile,
+    };
+    use crate::cache::DEFAULT_CACHE_TTL_SECS;
     use crate::i18n::Language;
     use std::env;
     use std::fs;
@@ -117,11 +245,63 @@ mod tests {
         let to_save = AppSettings {
             language: Language::Korean,
             aws_profile: Some("default".to_string()),
+            max_concurrency: 4,
+            cache_ttl_secs: 300,
+            max_attempts: 5,
+            timeout_secs: 10,
+            requests_per_second: 0,
+            endpoint_url: None,
+            service_endpoints: [("ec2".to_string(), "http://localhost:5000".to_string())]
+                .into_iter()
+                .collect(),
+            favorite_regions: vec!["sa-east-1".to_string()],
+            org_role_name: "ReadOnly".to_string(),
+            virtual_profiles: vec![VirtualProfile {
+                name: "prod".to_string(),
+                role_arn: "arn:aws:iam::444455556666:role/ReadOnly".to_string(),
+                source_profile: Some("default".to_string()),
+            }],
+            blueprint_profiles: [("bp-1".to_string(), "prod".to_string())]
+                .into_iter()
+                .collect(),
+            document_identity: DocumentIdentity::Redacted,
         };
         save_settings(&to_save).expect("save settings");
 
         let loaded = load_settings();
         assert_eq!(loaded.language, Language::Korean);
         assert_eq!(loaded.aws_profile.as_deref(), Some("default"));
+        assert_eq!(loaded.max_concurrency, 4);
+        assert_eq!(loaded.cache_ttl_secs, 300);
+        assert_eq!(loaded.request_policy().max_attempts, 5);
+        assert_eq!(loaded.request_policy().timeout_secs, 10);
+        assert_eq!(loaded.request_policy().requests_per_second, 0);
+        assert_eq!(loaded.favorite_regions, vec!["sa-east-1"]);
+        assert_eq!(loaded.org_role_name, "ReadOnly");
+        assert_eq!(loaded.virtual_profiles, to_save.virtual_profiles);
+        assert_eq!(loaded.blueprint_profiles, to_save.blueprint_profiles);
+        assert_eq!(loaded.document_identity, DocumentIdentity::Redacted);
+        let endpoints = loaded.endpoint_overrides();
+        assert_eq!(endpoints.url, None);
+        assert_eq!(
+            endpoints.services.get("ec2").map(String::as_str),
+            Some("http://localhost:5000")
+        );
+    }
+
+    #[test]
+    fn settings_without_max_concurrency_use_default_limit() {
+        let settings: AppSettings =
+            serde_json::from_str(r#"{"language":"Korean"}"#).expect("parse legacy settings");
+        assert_eq!(settings.language, Language::Korean);
+        assert_eq!(settings.max_concurrency, DEFAULT_MAX_CONCURRENCY);
+        assert_eq!(settings.cache_ttl_secs, DEFAULT_CACHE_TTL_SECS);
+        assert_eq!(settings.request_policy(), RequestPolicy::DEFAULT);
+        assert_eq!(settings.endpoint_overrides(), EndpointOverrides::NONE);
+        assert!(settings.favorite_regions.is_empty());
+        assert_eq!(settings.org_role_name, DEFAULT_ORG_ROLE_NAME);
+        assert!(settings.virtual_profiles.is_empty());
+        assert!(settings.blueprint_profiles.is_empty());
+        assert_eq!(settings.document_identity, DocumentIdentity::Full);
     }
 }
@@ -3,11 +3,16 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Tabs, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs, Wrap},
 };
 
-use crate::app::{App, LoadingTask, REGIONS, SERVICE_KEYS, Screen};
-use crate::aws_cli::AwsAuthErrorCode;
+use crate::app::{
+    App, BlueprintPart, CACHE_TTL_CHOICES, LoadingTask, MAX_ATTEMPTS_CHOICES,
+    MAX_CONCURRENCY_CHOICES, RATE_LIMIT_CHOICES, REQUEST_TIMEOUT_CHOICES, SERVICE_KEYS, Screen,
+    next_choice, region_name,
+};
+use crate::aws_cli::sso::SsoDeviceAuthorization;
+use crate::aws_cli::{AwsAuthErrorCode, AwsResource};
 
 const EMD_LOGO: &str = r#"
   ______ __  __ _____  
@@ -18,6 +23,8 @@ const EMD_LOGO: &str = r#"
  |______|_|  |_|_____/  AWS Markdown Template Generator 
 "#;
 use crate::blueprint::ResourceType;
+use crate::i18n::I18n;
+use crate::settings::DocumentIdentity;
 
 pub fn draw(frame: &mut Frame, app: &App) {
     let chunks = Layout::default()
@@ -31,10 +38,13 @@ pub fn draw(frame: &mut Frame, app: &App) {
         ])
         .split(frame.area());
 
-    draw_header(frame, chunks[0]);
+    draw_header(frame, app, chunks[0]);
     draw_tabs(frame, app, chunks[1]);
     draw_main(frame, app, chunks[2]);
     draw_footer(frame, app, chunks[3]);
+    if app.profile_switcher.is_some() {
+        draw_profile_switcher(frame, app, chunks[2]);
+    }
 }
 
 fn draw_tabs(frame: &mut Frame, app: &App, area: Rect) {
@@ -61,7 +71,7 @@ fn draw_tabs(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(tabs, area);
 }
 
-fn draw_header(frame: &mut Frame, area: Rect) {
+fn draw_header(frame: &mut Frame, app: &App, area: Rect) {
     let mut header_content = Vec::new();
     for line in EMD_LOGO.lines() {
         if !line.trim().is_empty() {
@@ -76,51 +86,188 @@ fn draw_header(frame: &mut Frame, area: Rect) {
 
     let title = Paragraph::new(header_content).block(Block::default().borders(Borders::BOTTOM));
     frame.render_widget(title, area);
+
+    // 로고 오른쪽: 로그인한 계정, 프로필/리전, 자격 증명 만료
+    let mut identity = identity_lines(app);
+    identity.extend(credentials_expiry_line(app));
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(58), Constraint::Min(0)])
+        .split(area);
+    let panel = Rect {
+        height: columns[1].height.saturating_sub(1),
+        ..columns[1]
+    };
+    frame.render_widget(Paragraph::new(identity), panel);
+}
+
+/// 헤더의 계정 정보: 별칭 (계정 ID), 호출자 ARN, 프로필과 리전
+fn identity_lines(app: &App) -> Vec<Line<'static>> {
+    let i = &app.i18n;
+    let label = Style::default().fg(Color::DarkGray);
+    let value = Style::default().fg(Color::White);
+    let region = if app.all_regions {
+        i.all_regions().to_string()
+    } else {
+        let (region, source) = crate::aws_cli::resolve_region();
+        format!("{} ({})", region, i.region_source(&source))
+    };
+    let mut lines = Vec::new();
+    if let Some(identity) = &app.login_info {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", i.account()), label),
+            Span::styled(
+                identity.label(),
+                value.add_modifier(Modifier::BOLD).fg(Color::Green),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", i.caller()), label),
+            Span::styled(identity.arn.clone(), value),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        Span::styled(format!("{}: ", i.profile()), label),
+        Span::styled(crate::aws_cli::current_profile(), value),
+        Span::styled(format!("  {}: ", i.region()), label),
+        Span::styled(region, value),
+    ]));
+    lines
+        .into_iter()
+        .map(|line| line.alignment(Alignment::Right))
+        .collect()
+}
+
+/// 자격 증명 만료 시각 (곧 만료되면 노란색, 만료됐으면 빨간색)
+fn credentials_expiry_line(app: &App) -> Option<Line<'static>> {
+    let expires_at = app.credentials_expire_at?;
+    let remaining = app.credentials_remaining()?;
+    let i = &app.i18n;
+    let (text, color) = if remaining <= chrono::Duration::zero() {
+        (i.credentials_expired().to_string(), Color::Red)
+    } else {
+        let time = expires_at
+            .with_timezone(&chrono::Local)
+            .format("%H:%M")
+            .to_string();
+        let color = if app.credentials_expiring_soon() {
+            Color::Yellow
+        } else {
+            Color::DarkGray
+        };
+        (
+            i.credentials_expire(&time, &format_remaining(remaining)),
+            color,
+        )
+    };
+    Some(Line::from(Span::styled(text, Style::default().fg(color))).alignment(Alignment::Right))
 }
 
 fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
-    let help = match &app.screen {
+    let help = if app.shows_loading() {
+        format!("Esc: {}", i.cancel())
+    } else if app.profile_switcher.is_some() {
+        format!(
+            "↑↓/jk: {} | Enter: {} | r: {} | Esc: {}",
+            i.move_cursor(),
+            i.select(),
+            i.refresh(),
+            i.cancel()
+        )
+    } else if app.can_switch_profile() {
+        format!("{} | Ctrl+P: {}", screen_help(app), i.switch_profile())
+    } else {
+        screen_help(app)
+    };
+
+    let mut msg = if app.message.is_empty() {
+        help
+    } else {
+        format!("{} | {}", app.message, help)
+    };
+    // 캐시에서 읽은 목록이면 저장 시각 표시
+    if let Some((screen, cached_at)) = &app.list_cached_at
+        && *screen == app.screen
+        && !app.shows_loading()
+    {
+        let time = cached_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+        msg = format!("[{}] {}", i.cached_at(&time), msg);
+    }
+
+    let footer = Paragraph::new(msg)
+        .style(Style::default().fg(Color::DarkGray))
+        .block(Block::default().borders(Borders::TOP));
+    frame.render_widget(footer, area);
+}
+
+fn screen_help(app: &App) -> String {
+    let i = &app.i18n;
+    match &app.screen {
         Screen::Login => format!(
-            "↑↓/jk: {} | Enter: {} | r: {} | q: {}",
+            "↑↓/jk: {} | Enter: {} | s: {} | r: {} | q: {}",
             i.move_cursor(),
             i.select(),
+            i.sso_login(),
             i.refresh(),
             i.exit()
         ),
         Screen::BlueprintSelect => format!(
-            "↑↓/jk: {} | Enter: {} | g: {} | d: {} | s: {} | ►: {} | q: {}",
+            "↑↓/jk: {} | Enter: {} | g: {} | d: {} | s: {} | o: {} | ►: {} | q: {}",
             i.move_cursor(),
             i.select(),
             i.markdown_generate(),
             i.delete(),
             i.single_mode(),
+            i.organization(),
             i.settings(),
             i.exit()
         ),
         Screen::BlueprintDetail => format!(
-            "↑↓/jk: {} | Shift+↑↓/JK: {} | a: {} | d: {} | g/Enter: {} | Esc: {} | q: {}",
+            "↑↓/jk: {} | Shift+↑↓/JK: {} | a: {} | c: {} | d: {} | g/Enter: {} | Esc: {} | q: {}",
             i.move_cursor(),
             i.reorder(),
             i.add(),
+            i.account(),
             i.delete(),
             i.generate(),
             i.back(),
             i.exit()
         ),
-        Screen::BlueprintNameInput => format!("Enter: {} | Esc: {}", i.confirm(), i.cancel()),
+        Screen::OrganizationSelect => format!(
+            "↑↓/jk: {} | Enter: {} | r: {} | p: {} | e: {} | Esc: {} | q: {}",
+            i.move_cursor(),
+            i.select(),
+            i.role(),
+            i.save_profiles(),
+            i.export_profiles(),
+            i.back(),
+            i.exit()
+        ),
+        Screen::MfaInput
+        | Screen::BlueprintNameInput
+        | Screen::AccountInput
+        | Screen::OrgRoleInput => {
+            format!("Enter: {} | Esc: {}", i.confirm(), i.cancel())
+        }
         Screen::BlueprintPreview => format!(
-            "↑↓/jk: {} | PgUp/PgDn: {} | Home/End | Enter/s: {} | Esc: {} | q: {}",
+            "↑↓/jk: {} | PgUp/PgDn: {} | Home/End | Enter/s: {} | r: {} | Esc: {} | q: {}",
             i.scroll(),
             i.page(),
             i.save(),
+            i.refresh(),
             i.back(),
             i.exit()
         ),
         Screen::RegionSelect => format!(
-            "↑↓/jk: {} | Enter: {} | Esc: {} | q: {}",
+            "↑↓/jk: {} | Enter: {} | a: {} | f: {} | Esc: {} | q: {}",
             i.move_cursor(),
             i.select(),
+            i.all_regions(),
+            i.favorite(),
             i.back(),
             i.exit()
         ),
@@ -137,7 +284,16 @@ fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
         | Screen::SecurityGroupSelect
         | Screen::LoadBalancerSelect
         | Screen::EcrSelect
-        | Screen::AsgSelect => format!(
+        | Screen::AsgSelect
+        | Screen::ApiGatewaySelect
+        | Screen::KmsKeySelect
+        | Screen::SecretSelect
+        | Screen::SsmParameterSelect
+        | Screen::EfsSelect
+        | Screen::AlarmSelect
+        | Screen::EventRuleSelect
+        | Screen::StateMachineSelect
+        | Screen::LambdaSelect => format!(
             "↑↓/jk: {} | Enter: {} | r: {} | Esc: {} | q: {}",
             i.move_cursor(),
             i.select(),
@@ -174,32 +330,28 @@ fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
             i.back(),
             i.exit()
         ),
-    };
-
-    let msg = if app.message.is_empty() {
-        help
-    } else {
-        format!("{} | {}", app.message, help)
-    };
-
-    let footer = Paragraph::new(msg)
-        .style(Style::default().fg(Color::DarkGray))
-        .block(Block::default().borders(Borders::TOP));
-    frame.render_widget(footer, area);
+    }
 }
 
 fn draw_main(frame: &mut Frame, app: &App, area: Rect) {
     // 로딩 중이면 로딩 화면 표시
-    if app.loading {
+    if app.shows_loading() {
         draw_loading(frame, app, area);
         return;
     }
 
     match &app.screen {
         Screen::Login => draw_login(frame, app, area),
+        Screen::MfaInput => {
+            draw_login(frame, app, area);
+            draw_mfa_input(frame, app, area);
+        }
         Screen::BlueprintSelect => draw_blueprint_select(frame, app, area),
         Screen::BlueprintDetail => draw_blueprint_detail(frame, app, area),
         Screen::BlueprintNameInput => draw_blueprint_name_input(frame, app, area),
+        Screen::AccountInput => draw_account_input(frame, app, area),
+        Screen::OrganizationSelect => draw_organization_select(frame, app, area),
+        Screen::OrgRoleInput => draw_org_role_input(frame, app, area),
         Screen::BlueprintPreview => draw_blueprint_preview(frame, app, area),
         Screen::RegionSelect => draw_region_select(frame, app, area),
         Screen::ServiceSelect => draw_service_select(frame, app, area),
@@ -210,10 +362,30 @@ fn draw_main(frame: &mut Frame, app: &App, area: Rect) {
         Screen::LoadBalancerSelect => draw_load_balancer_select(frame, app, area),
         Screen::EcrSelect => draw_ecr_select(frame, app, area),
         Screen::AsgSelect => draw_asg_select(frame, app, area),
+        Screen::ApiGatewaySelect => draw_api_gateway_select(frame, app, area),
+        Screen::KmsKeySelect => draw_kms_key_select(frame, app, area),
+        Screen::SecretSelect => draw_secret_select(frame, app, area),
+        Screen::SsmParameterSelect => draw_ssm_parameter_select(frame, app, area),
+        Screen::EfsSelect => draw_efs_select(frame, app, area),
+        Screen::AlarmSelect => draw_alarm_select(frame, app, area),
+        Screen::EventRuleSelect => draw_event_rule_select(frame, app, area),
+        Screen::StateMachineSelect => draw_state_machine_select(frame, app, area),
+        Screen::LambdaSelect => draw_lambda_select(frame, app, area),
         Screen::Settings => draw_settings(frame, app, area),
     }
 }
 
+const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// 로딩 시작 후 경과 시간 기준 스피너 프레임 (100ms마다 변경)
+fn spinner_frame(app: &App) -> &'static str {
+    let elapsed = app
+        .loading_started
+        .map(|started| started.elapsed().as_millis())
+        .unwrap_or_default();
+    SPINNER_FRAMES[(elapsed / 100) as usize % SPINNER_FRAMES.len()]
+}
+
 fn draw_loading(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
 
@@ -229,8 +401,24 @@ fn draw_loading(frame: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
+    // SSO 로그인은 인증이 시작되면 확인 주소와 코드 표시
+    if let (LoadingTask::SsoLogin(profile), Some(authorization)) =
+        (&app.loading_task, app.sso_login.as_ref())
+    {
+        draw_sso_login(frame, app, area, profile, authorization);
+        return;
+    }
+
     let task_name = match &app.loading_task {
         LoadingTask::None => i.processing(),
+        LoadingTask::LoadRegions => i.loading_regions(),
+        LoadingTask::LoadOrganization => i.loading_organization(),
+        LoadingTask::SsoLogin(_) => i.sso_starting(),
+        LoadingTask::MfaLogin => i.verifying_mfa_code(),
+        LoadingTask::ValidateLogin(_) | LoadingTask::CheckLogin | LoadingTask::CheckSession => {
+            i.aws_login_checking()
+        }
+        LoadingTask::LoadProfileIdentities => i.loading_profile_identities(),
         LoadingTask::RefreshEc2 => i.refreshing_ec2_list(),
         LoadingTask::RefreshVpc => i.refreshing_vpc_list(),
         LoadingTask::RefreshSecurityGroup => i.refreshing_sg_list(),
@@ -253,6 +441,41 @@ fn draw_loading(frame: &mut Frame, app: &App, area: Rect) {
         LoadingTask::RefreshAsg => i.loading_asg_list(),
         LoadingTask::LoadAsg => i.loading_asg_list(),
         LoadingTask::LoadAsgDetail(_) => i.loading_asg_detail(),
+
+        LoadingTask::RefreshApiGateway => i.refreshing_api_gateway_list(),
+        LoadingTask::LoadApiGateway => i.loading_api_gateway_list(),
+        LoadingTask::LoadApiGatewayDetail(_) => i.loading_api_gateway_detail(),
+
+        LoadingTask::RefreshKmsKey => i.refreshing_kms_key_list(),
+        LoadingTask::LoadKmsKey => i.loading_kms_key_list(),
+        LoadingTask::LoadKmsKeyDetail(_) => i.loading_kms_key_detail(),
+
+        LoadingTask::RefreshSecret => i.refreshing_secret_list(),
+        LoadingTask::LoadSecret => i.loading_secret_list(),
+        LoadingTask::LoadSecretDetail(_) => i.loading_secret_detail(),
+
+        LoadingTask::RefreshSsmParameter => i.refreshing_ssm_parameter_list(),
+        LoadingTask::LoadSsmParameter => i.loading_ssm_parameter_list(),
+        LoadingTask::LoadSsmParameterDetail(_) => i.loading_ssm_parameter_detail(),
+
+        LoadingTask::RefreshEfs => i.refreshing_efs_list(),
+        LoadingTask::LoadEfs => i.loading_efs_list(),
+        LoadingTask::LoadEfsDetail(_) => i.loading_efs_detail(),
+
+        LoadingTask::RefreshAlarm => i.refreshing_alarm_list(),
+        LoadingTask::LoadAlarm => i.loading_alarm_list(),
+        LoadingTask::LoadAlarmDetail(_) => i.loading_alarm_detail(),
+
+        LoadingTask::RefreshEventRule => i.refreshing_event_rule_list(),
+        LoadingTask::LoadEventRule => i.loading_event_rule_list(),
+        LoadingTask::LoadEventRuleDetail(_) => i.loading_event_rule_detail(),
+
+        LoadingTask::RefreshStateMachine => i.refreshing_state_machine_list(),
+        LoadingTask::LoadStateMachine => i.loading_state_machine_list(),
+        LoadingTask::LoadStateMachineDetail(_) => i.loading_state_machine_detail(),
+        LoadingTask::RefreshLambda => i.refreshing_lambda_list(),
+        LoadingTask::LoadLambda => i.loading_lambda_list(),
+        LoadingTask::LoadLambdaDetail(_) => i.loading_lambda_detail(),
         LoadingTask::LoadBlueprintResources(_) => i.loading_blueprint_resources(),
     };
 
@@ -260,7 +483,7 @@ fn draw_loading(frame: &mut Frame, app: &App, area: Rect) {
         Line::from(""),
         Line::from(""),
         Line::from(Span::styled(
-            format!("    ⏳ {}", i.loading_msg()),
+            format!("    {} {}", spinner_frame(app), i.loading_msg()),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
@@ -286,7 +509,7 @@ fn draw_vpc_loading_checklist(frame: &mut Frame, app: &App, area: Rect) {
     let p = &app.loading_progress;
     let i = &app.i18n;
 
-    // 현재 로딩 중인 단계 결정
+    // 기본 정보 이후의 단계는 동시에 조회되므로 아직 끝나지 않은 첫 단계를 표시
     let current_step = if !p.vpc_info {
         0
     } else if !p.subnets {
@@ -345,19 +568,19 @@ fn draw_vpc_loading_checklist(frame: &mut Frame, app: &App, area: Rect) {
     let content = vec![
         Line::from(""),
         Line::from(Span::styled(
-            format!("  ⏳ {}...", i.loading_vpc_detail()),
+            format!("  {} {}...", spinner_frame(app), i.loading_vpc_detail()),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         item(p.vpc_info, current_step == 0, steps[0]),
-        item(p.subnets, current_step == 1, steps[1]),
-        item(p.igws, current_step == 2, steps[2]),
-        item(p.nats, current_step == 3, steps[3]),
-        item(p.route_tables, current_step == 4, steps[4]),
-        item(p.eips, current_step == 5, steps[5]),
-        item(p.dns_attrs, current_step == 6, steps[6]),
+        item(p.subnets, p.vpc_info, steps[1]),
+        item(p.igws, p.vpc_info, steps[2]),
+        item(p.nats, p.vpc_info, steps[3]),
+        item(p.route_tables, p.vpc_info, steps[4]),
+        item(p.eips, p.vpc_info, steps[5]),
+        item(p.dns_attrs, p.vpc_info, steps[6]),
         Line::from(""),
         Line::from(Span::styled(
             i.current_loading(current_task),
@@ -370,6 +593,98 @@ fn draw_vpc_loading_checklist(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(para, area);
 }
 
+/// `area` 가운데에 놓일 팝업 영역
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// 어느 화면에서든 띄우는 프로필 전환 창 (프로필별 계정 별칭과 ID, `*`는 지금 프로필)
+fn draw_profile_switcher(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
+    let selected = app.profile_switcher.unwrap_or_default();
+    let current = crate::aws_cli::current_profile();
+    let content: Vec<Line> = app
+        .available_profiles
+        .iter()
+        .enumerate()
+        .map(|(index, profile)| {
+            let prefix = if index == selected { "▶ " } else { "  " };
+            let marker = if *profile == current { "*" } else { " " };
+            let style = if index == selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let account = match app.profile_identities.get(profile) {
+                Some(Some(identity)) => identity.label(),
+                Some(None) => i.not_signed_in().to_string(),
+                None => "-".to_string(),
+            };
+            Line::from(vec![
+                Span::styled(format!("{prefix}{marker} {profile:<24} "), style),
+                Span::styled(account, Style::default().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+
+    let height = (content.len() as u16).saturating_add(2).max(4);
+    let popup = centered_rect(72, height, area);
+    frame.render_widget(Clear, popup);
+    // 목록이 창보다 길면 선택한 줄이 보이도록 스크롤
+    let scroll = (selected as u16).saturating_sub(popup.height.saturating_sub(3));
+    let para = Paragraph::new(content).scroll((scroll, 0)).block(
+        Block::default()
+            .title(format!(" {} ", i.profile_switcher_title()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    frame.render_widget(para, popup);
+}
+
+/// 로그인 화면 위에 띄우는 MFA 코드 입력 창
+fn draw_mfa_input(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
+    let title = format!(" {} ", i.mfa_input_title());
+    let mfa_serial = app
+        .login_error
+        .as_ref()
+        .map(|error| error.as_str())
+        .unwrap_or_default();
+
+    let content = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {}", i.enter_mfa_code()),
+            Style::default().fg(Color::Cyan),
+        )),
+        Line::from(Span::styled(
+            format!("  {}: {}", i.mfa_device(), mfa_serial),
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+        Line::from(format!("  > {}_", app.input_buffer)),
+    ];
+
+    let popup = centered_rect(72, 8, area);
+    frame.render_widget(Clear, popup);
+    let para = Paragraph::new(content).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    frame.render_widget(para, popup);
+}
+
 fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
     let mut content = vec![Line::from("")];
@@ -384,6 +699,7 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
             AwsAuthErrorCode::CredentialsProviderMissing
             | AwsAuthErrorCode::CredentialsLoadFailed
             | AwsAuthErrorCode::CallerIdentityFailed => i.aws_login_retry_hint(),
+            AwsAuthErrorCode::MfaRequired => i.auth_mfa_required(),
             AwsAuthErrorCode::Network | AwsAuthErrorCode::Unknown => error.as_str(),
         };
         content.push(Line::from(Span::styled(
@@ -427,7 +743,7 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
         if let Some(info) = app.login_info.as_ref() {
             content.push(Line::from(""));
             content.push(Line::from(Span::styled(
-                info.as_str(),
+                format!("{} ({})", info.label(), info.arn),
                 Style::default().fg(Color::Green),
             )));
         }
@@ -446,10 +762,11 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
 
 fn draw_region_select(frame: &mut Frame, app: &App, area: Rect) {
     let lang = app.settings.language;
-    let items: Vec<ListItem> = REGIONS
+    let items: Vec<ListItem> = app
+        .regions
         .iter()
         .enumerate()
-        .map(|(i, r)| {
+        .map(|(i, code)| {
             let style = if i == app.selected_region {
                 Style::default()
                     .fg(Color::Yellow)
@@ -462,25 +779,38 @@ fn draw_region_select(frame: &mut Frame, app: &App, area: Rect) {
             } else {
                 "  "
             };
-            ListItem::new(format!("{}{} ({})", prefix, r.code, r.name(lang))).style(style)
+            let star = if app.is_favorite_region(code) {
+                "★ "
+            } else {
+                ""
+            };
+            ListItem::new(format!(
+                "{}{}{} ({})",
+                prefix,
+                star,
+                code,
+                region_name(code, lang)
+            ))
+            .style(style)
         })
         .collect();
 
-    let title = format!(" {} ", app.i18n.region());
+    let (current, source) = crate::aws_cli::resolve_region();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.region(),
+        current,
+        app.i18n.region_source(&source)
+    );
     let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
-    frame.render_widget(list, area);
+    let mut state = ListState::default().with_selected(Some(app.selected_region));
+    frame.render_stateful_widget(list, area, &mut state);
 }
 
 fn draw_service_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
     let i = &app.i18n;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(
-        " {} [{} - {}] ",
-        i.service(),
-        region.code,
-        region.name(lang)
-    );
+    let region = app.region_label();
+    let title = format!(" {} [{} - {}] ", i.service(), region.code, region.name);
 
     // Build service list: services + exit
     let services: Vec<&str> = SERVICE_KEYS.to_vec();
@@ -512,9 +842,8 @@ fn draw_service_select(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_ec2_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" EC2 [{} - {}] ", region.code, region.name(lang));
+    let region = app.region_label();
+    let title = format!(" EC2 [{} - {}] ", region.code, region.name);
 
     if app.instances.is_empty() {
         let para = Paragraph::new(app.i18n.no_instances())
@@ -550,6 +879,7 @@ fn draw_ec2_select(frame: &mut Frame, app: &App, area: Rect) {
             if is_in_blueprint {
                 prefix = format!("{}✓ ", prefix);
             }
+            prefix.push_str(&region_column(inst));
 
             ListItem::new(format!("{}{}", prefix, inst.name)).style(style)
         })
@@ -559,6 +889,15 @@ fn draw_ec2_select(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(list, area);
 }
 
+/// 모든 리전 목록에서 리소스 앞에 붙이는 리전 열
+fn region_column(resource: &AwsResource) -> String {
+    resource
+        .region
+        .as_deref()
+        .map(|region| format!("{:<15} ", region))
+        .unwrap_or_default()
+}
+
 fn draw_preview(frame: &mut Frame, app: &App, area: Rect) {
     let title = format!(" {} - {} ", app.i18n.preview(), app.preview_filename);
     let para = Paragraph::new(app.preview_content.as_str())
@@ -569,9 +908,8 @@ fn draw_preview(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_vpc_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" Network [{} - {}] ", region.code, region.name(lang));
+    let region = app.region_label();
+    let title = format!(" Network [{} - {}] ", region.code, region.name);
 
     if app.vpcs.is_empty() {
         let para = Paragraph::new(app.i18n.no_vpcs())
@@ -607,6 +945,7 @@ fn draw_vpc_select(frame: &mut Frame, app: &App, area: Rect) {
             if is_in_blueprint {
                 prefix = format!("{}✓ ", prefix);
             }
+            prefix.push_str(&region_column(vpc));
 
             ListItem::new(format!("{}{}", prefix, vpc.display())).style(style)
         })
@@ -617,9 +956,8 @@ fn draw_vpc_select(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_security_group_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" Security Group [{} - {}] ", region.code, region.name(lang));
+    let region = app.region_label();
+    let title = format!(" Security Group [{} - {}] ", region.code, region.name);
 
     if app.security_groups.is_empty() {
         let para = Paragraph::new(app.i18n.no_security_groups())
@@ -655,6 +993,7 @@ fn draw_security_group_select(frame: &mut Frame, app: &App, area: Rect) {
             if is_in_blueprint {
                 prefix = format!("{}✓ ", prefix);
             }
+            prefix.push_str(&region_column(sg));
 
             ListItem::new(format!("{}{}", prefix, sg.name)).style(style)
         })
@@ -665,9 +1004,8 @@ fn draw_security_group_select(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_load_balancer_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" Load Balancer [{} - {}] ", region.code, region.name(lang));
+    let region = app.region_label();
+    let title = format!(" Load Balancer [{} - {}] ", region.code, region.name);
 
     if app.load_balancers.is_empty() {
         let para = Paragraph::new(app.i18n.no_load_balancers())
@@ -703,6 +1041,7 @@ fn draw_load_balancer_select(frame: &mut Frame, app: &App, area: Rect) {
             if is_in_blueprint {
                 prefix = format!("{}✓ ", prefix);
             }
+            prefix.push_str(&region_column(lb));
 
             ListItem::new(format!("{}{}", prefix, lb.display())).style(style)
         })
@@ -713,9 +1052,8 @@ fn draw_load_balancer_select(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_ecr_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" ECR [{} - {}] ", region.code, region.name(lang));
+    let region = app.region_label();
+    let title = format!(" ECR [{} - {}] ", region.code, region.name);
 
     if app.ecr_repositories.is_empty() {
         let para = Paragraph::new(app.i18n.no_ecr_repos())
@@ -751,6 +1089,7 @@ fn draw_ecr_select(frame: &mut Frame, app: &App, area: Rect) {
             if is_in_blueprint {
                 prefix = format!("{}✓ ", prefix);
             }
+            prefix.push_str(&region_column(repo));
 
             ListItem::new(format!("{}{}", prefix, repo.name)).style(style)
         })
@@ -872,6 +1211,15 @@ fn draw_blueprint_detail(frame: &mut Frame, app: &App, area: Rect) {
                 ResourceType::LoadBalancer => Color::Blue,
                 ResourceType::Ecr => Color::LightRed,
                 ResourceType::Asg => Color::LightCyan,
+                ResourceType::ApiGateway => Color::LightMagenta,
+                ResourceType::KmsKey => Color::LightYellow,
+                ResourceType::Secret => Color::LightRed,
+                ResourceType::SsmParameter => Color::LightGreen,
+                ResourceType::Efs => Color::Cyan,
+                ResourceType::CloudWatchAlarm => Color::Red,
+                ResourceType::EventRule => Color::LightBlue,
+                ResourceType::StateMachine => Color::LightGreen,
+                ResourceType::Lambda => Color::Yellow,
             };
 
             ListItem::new(Line::from(vec![
@@ -881,6 +1229,13 @@ fn draw_blueprint_detail(frame: &mut Frame, app: &App, area: Rect) {
                     Style::default().fg(type_color),
                 ),
                 Span::styled(format!("{} ({})", res.resource_name, res.region), style),
+                Span::styled(
+                    res.account
+                        .as_ref()
+                        .map(|account| format!(" @ {}", account.label()))
+                        .unwrap_or_default(),
+                    Style::default().fg(Color::DarkGray),
+                ),
             ]))
         })
         .collect();
@@ -907,6 +1262,76 @@ fn draw_blueprint_name_input(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(para, area);
 }
 
+fn draw_account_input(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
+    let title = format!(" {} ", i.account_input_title());
+
+    let content = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {}", i.enter_account()),
+            Style::default().fg(Color::Cyan),
+        )),
+        Line::from(""),
+        Line::from(format!("  > {}_", app.input_buffer)),
+    ];
+
+    let para = Paragraph::new(content).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(para, area);
+}
+
+fn draw_organization_select(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .org_accounts
+        .iter()
+        .enumerate()
+        .map(|(i, account)| {
+            let selected = i == app.selected_org_account;
+            let style = if selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let prefix = if selected { "▶ " } else { "  " };
+            ListItem::new(format!(
+                "{}{:<30} {} {:<30} {}",
+                prefix, account.name, account.id, account.ou_path, account.status
+            ))
+            .style(style)
+        })
+        .collect();
+
+    let title = format!(
+        " {} [{}: {}] ",
+        app.i18n.organization(),
+        app.i18n.role(),
+        app.settings.org_role_name
+    );
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    let mut state = ListState::default().with_selected(Some(app.selected_org_account));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_org_role_input(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
+    let title = format!(" {} ", i.org_role_input_title());
+
+    let content = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {}", i.enter_org_role_name()),
+            Style::default().fg(Color::Cyan),
+        )),
+        Line::from(""),
+        Line::from(format!("  > {}_", app.input_buffer)),
+    ];
+
+    let para = Paragraph::new(content).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(para, area);
+}
+
 fn draw_blueprint_preview(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
     let title = format!(
@@ -922,7 +1347,7 @@ fn draw_blueprint_preview(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(para, area);
 }
 
-fn draw_blueprint_loading(frame: &mut Frame, app: &App, area: Rect, current_index: usize) {
+fn draw_blueprint_loading(frame: &mut Frame, app: &App, area: Rect, loaded: usize) {
     let i = &app.i18n;
     let bp = match &app.current_blueprint {
         Some(bp) => bp,
@@ -962,9 +1387,10 @@ fn draw_blueprint_loading(frame: &mut Frame, app: &App, area: Rect, current_inde
         Line::from(""),
         Line::from(Span::styled(
             format!(
-                "  {}... ({}/{})",
+                "  {} {}... ({}/{})",
+                spinner_frame(app),
                 i.loading_blueprint_resources(),
-                current_index,
+                loaded,
                 total
             ),
             Style::default()
@@ -973,10 +1399,19 @@ fn draw_blueprint_loading(frame: &mut Frame, app: &App, area: Rect, current_inde
         )),
         Line::from(""),
     ];
+    // 긴 블루프린트 조회 중 자격 증명이 만료되지 않도록 미리 경고
+    if app.credentials_expiring_soon() {
+        content.push(Line::from(Span::styled(
+            format!("  ⚠ {}", i.credentials_expiring_warning()),
+            Style::default().fg(Color::Red),
+        )));
+        content.push(Line::from(""));
+    }
 
     for (idx, res) in bp.resources.iter().enumerate() {
-        let done = idx < current_index;
-        let loading = idx == current_index;
+        let part = app.blueprint_markdown_parts.get(idx);
+        let done = matches!(part, Some(BlueprintPart::Done(_)));
+        let loading = matches!(part, Some(BlueprintPart::Loading));
         content.push(item(done, loading, res.display()));
     }
 
@@ -985,6 +1420,57 @@ fn draw_blueprint_loading(frame: &mut Frame, app: &App, area: Rect, current_inde
     frame.render_widget(para, area);
 }
 
+fn draw_sso_login(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    profile: &str,
+    authorization: &SsoDeviceAuthorization,
+) {
+    let i = &app.i18n;
+    let remaining = (authorization.expires_at - chrono::Utc::now()).max(chrono::Duration::zero());
+    let content = vec![
+        Line::from(""),
+        Line::from(format!("  {}", i.sso_open_url())),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {}", authorization.verification_uri),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::UNDERLINED),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {}: {}", i.sso_user_code(), authorization.user_code),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(format!(
+            "  {} {}",
+            spinner_frame(app),
+            i.sso_waiting(&format_remaining(remaining))
+        )),
+    ];
+
+    let title = format!(" {} - {} ", i.sso_login(), profile);
+    let para = Paragraph::new(content)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(para, area);
+}
+
+/// 남은 시간 표시 (`1h 05m`, `4m 30s`)
+fn format_remaining(remaining: chrono::Duration) -> String {
+    let secs = remaining.num_seconds().max(0);
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
 fn draw_settings(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
     let title = format!(" {} ", i.settings());
@@ -992,49 +1478,125 @@ fn draw_settings(frame: &mut Frame, app: &App, area: Rect) {
     let lang_current = app.settings.language.display();
     let lang_next = app.settings.language.toggle().display();
 
-    let items: Vec<ListItem> = vec![ListItem::new(Line::from(vec![
-        Span::styled(
-            if app.selected_setting == 0 {
-                "▶ "
-            } else {
-                "  "
-            },
-            if app.selected_setting == 0 {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
-            } else {
+    fn setting_row(
+        selected: bool,
+        label: &str,
+        current: String,
+        next: String,
+    ) -> ListItem<'static> {
+        ListItem::new(Line::from(vec![
+            Span::styled(
+                if selected { "▶ " } else { "  " },
+                if selected {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                },
+            ),
+            Span::styled(format!("{}: ", label), Style::default().fg(Color::Cyan)),
+            Span::styled(
+                current,
                 Style::default()
-            },
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!(" → {}", next), Style::default().fg(Color::DarkGray)),
+        ]))
+    }
+
+    let items: Vec<ListItem> = vec![
+        setting_row(
+            app.selected_setting == 0,
+            i.language(),
+            lang_current.to_string(),
+            lang_next.to_string(),
         ),
-        Span::styled(
-            format!("{}: ", i.language()),
-            Style::default().fg(Color::Cyan),
+        setting_row(
+            app.selected_setting == 1,
+            i.max_concurrency(),
+            app.settings.max_concurrency.to_string(),
+            next_choice(MAX_CONCURRENCY_CHOICES, app.settings.max_concurrency).to_string(),
         ),
-        Span::styled(
-            lang_current,
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
+        setting_row(
+            app.selected_setting == 2,
+            i.cache_ttl(),
+            format_ttl(i, app.settings.cache_ttl_secs),
+            format_ttl(
+                i,
+                next_choice(CACHE_TTL_CHOICES, app.settings.cache_ttl_secs),
+            ),
         ),
-        Span::styled(
-            format!(" → {}", lang_next),
-            Style::default().fg(Color::DarkGray),
+        setting_row(
+            app.selected_setting == 3,
+            i.max_attempts(),
+            app.settings.max_attempts.to_string(),
+            next_choice(MAX_ATTEMPTS_CHOICES, app.settings.max_attempts).to_string(),
+        ),
+        setting_row(
+            app.selected_setting == 4,
+            i.request_timeout(),
+            format_ttl(i, app.settings.timeout_secs),
+            format_ttl(
+                i,
+                next_choice(REQUEST_TIMEOUT_CHOICES, app.settings.timeout_secs),
+            ),
+        ),
+        setting_row(
+            app.selected_setting == 5,
+            i.rate_limit(),
+            format_rate(i, app.settings.requests_per_second),
+            format_rate(
+                i,
+                next_choice(RATE_LIMIT_CHOICES, app.settings.requests_per_second),
+            ),
         ),
-    ]))];
+        setting_row(
+            app.selected_setting == 6,
+            i.document_identity(),
+            format_document_identity(i, app.settings.document_identity).to_string(),
+            format_document_identity(i, app.settings.document_identity.next()).to_string(),
+        ),
+    ];
 
     let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
     frame.render_widget(list, area);
 }
 
+fn format_document_identity(i: &I18n, mode: DocumentIdentity) -> &'static str {
+    match mode {
+        DocumentIdentity::Full => i.identity_full(),
+        DocumentIdentity::Redacted => i.identity_redacted(),
+        DocumentIdentity::Hidden => i.identity_hidden(),
+    }
+}
+
+fn format_rate(i: &I18n, requests_per_second: u32) -> String {
+    if requests_per_second == 0 {
+        i.unlimited().to_string()
+    } else {
+        format!("{}/s", requests_per_second)
+    }
+}
+
+fn format_ttl(i: &I18n, secs: u64) -> String {
+    match secs {
+        0 => i.cache_off().to_string(),
+        s if s % 86_400 == 0 => format!("{}d", s / 86_400),
+        s if s % 3_600 == 0 => format!("{}h", s / 3_600),
+        s if s % 60 == 0 => format!("{}m", s / 60),
+        s => format!("{}s", s),
+    }
+}
+
 fn draw_asg_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
+    let region = app.region_label();
     let title = format!(
         " {} [{} - {}] ",
         app.i18n.auto_scaling_group(),
         region.code,
-        region.name(lang)
+        region.name
     );
 
     if app.auto_scaling_groups.is_empty() {
@@ -1078,7 +1640,7 @@ fn draw_asg_select(frame: &mut Frame, app: &App, area: Rect) {
                 Style::default().fg(Color::LightCyan)
             };
 
-            let content = format!("{} [{}]", asg.display(), asg.state);
+            let content = format!("{}{} [{}]", region_column(asg), asg.display(), asg.state);
 
             ListItem::new(Line::from(vec![
                 Span::styled(prefix, style),
@@ -1091,11 +1653,495 @@ fn draw_asg_select(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(list, area);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::draw;
-    use crate::app::{App, LoadingTask, Screen};
-    use crate::aws_cli::{AwsAuthError, AwsAuthErrorCode, AwsResource};
+fn draw_api_gateway_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.api_gateway(),
+        region.code,
+        region.name
+    );
+
+    if app.api_gateways.is_empty() {
+        let para = Paragraph::new(app.i18n.no_api_gateways())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .api_gateways
+        .iter()
+        .enumerate()
+        .map(|(i, api)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_id == api.id && r.resource_type == ResourceType::ApiGateway)
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(api));
+
+            ListItem::new(format!("{}{} [{}]", prefix, api.display(), api.state)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_kms_key_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.kms_key(),
+        region.code,
+        region.name
+    );
+
+    if app.kms_keys.is_empty() {
+        let para = Paragraph::new(app.i18n.no_kms_keys())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .kms_keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_id == key.id && r.resource_type == ResourceType::KmsKey)
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(key));
+
+            ListItem::new(format!("{}{} [{}]", prefix, key.display(), key.state)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_secret_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.secret(),
+        region.code,
+        region.name
+    );
+
+    if app.secrets.is_empty() {
+        let para = Paragraph::new(app.i18n.no_secrets())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .secrets
+        .iter()
+        .enumerate()
+        .map(|(i, secret)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_id == secret.id && r.resource_type == ResourceType::Secret)
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(secret));
+
+            ListItem::new(format!("{}{} [{}]", prefix, secret.display(), secret.state)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_ssm_parameter_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.ssm_parameter(),
+        region.code,
+        region.name
+    );
+
+    if app.ssm_parameters.is_empty() {
+        let para = Paragraph::new(app.i18n.no_ssm_parameters())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .ssm_parameters
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_id == param.id && r.resource_type == ResourceType::SsmParameter
+                })
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(param));
+
+            ListItem::new(format!("{}{} [{}]", prefix, param.display(), param.state)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_efs_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(" {} [{} - {}] ", app.i18n.efs(), region.code, region.name);
+
+    if app.efs_file_systems.is_empty() {
+        let para = Paragraph::new(app.i18n.no_efs_file_systems())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .efs_file_systems
+        .iter()
+        .enumerate()
+        .map(|(i, fs)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_id == fs.id && r.resource_type == ResourceType::Efs)
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(fs));
+
+            ListItem::new(format!("{}{} [{}]", prefix, fs.display(), fs.state)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_alarm_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.cloudwatch_alarm(),
+        region.code,
+        region.name
+    );
+
+    if app.alarms.is_empty() {
+        let para = Paragraph::new(app.i18n.no_alarms())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .alarms
+        .iter()
+        .enumerate()
+        .map(|(i, alarm)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_id == alarm.id && r.resource_type == ResourceType::CloudWatchAlarm
+                })
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(alarm));
+
+            ListItem::new(format!("{}{} [{}]", prefix, alarm.display(), alarm.state)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_event_rule_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.event_rule(),
+        region.code,
+        region.name
+    );
+
+    if app.event_rules.is_empty() {
+        let para = Paragraph::new(app.i18n.no_event_rules())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .event_rules
+        .iter()
+        .enumerate()
+        .map(|(i, rule)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_id == rule.id && r.resource_type == ResourceType::EventRule)
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(rule));
+
+            ListItem::new(format!("{}{} [{}]", prefix, rule.display(), rule.state)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_state_machine_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.state_machine(),
+        region.code,
+        region.name
+    );
+
+    if app.state_machines.is_empty() {
+        let para = Paragraph::new(app.i18n.no_state_machines())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .state_machines
+        .iter()
+        .enumerate()
+        .map(|(i, machine)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_id == machine.id && r.resource_type == ResourceType::StateMachine
+                })
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(machine));
+
+            ListItem::new(format!(
+                "{}{} [{}]",
+                prefix,
+                machine.display(),
+                machine.state
+            ))
+            .style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_lambda_select(frame: &mut Frame, app: &App, area: Rect) {
+    let region = app.region_label();
+    let title = format!(
+        " {} [{} - {}] ",
+        app.i18n.lambda_function(),
+        region.code,
+        region.name
+    );
+
+    if app.lambda_functions.is_empty() {
+        let para = Paragraph::new(app.i18n.no_lambda_functions())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .lambda_functions
+        .iter()
+        .enumerate()
+        .map(|(i, function)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_id == function.id && r.resource_type == ResourceType::Lambda
+                })
+            });
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut prefix = if i == app.selected_index {
+                "▶ ".to_string()
+            } else {
+                "  ".to_string()
+            };
+
+            if is_in_blueprint {
+                prefix = format!("{}✓ ", prefix);
+            }
+            prefix.push_str(&region_column(function));
+
+            ListItem::new(format!(
+                "{}{} [{}]",
+                prefix,
+                function.display(),
+                function.state
+            ))
+            .style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{draw, format_remaining};
+    use crate::app::{App, BlueprintPart, LoadingTask, Screen};
+    use crate::aws_cli::{AwsAuthError, AwsAuthErrorCode, AwsResource};
     use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
     use chrono::Utc;
     use ratatui::Terminal;
@@ -1108,6 +2154,7 @@ mod tests {
             state: "running".to_string(),
             az: "ap-northeast-2a".to_string(),
             cidr: "10.0.0.0/24".to_string(),
+            region: None,
         }
     }
 
@@ -1126,6 +2173,7 @@ mod tests {
                 region: "ap-northeast-2".to_string(),
                 resource_id: "i-1234".to_string(),
                 resource_name: "web-1".to_string(),
+                account: None,
             }],
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -1141,6 +2189,24 @@ mod tests {
         app.load_balancers = vec![resource("lb-1234", "alb-main")];
         app.ecr_repositories = vec![resource("repo-a", "repo-a")];
         app.auto_scaling_groups = vec![resource("asg-a", "asg-a")];
+        app.api_gateways = vec![resource("a1b2c3d4e5", "orders-api")];
+        app.kms_keys = vec![resource("1234abcd", "alias/app-data")];
+        app.secrets = vec![resource("arn:secret", "prod/db/password")];
+        app.ssm_parameters = vec![resource("/app/prod/db-url", "/app/prod/db-url")];
+        app.efs_file_systems = vec![resource("fs-0123", "shared-data")];
+        app.alarms = vec![resource("web-cpu-high", "web-cpu-high")];
+        app.event_rules = vec![resource(
+            "arn:aws:events:ap-northeast-2:123456789012:rule/nightly-report",
+            "nightly-report",
+        )];
+        app.state_machines = vec![resource(
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow",
+            "order-workflow",
+        )];
+        app.lambda_functions = vec![resource(
+            "arn:aws:lambda:ap-northeast-2:123456789012:function:list-orders",
+            "list-orders",
+        )];
         app.preview_filename = "preview.md".to_string();
         app.preview_content = "# hello\nworld\n".to_string();
         app.current_blueprint = Some(sample_blueprint());
@@ -1149,9 +2215,13 @@ mod tests {
 
         let screens = [
             Screen::Login,
+            Screen::MfaInput,
             Screen::BlueprintSelect,
             Screen::BlueprintDetail,
             Screen::BlueprintNameInput,
+            Screen::AccountInput,
+            Screen::OrganizationSelect,
+            Screen::OrgRoleInput,
             Screen::BlueprintPreview,
             Screen::RegionSelect,
             Screen::ServiceSelect,
@@ -1161,6 +2231,15 @@ mod tests {
             Screen::LoadBalancerSelect,
             Screen::EcrSelect,
             Screen::AsgSelect,
+            Screen::ApiGatewaySelect,
+            Screen::KmsKeySelect,
+            Screen::SecretSelect,
+            Screen::SsmParameterSelect,
+            Screen::EfsSelect,
+            Screen::AlarmSelect,
+            Screen::EventRuleSelect,
+            Screen::StateMachineSelect,
+            Screen::LambdaSelect,
             Screen::Preview,
             Screen::Settings,
         ];
@@ -1177,7 +2256,7 @@ mod tests {
         app.loading = true;
         app.current_blueprint = Some(sample_blueprint());
         app.blueprint_store.blueprints = vec![sample_blueprint()];
-        app.blueprint_markdown_parts = vec!["## sample".to_string()];
+        app.blueprint_markdown_parts = vec![BlueprintPart::Done("## sample".to_string())];
 
         app.loading_task = LoadingTask::RefreshEc2;
         render_app(&app);
@@ -1189,6 +2268,41 @@ mod tests {
 
         app.loading_task = LoadingTask::LoadBlueprintResources(0);
         render_app(&app);
+
+        // 자격 증명 만료 임박 경고와 SSO 확인 코드 화면
+        app.credentials_expire_at = Some(Utc::now() + chrono::Duration::minutes(5));
+        assert!(app.credentials_expiring_soon());
+        render_app(&app);
+
+        app.loading_task = LoadingTask::SsoLogin("dev".to_string());
+        render_app(&app);
+        app.sso_login = Some(crate::aws_cli::sso::SsoDeviceAuthorization::sample());
+        render_app(&app);
+    }
+
+    #[test]
+    fn format_remaining_shows_hours_or_minutes() {
+        assert_eq!(format_remaining(chrono::Duration::seconds(270)), "4m 30s");
+        assert_eq!(format_remaining(chrono::Duration::minutes(65)), "1h 05m");
+        assert_eq!(format_remaining(chrono::Duration::seconds(-5)), "0m 00s");
+    }
+
+    #[test]
+    fn draw_profile_switcher_over_any_screen_without_panic() {
+        let mut app = App::new();
+        app.screen = Screen::ServiceSelect;
+        app.available_profiles = vec!["default".to_string(), "prod".to_string()];
+        app.profile_identities.insert(
+            "prod".to_string(),
+            Some(crate::aws_cli::identity::AccountIdentity {
+                account_id: "111122223333".to_string(),
+                alias: Some("acme-prod".to_string()),
+                arn: "arn:aws:iam::111122223333:user/alice".to_string(),
+            }),
+        );
+        app.profile_identities.insert("default".to_string(), None);
+        app.profile_switcher = Some(1);
+        render_app(&app);
     }
 
     #[test]
@@ -1207,7 +2321,11 @@ mod tests {
             code: AwsAuthErrorCode::Unknown,
             detail: "ExpiredToken".to_string(),
         });
-        app.login_info = Some("161203794945 (arn:aws:iam::161203794945:user/pyh5523)".to_string());
+        app.login_info = Some(crate::aws_cli::identity::AccountIdentity {
+            account_id: "161203794945".to_string(),
+            alias: Some("acme-dev".to_string()),
+            arn: "arn:aws:iam::161203794945:user/pyh5523".to_string(),
+        });
         render_app(&app);
     }
 
@@ -1221,6 +2339,15 @@ mod tests {
             Screen::LoadBalancerSelect,
             Screen::EcrSelect,
             Screen::AsgSelect,
+            Screen::ApiGatewaySelect,
+            Screen::KmsKeySelect,
+            Screen::SecretSelect,
+            Screen::SsmParameterSelect,
+            Screen::EfsSelect,
+            Screen::AlarmSelect,
+            Screen::EventRuleSelect,
+            Screen::StateMachineSelect,
+            Screen::LambdaSelect,
         ];
 
         for screen in screens {
@@ -1271,6 +2398,40 @@ mod tests {
             LoadingTask::RefreshAsg,
             LoadingTask::LoadAsg,
             LoadingTask::LoadAsgDetail("asg-a".to_string()),
+            LoadingTask::RefreshApiGateway,
+            LoadingTask::LoadApiGateway,
+            LoadingTask::LoadApiGatewayDetail("a1b2c3d4e5".to_string()),
+            LoadingTask::RefreshKmsKey,
+            LoadingTask::LoadKmsKey,
+            LoadingTask::LoadKmsKeyDetail("1234abcd".to_string()),
+            LoadingTask::RefreshSecret,
+            LoadingTask::LoadSecret,
+            LoadingTask::LoadSecretDetail("arn:secret".to_string()),
+            LoadingTask::RefreshSsmParameter,
+            LoadingTask::LoadSsmParameter,
+            LoadingTask::LoadSsmParameterDetail("/app/prod/db-url".to_string()),
+            LoadingTask::RefreshEfs,
+            LoadingTask::LoadEfs,
+            LoadingTask::LoadEfsDetail("fs-0123".to_string()),
+            LoadingTask::RefreshAlarm,
+            LoadingTask::LoadAlarm,
+            LoadingTask::LoadAlarmDetail("web-cpu-high".to_string()),
+            LoadingTask::RefreshEventRule,
+            LoadingTask::LoadEventRule,
+            LoadingTask::LoadEventRuleDetail(
+                "arn:aws:events:ap-northeast-2:123456789012:rule/nightly-report".to_string(),
+            ),
+            LoadingTask::RefreshStateMachine,
+            LoadingTask::LoadStateMachine,
+            LoadingTask::LoadStateMachineDetail(
+                "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-workflow"
+                    .to_string(),
+            ),
+            LoadingTask::RefreshLambda,
+            LoadingTask::LoadLambda,
+            LoadingTask::LoadLambdaDetail(
+                "arn:aws:lambda:ap-northeast-2:123456789012:function:list-orders".to_string(),
+            ),
         ];
 
         for task in tasks {
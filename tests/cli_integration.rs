@@ -14,6 +14,10 @@ fn cli_help_runs_without_aws_account() {
     assert!(output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("Usage"));
+    assert!(stdout.contains("--offline"));
+    assert!(stdout.contains("--endpoint-url"));
+    assert!(stdout.contains("--record"));
+    assert!(stdout.contains("--replay"));
 }
 
 #[test]
@@ -39,3 +43,52 @@ fn cli_update_help_is_accessible() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("update"));
 }
+
+#[test]
+fn cli_generate_unknown_blueprint_exits_with_failure() {
+    let home = std::env::temp_dir().join(format!("emd-generate-{}", std::process::id()));
+    std::fs::create_dir_all(&home).expect("create temp home");
+
+    let output = Command::new(emd_bin())
+        .args(["generate", "missing", "--offline"])
+        .env("HOME", &home)
+        .output()
+        .expect("run generate");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Blueprint not found"));
+
+    let _ = std::fs::remove_dir_all(home);
+}
+
+#[test]
+fn cli_replay_does_not_prompt_for_mfa_codes() {
+    let home = std::env::temp_dir().join(format!("emd-replay-mfa-{}", std::process::id()));
+    let aws_dir = home.join(".aws");
+    std::fs::create_dir_all(&aws_dir).expect("create temp .aws");
+    std::fs::write(
+        aws_dir.join("config"),
+        "[default]\nregion = us-east-1\n\n[profile admin]\n\
+         role_arn = arn:aws:iam::111122223333:role/Admin\nsource_profile = default\n\
+         mfa_serial = arn:aws:iam::111122223333:mfa/ops\n",
+    )
+    .expect("write config");
+
+    let output = Command::new(emd_bin())
+        .args(["generate", "missing", "--replay"])
+        .arg(home.join("fixtures"))
+        .env("HOME", &home)
+        .env("AWS_PROFILE", "admin")
+        .env_remove("AWS_CONFIG_FILE")
+        .stdin(std::process::Stdio::null())
+        .output()
+        .expect("run generate");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("MFA code for"), "{}", stderr);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("Blueprint not found"));
+
+    let _ = std::fs::remove_dir_all(home);
+}